@@ -169,6 +169,7 @@ pub fn flight_data_to_arrow_batch(
                 &dictionaries_by_field,
                 &mut reader,
                 0,
+                message.version(),
             )
         })?
 }
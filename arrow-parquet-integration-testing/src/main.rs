@@ -5,7 +5,7 @@ use arrow2::error::Result;
 use arrow2::io::parquet::write::RowGroupIterator;
 use arrow2::io::{
     json_integration::ArrowJson,
-    parquet::write::{write_file, CompressionCodec, Version, WriteOptions},
+    parquet::write::{write_file, CompressionCodec, Encoding, Version, WriteOptions},
 };
 use arrow2::{datatypes::Schema, io::json_integration::to_record_batch, record_batch::RecordBatch};
 
@@ -68,6 +68,12 @@ fn main() -> Result<()> {
                 .required(true)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("encoding")
+                .long("encoding")
+                .required(false)
+                .takes_value(true),
+        )
         .get_matches();
     let json_file = matches
         .value_of("json")
@@ -78,6 +84,7 @@ fn main() -> Result<()> {
     let version = matches
         .value_of("version")
         .expect("must provide version of parquet");
+    let encoding = matches.value_of("encoding").unwrap_or("plain");
 
     let (schema, batches) = read_gzip_json("1.0.0-littleendian", json_file);
 
@@ -93,7 +100,15 @@ fn main() -> Result<()> {
         version,
     };
 
-    let row_groups = RowGroupIterator::try_new(batches.into_iter().map(Ok), &schema, options)?;
+    let encoding = match encoding {
+        "plain" => Encoding::Plain,
+        "dictionary" => Encoding::RleDictionary,
+        other => panic!("Encoding \"{}\" not supported", other),
+    };
+    let encodings = vec![encoding; schema.fields().len()];
+
+    let row_groups =
+        RowGroupIterator::try_new(batches.into_iter().map(Ok), &schema, options, encodings)?;
     let parquet_schema = row_groups.parquet_schema().clone();
 
     let mut writer = File::create(write_path)?;
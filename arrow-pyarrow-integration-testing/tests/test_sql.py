@@ -139,3 +139,48 @@ class TestCase(unittest.TestCase):
         b.validate(full=True)
         assert a.to_pylist() == b.to_pylist()
         assert a.type == b.type
+
+    def test_list_list_array(self):
+        """
+        Python -> Rust -> Python
+        """
+        a = pyarrow.array(
+            [[[1, 2], None], None, [[], [3, None, 4]]],
+            pyarrow.list_(pyarrow.list_(pyarrow.int64())),
+        )
+        b = arrow_pyarrow_integration_testing.round_trip(a)
+
+        b.validate(full=True)
+        assert a.to_pylist() == b.to_pylist()
+        assert a.type == b.type
+
+    def test_struct_list_array(self):
+        """
+        Python -> Rust -> Python
+        """
+        fields = [
+            ('f1', pyarrow.list_(pyarrow.int32())),
+            ('f2', pyarrow.string()),
+        ]
+        a = pyarrow.array([
+            {"f1": [1, 2], "f2": "a"},
+            None,
+            {"f1": None, "f2": None},
+            {"f1": [None, 3], "f2": "d"},
+        ], pyarrow.struct(fields))
+        b = arrow_pyarrow_integration_testing.round_trip(a)
+
+        b.validate(full=True)
+        assert a.to_pylist() == b.to_pylist()
+        assert a.type == b.type
+
+    def test_dictionary_array(self):
+        """
+        Python -> Rust -> Python
+        """
+        a = pyarrow.array(["a", None, "b", "a", "c"]).dictionary_encode()
+        b = arrow_pyarrow_integration_testing.round_trip(a)
+
+        b.validate(full=True)
+        assert a.to_pylist() == b.to_pylist()
+        assert a.type == b.type
@@ -25,6 +25,7 @@ fn write(array: &dyn Array) -> Result<()> {
             array,
             parquet_schema.columns()[0].clone(),
             options,
+            Encoding::Plain,
         )),
     ))))));
 
@@ -5,84 +5,62 @@ use std::sync::Arc;
 use std::thread;
 use std::time::SystemTime;
 
-use arrow2::{array::Array, error::Result, io::parquet::read};
+use arrow2::{
+    array::Array,
+    error::Result,
+    io::parquet::read::parallel::{ColumnChunk, ParallelReader, RowGroupChunks},
+    record_batch::RecordBatch,
+};
 
-fn parallel_read(path: &str) -> Result<Vec<Box<dyn Array>>> {
-    // prepare a channel to send serialized records from threads
-    let (tx, rx) = unbounded();
+/// Decodes each column chunk of a row group in its own thread.
+fn decode(columns: Vec<ColumnChunk>) -> Vec<Result<Arc<dyn Array>>> {
+    let children = columns
+        .into_iter()
+        .map(|column| {
+            thread::spawn(move || {
+                let start = SystemTime::now();
+                let name = column.field().name().clone();
+                let array = column.decode();
+                println!("decoded {} - {:?}", name, start.elapsed().unwrap());
+                array
+            })
+        })
+        .collect::<Vec<_>>();
+    children
+        .into_iter()
+        .map(|child| child.join().expect("child thread panicked"))
+        .collect()
+}
 
-    let mut file = File::open(path)?;
-    let file_metadata = read::read_metadata(&mut file)?;
-    let arrow_schema = Arc::new(read::get_schema(&file_metadata)?);
+fn parallel_read(path: &str) -> Result<Vec<RecordBatch>> {
+    // prepare a channel to send the column chunks of each row group from the reader
+    let (tx, rx) = unbounded::<RowGroupChunks>();
 
-    let file_metadata = Arc::new(file_metadata);
+    let file = File::open(path)?;
+    let reader = ParallelReader::try_new(file, None, None, Arc::new(|_, _| true))?;
 
     let start = SystemTime::now();
-    // spawn a thread to produce `Vec<CompressedPage>` (IO bounded)
-    let producer_metadata = file_metadata.clone();
-    let child = thread::spawn(move || {
-        for column in 0..producer_metadata.schema().num_columns() {
-            for row_group in 0..producer_metadata.row_groups.len() {
-                let start = SystemTime::now();
-                println!("produce start: {} {}", column, row_group);
-                let pages = read::get_page_iterator(
-                    &producer_metadata,
-                    row_group,
-                    column,
-                    &mut file,
-                    vec![],
-                )
-                .unwrap()
-                .collect::<Vec<_>>();
-                println!(
-                    "produce end - {:?}: {} {}",
-                    start.elapsed().unwrap(),
-                    column,
-                    row_group
-                );
-                tx.send((column, row_group, pages)).unwrap();
-            }
+    // spawn a thread to read the column chunks of each row group (IO bounded)
+    let producer = thread::spawn(move || {
+        for (row_group, chunks) in reader.enumerate() {
+            let start = SystemTime::now();
+            let chunks = chunks?;
+            println!("read {} - {:?}", row_group, start.elapsed().unwrap());
+            tx.send(chunks).unwrap();
         }
+        Result::Ok(())
     });
 
-    let mut children = Vec::new();
-    // use 3 consumers of to decompress, decode and deserialize.
-    for _ in 0..3 {
-        let rx_consumer = rx.clone();
-        let metadata_consumer = file_metadata.clone();
-        let arrow_schema_consumer = arrow_schema.clone();
-        let child = thread::spawn(move || {
-            let (column, row_group, iter) = rx_consumer.recv().unwrap();
-            let start = SystemTime::now();
-            println!("consumer start - {} {}", column, row_group);
-            let metadata = metadata_consumer.row_groups[row_group].column(column);
-            let data_type = arrow_schema_consumer.fields()[column].data_type().clone();
-
-            let pages = iter
-                .into_iter()
-                .map(|x| x.and_then(|x| read::decompress(x, &mut vec![])));
-            let mut pages = read::streaming_iterator::convert(pages);
-            let array = read::page_iter_to_array(&mut pages, metadata, data_type);
-            println!(
-                "consumer end - {:?}: {} {}",
-                start.elapsed().unwrap(),
-                column,
-                row_group
-            );
-            array
-        });
-        children.push(child);
-    }
-
-    child.join().expect("child thread panicked");
-
-    let arrays = children
+    // decompress, decode and deserialize the row groups, in order (CPU bounded)
+    let batches = rx
         .into_iter()
-        .map(|x| x.join().unwrap())
+        .map(|chunks| chunks.decode(decode))
         .collect::<Result<Vec<_>>>()?;
+
+    producer.join().expect("producer thread panicked")?;
     println!("Finished - {:?}", start.elapsed().unwrap());
 
-    Ok(arrays)
+    Ok(batches)
 }
 
 fn main() -> Result<()> {
@@ -90,9 +68,11 @@ fn main() -> Result<()> {
     let args: Vec<String> = env::args().collect();
     let file_path = &args[1];
 
-    let arrays = parallel_read(file_path)?;
-    for array in arrays {
-        println!("{}", array)
+    let batches = parallel_read(file_path)?;
+    for batch in batches {
+        for array in batch.columns() {
+            println!("{}", array)
+        }
     }
     Ok(())
 }
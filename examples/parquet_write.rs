@@ -7,7 +7,7 @@ use arrow2::{
     datatypes::{Field, Schema},
     error::Result,
     io::parquet::write::{
-        array_to_page, write_file, CompressionCodec, DynIter, Version, WriteOptions,
+        array_to_page, write_file, CompressionCodec, DynIter, Encoding, Version, WriteOptions,
     },
 };
 
@@ -32,7 +32,7 @@ fn write_single_array(path: &str, array: &dyn Array, field: Field) -> Result<()>
     let row_groups = once(Result::Ok(DynIter::new(once(Ok(DynIter::new(
         once(array)
             .zip(parquet_schema.columns().to_vec().into_iter())
-            .map(|(array, descriptor)| array_to_page(array, descriptor, options)),
+            .map(|(array, descriptor)| array_to_page(array, descriptor, options, Encoding::Plain)),
     ))))));
 
     // Create a new empty file
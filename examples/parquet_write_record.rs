@@ -5,7 +5,9 @@ use arrow2::{
     array::{Array, Int32Array},
     datatypes::{Field, Schema},
     error::Result,
-    io::parquet::write::{write_file, CompressionCodec, RowGroupIterator, Version, WriteOptions},
+    io::parquet::write::{
+        write_file, CompressionCodec, Encoding, RowGroupIterator, Version, WriteOptions,
+    },
     record_batch::RecordBatch,
 };
 
@@ -20,7 +22,9 @@ fn write_batch(path: &str, batch: RecordBatch) -> Result<()> {
 
     let iter = vec![Ok(batch)];
 
-    let row_groups = RowGroupIterator::try_new(iter.into_iter(), &schema, options)?;
+    let encodings = vec![Encoding::Plain; schema.fields().len()];
+
+    let row_groups = RowGroupIterator::try_new(iter.into_iter(), &schema, options, encodings)?;
 
     // Create a new empty file
     let mut file = File::create(path)?;
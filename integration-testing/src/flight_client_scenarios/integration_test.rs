@@ -262,6 +262,7 @@ async fn receive_batch_flight_data(
             dictionaries_by_field,
             &mut reader,
             0,
+            message.version(),
         )
         .expect("Error reading dictionary");
 
@@ -296,6 +296,7 @@ async fn record_batch_from_message(
         &dictionaries_by_field,
         &mut reader,
         0,
+        message.version(),
     );
 
     arrow_batch_result
@@ -321,6 +322,7 @@ async fn dictionary_from_message(
         dictionaries_by_field,
         &mut reader,
         0,
+        message.version(),
     );
     dictionary_batch_result
         .map_err(|e| Status::internal(format!("Could not convert to Dictionary: {:?}", e)))
@@ -137,6 +137,61 @@ def case_nested(size):
     )
 
 
+def case_struct(size):
+    string = ["Hello", None, "aa", "", None, "abc", None, None, "def", "aaa"]
+    boolean = [True, None, False, False, None, True, None, None, True, True]
+    struct_fields = [
+        ("f1", pa.utf8()),
+        ("f2", pa.bool_()),
+    ]
+    struct_ = [
+        None if i in (1, 6) else {"f1": string[i], "f2": boolean[i]}
+        for i in range(10)
+    ]
+    list_struct = [
+        [{"f1": "Hello", "f2": True}],
+        None,
+        [],
+        [{"f1": "aa", "f2": False}, None],
+        [{"f1": None, "f2": None}],
+        [{"f1": "abc", "f2": True}, {"f1": "def", "f2": False}],
+        None,
+        [],
+        [{"f1": "aaa", "f2": True}],
+        [None],
+    ]
+    struct_list = [
+        {"f1": [0, 1], "f2": True},
+        None,
+        {"f1": None, "f2": False},
+        {"f1": [], "f2": None},
+        {"f1": [2, None], "f2": True},
+        {"f1": [3], "f2": False},
+        None,
+        {"f1": [4, 5, 6], "f2": True},
+        {"f1": None, "f2": None},
+        {"f1": [7], "f2": True},
+    ]
+    fields = [
+        pa.field("struct", pa.struct(struct_fields)),
+        pa.field("list_struct", pa.list_(pa.struct(struct_fields))),
+        pa.field(
+            "struct_list",
+            pa.struct([("f1", pa.list_(pa.int64())), ("f2", pa.bool_())]),
+        ),
+    ]
+    schema = pa.schema(fields)
+    return (
+        {
+            "struct": struct_ * size,
+            "list_struct": list_struct * size,
+            "struct_list": struct_list * size,
+        },
+        schema,
+        f"struct_nullable_{size*10}.parquet",
+    )
+
+
 def write_pyarrow(case, size=1, page_version=1, use_dictionary=False):
     data, schema, path = case(size)
 
@@ -158,7 +213,7 @@ def write_pyarrow(case, size=1, page_version=1, use_dictionary=False):
     )
 
 
-for case in [case_basic_nullable, case_basic_required, case_nested]:
+for case in [case_basic_nullable, case_basic_required, case_nested, case_struct]:
     for version in [1, 2]:
         for use_dict in [True, False]:
             write_pyarrow(case, 1, version, use_dict)
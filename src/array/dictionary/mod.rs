@@ -3,6 +3,8 @@ use std::sync::Arc;
 use crate::{
     bitmap::Bitmap,
     datatypes::DataType,
+    error::{ArrowError, Result},
+    ffi,
     types::{NativeType, NaturalDataType},
 };
 
@@ -11,7 +13,7 @@ mod mutable;
 pub use iterator::*;
 pub use mutable::*;
 
-use super::{ffi::ToFfi, new_empty_array, primitive::PrimitiveArray, Array};
+use super::{ffi::ToFfi, new_empty_array, primitive::PrimitiveArray, Array, FromFfi};
 
 /// Trait denoting [`NativeType`]s that can be used as keys of a dictionary.
 pub trait DictionaryKey: NativeType + NaturalDataType + num::NumCast + num::FromPrimitive {}
@@ -146,11 +148,37 @@ where
 
 unsafe impl<K: DictionaryKey> ToFfi for DictionaryArray<K> {
     fn buffers(&self) -> Vec<Option<std::ptr::NonNull<u8>>> {
-        vec![self.keys.validity().as_ref().map(|x| x.as_ptr())]
+        self.keys.buffers()
     }
 
     #[inline]
     fn offset(&self) -> usize {
         self.offset
     }
+
+    fn dictionary(&self) -> Option<Arc<dyn Array>> {
+        Some(self.values.clone())
+    }
+}
+
+unsafe impl<K: DictionaryKey, A: ffi::ArrowArrayRef> FromFfi<A> for DictionaryArray<K> {
+    fn try_from_ffi(array: A) -> Result<Self> {
+        let length = array.array().len();
+        let offset = array.array().offset();
+        let mut validity = unsafe { array.validity() }?;
+        let mut values = unsafe { array.buffer::<K>(0) }?;
+
+        if offset > 0 {
+            values = values.slice(offset, length);
+            validity = validity.map(|x| x.slice(offset, length))
+        }
+        let keys = PrimitiveArray::<K>::from_data(K::DATA_TYPE, values, validity);
+
+        let dictionary = array.dictionary()?.ok_or_else(|| {
+            ArrowError::Ffi("A dictionary-encoded array must have a dictionary".to_string())
+        })?;
+        let values = ffi::try_from(dictionary)?.into();
+
+        Ok(Self::from_data(keys, values))
+    }
 }
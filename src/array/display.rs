@@ -3,6 +3,7 @@ use crate::{
     datatypes::{DataType, IntervalUnit, TimeUnit},
     error::{ArrowError, Result},
     temporal_conversions,
+    util::decimal_to_string,
 };
 
 macro_rules! dyn_display {
@@ -50,7 +51,7 @@ pub fn get_value_display<'a>(array: &'a dyn Array) -> Result<Box<dyn Fn(usize) -
         UInt16 => dyn_primitive!(array, u16, |x| x),
         UInt32 => dyn_primitive!(array, u32, |x| x),
         UInt64 => dyn_primitive!(array, u64, |x| x),
-        Float16 => unreachable!(),
+        Float16 => dyn_primitive!(array, f16, |x| x),
         Float32 => dyn_primitive!(array, f32, |x| x),
         Float64 => dyn_primitive!(array, f64, |x| x),
         Date32 => dyn_primitive!(array, i32, temporal_conversions::date32_to_date),
@@ -113,13 +114,8 @@ pub fn get_value_display<'a>(array: &'a dyn Array) -> Result<Box<dyn Fn(usize) -
         LargeUtf8 => dyn_display!(array, Utf8Array<i64>, |x| x),
         Decimal(_, scale) => {
             // The number 999.99 has a precision of 5 and scale of 2
-            let scale = *scale as u32;
-            let display = move |x| {
-                let base = x / 10i128.pow(scale);
-                let decimals = x - base * 10i128.pow(scale);
-                format!("{}.{}", base, decimals)
-            };
-            dyn_primitive!(array, i128, display)
+            let scale = *scale;
+            dyn_primitive!(array, i128, |x| decimal_to_string(x, scale))
         }
         List(_) => {
             let f = |x: Box<dyn Array>| {
@@ -145,6 +141,14 @@ pub fn get_value_display<'a>(array: &'a dyn Array) -> Result<Box<dyn Fn(usize) -
             };
             dyn_display!(array, ListArray<i64>, f)
         }
+        Map(_, _) => {
+            let f = |x: Box<dyn Array>| {
+                let display = get_value_display(x.as_ref()).unwrap();
+                let string_values = (0..x.len()).map(|i| display(i)).collect::<Vec<String>>();
+                format!("[{}]", string_values.join(", "))
+            };
+            dyn_display!(array, MapArray, f)
+        }
         DataType::Dictionary(key_type, _) => match key_type.as_ref() {
             DataType::Int8 => dyn_dict!(array, i8),
             DataType::Int16 => dyn_dict!(array, i16),
@@ -1,13 +1,12 @@
-use std::unimplemented;
-
 use crate::{
     datatypes::{DataType, IntervalUnit},
-    types::{days_ms, NativeType},
+    types::{days_ms, f16, NativeType},
 };
 
 use super::{
     primitive::PrimitiveArray, Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey,
-    FixedSizeBinaryArray, FixedSizeListArray, ListArray, NullArray, Offset, StructArray, Utf8Array,
+    FixedSizeBinaryArray, FixedSizeListArray, ListArray, MapArray, NullArray, Offset, StructArray,
+    UnionArray, Utf8Array,
 };
 
 mod binary;
@@ -16,9 +15,11 @@ mod dictionary;
 mod fixed_size_binary;
 mod fixed_size_list;
 mod list;
+mod map;
 mod null;
 mod primitive;
 mod struct_;
+mod union;
 mod utf8;
 
 impl PartialEq for dyn Array {
@@ -135,6 +136,30 @@ impl PartialEq<&dyn Array> for StructArray {
     }
 }
 
+impl PartialEq<MapArray> for MapArray {
+    fn eq(&self, other: &Self) -> bool {
+        equal(self, other)
+    }
+}
+
+impl PartialEq<&dyn Array> for MapArray {
+    fn eq(&self, other: &&dyn Array) -> bool {
+        equal(self, *other)
+    }
+}
+
+impl PartialEq<UnionArray> for UnionArray {
+    fn eq(&self, other: &Self) -> bool {
+        equal(self, other)
+    }
+}
+
+impl PartialEq<&dyn Array> for UnionArray {
+    fn eq(&self, other: &&dyn Array) -> bool {
+        equal(self, *other)
+    }
+}
+
 impl<K: DictionaryKey> PartialEq<DictionaryArray<K>> for DictionaryArray<K> {
     fn eq(&self, other: &Self) -> bool {
         equal(self, other)
@@ -224,7 +249,11 @@ pub fn equal(lhs: &dyn Array, rhs: &dyn Array) -> bool {
             let rhs = rhs.as_any().downcast_ref().unwrap();
             primitive::equal::<days_ms>(lhs, rhs)
         }
-        DataType::Float16 => unreachable!(),
+        DataType::Float16 => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            primitive::equal::<f16>(lhs, rhs)
+        }
         DataType::Float32 => {
             let lhs = lhs.as_any().downcast_ref().unwrap();
             let rhs = rhs.as_any().downcast_ref().unwrap();
@@ -323,7 +352,16 @@ pub fn equal(lhs: &dyn Array, rhs: &dyn Array) -> bool {
             let rhs = rhs.as_any().downcast_ref().unwrap();
             fixed_size_list::equal(lhs, rhs)
         }
-        DataType::Union(_) => unimplemented!(),
+        DataType::Union(_, _, _) => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            union::equal(lhs, rhs)
+        }
+        DataType::Map(_, _) => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            map::equal(lhs, rhs)
+        }
     }
 }
 
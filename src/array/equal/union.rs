@@ -0,0 +1,88 @@
+use crate::array::{Array, UnionArray};
+
+pub(super) fn equal(lhs: &UnionArray, rhs: &UnionArray) -> bool {
+    lhs.data_type() == rhs.data_type()
+        && lhs.len() == rhs.len()
+        && lhs.types().as_slice() == rhs.types().as_slice()
+        && lhs.iter().eq(rhs.iter())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::array::equal::tests::test_equal;
+    use crate::array::{Int32Array, Utf8Array};
+    use crate::buffer::Buffer;
+    use crate::datatypes::{DataType, Field, UnionMode};
+
+    use super::*;
+
+    fn data_type(mode: UnionMode) -> DataType {
+        let fields = vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ];
+        DataType::Union(fields, None, mode)
+    }
+
+    #[test]
+    fn test_sparse_equal() {
+        let data_type = data_type(UnionMode::Sparse);
+        let fields = vec![
+            Arc::new(Int32Array::from(&[Some(1), None, Some(2)])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("b"), Some("c")])) as Arc<dyn Array>,
+        ];
+        let a = UnionArray::from_data(data_type.clone(), Buffer::from(&[0, 0, 1]), fields, None);
+
+        // values in slots that are not selected by the types do not matter
+        let fields = vec![
+            Arc::new(Int32Array::from(&[Some(1), None, None])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[None, None, Some("c")])) as Arc<dyn Array>,
+        ];
+        let b = UnionArray::from_data(data_type.clone(), Buffer::from(&[0, 0, 1]), fields, None);
+        test_equal(&a, &b, true);
+
+        let fields = vec![
+            Arc::new(Int32Array::from(&[Some(1), None, None])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[None, None, Some("d")])) as Arc<dyn Array>,
+        ];
+        let b = UnionArray::from_data(data_type, Buffer::from(&[0, 0, 1]), fields, None);
+        test_equal(&a, &b, false);
+    }
+
+    #[test]
+    fn test_dense_equal() {
+        let data_type = data_type(UnionMode::Dense);
+        let fields = vec![
+            Arc::new(Int32Array::from(&[Some(1), None])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[Some("c")])) as Arc<dyn Array>,
+        ];
+        let a = UnionArray::from_data(
+            data_type.clone(),
+            Buffer::from(&[0, 1, 0]),
+            fields,
+            Some(Buffer::from(&[0, 0, 1])),
+        );
+
+        let fields = vec![
+            Arc::new(Int32Array::from(&[None, Some(1)])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[Some("c")])) as Arc<dyn Array>,
+        ];
+        let b = UnionArray::from_data(
+            data_type.clone(),
+            Buffer::from(&[0, 1, 0]),
+            fields.clone(),
+            Some(Buffer::from(&[1, 0, 0])),
+        );
+        test_equal(&a, &b, true);
+
+        let b = UnionArray::from_data(
+            data_type,
+            Buffer::from(&[0, 1, 0]),
+            fields,
+            Some(Buffer::from(&[0, 0, 1])),
+        );
+        test_equal(&a, &b, false);
+    }
+}
@@ -18,6 +18,11 @@ pub unsafe trait ToFfi {
     fn children(&self) -> Vec<Arc<dyn Array>> {
         vec![]
     }
+
+    /// The values of a dictionary-encoded array
+    fn dictionary(&self) -> Option<Arc<dyn Array>> {
+        None
+    }
 }
 
 /// Trait describing how a struct imports into itself from the
@@ -29,12 +34,18 @@ pub unsafe trait FromFfi<T: ffi::ArrowArrayRef>: Sized {
 macro_rules! ffi_dyn {
     ($array:expr, $ty:ty) => {{
         let array = $array.as_any().downcast_ref::<$ty>().unwrap();
-        (array.buffers(), array.children())
+        (array.buffers(), array.children(), array.dictionary())
     }};
 }
 
-type BuffersChildren = (Vec<Option<std::ptr::NonNull<u8>>>, Vec<Arc<dyn Array>>);
+type BuffersChildren = (
+    Vec<Option<std::ptr::NonNull<u8>>>,
+    Vec<Arc<dyn Array>>,
+    Option<Arc<dyn Array>>,
+);
 
+/// Returns the buffers, the children and the dictionary of `array`, as exported to the C data
+/// interface.
 pub fn buffers_children(array: &dyn Array) -> BuffersChildren {
     match array.data_type() {
         DataType::Null => ffi_dyn!(array, NullArray),
@@ -58,7 +69,7 @@ pub fn buffers_children(array: &dyn Array) -> BuffersChildren {
         DataType::UInt16 => ffi_dyn!(array, PrimitiveArray<u16>),
         DataType::UInt32 => ffi_dyn!(array, PrimitiveArray<u32>),
         DataType::UInt64 => ffi_dyn!(array, PrimitiveArray<u64>),
-        DataType::Float16 => unreachable!(),
+        DataType::Float16 => ffi_dyn!(array, PrimitiveArray<f16>),
         DataType::Float32 => ffi_dyn!(array, PrimitiveArray<f32>),
         DataType::Float64 => ffi_dyn!(array, PrimitiveArray<f64>),
         DataType::Binary => ffi_dyn!(array, BinaryArray<i32>),
@@ -70,7 +81,8 @@ pub fn buffers_children(array: &dyn Array) -> BuffersChildren {
         DataType::LargeList(_) => ffi_dyn!(array, ListArray::<i64>),
         DataType::FixedSizeList(_, _) => ffi_dyn!(array, FixedSizeListArray),
         DataType::Struct(_) => ffi_dyn!(array, StructArray),
-        DataType::Union(_) => unimplemented!(),
+        DataType::Union(_, _, _) => ffi_dyn!(array, UnionArray),
+        DataType::Map(_, _) => ffi_dyn!(array, MapArray),
         DataType::Dictionary(key_type, _) => match key_type.as_ref() {
             DataType::Int8 => ffi_dyn!(array, DictionaryArray::<i8>),
             DataType::Int16 => ffi_dyn!(array, DictionaryArray::<i16>),
@@ -0,0 +1,213 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{Array, MapArray},
+    bitmap::MutableBitmap,
+    buffer::MutableBuffer,
+};
+
+use super::{
+    make_growable,
+    utils::{build_extend_null_bits, extend_offsets, ExtendNullBits},
+    Growable,
+};
+
+fn extend_offset_values(growable: &mut GrowableMap<'_>, index: usize, start: usize, len: usize) {
+    let array = growable.arrays[index];
+    let offsets = array.offsets();
+
+    if array.null_count() == 0 {
+        // offsets
+        extend_offsets::<i32>(
+            &mut growable.offsets,
+            &mut growable.last_offset,
+            &offsets[start..start + len + 1],
+        );
+
+        let end = offsets[start + len] as usize;
+        let start = offsets[start] as usize;
+        let len = end - start;
+        growable.values.extend(index, start, len)
+    } else {
+        growable.offsets.reserve(len);
+
+        let new_offsets = &mut growable.offsets;
+        let inner_values = &mut growable.values;
+        let last_offset = &mut growable.last_offset;
+        (start..start + len).for_each(|i| {
+            if array.is_valid(i) {
+                let len = offsets[i + 1] - offsets[i];
+                // compute the new offset
+                *last_offset += len;
+
+                // append value
+                inner_values.extend(index, offsets[i] as usize, len as usize);
+            }
+            // append offset
+            new_offsets.push(*last_offset);
+        })
+    }
+}
+
+/// Concrete [`Growable`] for the [`MapArray`].
+pub struct GrowableMap<'a> {
+    arrays: Vec<&'a MapArray>,
+    validity: MutableBitmap,
+    values: Box<dyn Growable<'a> + 'a>,
+    offsets: MutableBuffer<i32>,
+    last_offset: i32, // always equal to the last offset at `offsets`.
+    // function used to extend nulls from arrays. This function's lifetime is bound to the array
+    // because it reads nulls from it.
+    extend_null_bits: Vec<ExtendNullBits<'a>>,
+}
+
+impl<'a> GrowableMap<'a> {
+    /// # Panics
+    /// This function panics if any of the `arrays` is not downcastable to `MapArray`.
+    pub fn new(arrays: &[&'a dyn Array], mut use_validity: bool, capacity: usize) -> Self {
+        // if any of the arrays has nulls, insertions from any array requires setting bits
+        // as there is at least one array with nulls.
+        if arrays.iter().any(|array| array.null_count() > 0) {
+            use_validity = true;
+        };
+
+        let extend_null_bits = arrays
+            .iter()
+            .map(|array| build_extend_null_bits(*array, use_validity))
+            .collect();
+
+        let arrays = arrays
+            .iter()
+            .map(|array| array.as_any().downcast_ref::<MapArray>().unwrap())
+            .collect::<Vec<_>>();
+
+        let inner = arrays
+            .iter()
+            .map(|array| array.field().as_ref())
+            .collect::<Vec<_>>();
+        let values = make_growable(&inner, use_validity, 0);
+
+        let mut offsets = MutableBuffer::with_capacity(capacity + 1);
+        unsafe { offsets.push_unchecked(0) };
+
+        Self {
+            arrays,
+            offsets,
+            values,
+            validity: MutableBitmap::with_capacity(capacity),
+            last_offset: 0,
+            extend_null_bits,
+        }
+    }
+
+    fn to(&mut self) -> MapArray {
+        let validity = std::mem::take(&mut self.validity);
+        let offsets = std::mem::take(&mut self.offsets);
+        let values = self.values.as_arc();
+
+        MapArray::from_data(
+            self.arrays[0].data_type().clone(),
+            offsets.into(),
+            values,
+            validity.into(),
+        )
+    }
+}
+
+impl<'a> Growable<'a> for GrowableMap<'a> {
+    fn extend(&mut self, index: usize, start: usize, len: usize) {
+        (self.extend_null_bits[index])(&mut self.validity, start, len);
+        extend_offset_values(self, index, start, len);
+    }
+
+    fn extend_validity(&mut self, additional: usize) {
+        self.offsets.extend_constant(additional, self.last_offset);
+        self.validity.extend_constant(additional, false);
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        Arc::new(self.to())
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(self.to())
+    }
+}
+
+impl<'a> From<GrowableMap<'a>> for MapArray {
+    fn from(val: GrowableMap<'a>) -> Self {
+        let mut values = val.values;
+        let values = values.as_arc();
+
+        MapArray::from_data(
+            val.arrays[0].data_type().clone(),
+            val.offsets.into(),
+            values,
+            val.validity.into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::array::{Int32Array, StructArray, Utf8Array};
+    use crate::bitmap::Bitmap;
+    use crate::buffer::Buffer;
+    use crate::datatypes::{DataType, Field};
+
+    fn create_map_array(offsets: &[i32], keys: &[&str], validity: Option<Bitmap>) -> MapArray {
+        let fields = vec![
+            Field::new("key", DataType::Utf8, false),
+            Field::new("value", DataType::Int32, true),
+        ];
+        let data_type = DataType::Map(
+            Box::new(Field::new(
+                "entries",
+                DataType::Struct(fields.clone()),
+                false,
+            )),
+            false,
+        );
+        let values = (0..keys.len() as i32).map(Some).collect::<Int32Array>();
+        let field = StructArray::from_data(
+            fields,
+            vec![
+                Arc::new(Utf8Array::<i32>::from_slice(keys)),
+                Arc::new(values),
+            ],
+            None,
+        );
+        MapArray::from_data(data_type, Buffer::from(offsets), Arc::new(field), validity)
+    }
+
+    #[test]
+    fn basic() {
+        let array = create_map_array(&[0, 2, 2, 3], &["a", "b", "c"], None);
+
+        let mut a = GrowableMap::new(&[&array], false, 0);
+        a.extend(0, 1, 2);
+
+        let result: MapArray = a.into();
+
+        let expected = create_map_array(&[0, 2, 2, 3], &["a", "b", "c"], None).slice(1, 2);
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn with_nulls() {
+        let validity = Bitmap::from([true, false, true]);
+        let array = create_map_array(&[0, 2, 2, 3], &["a", "b", "c"], Some(validity));
+
+        let mut a = GrowableMap::new(&[&array], false, 0);
+        a.extend(0, 0, 3);
+        a.extend_validity(1);
+
+        let result: MapArray = a.into();
+
+        assert_eq!(result.len(), 4);
+        assert_eq!(result.null_count(), 2);
+        assert_eq!(result.value(2).as_ref(), array.value(2).as_ref());
+    }
+}
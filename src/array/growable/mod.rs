@@ -16,8 +16,12 @@ mod primitive;
 pub use primitive::GrowablePrimitive;
 mod list;
 pub use list::GrowableList;
+mod map;
+pub use map::GrowableMap;
 mod structure;
 pub use structure::GrowableStruct;
+mod union;
+pub use union::GrowableUnion;
 mod utf8;
 pub use utf8::GrowableUtf8;
 mod dictionary;
@@ -131,7 +135,7 @@ pub fn make_growable<'a>(
         DataType::UInt16 => dyn_growable!(u16, arrays, use_validity, capacity),
         DataType::UInt32 => dyn_growable!(u32, arrays, use_validity, capacity),
         DataType::UInt64 => dyn_growable!(u64, arrays, use_validity, capacity),
-        DataType::Float16 => unreachable!(),
+        DataType::Float16 => dyn_growable!(f16, arrays, use_validity, capacity),
         DataType::Float32 => dyn_growable!(f32, arrays, use_validity, capacity),
         DataType::Float64 => dyn_growable!(f64, arrays, use_validity, capacity),
         DataType::Utf8 => {
@@ -188,7 +192,8 @@ pub fn make_growable<'a>(
             capacity,
         )),
         DataType::FixedSizeList(_, _) => todo!(),
-        DataType::Union(_) => todo!(),
+        DataType::Union(_, _, _) => Box::new(union::GrowableUnion::new(arrays, capacity)),
+        DataType::Map(_, _) => Box::new(map::GrowableMap::new(arrays, use_validity, capacity)),
         DataType::Dictionary(key, _) => match key.as_ref() {
             DataType::UInt8 => dyn_dict_growable!(u8, arrays, use_validity, capacity),
             DataType::UInt16 => dyn_dict_growable!(u16, arrays, use_validity, capacity),
@@ -0,0 +1,233 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{Array, UnionArray},
+    buffer::MutableBuffer,
+};
+
+use super::{make_growable, Growable};
+
+/// Concrete [`Growable`] for the [`UnionArray`].
+pub struct GrowableUnion<'a> {
+    arrays: Vec<&'a UnionArray>,
+    types: MutableBuffer<i8>,
+    offsets: Option<MutableBuffer<i32>>,
+    fields: Vec<Box<dyn Growable<'a> + 'a>>,
+    // the current length of each field, used to compute the offsets of dense unions
+    lengths: Vec<i32>,
+}
+
+impl<'a> GrowableUnion<'a> {
+    /// Creates a new [`GrowableUnion`] bound to `arrays` with a pre-allocated `capacity`.
+    /// # Panics
+    /// If `arrays` is empty or any of them is not an [`UnionArray`].
+    pub fn new(arrays: &[&'a dyn Array], capacity: usize) -> Self {
+        let arrays = arrays
+            .iter()
+            .map(|array| array.as_any().downcast_ref::<UnionArray>().unwrap())
+            .collect::<Vec<_>>();
+
+        let first = arrays[0];
+        let has_offsets = first.offsets().is_some();
+
+        // unions do not have a validity; the children must track their own nulls.
+        let fields = (0..first.fields().len())
+            .map(|i| {
+                make_growable(
+                    &arrays
+                        .iter()
+                        .map(|x| x.fields()[i].as_ref())
+                        .collect::<Vec<_>>(),
+                    true,
+                    capacity,
+                )
+            })
+            .collect::<Vec<Box<dyn Growable>>>();
+
+        Self {
+            lengths: vec![0; fields.len()],
+            arrays,
+            fields,
+            offsets: if has_offsets {
+                Some(MutableBuffer::with_capacity(capacity))
+            } else {
+                None
+            },
+            types: MutableBuffer::with_capacity(capacity),
+        }
+    }
+
+    fn to(&mut self) -> UnionArray {
+        let types = std::mem::take(&mut self.types);
+        let fields = std::mem::take(&mut self.fields);
+        let offsets = std::mem::take(&mut self.offsets);
+        let fields = fields.into_iter().map(|mut x| x.as_arc()).collect();
+
+        UnionArray::from_data(
+            self.arrays[0].data_type().clone(),
+            types.into(),
+            fields,
+            offsets.map(|x| x.into()),
+        )
+    }
+}
+
+impl<'a> Growable<'a> for GrowableUnion<'a> {
+    fn extend(&mut self, index: usize, start: usize, len: usize) {
+        let array = self.arrays[index];
+
+        let types = &array.types()[start..start + len];
+        self.types.extend_from_slice(types);
+        if let Some(x) = self.offsets.as_mut() {
+            let offsets = &array.offsets().as_ref().unwrap()[start..start + len];
+
+            // in a dense union, each slot may come from a different child and its offset
+            // must be re-computed with respect to the new children.
+            for (&type_, &offset) in types.iter().zip(offsets.iter()) {
+                let field_index = array.field_index(type_).unwrap();
+                self.fields[field_index].extend(index, offset as usize, 1);
+                x.push(self.lengths[field_index]);
+                self.lengths[field_index] += 1;
+            }
+        } else {
+            self.fields
+                .iter_mut()
+                .for_each(|field| field.extend(index, start, len))
+        }
+    }
+
+    fn extend_validity(&mut self, additional: usize) {
+        // nulls are represented as null values of the first field
+        let (_, ids, _) = UnionArray::get_all(self.arrays[0].data_type());
+        let type_ = ids.map(|ids| ids[0] as i8).unwrap_or(0);
+        let field_index = 0;
+        self.types.extend_constant(additional, type_);
+        if let Some(x) = self.offsets.as_mut() {
+            let length = self.lengths[field_index];
+            x.extend(length..length + additional as i32);
+            self.lengths[field_index] += additional as i32;
+            self.fields[field_index].extend_validity(additional);
+        } else {
+            self.fields
+                .iter_mut()
+                .for_each(|field| field.extend_validity(additional))
+        }
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        Arc::new(self.to())
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(self.to())
+    }
+}
+
+impl<'a> From<GrowableUnion<'a>> for UnionArray {
+    fn from(mut val: GrowableUnion<'a>) -> Self {
+        val.to()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{Int32Array, Utf8Array};
+    use crate::buffer::Buffer;
+    use crate::datatypes::{DataType, Field, UnionMode};
+
+    use super::*;
+
+    fn data_type(mode: UnionMode) -> DataType {
+        let fields = vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ];
+        DataType::Union(fields, None, mode)
+    }
+
+    #[test]
+    fn sparse() {
+        let fields = vec![
+            Arc::new(Int32Array::from(&[Some(1), None, Some(2)])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("b"), Some("c")])) as Arc<dyn Array>,
+        ];
+        let array = UnionArray::from_data(
+            data_type(UnionMode::Sparse),
+            Buffer::from(&[0, 0, 1]),
+            fields,
+            None,
+        );
+
+        for length in 1..3 {
+            for index in 0..(array.len() - length + 1) {
+                let mut a = GrowableUnion::new(&[&array], 10);
+
+                a.extend(0, index, length);
+                let expected = array.slice(index, length);
+
+                let result: UnionArray = a.into();
+
+                assert_eq!(result, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn dense() {
+        let fields = vec![
+            Arc::new(Int32Array::from(&[Some(1), None, Some(2)])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("b"), Some("c")])) as Arc<dyn Array>,
+        ];
+        let array = UnionArray::from_data(
+            data_type(UnionMode::Dense),
+            Buffer::from(&[0, 0, 1]),
+            fields,
+            Some(Buffer::from(&[0, 1, 0])),
+        );
+
+        for length in 1..3 {
+            for index in 0..(array.len() - length + 1) {
+                let mut a = GrowableUnion::new(&[&array], 10);
+
+                a.extend(0, index, length);
+                let expected = array.slice(index, length);
+
+                let result: UnionArray = a.into();
+
+                assert_eq!(result, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn many_with_nulls() {
+        let fields = vec![
+            Arc::new(Int32Array::from(&[Some(1), Some(2)])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[Some("a")])) as Arc<dyn Array>,
+        ];
+        let array = UnionArray::from_data(
+            data_type(UnionMode::Dense),
+            Buffer::from(&[1, 0, 0]),
+            fields,
+            Some(Buffer::from(&[0, 1, 0])),
+        );
+
+        let mut a = GrowableUnion::new(&[&array, &array], 10);
+        a.extend(0, 0, 2);
+        a.extend_validity(1);
+        a.extend(1, 2, 1);
+        let result: UnionArray = a.into();
+
+        let fields = vec![
+            Arc::new(Int32Array::from(&[Some(2), None, Some(1)])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[Some("a")])) as Arc<dyn Array>,
+        ];
+        let expected = UnionArray::from_data(
+            data_type(UnionMode::Dense),
+            Buffer::from(&[1, 0, 0, 0]),
+            fields,
+            Some(Buffer::from(&[0, 0, 1, 2])),
+        );
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,34 @@
+use crate::array::{list::ListValuesIter, Array, IterableListArray};
+use crate::bitmap::utils::{zip_validity, ZipValidity};
+
+use super::MapArray;
+
+impl IterableListArray for MapArray {
+    fn value(&self, i: usize) -> Box<dyn Array> {
+        MapArray::value(self, i)
+    }
+}
+
+type ValuesIter<'a> = ListValuesIter<'a, MapArray>;
+type ZipIter<'a> = ZipValidity<'a, Box<dyn Array>, ValuesIter<'a>>;
+
+impl<'a> IntoIterator for &'a MapArray {
+    type Item = Option<Box<dyn Array>>;
+    type IntoIter = ZipIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> MapArray {
+    /// Returns an iterator of `Option<Box<dyn Array>>`
+    pub fn iter(&'a self) -> ZipIter<'a> {
+        zip_validity(ListValuesIter::new(self), &self.validity)
+    }
+
+    /// Returns an iterator of `Box<dyn Array>`
+    pub fn values_iter(&'a self) -> ValuesIter<'a> {
+        ListValuesIter::new(self)
+    }
+}
@@ -0,0 +1,262 @@
+use std::sync::Arc;
+
+use crate::{
+    bitmap::Bitmap,
+    buffer::Buffer,
+    datatypes::{DataType, Field},
+};
+
+use super::{display_fmt, new_empty_array, specification::check_offsets, Array};
+
+mod ffi;
+mod iterator;
+
+/// An array representing a (key, value), both of arbitrary logical types.
+/// It is represented as a list of `entries`, a [`StructArray`](super::StructArray)
+/// with two fields, the keys and the values.
+#[derive(Debug, Clone)]
+pub struct MapArray {
+    data_type: DataType,
+    // invariant: field.len() == offsets.len() - 1
+    offsets: Buffer<i32>,
+    field: Arc<dyn Array>,
+    // invariant: offsets.len() - 1 == Bitmap::len()
+    validity: Option<Bitmap>,
+    offset: usize,
+}
+
+impl MapArray {
+    pub fn new_empty(data_type: DataType) -> Self {
+        let field = new_empty_array(Self::get_field(&data_type).data_type().clone()).into();
+        Self::from_data(data_type, Buffer::from(&[0i32]), field, None)
+    }
+
+    #[inline]
+    pub fn new_null(data_type: DataType, length: usize) -> Self {
+        let field = new_empty_array(Self::get_field(&data_type).data_type().clone()).into();
+        Self::from_data(
+            data_type,
+            Buffer::new_zeroed(length + 1),
+            field,
+            Some(Bitmap::new_zeroed(length)),
+        )
+    }
+
+    /// Creates a new [`MapArray`].
+    /// # Panics
+    /// This function panics iff:
+    /// * `data_type` is not [`DataType::Map`]
+    /// * the data type of `field` is not the inner type of `data_type`
+    /// * the inner type of `data_type` is not a [`DataType::Struct`] with two fields
+    /// * the `offsets` are not monotonically increasing or exceed the length of `field`
+    pub fn from_data(
+        data_type: DataType,
+        offsets: Buffer<i32>,
+        field: Arc<dyn Array>,
+        validity: Option<Bitmap>,
+    ) -> Self {
+        check_offsets(&offsets, field.len());
+
+        let inner_field = Self::get_field(&data_type);
+        if let DataType::Struct(inner) = inner_field.data_type() {
+            assert_eq!(
+                inner.len(),
+                2,
+                "MapArray's inner `Struct` must have 2 fields (keys and maps)"
+            );
+        } else {
+            panic!("MapArray expects `DataType::Struct` as its inner logical type");
+        }
+        assert_eq!(
+            inner_field.data_type(),
+            field.data_type(),
+            "The child's datatype must match the inner type of the \'data_type\'"
+        );
+        if let Some(ref validity) = validity {
+            assert_eq!(offsets.len() - 1, validity.len());
+        }
+
+        Self {
+            data_type,
+            offsets,
+            field,
+            validity,
+            offset: 0,
+        }
+    }
+
+    /// Returns a slice of this [`MapArray`].
+    /// # Implementation
+    /// This operation is `O(1)`.
+    /// # Panic
+    /// This function panics iff `offset + length >= self.len()`.
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        let validity = self.validity.clone().map(|x| x.slice(offset, length));
+        let offsets = self.offsets.clone().slice(offset, length + 1);
+        Self {
+            data_type: self.data_type.clone(),
+            offsets,
+            field: self.field.clone(),
+            validity,
+            offset: self.offset + offset,
+        }
+    }
+
+    /// Returns the field of a [`DataType::Map`], the `entries` of the map.
+    /// # Panic
+    /// Panics iff `data_type` is not [`DataType::Map`].
+    pub fn get_field(data_type: &DataType) -> &Field {
+        if let DataType::Map(field, _) = data_type {
+            field.as_ref()
+        } else {
+            panic!("MapArray expects `DataType::Map` logical type")
+        }
+    }
+}
+
+// Accessors
+impl MapArray {
+    /// The offsets of this [`MapArray`] into its entries.
+    #[inline]
+    pub fn offsets(&self) -> &Buffer<i32> {
+        &self.offsets
+    }
+
+    /// The entries of this [`MapArray`], a [`StructArray`](super::StructArray) of keys and values.
+    #[inline]
+    pub fn field(&self) -> &Arc<dyn Array> {
+        &self.field
+    }
+
+    /// Returns the entries of slot `i`.
+    /// # Panic
+    /// Panics iff `i >= self.len()`.
+    #[inline]
+    pub fn value(&self, i: usize) -> Box<dyn Array> {
+        let offset = self.offsets[i];
+        let offset_1 = self.offsets[i + 1];
+        let length = (offset_1 - offset) as usize;
+
+        self.field.slice(offset as usize, length)
+    }
+
+    /// Returns the entries of slot `i`.
+    /// # Safety
+    /// Assumes that the `i < self.len`.
+    #[inline]
+    pub unsafe fn value_unchecked(&self, i: usize) -> Box<dyn Array> {
+        let offset = *self.offsets.as_ptr().add(i);
+        let offset_1 = *self.offsets.as_ptr().add(i + 1);
+        let length = (offset_1 - offset) as usize;
+
+        self.field.slice(offset as usize, length)
+    }
+}
+
+impl Array for MapArray {
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    #[inline]
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    #[inline]
+    fn validity(&self) -> &Option<Bitmap> {
+        &self.validity
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> Box<dyn Array> {
+        Box::new(self.slice(offset, length))
+    }
+}
+
+impl std::fmt::Display for MapArray {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        display_fmt(self.iter(), "MapArray", f, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::*;
+
+    fn array() -> MapArray {
+        let fields = vec![
+            Field::new("key", DataType::Utf8, false),
+            Field::new("value", DataType::Int32, true),
+        ];
+        let data_type = DataType::Map(
+            Box::new(Field::new(
+                "entries",
+                DataType::Struct(fields.clone()),
+                false,
+            )),
+            false,
+        );
+        let field = StructArray::from_data(
+            fields,
+            vec![
+                Arc::new(Utf8Array::<i32>::from_slice(&["a", "b", "c"])),
+                Arc::new(Int32Array::from(&[Some(1), None, Some(3)])),
+            ],
+            None,
+        );
+        MapArray::from_data(
+            data_type,
+            Buffer::from(&[0, 2, 2, 3]),
+            Arc::new(field),
+            Some(Bitmap::from([true, false, true])),
+        )
+    }
+
+    #[test]
+    fn value() {
+        let array = array();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.null_count(), 1);
+        assert_eq!(array.value(0).len(), 2);
+        assert_eq!(array.value(1).len(), 0);
+        assert_eq!(array.value(2).len(), 1);
+    }
+
+    #[test]
+    fn slice() {
+        let array = array().slice(1, 2);
+        assert_eq!(array.len(), 2);
+        assert!(array.is_null(0));
+        assert_eq!(array.value(1).as_ref(), array.field().slice(2, 1).as_ref());
+    }
+
+    #[test]
+    fn iter() {
+        let array = array();
+        let lengths = array.iter().map(|x| x.map(|x| x.len())).collect::<Vec<_>>();
+        assert_eq!(lengths, vec![Some(2), None, Some(1)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "MapArray's inner `Struct` must have 2 fields (keys and maps)")]
+    fn wrong_inner_type() {
+        let fields = vec![Field::new("key", DataType::Utf8, false)];
+        let data_type = DataType::Map(
+            Box::new(Field::new(
+                "entries",
+                DataType::Struct(fields.clone()),
+                false,
+            )),
+            false,
+        );
+        let field = StructArray::new_empty(&fields);
+        MapArray::from_data(data_type, Buffer::from(&[0]), Arc::new(field), None);
+    }
+}
@@ -8,6 +8,8 @@
 //! * [`BinaryArray`], an array of binary values
 //! * [`ListArray`], an array of arrays (e.g. `[[1, 2], None, [], [None]]`)
 //! * [`StructArray`], an array of arrays identified by a string (e.g. `{"a": [1, 2], "b": [true, false]}`)
+//! * [`UnionArray`], an array whose slots can be of different types
+//! * [`MapArray`], an array of key-value pairs (e.g. `[{"a": 1, "b": 2}, None, {}]`)
 //!
 //! This module contains constructors and accessors to operate on the arrays.
 //! All the arrays implement the trait [`Array`] and are often trait objects.
@@ -21,7 +23,7 @@ use std::any::Any;
 use std::fmt::Display;
 
 use crate::error::Result;
-use crate::types::days_ms;
+use crate::types::{days_ms, f16};
 use crate::{
     bitmap::{Bitmap, MutableBitmap},
     datatypes::{DataType, IntervalUnit},
@@ -173,7 +175,7 @@ impl Display for dyn Array {
             DataType::UInt16 => fmt_dyn!(self, PrimitiveArray<u16>, f),
             DataType::UInt32 => fmt_dyn!(self, PrimitiveArray<u32>, f),
             DataType::UInt64 => fmt_dyn!(self, PrimitiveArray<u64>, f),
-            DataType::Float16 => unreachable!(),
+            DataType::Float16 => fmt_dyn!(self, PrimitiveArray<f16>, f),
             DataType::Float32 => fmt_dyn!(self, PrimitiveArray<f32>, f),
             DataType::Float64 => fmt_dyn!(self, PrimitiveArray<f64>, f),
             DataType::Binary => fmt_dyn!(self, BinaryArray<i32>, f),
@@ -185,7 +187,8 @@ impl Display for dyn Array {
             DataType::LargeList(_) => fmt_dyn!(self, ListArray::<i64>, f),
             DataType::FixedSizeList(_, _) => fmt_dyn!(self, FixedSizeListArray, f),
             DataType::Struct(_) => fmt_dyn!(self, StructArray, f),
-            DataType::Union(_) => unimplemented!(),
+            DataType::Union(_, _, _) => fmt_dyn!(self, UnionArray, f),
+            DataType::Map(_, _) => fmt_dyn!(self, MapArray, f),
             DataType::Dictionary(key_type, _) => match key_type.as_ref() {
                 DataType::Int8 => fmt_dyn!(self, DictionaryArray::<i8>, f),
                 DataType::Int16 => fmt_dyn!(self, DictionaryArray::<i16>, f),
@@ -227,7 +230,7 @@ pub fn new_empty_array(data_type: DataType) -> Box<dyn Array> {
         DataType::UInt16 => Box::new(PrimitiveArray::<u16>::new_empty(data_type)),
         DataType::UInt32 => Box::new(PrimitiveArray::<u32>::new_empty(data_type)),
         DataType::UInt64 => Box::new(PrimitiveArray::<u64>::new_empty(data_type)),
-        DataType::Float16 => unreachable!(),
+        DataType::Float16 => Box::new(PrimitiveArray::<f16>::new_empty(data_type)),
         DataType::Float32 => Box::new(PrimitiveArray::<f32>::new_empty(data_type)),
         DataType::Float64 => Box::new(PrimitiveArray::<f64>::new_empty(data_type)),
         DataType::Binary => Box::new(BinaryArray::<i32>::new_empty()),
@@ -239,7 +242,8 @@ pub fn new_empty_array(data_type: DataType) -> Box<dyn Array> {
         DataType::LargeList(_) => Box::new(ListArray::<i64>::new_empty(data_type)),
         DataType::FixedSizeList(_, _) => Box::new(FixedSizeListArray::new_empty(data_type)),
         DataType::Struct(fields) => Box::new(StructArray::new_empty(&fields)),
-        DataType::Union(_) => unimplemented!(),
+        DataType::Union(_, _, _) => Box::new(UnionArray::new_empty(data_type)),
+        DataType::Map(_, _) => Box::new(MapArray::new_empty(data_type)),
         DataType::Dictionary(key_type, value_type) => match key_type.as_ref() {
             DataType::Int8 => Box::new(DictionaryArray::<i8>::new_empty(*value_type)),
             DataType::Int16 => Box::new(DictionaryArray::<i16>::new_empty(*value_type)),
@@ -255,7 +259,8 @@ pub fn new_empty_array(data_type: DataType) -> Box<dyn Array> {
 }
 
 /// Creates a new [`Array`] of [`DataType`] `data_type` and `length`.
-/// The array is guaranteed to have [`Array::null_count`] equal to [`Array::len`].
+/// The array is guaranteed to have [`Array::null_count`] equal to [`Array::len`]
+/// for all types except [`DataType::Union`], which does not have a validity.
 pub fn new_null_array(data_type: DataType, length: usize) -> Box<dyn Array> {
     match data_type {
         DataType::Null => Box::new(NullArray::new_null(length)),
@@ -281,7 +286,7 @@ pub fn new_null_array(data_type: DataType, length: usize) -> Box<dyn Array> {
         DataType::UInt16 => Box::new(PrimitiveArray::<u16>::new_null(data_type, length)),
         DataType::UInt32 => Box::new(PrimitiveArray::<u32>::new_null(data_type, length)),
         DataType::UInt64 => Box::new(PrimitiveArray::<u64>::new_null(data_type, length)),
-        DataType::Float16 => unreachable!(),
+        DataType::Float16 => Box::new(PrimitiveArray::<f16>::new_null(data_type, length)),
         DataType::Float32 => Box::new(PrimitiveArray::<f32>::new_null(data_type, length)),
         DataType::Float64 => Box::new(PrimitiveArray::<f64>::new_null(data_type, length)),
         DataType::Binary => Box::new(BinaryArray::<i32>::new_null(length)),
@@ -293,7 +298,8 @@ pub fn new_null_array(data_type: DataType, length: usize) -> Box<dyn Array> {
         DataType::LargeList(_) => Box::new(ListArray::<i64>::new_null(data_type, length)),
         DataType::FixedSizeList(_, _) => Box::new(FixedSizeListArray::new_null(data_type, length)),
         DataType::Struct(fields) => Box::new(StructArray::new_null(&fields, length)),
-        DataType::Union(_) => unimplemented!(),
+        DataType::Union(_, _, _) => Box::new(UnionArray::new_null(data_type, length)),
+        DataType::Map(_, _) => Box::new(MapArray::new_null(data_type, length)),
         DataType::Dictionary(key_type, value_type) => match key_type.as_ref() {
             DataType::Int8 => Box::new(DictionaryArray::<i8>::new_null(*value_type, length)),
             DataType::Int16 => Box::new(DictionaryArray::<i16>::new_null(*value_type, length)),
@@ -342,7 +348,7 @@ pub fn clone(array: &dyn Array) -> Box<dyn Array> {
         DataType::UInt16 => clone_dyn!(array, PrimitiveArray<u16>),
         DataType::UInt32 => clone_dyn!(array, PrimitiveArray<u32>),
         DataType::UInt64 => clone_dyn!(array, PrimitiveArray<u64>),
-        DataType::Float16 => unreachable!(),
+        DataType::Float16 => clone_dyn!(array, PrimitiveArray<f16>),
         DataType::Float32 => clone_dyn!(array, PrimitiveArray<f32>),
         DataType::Float64 => clone_dyn!(array, PrimitiveArray<f64>),
         DataType::Binary => clone_dyn!(array, BinaryArray<i32>),
@@ -354,7 +360,8 @@ pub fn clone(array: &dyn Array) -> Box<dyn Array> {
         DataType::LargeList(_) => clone_dyn!(array, ListArray::<i64>),
         DataType::FixedSizeList(_, _) => clone_dyn!(array, FixedSizeListArray),
         DataType::Struct(_) => clone_dyn!(array, StructArray),
-        DataType::Union(_) => unimplemented!(),
+        DataType::Union(_, _, _) => clone_dyn!(array, UnionArray),
+        DataType::Map(_, _) => clone_dyn!(array, MapArray),
         DataType::Dictionary(key_type, _) => match key_type.as_ref() {
             DataType::Int8 => clone_dyn!(array, DictionaryArray::<i8>),
             DataType::Int16 => clone_dyn!(array, DictionaryArray::<i16>),
@@ -376,10 +383,12 @@ mod display;
 mod fixed_size_binary;
 mod fixed_size_list;
 mod list;
+mod map;
 mod null;
 mod primitive;
 mod specification;
 mod struct_;
+mod union;
 mod utf8;
 
 mod equal;
@@ -395,10 +404,12 @@ pub use dictionary::{DictionaryArray, DictionaryKey, MutableDictionaryArray};
 pub use fixed_size_binary::FixedSizeBinaryArray;
 pub use fixed_size_list::FixedSizeListArray;
 pub use list::{ListArray, MutableListArray};
+pub use map::MapArray;
 pub use null::NullArray;
 pub use primitive::*;
 pub use specification::{Index, Offset};
 pub use struct_::StructArray;
+pub use union::{UnionArray, UnionIter};
 pub use utf8::{MutableUtf8Array, Utf8Array, Utf8ValuesIter};
 
 pub(crate) use self::ffi::buffers_children;
@@ -6,12 +6,24 @@ use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::{
     array::*,
-    types::{days_ms, NativeType},
+    types::{days_ms, f16, NativeType},
 };
 
 /// Compare the values at two arbitrary indices in two arrays.
 pub type DynComparator<'a> = Box<dyn Fn(usize, usize) -> Ordering + 'a>;
 
+/// implements comparison using IEEE 754 total ordering for [`f16`]
+#[inline]
+pub fn total_cmp_f16(l: &f16, r: &f16) -> std::cmp::Ordering {
+    let mut left = l.to_bits() as i16;
+    let mut right = r.to_bits() as i16;
+
+    left ^= (((left >> 15) as u16) >> 1) as i16;
+    right ^= (((right >> 15) as u16) >> 1) as i16;
+
+    left.cmp(&right)
+}
+
 /// implements comparison using IEEE 754 total ordering for f32
 // Original implementation from https://doc.rust-lang.org/std/primitive.f32.html#method.total_cmp
 // TODO to change to use std when it becomes stable
@@ -67,6 +79,17 @@ fn compare_boolean<'a>(left: &'a dyn Array, right: &'a dyn Array) -> DynComparat
     Box::new(move |i, j| left.value(i).cmp(&right.value(j)))
 }
 
+fn compare_f16<'a>(left: &'a dyn Array, right: &'a dyn Array) -> DynComparator<'a> {
+    let left = left.as_any().downcast_ref::<PrimitiveArray<f16>>().unwrap();
+    let right = right
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f16>>()
+        .unwrap();
+    let left = left.values();
+    let right = right.values();
+    Box::new(move |i, j| total_cmp_f16(&left[i], &right[j]))
+}
+
 fn compare_f32<'a>(left: &'a dyn Array, right: &'a dyn Array) -> DynComparator<'a> {
     let left = left.as_any().downcast_ref::<PrimitiveArray<f32>>().unwrap();
     let right = right
@@ -95,6 +118,112 @@ fn compare_string<'a, O: Offset>(left: &'a dyn Array, right: &'a dyn Array) -> D
     Box::new(move |i, j| left.value(i).cmp(&right.value(j)))
 }
 
+fn compare_binary<'a, O: Offset>(left: &'a dyn Array, right: &'a dyn Array) -> DynComparator<'a> {
+    let left = left.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    let right = right.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    Box::new(move |i, j| left.value(i).cmp(right.value(j)))
+}
+
+fn compare_fixed_size_binary<'a>(left: &'a dyn Array, right: &'a dyn Array) -> DynComparator<'a> {
+    let left = left
+        .as_any()
+        .downcast_ref::<FixedSizeBinaryArray>()
+        .unwrap();
+    let right = right
+        .as_any()
+        .downcast_ref::<FixedSizeBinaryArray>()
+        .unwrap();
+    Box::new(move |i, j| left.value(i).cmp(right.value(j)))
+}
+
+/// Returns a comparator of two arrays of the same type where nulls are smaller than any value.
+fn build_compare_with_nulls<'a>(
+    left: &'a dyn Array,
+    right: &'a dyn Array,
+) -> Result<DynComparator<'a>> {
+    let comparator = build_compare(left, right)?;
+    Ok(Box::new(move |i, j| {
+        match (left.is_valid(i), right.is_valid(j)) {
+            (true, true) => comparator(i, j),
+            (false, true) => Ordering::Less,
+            (true, false) => Ordering::Greater,
+            (false, false) => Ordering::Equal,
+        }
+    }))
+}
+
+/// Compares the items in the ranges `left` and `right` lexicographically: item by item and
+/// then by length.
+#[inline]
+fn compare_ranges(
+    comparator: &DynComparator,
+    left: std::ops::Range<usize>,
+    right: std::ops::Range<usize>,
+) -> Ordering {
+    let left_len = left.len();
+    let right_len = right.len();
+    left.zip(right)
+        .map(|(i, j)| comparator(i, j))
+        .find(|ordering| *ordering != Ordering::Equal)
+        .unwrap_or_else(|| left_len.cmp(&right_len))
+}
+
+fn compare_list<'a, O: Offset>(
+    left: &'a dyn Array,
+    right: &'a dyn Array,
+) -> Result<DynComparator<'a>> {
+    let left = left.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let right = right.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let comparator = build_compare_with_nulls(left.values().as_ref(), right.values().as_ref())?;
+    let left = left.offsets().as_slice();
+    let right = right.offsets().as_slice();
+
+    Ok(Box::new(move |i, j| {
+        compare_ranges(
+            &comparator,
+            left[i].to_usize()..left[i + 1].to_usize(),
+            right[j].to_usize()..right[j + 1].to_usize(),
+        )
+    }))
+}
+
+fn compare_fixed_size_list<'a>(
+    left: &'a dyn Array,
+    right: &'a dyn Array,
+) -> Result<DynComparator<'a>> {
+    let size = *FixedSizeListArray::get_child_and_size(left.data_type()).1 as usize;
+    let left = left.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let right = right.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let comparator = build_compare_with_nulls(left.values().as_ref(), right.values().as_ref())?;
+
+    Ok(Box::new(move |i, j| {
+        compare_ranges(
+            &comparator,
+            i * size..(i + 1) * size,
+            j * size..(j + 1) * size,
+        )
+    }))
+}
+
+fn compare_struct<'a>(left: &'a dyn Array, right: &'a dyn Array) -> Result<DynComparator<'a>> {
+    let left = left.as_any().downcast_ref::<StructArray>().unwrap();
+    let right = right.as_any().downcast_ref::<StructArray>().unwrap();
+    let comparators = left
+        .values()
+        .iter()
+        .zip(right.values().iter())
+        .map(|(left, right)| build_compare_with_nulls(left.as_ref(), right.as_ref()))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Box::new(move |i, j| {
+        comparators
+            .iter()
+            .map(|comparator| comparator(i, j))
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }))
+}
+
 fn compare_dict<'a, K>(
     left: &'a DictionaryArray<K>,
     right: &'a DictionaryArray<K>,
@@ -140,6 +269,8 @@ macro_rules! dyn_dict {
 /// # Ok(())
 /// # }
 /// ```
+/// Nested types are compared item by item (and lists then by length), with null items
+/// ordered before valid ones.
 /// # Error
 /// The arrays' [`DataType`] must be equal and the types must have a natural order.
 // This is a factory of comparisons.
@@ -178,11 +309,20 @@ pub fn build_compare<'a>(left: &'a dyn Array, right: &'a dyn Array) -> Result<Dy
         | (Duration(Millisecond), Duration(Millisecond))
         | (Duration(Microsecond), Duration(Microsecond))
         | (Duration(Nanosecond), Duration(Nanosecond)) => compare_primitives::<i64>(left, right),
+        (Float16, Float16) => compare_f16(left, right),
         (Float32, Float32) => compare_f32(left, right),
         (Float64, Float64) => compare_f64(left, right),
         (Interval(DayTime), Interval(DayTime)) => compare_primitives::<days_ms>(left, right),
         (Utf8, Utf8) => compare_string::<i32>(left, right),
         (LargeUtf8, LargeUtf8) => compare_string::<i64>(left, right),
+        (Binary, Binary) => compare_binary::<i32>(left, right),
+        (LargeBinary, LargeBinary) => compare_binary::<i64>(left, right),
+        (FixedSizeBinary(_), FixedSizeBinary(_)) => compare_fixed_size_binary(left, right),
+        (Decimal(_, _), Decimal(_, _)) => compare_primitives::<i128>(left, right),
+        (List(_), List(_)) => compare_list::<i32>(left, right)?,
+        (LargeList(_), LargeList(_)) => compare_list::<i64>(left, right)?,
+        (FixedSizeList(_, _), FixedSizeList(_, _)) => compare_fixed_size_list(left, right)?,
+        (Struct(_), Struct(_)) => compare_struct(left, right)?,
         (Dictionary(key_type_lhs, _), Dictionary(key_type_rhs, _)) => {
             match (key_type_lhs.as_ref(), key_type_rhs.as_ref()) {
                 (UInt8, UInt8) => dyn_dict!(u8, left, right),
@@ -213,8 +353,10 @@ pub fn build_compare<'a>(left: &'a dyn Array, right: &'a dyn Array) -> Result<Dy
 #[cfg(test)]
 pub mod tests {
     use super::*;
+    use crate::buffer::Buffer;
     use crate::error::Result;
     use std::cmp::Ordering;
+    use std::sync::Arc;
 
     #[test]
     fn test_i32() -> Result<()> {
@@ -313,4 +455,52 @@ pub mod tests {
         assert_eq!(Ordering::Greater, (cmp)(2, 3));
         Ok(())
     }
+
+    #[test]
+    fn test_binary() -> Result<()> {
+        let array = BinaryArray::<i32>::from_slice([b"ab".as_ref(), b"b", b"a"]);
+
+        let cmp = build_compare(&array, &array)?;
+
+        assert_eq!(Ordering::Less, (cmp)(0, 1));
+        assert_eq!(Ordering::Greater, (cmp)(0, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_list() -> Result<()> {
+        let values = Int32Array::from([Some(1), Some(2), Some(1), None, Some(1)]);
+        // [[1, 2], [1, null], [1]]
+        let array = ListArray::<i32>::from_data(
+            ListArray::<i32>::default_datatype(DataType::Int32),
+            Buffer::from([0, 2, 4, 5]),
+            Arc::new(values),
+            None,
+        );
+
+        let cmp = build_compare(&array, &array)?;
+
+        assert_eq!(Ordering::Greater, (cmp)(0, 1));
+        assert_eq!(Ordering::Greater, (cmp)(0, 2));
+        assert_eq!(Ordering::Greater, (cmp)(1, 2));
+        assert_eq!(Ordering::Equal, (cmp)(1, 1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_struct() -> Result<()> {
+        let a = Int32Array::from_slice([1, 1, 0]);
+        let b = Utf8Array::<i32>::from_slice(["b", "a", "c"]);
+        let fields = vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, false),
+        ];
+        let array = StructArray::from_data(fields, vec![Arc::new(a), Arc::new(b)], None);
+
+        let cmp = build_compare(&array, &array)?;
+
+        assert_eq!(Ordering::Greater, (cmp)(0, 1));
+        assert_eq!(Ordering::Greater, (cmp)(1, 2));
+        Ok(())
+    }
 }
@@ -1,4 +1,9 @@
-use crate::{datatypes::*, temporal_conversions, types::days_ms};
+use crate::{
+    datatypes::*,
+    temporal_conversions,
+    types::{days_ms, f16},
+    util::decimal_to_string,
+};
 
 use super::super::{display_fmt, Array};
 use super::PrimitiveArray;
@@ -133,13 +138,9 @@ impl std::fmt::Display for PrimitiveArray<i128> {
                 let new_lines = false;
                 let head = &format!("{}", self.data_type());
                 // The number 999.99 has a precision of 5 and scale of 2
-                let iter = self.iter().map(|x| {
-                    x.copied().map(|x| {
-                        let base = x / 10i128.pow(*scale as u32);
-                        let decimals = x - base * 10i128.pow(*scale as u32);
-                        format!("{}.{}", base, decimals)
-                    })
-                });
+                let iter = self
+                    .iter()
+                    .map(|x| x.copied().map(|x| decimal_to_string(x, *scale)));
                 display_fmt(iter, head, f, new_lines)
             }
             _ => unreachable!(),
@@ -176,6 +177,7 @@ display!(u8);
 display!(u16);
 display!(u32);
 display!(u64);
+display!(f16);
 display!(f32);
 display!(f64);
 
@@ -190,6 +192,13 @@ mod tests {
         assert_eq!(format!("{}", array), "Int32[1, , 2]");
     }
 
+    #[test]
+    fn display_f16() {
+        use crate::types::f16;
+        let array = PrimitiveArray::<f16>::from(&[Some(f16::from_f32(1.5)), None]);
+        assert_eq!(format!("{}", array), "Float16[1.5, ]");
+    }
+
     #[test]
     fn display_date32() {
         let array = Int32Array::from(&[Some(1), None, Some(2)]).to(DataType::Date32);
@@ -8,8 +8,10 @@ use crate::{
 };
 
 /// Trait describing any type that can be used to index a slot of an array.
-pub trait Index: NativeType {
+pub trait Index: NativeType + NaturalDataType {
     fn to_usize(&self) -> usize;
+
+    fn from_usize(value: usize) -> Option<Self>;
 }
 
 /// Trait describing types that can be used as offsets as per Arrow specification.
@@ -20,8 +22,6 @@ pub unsafe trait Offset:
     Index + NaturalDataType + Num + Ord + std::ops::AddAssign + std::ops::Sub + num::CheckedAdd
 {
     fn is_large() -> bool;
-
-    fn from_usize(value: usize) -> Option<Self>;
 }
 
 unsafe impl Offset for i32 {
@@ -29,11 +29,6 @@ unsafe impl Offset for i32 {
     fn is_large() -> bool {
         false
     }
-
-    #[inline]
-    fn from_usize(value: usize) -> Option<Self> {
-        Self::try_from(value).ok()
-    }
 }
 
 unsafe impl Offset for i64 {
@@ -41,11 +36,6 @@ unsafe impl Offset for i64 {
     fn is_large() -> bool {
         true
     }
-
-    #[inline]
-    fn from_usize(value: usize) -> Option<Self> {
-        Some(value as i64)
-    }
 }
 
 impl Index for i32 {
@@ -53,6 +43,11 @@ impl Index for i32 {
     fn to_usize(&self) -> usize {
         *self as usize
     }
+
+    #[inline]
+    fn from_usize(value: usize) -> Option<Self> {
+        Self::try_from(value).ok()
+    }
 }
 
 impl Index for i64 {
@@ -60,6 +55,11 @@ impl Index for i64 {
     fn to_usize(&self) -> usize {
         *self as usize
     }
+
+    #[inline]
+    fn from_usize(value: usize) -> Option<Self> {
+        Some(value as i64)
+    }
 }
 
 impl Index for u32 {
@@ -67,6 +67,11 @@ impl Index for u32 {
     fn to_usize(&self) -> usize {
         *self as usize
     }
+
+    #[inline]
+    fn from_usize(value: usize) -> Option<Self> {
+        Self::try_from(value).ok()
+    }
 }
 
 impl Index for u64 {
@@ -74,6 +79,11 @@ impl Index for u64 {
     fn to_usize(&self) -> usize {
         *self as usize
     }
+
+    #[inline]
+    fn from_usize(value: usize) -> Option<Self> {
+        Some(value as u64)
+    }
 }
 
 #[inline]
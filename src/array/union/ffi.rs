@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use crate::{array::FromFfi, error::Result, ffi};
+
+use super::super::{ffi::ToFfi, Array};
+use super::UnionArray;
+
+unsafe impl ToFfi for UnionArray {
+    fn buffers(&self) -> Vec<Option<std::ptr::NonNull<u8>>> {
+        // unions do not have a validity bitmap
+        unsafe {
+            let types = Some(std::ptr::NonNull::new_unchecked(
+                self.types.as_ptr() as *mut u8
+            ));
+            if let Some(offsets) = &self.offsets {
+                vec![
+                    types,
+                    Some(std::ptr::NonNull::new_unchecked(offsets.as_ptr() as *mut u8)),
+                ]
+            } else {
+                vec![types]
+            }
+        }
+    }
+
+    fn offset(&self) -> usize {
+        // the buffers and the fields of sparse unions are sliced when the union is sliced
+        0
+    }
+
+    fn children(&self) -> Vec<Arc<dyn Array>> {
+        self.fields.clone()
+    }
+}
+
+unsafe impl<A: ffi::ArrowArrayRef> FromFfi<A> for UnionArray {
+    fn try_from_ffi(array: A) -> Result<Self> {
+        let data_type = array.data_type()?;
+        let fields = Self::get_fields(&data_type);
+
+        let length = array.array().len();
+        let offset = array.array().offset();
+        let mut types = unsafe { array.buffer::<i8>(0) }?;
+        let mut offsets = if Self::is_sparse(&data_type) {
+            None
+        } else {
+            Some(unsafe { array.buffer::<i32>(1) }?)
+        };
+
+        let mut fields = (0..fields.len())
+            .map(|index| {
+                let child = array.child(index)?;
+                Ok(ffi::try_from(child)?.into())
+            })
+            .collect::<Result<Vec<Arc<dyn Array>>>>()?;
+
+        if offset > 0 {
+            types = types.slice(offset, length);
+            if offsets.is_some() {
+                offsets = offsets.map(|x| x.slice(offset, length));
+            } else {
+                fields = fields
+                    .iter()
+                    .map(|x| x.slice(offset, length).into())
+                    .collect();
+            }
+        }
+        Self::try_new(data_type, types, fields, offsets)
+    }
+}
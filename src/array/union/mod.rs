@@ -0,0 +1,436 @@
+use std::{collections::HashMap, sync::Arc};
+
+use crate::{
+    bitmap::Bitmap,
+    buffer::Buffer,
+    datatypes::{DataType, Field, UnionMode},
+    error::{ArrowError, Result},
+};
+
+use super::{display_fmt, new_empty_array, new_null_array, Array};
+
+mod ffi;
+mod iterator;
+pub use iterator::*;
+
+/// An [`Array`] whose slots can be of different types.
+/// Each slot has a type id in [`UnionArray::types`] that identifies the child
+/// in [`UnionArray::fields`] holding its value.
+/// In dense mode, the value of slot `i` is at position `offsets[i]` of that child;
+/// in sparse mode, every child has the length of the union and the value is at position `i`.
+/// # Validity
+/// Unions do not have a validity bitmap: a slot is null iff its value in the child is null.
+#[derive(Debug, Clone)]
+pub struct UnionArray {
+    data_type: DataType,
+    types: Buffer<i8>,
+    // maps a type id to the position of its field.
+    // `None` when the type ids are the positions themselves.
+    fields_map: Option<HashMap<i8, usize>>,
+    fields: Vec<Arc<dyn Array>>,
+    offsets: Option<Buffer<i32>>,
+}
+
+impl UnionArray {
+    pub fn new_empty(data_type: DataType) -> Self {
+        let (fields, _, mode) = Self::get_all(&data_type);
+        let fields = fields
+            .iter()
+            .map(|field| new_empty_array(field.data_type().clone()).into())
+            .collect();
+        let offsets = if mode.is_sparse() {
+            None
+        } else {
+            Some(Buffer::new())
+        };
+        Self::from_data(data_type, Buffer::new(), fields, offsets)
+    }
+
+    /// Creates a new [`UnionArray`] whose slots are all null.
+    /// All slots are assigned to the first field, whose values are null.
+    pub fn new_null(data_type: DataType, length: usize) -> Self {
+        let (fields, ids, mode) = Self::get_all(&data_type);
+        let first_id = ids.map(|ids| ids[0] as i8).unwrap_or(0);
+        let types = Buffer::from_trusted_len_iter(std::iter::repeat(first_id).take(length));
+
+        let (fields, offsets) = if mode.is_sparse() {
+            let fields = fields
+                .iter()
+                .map(|field| new_null_array(field.data_type().clone(), length).into())
+                .collect();
+            (fields, None)
+        } else {
+            let fields = fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| {
+                    let length = if i == 0 { length } else { 0 };
+                    new_null_array(field.data_type().clone(), length).into()
+                })
+                .collect();
+            let offsets = (0..length as i32).collect::<Buffer<i32>>();
+            (fields, Some(offsets))
+        };
+        Self::from_data(data_type, types, fields, offsets)
+    }
+
+    /// Creates a new [`UnionArray`].
+    /// # Panics
+    /// This function panics iff [`UnionArray::try_new`] errors.
+    pub fn from_data(
+        data_type: DataType,
+        types: Buffer<i8>,
+        fields: Vec<Arc<dyn Array>>,
+        offsets: Option<Buffer<i32>>,
+    ) -> Self {
+        Self::try_new(data_type, types, fields, offsets).unwrap()
+    }
+
+    /// Creates a new [`UnionArray`], validating its type ids and offsets against its fields.
+    /// # Errors
+    /// This function errors iff:
+    /// * `data_type` is not [`DataType::Union`]
+    /// * the number of `fields` differs from the number of fields in `data_type`
+    /// * the data types of the `fields` do not match the ones in `data_type`
+    /// * `offsets` is `None` for a dense union or `Some` for a sparse union
+    /// * the union is sparse and any field has a length different from `types`
+    /// * the union is dense and `offsets` has a length different from `types`
+    /// * any type id does not correspond to a field
+    /// * the union is dense and any offset is out of bounds of the field of its slot
+    pub fn try_new(
+        data_type: DataType,
+        types: Buffer<i8>,
+        fields: Vec<Arc<dyn Array>>,
+        offsets: Option<Buffer<i32>>,
+    ) -> Result<Self> {
+        if !matches!(data_type, DataType::Union(_, _, _)) {
+            return Err(ArrowError::InvalidArgumentError(
+                "A UnionArray must have a data type Union".to_string(),
+            ));
+        }
+        let (f, ids, mode) = Self::get_all(&data_type);
+
+        if f.len() != fields.len() {
+            return Err(ArrowError::InvalidArgumentError(
+                "A UnionArray must have as many fields as its data type".to_string(),
+            ));
+        }
+        if !f
+            .iter()
+            .zip(fields.iter())
+            .all(|(f, array)| f.data_type() == array.data_type())
+        {
+            return Err(ArrowError::InvalidArgumentError(
+                "The fields of a UnionArray must match the fields of its data type".to_string(),
+            ));
+        }
+        if mode.is_sparse() != offsets.is_none() {
+            return Err(ArrowError::InvalidArgumentError(
+                "A UnionArray must have offsets iff it is dense".to_string(),
+            ));
+        }
+        if let Some(ref offsets) = offsets {
+            if offsets.len() != types.len() {
+                return Err(ArrowError::InvalidArgumentError(
+                    "The offsets of a dense UnionArray must have the length of its types"
+                        .to_string(),
+                ));
+            }
+        } else if fields.iter().any(|x| x.len() != types.len()) {
+            return Err(ArrowError::InvalidArgumentError(
+                "The fields of a sparse UnionArray must have the length of its types".to_string(),
+            ));
+        }
+
+        let fields_map = ids.map(|ids| {
+            ids.iter()
+                .enumerate()
+                .map(|(position, &id)| (id as i8, position))
+                .collect()
+        });
+
+        let array = Self {
+            data_type,
+            types,
+            fields_map,
+            fields,
+            offsets,
+        };
+
+        for (index, &type_) in array.types.iter().enumerate() {
+            let field_index = array.field_index(type_).ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "The type id {} of slot {} does not correspond to a field of the UnionArray",
+                    type_, index
+                ))
+            })?;
+            if let Some(offsets) = &array.offsets {
+                let offset = offsets[index];
+                if offset < 0 || offset as usize >= array.fields[field_index].len() {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "The offset {} of slot {} is out of bounds of its field",
+                        offset, index
+                    )));
+                }
+            }
+        }
+        Ok(array)
+    }
+
+    /// Returns a slice of this [`UnionArray`].
+    /// # Implementation
+    /// This operation is `O(F)` where `F` is the number of fields.
+    /// # Panic
+    /// This function panics iff `offset + length >= self.len()`.
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        // in sparse mode the children are aligned with the types and must be sliced with them.
+        let fields = if self.offsets.is_none() {
+            self.fields
+                .iter()
+                .map(|x| x.slice(offset, length).into())
+                .collect()
+        } else {
+            self.fields.clone()
+        };
+        Self {
+            data_type: self.data_type.clone(),
+            types: self.types.clone().slice(offset, length),
+            fields_map: self.fields_map.clone(),
+            fields,
+            offsets: self.offsets.clone().map(|x| x.slice(offset, length)),
+        }
+    }
+
+    /// The type ids of this [`UnionArray`], one per slot.
+    #[inline]
+    pub fn types(&self) -> &Buffer<i8> {
+        &self.types
+    }
+
+    /// The offsets of this [`UnionArray`] into its fields. `None` when the union is sparse.
+    #[inline]
+    pub fn offsets(&self) -> &Option<Buffer<i32>> {
+        &self.offsets
+    }
+
+    /// The children of this [`UnionArray`].
+    #[inline]
+    pub fn fields(&self) -> &Vec<Arc<dyn Array>> {
+        &self.fields
+    }
+
+    /// Returns the position in [`UnionArray::fields`] of the field with type id `type_`,
+    /// or `None` if no field has that type id.
+    #[inline]
+    pub fn field_index(&self, type_: i8) -> Option<usize> {
+        match &self.fields_map {
+            Some(map) => map.get(&type_).copied(),
+            None => Some(type_ as usize).filter(|&x| type_ >= 0 && x < self.fields.len()),
+        }
+    }
+
+    /// Returns the index of the field and the offset within that field of slot `index`.
+    /// # Panic
+    /// Panics iff `index >= self.len()`.
+    #[inline]
+    pub fn index(&self, index: usize) -> (usize, usize) {
+        // the type ids were validated on construction
+        let field_index = self.field_index(self.types()[index]).unwrap();
+        let offset = self
+            .offsets()
+            .as_ref()
+            .map(|x| x[index] as usize)
+            .unwrap_or(index);
+        (field_index, offset)
+    }
+
+    /// Returns the slot `index` as a [`Array`] of length 1 of the type of its field.
+    /// # Panic
+    /// Panics iff `index >= self.len()`.
+    pub fn value(&self, index: usize) -> Box<dyn Array> {
+        let (field_index, offset) = self.index(index);
+        self.fields()[field_index].as_ref().slice(offset, 1)
+    }
+}
+
+impl UnionArray {
+    /// Returns the fields, the optional type ids and the [`UnionMode`] of a [`DataType::Union`].
+    /// # Panic
+    /// Panics iff `data_type` is not [`DataType::Union`].
+    pub fn get_all(data_type: &DataType) -> (&[Field], Option<&[i32]>, UnionMode) {
+        if let DataType::Union(fields, ids, mode) = data_type {
+            (fields, ids.as_ref().map(|x| x.as_ref()), *mode)
+        } else {
+            panic!("Wrong datatype passed to UnionArray.")
+        }
+    }
+
+    /// Returns the fields of a [`DataType::Union`].
+    /// # Panic
+    /// Panics iff `data_type` is not [`DataType::Union`].
+    pub fn get_fields(data_type: &DataType) -> &[Field] {
+        Self::get_all(data_type).0
+    }
+
+    /// Returns whether a [`DataType::Union`] is sparse.
+    /// # Panic
+    /// Panics iff `data_type` is not [`DataType::Union`].
+    pub fn is_sparse(data_type: &DataType) -> bool {
+        Self::get_all(data_type).2.is_sparse()
+    }
+}
+
+impl Array for UnionArray {
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    #[inline]
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    #[inline]
+    fn validity(&self) -> &Option<Bitmap> {
+        &None
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> Box<dyn Array> {
+        Box::new(self.slice(offset, length))
+    }
+}
+
+impl std::fmt::Display for UnionArray {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let head = if Self::is_sparse(&self.data_type) {
+            "SparseUnionArray"
+        } else {
+            "DenseUnionArray"
+        };
+        display_fmt(self.iter().map(Some), head, f, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::*;
+
+    fn sparse() -> UnionArray {
+        let fields = vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ];
+        let data_type = DataType::Union(fields, None, UnionMode::Sparse);
+        let types = Buffer::from(&[0, 0, 1]);
+        let fields = vec![
+            Arc::new(Int32Array::from(&[Some(1), None, Some(2)])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("b"), Some("c")])) as Arc<dyn Array>,
+        ];
+        UnionArray::from_data(data_type, types, fields, None)
+    }
+
+    fn dense() -> UnionArray {
+        let fields = vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ];
+        let data_type = DataType::Union(fields, Some(vec![5, 7]), UnionMode::Dense);
+        let types = Buffer::from(&[5, 7, 5]);
+        let offsets = Buffer::from(&[0, 0, 1]);
+        let fields = vec![
+            Arc::new(Int32Array::from(&[Some(1), None])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[Some("c")])) as Arc<dyn Array>,
+        ];
+        UnionArray::from_data(data_type, types, fields, Some(offsets))
+    }
+
+    #[test]
+    fn sparse_value() {
+        let array = sparse();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.index(2), (1, 2));
+        assert_eq!(
+            array.value(2).as_ref(),
+            &Utf8Array::<i32>::from(&[Some("c")]) as &dyn Array
+        );
+        assert!(array.value(1).is_null(0));
+    }
+
+    #[test]
+    fn dense_value() {
+        let array = dense();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.index(1), (1, 0));
+        assert_eq!(array.index(2), (0, 1));
+        assert_eq!(
+            array.value(1).as_ref(),
+            &Utf8Array::<i32>::from(&[Some("c")]) as &dyn Array
+        );
+        assert!(array.value(2).is_null(0));
+    }
+
+    #[test]
+    fn slice() {
+        let array = sparse().slice(1, 2);
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.fields()[0].len(), 2);
+        assert_eq!(
+            array.value(1).as_ref(),
+            &Utf8Array::<i32>::from(&[Some("c")]) as &dyn Array
+        );
+
+        let array = dense().slice(1, 2);
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.index(1), (0, 1));
+    }
+
+    #[test]
+    fn field_index() {
+        let array = dense();
+        assert_eq!(array.field_index(7), Some(1));
+        assert_eq!(array.field_index(0), None);
+        assert_eq!(sparse().field_index(2), None);
+        assert_eq!(sparse().field_index(-1), None);
+    }
+
+    #[test]
+    fn try_new_invalid() {
+        let fields = vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ];
+        let data_type = DataType::Union(fields, Some(vec![5, 7]), UnionMode::Dense);
+        let fields = vec![
+            Arc::new(Int32Array::from(&[Some(1), None])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[Some("c")])) as Arc<dyn Array>,
+        ];
+
+        // type id 6 is not a field
+        let types = Buffer::from(&[5, 6]);
+        let offsets = Buffer::from(&[0, 0]);
+        assert!(
+            UnionArray::try_new(data_type.clone(), types, fields.clone(), Some(offsets)).is_err()
+        );
+
+        // the field with type id 7 has a single value
+        let types = Buffer::from(&[5, 7]);
+        let offsets = Buffer::from(&[0, 1]);
+        assert!(UnionArray::try_new(data_type, types, fields, Some(offsets)).is_err());
+    }
+
+    #[test]
+    fn display() {
+        let array = sparse();
+        assert_eq!(
+            format!("{}", array),
+            "SparseUnionArray[\nInt32[1],\nInt32[],\nUtf8[c]\n]"
+        );
+    }
+}
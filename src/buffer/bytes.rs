@@ -2,19 +2,20 @@
 //! how to de-allocate itself, [`Bytes`].
 
 use std::slice;
-use std::{fmt::Debug, fmt::Formatter};
+use std::{any::Any, fmt::Debug, fmt::Formatter};
 use std::{ptr::NonNull, sync::Arc};
 
 use crate::alloc;
-use crate::ffi;
 use crate::types::NativeType;
 
 /// Mode of deallocating memory regions
 pub enum Deallocation {
     /// Native deallocation, using Rust deallocator with Arrow-specific memory aligment
     Native(usize),
-    // Foreign interface, via a callback
-    Foreign(Arc<ffi::ArrowArray>),
+    /// Foreign memory region, kept alive by its owner, e.g. an array imported via the C data
+    /// interface (whose release callback frees it) or a memory-mapped file. The owner must be
+    /// `Send + Sync` since [`Bytes`] can be shared and dropped across threads.
+    Foreign(Arc<dyn Any + Send + Sync>),
 }
 
 impl Debug for Deallocation {
@@ -34,8 +35,8 @@ impl Debug for Deallocation {
 ///
 /// In the most common case, this buffer is allocated using [`allocate_aligned`](alloc::allocate_aligned)
 /// and deallocated accordingly [`free_aligned`](alloc::free_aligned).
-/// When the region is allocated by a foreign allocator, [Deallocation::Foreign], this calls the
-/// foreign deallocator to deallocate the region when it is no longer needed.
+/// When the region is allocated by a foreign allocator, [Deallocation::Foreign], the region is
+/// deallocated by its owner once it is no longer needed.
 pub struct Bytes<T: NativeType> {
     /// The raw pointer to be begining of the region
     ptr: NonNull<T>,
@@ -97,7 +98,7 @@ impl<T: NativeType> Drop for Bytes<T> {
             Deallocation::Native(capacity) => {
                 unsafe { alloc::free_aligned(self.ptr, *capacity) };
             }
-            // foreign interface knows how to deallocate itself.
+            // the owner knows how to deallocate itself.
             Deallocation::Foreign(_) => (),
         }
     }
@@ -127,6 +128,6 @@ impl<T: NativeType> Debug for Bytes<T> {
     }
 }
 
-// This is sound because `Bytes` is an imutable container
+// This is sound because `Bytes` is an imutable container whose owner, if any, is `Send + Sync`
 unsafe impl<T: NativeType> Send for Bytes<T> {}
 unsafe impl<T: NativeType> Sync for Bytes<T> {}
@@ -1,7 +1,7 @@
 use crate::array::*;
 use crate::bitmap::Bitmap;
 use crate::datatypes::{DataType, IntervalUnit};
-use crate::types::days_ms;
+use crate::types::{days_ms, f16};
 
 fn validity_size(validity: &Option<Bitmap>) -> usize {
     validity.as_ref().map(|b| b.bytes().len()).unwrap_or(0)
@@ -67,7 +67,7 @@ pub fn estimated_bytes_size(array: &dyn Array) -> usize {
         UInt16 => dyn_primitive!(array, u16),
         UInt32 => dyn_primitive!(array, u32),
         UInt64 => dyn_primitive!(array, u64),
-        Float16 => unreachable!(),
+        Float16 => dyn_primitive!(array, f16),
         Float32 => dyn_primitive!(array, f32),
         Float64 => dyn_primitive!(array, f64),
         Decimal(_, _) => dyn_primitive!(array, i128),
@@ -109,7 +109,28 @@ pub fn estimated_bytes_size(array: &dyn Array) -> usize {
                 .sum::<usize>()
                 + validity_size(array.validity())
         }
-        Union(_) => unreachable!(),
+        Map(_, _) => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            estimated_bytes_size(array.field().as_ref())
+                + array.offsets().len() * std::mem::size_of::<i32>()
+                + validity_size(array.validity())
+        }
+        Union(_, _, _) => {
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+            let types = array.types().len() * std::mem::size_of::<i8>();
+            let offsets = array
+                .offsets()
+                .as_ref()
+                .map(|x| x.len() * std::mem::size_of::<i32>())
+                .unwrap_or_default();
+            let fields = array
+                .fields()
+                .iter()
+                .map(|x| x.as_ref())
+                .map(estimated_bytes_size)
+                .sum::<usize>();
+            types + offsets + fields
+        }
         Dictionary(keys, _) => match keys.as_ref() {
             Int8 => dyn_dict!(array, i8),
             Int16 => dyn_dict!(array, i16),
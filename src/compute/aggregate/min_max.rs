@@ -221,6 +221,16 @@ where
     })
 }
 
+/// Returns the minimum value in the Decimal array.
+pub fn min_decimal(array: &PrimitiveArray<i128>) -> Option<i128> {
+    array.iter().flatten().copied().min()
+}
+
+/// Returns the maximum value in the Decimal array.
+pub fn max_decimal(array: &PrimitiveArray<i128>) -> Option<i128> {
+    array.iter().flatten().copied().max()
+}
+
 /// Returns the maximum value in the string array, according to the natural order.
 pub fn max_string<O: Offset>(array: &Utf8Array<O>) -> Option<&str> {
     min_max_string(array, |a, b| a < b)
@@ -371,6 +381,14 @@ mod tests {
         assert_eq!(Some(f64::INFINITY), max_primitive(&a));
     }
 
+    #[test]
+    fn test_decimal_min_max() {
+        let a = Int128Array::from(&[Some(12345), None, Some(-105)])
+            .to(crate::datatypes::DataType::Decimal(5, 2));
+        assert_eq!(Some(-105), min_decimal(&a));
+        assert_eq!(Some(12345), max_decimal(&a));
+    }
+
     // todo: convert me
     /*
     #[test]
@@ -8,6 +8,9 @@ use crate::types::NativeType;
 use crate::{
     array::{Array, PrimitiveArray},
     bitmap::Bitmap,
+    compute::arithmetics::decimal::try_max_value,
+    datatypes::DataType,
+    error::{ArrowError, Result},
 };
 
 /// Object that can reduce itself to a number. This is used in the context of SIMD to reduce
@@ -102,6 +105,40 @@ where
     }
 }
 
+/// Returns the sum of values of a Decimal array, with the same precision and scale.
+///
+/// Returns `None` if the array is empty or only contains null values.
+/// # Errors
+/// If the array is not a Decimal or the sum does not fit in its precision.
+pub fn sum_decimal(array: &PrimitiveArray<i128>) -> Result<Option<i128>> {
+    let precision = match array.data_type() {
+        DataType::Decimal(precision, _) => *precision,
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(
+                "Incorrect data type for the array".to_string(),
+            ))
+        }
+    };
+    if array.null_count() == array.len() {
+        return Ok(None);
+    }
+
+    let overflow = || {
+        ArrowError::InvalidArgumentError(format!(
+            "Overflow in sum presented for precision {}",
+            precision
+        ))
+    };
+    let sum = array
+        .iter()
+        .flatten()
+        .try_fold(0i128, |acc, x| acc.checked_add(*x).ok_or_else(overflow))?;
+    if sum.checked_abs().ok_or_else(overflow)? > try_max_value(precision)? {
+        return Err(overflow());
+    }
+    Ok(Some(sum))
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::super::arithmetics;
@@ -132,6 +169,18 @@ mod tests {
         assert_eq!(None, sum(&a));
     }
 
+    #[test]
+    fn test_decimal_sum() {
+        let a = Int128Array::from(&[Some(12345), None, Some(-105)]).to(DataType::Decimal(5, 2));
+        assert_eq!(Some(12240), sum_decimal(&a).unwrap());
+
+        let a = Int128Array::from(&[None, None]).to(DataType::Decimal(5, 2));
+        assert_eq!(None, sum_decimal(&a).unwrap());
+
+        let a = Int128Array::from(&[Some(99999), Some(1)]).to(DataType::Decimal(5, 2));
+        assert!(sum_decimal(&a).is_err());
+    }
+
     #[test]
     fn test_primitive_array_sum_large_64() {
         let a: Int64Array = (1..=100)
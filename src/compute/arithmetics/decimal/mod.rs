@@ -3,17 +3,35 @@
 //! precision and scale parameters. These affect the arithmetic operations and
 //! need to be considered while doing operations with Decimal numbers.
 
+use crate::error::{ArrowError, Result};
+
 pub mod add;
 pub mod div;
 pub mod mul;
 pub mod sub;
 
+/// The maximum precision of a decimal, whose values are stored as `i128`.
+pub(crate) const MAX_PRECISION: usize = 38;
+
 /// Maximum value that can exist with a selected precision
 #[inline]
-fn max_value(precision: usize) -> i128 {
+pub(crate) fn max_value(precision: usize) -> i128 {
     10i128.pow(precision as u32) - 1
 }
 
+/// Maximum value that can exist with a selected precision.
+/// # Errors
+/// This function errors if `precision` is larger than 38, the maximum precision of an `i128`.
+pub(crate) fn try_max_value(precision: usize) -> Result<i128> {
+    if precision > MAX_PRECISION {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The precision of a decimal must be at most {}, got {}",
+            MAX_PRECISION, precision
+        )));
+    }
+    Ok(max_value(precision))
+}
+
 // Calculates the number of digits in a i128 number
 fn number_digits(num: i128) -> usize {
     let mut num = num.abs();
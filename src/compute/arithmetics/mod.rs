@@ -65,6 +65,7 @@ use num::Zero;
 
 use crate::datatypes::{DataType, TimeUnit};
 use crate::error::{ArrowError, Result};
+use crate::scalar::{PrimitiveScalar, Scalar};
 use crate::types::NativeType;
 use crate::{array::*, bitmap::Bitmap};
 
@@ -165,6 +166,86 @@ pub fn arithmetic(lhs: &dyn Array, op: Operator, rhs: &dyn Array) -> Result<Box<
     }
 }
 
+// Macro to evaluate match branch in arithmetic_scalar function.
+// The macro is used to downcast the array to a primitive_array_type and the
+// scalar to the corresponding primitive scalar.
+macro_rules! primitive_scalar {
+    ($lhs: expr, $rhs: expr, $op: expr, $array_type: ty) => {{
+        let res_lhs = $lhs.as_any().downcast_ref().unwrap();
+        let res_rhs = $rhs
+            .as_any()
+            .downcast_ref::<PrimitiveScalar<$array_type>>()
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "A scalar of logical type {:?} must be a PrimitiveScalar<{}>",
+                    $rhs.data_type(),
+                    stringify!($array_type)
+                ))
+            })?;
+        arithmetic_primitive_scalar::<$array_type>(res_lhs, $op, &res_rhs.value())
+            .map(Box::new)
+            .map(|x| x as Box<dyn Array>)
+    }};
+}
+
+/// Execute an arithmetic operation between an array and a [`Scalar`]. It uses
+/// the enum Operator to select the type of operation that is going to be
+/// performed. When the scalar is null, the result is an array of nulls.
+///
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::arithmetics::{arithmetic_scalar, Operator};
+/// use arrow2::datatypes::DataType;
+/// use arrow2::scalar::PrimitiveScalar;
+///
+/// let a = Int32Array::from(&[Some(1), None, Some(3)]);
+/// let scalar = PrimitiveScalar::new(DataType::Int32, Some(2i32));
+/// let result = arithmetic_scalar(&a, Operator::Multiply, &scalar).unwrap();
+/// let expected = Int32Array::from(&[Some(2), None, Some(6)]);
+/// assert_eq!(result.as_ref(), &expected as &dyn arrow2::array::Array);
+/// ```
+pub fn arithmetic_scalar(
+    lhs: &dyn Array,
+    op: Operator,
+    rhs: &dyn Scalar,
+) -> Result<Box<dyn Array>> {
+    use DataType::*;
+    let data_type = lhs.data_type();
+    if data_type != rhs.data_type() {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "Arithmetics of ({:?}, {:?}, {:?}) is not supported",
+            data_type,
+            op,
+            rhs.data_type()
+        )));
+    }
+    match data_type {
+        Int8 | Int16 | Int32 | Int64 | Duration(_) | UInt8 | UInt16 | UInt32 | UInt64 | Float32
+        | Float64
+            if !rhs.is_valid() =>
+        {
+            Ok(new_null_array(data_type.clone(), lhs.len()))
+        }
+        Int8 => primitive_scalar!(lhs, rhs, op, i8),
+        Int16 => primitive_scalar!(lhs, rhs, op, i16),
+        Int32 => primitive_scalar!(lhs, rhs, op, i32),
+        Int64 | Duration(_) => primitive_scalar!(lhs, rhs, op, i64),
+        UInt8 => primitive_scalar!(lhs, rhs, op, u8),
+        UInt16 => primitive_scalar!(lhs, rhs, op, u16),
+        UInt32 => primitive_scalar!(lhs, rhs, op, u32),
+        UInt64 => primitive_scalar!(lhs, rhs, op, u64),
+        Float32 => primitive_scalar!(lhs, rhs, op, f32),
+        Float64 => primitive_scalar!(lhs, rhs, op, f64),
+        _ => Err(ArrowError::NotYetImplemented(format!(
+            "Arithmetics of ({:?}, {:?}, {:?}) is not supported",
+            data_type,
+            op,
+            rhs.data_type()
+        ))),
+    }
+}
+
 /// Checks if an array of type `datatype` can perform basic arithmetic
 /// operations. These operations include add, subtract, multiply, divide.
 ///
@@ -486,4 +567,24 @@ mod tests {
             }
         });
     }
+
+    #[test]
+    fn scalar() -> Result<()> {
+        let a = Float64Array::from(&[Some(1.0), None, Some(3.0)]);
+
+        let scalar = PrimitiveScalar::new(DataType::Float64, Some(2.0f64));
+        let result = arithmetic_scalar(&a, Operator::Subtract, &scalar)?;
+        let expected = Float64Array::from(&[Some(-1.0), None, Some(1.0)]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        // a null scalar results in nulls
+        let scalar = PrimitiveScalar::<f64>::new(DataType::Float64, None);
+        let result = arithmetic_scalar(&a, Operator::Add, &scalar)?;
+        assert_eq!(result.null_count(), 3);
+
+        // the scalar must have the same logical type as the array
+        let scalar = PrimitiveScalar::new(DataType::Float32, Some(2.0f32));
+        assert!(arithmetic_scalar(&a, Operator::Add, &scalar).is_err());
+        Ok(())
+    }
 }
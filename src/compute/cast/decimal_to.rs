@@ -0,0 +1,117 @@
+use crate::{
+    array::*, compute::arithmetics::decimal::try_max_value, datatypes::DataType, error::Result,
+    types::NativeType, util::decimal_to_string,
+};
+
+/// Returns the scale of a [`DataType::Decimal`].
+fn decimal_scale(data_type: &DataType) -> usize {
+    if let DataType::Decimal(_, scale) = data_type {
+        *scale
+    } else {
+        panic!("The data type of a decimal array must be a Decimal")
+    }
+}
+
+/// Casts a Decimal [`PrimitiveArray`] to another Decimal with a different precision and scale.
+/// Values are rescaled, truncating digits that do not fit in `to_scale`; values that do not
+/// fit in `to_precision` become null.
+/// # Errors
+/// This function errors if `to_precision` is larger than 38.
+pub fn decimal_to_decimal(
+    from: &PrimitiveArray<i128>,
+    to_precision: usize,
+    to_scale: usize,
+) -> Result<PrimitiveArray<i128>> {
+    let from_scale = decimal_scale(from.data_type());
+    let max = try_max_value(to_precision)?;
+
+    let rescale = |x: i128| -> Option<i128> {
+        if to_scale >= from_scale {
+            x.checked_mul(10i128.checked_pow((to_scale - from_scale) as u32)?)
+        } else {
+            // at least one digit is dropped, so it can't overflow
+            Some(
+                10i128
+                    .checked_pow((from_scale - to_scale) as u32)
+                    .map(|factor| x / factor)
+                    .unwrap_or(0),
+            )
+        }
+    };
+
+    let iter = from.iter().map(|x| {
+        x.and_then(|x| rescale(*x))
+            .filter(|x| x.checked_abs().map(|x| x <= max).unwrap_or(false))
+    });
+    Ok(PrimitiveArray::<i128>::from_trusted_len_iter(iter)
+        .to(DataType::Decimal(to_precision, to_scale)))
+}
+
+pub(super) fn decimal_to_decimal_dyn(
+    from: &dyn Array,
+    to_precision: usize,
+    to_scale: usize,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(decimal_to_decimal(from, to_precision, to_scale)?))
+}
+
+/// Casts a Decimal [`PrimitiveArray`] to a float [`PrimitiveArray`].
+pub fn decimal_to_float<T>(from: &PrimitiveArray<i128>) -> PrimitiveArray<T>
+where
+    T: NativeType + num::Float,
+{
+    let factor = 10f64.powi(decimal_scale(from.data_type()) as i32);
+
+    let iter = from
+        .iter()
+        .map(|x| x.and_then(|x| T::from(*x as f64 / factor)));
+    PrimitiveArray::<T>::from_trusted_len_iter(iter).to(T::DATA_TYPE)
+}
+
+pub(super) fn decimal_to_float_dyn<T>(from: &dyn Array) -> Result<Box<dyn Array>>
+where
+    T: NativeType + num::Float,
+{
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(decimal_to_float::<T>(from)))
+}
+
+/// Casts a Decimal [`PrimitiveArray`] to an integer [`PrimitiveArray`], truncating the fractional
+/// part. Values that do not fit in `T` become null.
+pub fn decimal_to_integer<T>(from: &PrimitiveArray<i128>) -> PrimitiveArray<T>
+where
+    T: NativeType + num::NumCast,
+{
+    let factor = 10i128.checked_pow(decimal_scale(from.data_type()) as u32);
+
+    let iter = from.iter().map(|x| {
+        x.and_then(|x| {
+            let x = factor.map(|factor| *x / factor).unwrap_or(0);
+            num::cast::cast::<i128, T>(x)
+        })
+    });
+    PrimitiveArray::<T>::from_trusted_len_iter(iter).to(T::DATA_TYPE)
+}
+
+pub(super) fn decimal_to_integer_dyn<T>(from: &dyn Array) -> Result<Box<dyn Array>>
+where
+    T: NativeType + num::NumCast,
+{
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(decimal_to_integer::<T>(from)))
+}
+
+/// Returns a [`Utf8Array`] where every element is the string representation of the decimal,
+/// e.g. `"-1.05"`.
+pub fn decimal_to_utf8<O: Offset>(from: &PrimitiveArray<i128>) -> Utf8Array<O> {
+    let scale = decimal_scale(from.data_type());
+
+    let iter = from.iter().map(|x| x.map(|x| decimal_to_string(*x, scale)));
+    Utf8Array::from_trusted_len_iter(iter)
+}
+
+pub(super) fn decimal_to_utf8_dyn<O: Offset>(from: &dyn Array) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(decimal_to_utf8::<O>(from)))
+}
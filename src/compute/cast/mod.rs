@@ -24,6 +24,7 @@ use crate::{
 
 mod binary_to;
 mod boolean_to;
+mod decimal_to;
 mod dictionary_to;
 mod primitive_to;
 mod timestamps;
@@ -31,6 +32,7 @@ mod utf8_to;
 
 pub use binary_to::*;
 pub use boolean_to::*;
+pub use decimal_to::*;
 pub use dictionary_to::*;
 pub use primitive_to::*;
 pub use timestamps::*;
@@ -92,6 +94,14 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Dictionary(_, value_type), _) => can_cast_types(value_type, to_type),
         (_, Dictionary(_, value_type)) => can_cast_types(from_type, value_type),
 
+        (Decimal(_, _), Decimal(_, _)) => true,
+        (Decimal(_, _), Utf8) => true,
+        (Decimal(_, _), LargeUtf8) => true,
+        (Decimal(_, _), _) => is_numeric(to_type),
+        (Utf8, Decimal(_, _)) => true,
+        (LargeUtf8, Decimal(_, _)) => true,
+        (_, Decimal(_, _)) => is_numeric(from_type),
+
         (_, Boolean) => is_numeric(from_type),
         (Boolean, _) => is_numeric(to_type) || to_type == &Utf8,
 
@@ -206,6 +216,11 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Float64, Int32) => true,
         (Float64, Int64) => true,
         (Float64, Float32) => true,
+
+        (Float16, Float32) => true,
+        (Float16, Float64) => true,
+        (Float32, Float16) => true,
+        (Float64, Float16) => true,
         // end numeric casts
 
         // temporal casts
@@ -291,6 +306,9 @@ fn cast_large_to_list(array: &ListArray<i64>, to_type: &DataType) -> ListArray<i
 /// * Time32 and Time64: precision lost when going to higher interval
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
 /// * Temporal to/from backing primitive: zero-copy with data type change
+/// * Decimal to Decimal: values are rescaled, truncating digits beyond the new scale
+/// * Numeric and Utf8 to/from Decimal: values that do not fit in the target's precision or
+///   type return null; floats are rounded and integers are truncated
 ///
 /// Unsupported Casts
 /// * To or from `StructArray`
@@ -367,6 +385,48 @@ pub fn cast(array: &dyn Array, to_type: &DataType) -> Result<Box<dyn Array>> {
                 from_type, to_type,
             ))),
         },
+        (Decimal(_, _), Decimal(to_precision, to_scale)) => {
+            decimal_to_decimal_dyn(array, *to_precision, *to_scale)
+        }
+        (Decimal(_, _), _) => match to_type {
+            UInt8 => decimal_to_integer_dyn::<u8>(array),
+            UInt16 => decimal_to_integer_dyn::<u16>(array),
+            UInt32 => decimal_to_integer_dyn::<u32>(array),
+            UInt64 => decimal_to_integer_dyn::<u64>(array),
+            Int8 => decimal_to_integer_dyn::<i8>(array),
+            Int16 => decimal_to_integer_dyn::<i16>(array),
+            Int32 => decimal_to_integer_dyn::<i32>(array),
+            Int64 => decimal_to_integer_dyn::<i64>(array),
+            Float32 => decimal_to_float_dyn::<f32>(array),
+            Float64 => decimal_to_float_dyn::<f64>(array),
+            Utf8 => decimal_to_utf8_dyn::<i32>(array),
+            LargeUtf8 => decimal_to_utf8_dyn::<i64>(array),
+            _ => Err(ArrowError::NotYetImplemented(format!(
+                "Casting from {:?} to {:?} not supported",
+                from_type, to_type,
+            ))),
+        },
+        (_, Decimal(to_precision, to_scale)) => {
+            let (p, s) = (*to_precision, *to_scale);
+            match from_type {
+                UInt8 => integer_to_decimal_dyn::<u8>(array, p, s),
+                UInt16 => integer_to_decimal_dyn::<u16>(array, p, s),
+                UInt32 => integer_to_decimal_dyn::<u32>(array, p, s),
+                UInt64 => integer_to_decimal_dyn::<u64>(array, p, s),
+                Int8 => integer_to_decimal_dyn::<i8>(array, p, s),
+                Int16 => integer_to_decimal_dyn::<i16>(array, p, s),
+                Int32 => integer_to_decimal_dyn::<i32>(array, p, s),
+                Int64 => integer_to_decimal_dyn::<i64>(array, p, s),
+                Float32 => float_to_decimal_dyn::<f32>(array, p, s),
+                Float64 => float_to_decimal_dyn::<f64>(array, p, s),
+                Utf8 => utf8_to_decimal_dyn::<i32>(array, p, s),
+                LargeUtf8 => utf8_to_decimal_dyn::<i64>(array, p, s),
+                _ => Err(ArrowError::NotYetImplemented(format!(
+                    "Casting from {:?} to {:?} not supported",
+                    from_type, to_type,
+                ))),
+            }
+        }
         (_, Boolean) => match from_type {
             UInt8 => primitive_to_boolean_dyn::<u8>(array),
             UInt16 => primitive_to_boolean_dyn::<u16>(array),
@@ -608,6 +668,11 @@ pub fn cast(array: &dyn Array, to_type: &DataType) -> Result<Box<dyn Array>> {
         (Float64, Int32) => primitive_to_primitive_dyn::<f64, i32>(array, to_type),
         (Float64, Int64) => primitive_to_primitive_dyn::<f64, i64>(array, to_type),
         (Float64, Float32) => primitive_to_primitive_dyn::<f64, f32>(array, to_type),
+
+        (Float16, Float32) => primitive_dyn!(array, f16_to_f32),
+        (Float16, Float64) => primitive_dyn!(array, f16_to_f64),
+        (Float32, Float16) => primitive_dyn!(array, f32_to_f16),
+        (Float64, Float16) => primitive_dyn!(array, f64_to_f16),
         // end numeric casts
 
         // temporal casts
@@ -691,6 +756,7 @@ fn cast_to_dictionary<K: DictionaryKey>(
         DataType::UInt64 => primitive_to_dictionary_dyn::<u64, K>(array),
         DataType::Utf8 => utf8_to_dictionary_dyn::<i32, K>(array),
         DataType::LargeUtf8 => utf8_to_dictionary_dyn::<i64, K>(array),
+        DataType::Decimal(_, _) => primitive_to_dictionary_dyn::<i128, K>(array),
         _ => Err(ArrowError::NotYetImplemented(format!(
             "Unsupported output type for dictionary packing: {:?}",
             dict_value_type
@@ -884,6 +950,8 @@ mod tests {
             Duration(TimeUnit::Microsecond),
             Duration(TimeUnit::Nanosecond),
             List(Box::new(Field::new("a", Utf8, true))),
+            Decimal(10, 2),
+            Float16,
         ];
         datatypes
             .clone()
@@ -904,6 +972,99 @@ mod tests {
             });
     }
 
+    #[test]
+    fn decimal_to_decimal() {
+        let array =
+            Int128Array::from(&[Some(12345), None, Some(-99999)]).to(DataType::Decimal(5, 2));
+
+        let b = cast(&array, &DataType::Decimal(6, 3)).unwrap();
+        let expected =
+            Int128Array::from(&[Some(123450), None, Some(-999990)]).to(DataType::Decimal(6, 3));
+        assert_eq!(expected, b.as_ref());
+
+        // values that do not fit in the precision become null
+        let b = cast(&array, &DataType::Decimal(5, 3)).unwrap();
+        let expected = Int128Array::from(&[None, None, None]).to(DataType::Decimal(5, 3));
+        assert_eq!(expected, b.as_ref());
+
+        let b = cast(&array, &DataType::Decimal(4, 1)).unwrap();
+        let expected =
+            Int128Array::from(&[Some(1234), None, Some(-9999)]).to(DataType::Decimal(4, 1));
+        assert_eq!(expected, b.as_ref());
+    }
+
+    #[test]
+    fn decimal_to_numeric() {
+        let array = Int128Array::from(&[Some(12345), None, Some(-105)]).to(DataType::Decimal(5, 2));
+
+        let b = cast(&array, &DataType::Int8).unwrap();
+        let expected = Int8Array::from(&[Some(123), None, Some(-1)]);
+        assert_eq!(expected, b.as_ref());
+
+        let b = cast(&array, &DataType::Float64).unwrap();
+        let expected = Float64Array::from(&[Some(123.45), None, Some(-1.05)]);
+        assert_eq!(expected, b.as_ref());
+
+        let b = cast(&array, &DataType::Utf8).unwrap();
+        let expected = Utf8Array::<i32>::from([Some("123.45"), None, Some("-1.05")]);
+        assert_eq!(expected, b.as_ref());
+    }
+
+    #[test]
+    fn numeric_to_decimal() {
+        let array = Int32Array::from(&[Some(123), None, Some(-1000)]);
+        let b = cast(&array, &DataType::Decimal(5, 2)).unwrap();
+        let expected = Int128Array::from(&[Some(12300), None, None]).to(DataType::Decimal(5, 2));
+        assert_eq!(expected, b.as_ref());
+
+        let array = Float64Array::from(&[Some(1.005), None, Some(-0.125), Some(f64::NAN)]);
+        let b = cast(&array, &DataType::Decimal(5, 2)).unwrap();
+        let expected =
+            Int128Array::from(&[Some(100), None, Some(-13), None]).to(DataType::Decimal(5, 2));
+        assert_eq!(expected, b.as_ref());
+
+        let array = Utf8Array::<i32>::from([Some("1.05"), None, Some("a"), Some("1000")]);
+        let b = cast(&array, &DataType::Decimal(5, 2)).unwrap();
+        let expected =
+            Int128Array::from(&[Some(105), None, None, None]).to(DataType::Decimal(5, 2));
+        assert_eq!(expected, b.as_ref());
+    }
+
+    #[test]
+    fn decimal_precision_too_large() {
+        let array = Int128Array::from(&[Some(12345), None]).to(DataType::Decimal(5, 2));
+        assert!(cast(&array, &DataType::Decimal(39, 2)).is_err());
+
+        let array = Int32Array::from(&[Some(123), None]);
+        assert!(cast(&array, &DataType::Decimal(39, 2)).is_err());
+
+        let array = Float64Array::from(&[Some(1.5), None]);
+        assert!(cast(&array, &DataType::Decimal(39, 2)).is_err());
+
+        let array = Utf8Array::<i32>::from([Some("1.05"), None]);
+        assert!(cast(&array, &DataType::Decimal(39, 2)).is_err());
+    }
+
+    #[test]
+    fn f16_to_float() {
+        use crate::types::f16;
+
+        let array =
+            Float16Array::from(&[Some(f16::from_f32(1.5)), None, Some(f16::from_f32(-2.0))]);
+        let b = cast(&array, &DataType::Float32).unwrap();
+        let expected = Float32Array::from(&[Some(1.5), None, Some(-2.0)]);
+        assert_eq!(expected, b.as_ref());
+
+        let b = cast(b.as_ref(), &DataType::Float16).unwrap();
+        assert_eq!(array, b.as_ref());
+
+        let array = Float64Array::from(&[Some(1.0e6), Some(0.1)]);
+        let b = cast(&array, &DataType::Float16).unwrap();
+        let expected =
+            Float16Array::from(&[Some(f16::from_bits(0x7C00)), Some(f16::from_bits(0x2E66))]);
+        assert_eq!(expected, b.as_ref());
+    }
+
     /*
     #[test]
     fn test_cast_list_i32_to_list_u16() {
@@ -3,10 +3,10 @@ use std::hash::Hash;
 use crate::{
     array::*,
     bitmap::Bitmap,
-    compute::arity::unary,
+    compute::{arithmetics::decimal::try_max_value, arity::unary},
     datatypes::{DataType, TimeUnit},
     temporal_conversions::*,
-    types::NativeType,
+    types::{f16, NativeType},
 };
 use crate::{error::Result, util::lexical_to_string};
 
@@ -126,6 +126,87 @@ pub fn primitive_to_dictionary<T: NativeType + Eq + Hash, K: DictionaryKey>(
     Ok(array.into())
 }
 
+/// Cast an integer [`PrimitiveArray`] to a Decimal [`PrimitiveArray`] of precision `to_precision`
+/// and scale `to_scale`. Values that do not fit in `to_precision` become null.
+/// # Errors
+/// This function errors if `to_precision` is larger than 38.
+pub fn integer_to_decimal<T>(
+    from: &PrimitiveArray<T>,
+    to_precision: usize,
+    to_scale: usize,
+) -> Result<PrimitiveArray<i128>>
+where
+    T: NativeType + num::NumCast,
+{
+    let factor = 10i128.checked_pow(to_scale as u32);
+    let max = try_max_value(to_precision)?;
+
+    let iter = from.iter().map(|x| {
+        x.and_then(|x| num::cast::cast::<T, i128>(*x))
+            .and_then(|x| x.checked_mul(factor?))
+            .filter(|x| x.checked_abs().map(|x| x <= max).unwrap_or(false))
+    });
+    Ok(PrimitiveArray::<i128>::from_trusted_len_iter(iter)
+        .to(DataType::Decimal(to_precision, to_scale)))
+}
+
+pub(super) fn integer_to_decimal_dyn<T>(
+    from: &dyn Array,
+    to_precision: usize,
+    to_scale: usize,
+) -> Result<Box<dyn Array>>
+where
+    T: NativeType + num::NumCast,
+{
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(integer_to_decimal::<T>(
+        from,
+        to_precision,
+        to_scale,
+    )?))
+}
+
+/// Cast a float [`PrimitiveArray`] to a Decimal [`PrimitiveArray`] of precision `to_precision`
+/// and scale `to_scale`, rounding to the nearest decimal. Values that do not fit in
+/// `to_precision` and non-finite values become null.
+/// # Errors
+/// This function errors if `to_precision` is larger than 38.
+pub fn float_to_decimal<T>(
+    from: &PrimitiveArray<T>,
+    to_precision: usize,
+    to_scale: usize,
+) -> Result<PrimitiveArray<i128>>
+where
+    T: NativeType + num::Float,
+{
+    let factor = 10f64.powi(to_scale as i32);
+    let max = try_max_value(to_precision)?;
+
+    let iter = from.iter().map(|x| {
+        x.and_then(|x| x.to_f64())
+            .and_then(|x| num::cast::cast::<f64, i128>((x * factor).round()))
+            .filter(|x| x.checked_abs().map(|x| x <= max).unwrap_or(false))
+    });
+    Ok(PrimitiveArray::<i128>::from_trusted_len_iter(iter)
+        .to(DataType::Decimal(to_precision, to_scale)))
+}
+
+pub(super) fn float_to_decimal_dyn<T>(
+    from: &dyn Array,
+    to_precision: usize,
+    to_scale: usize,
+) -> Result<Box<dyn Array>>
+where
+    T: NativeType + num::Float,
+{
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(float_to_decimal::<T>(
+        from,
+        to_precision,
+        to_scale,
+    )?))
+}
+
 /// Get the time unit as a multiple of a second
 const fn time_unit_multiple(unit: &TimeUnit) -> i64 {
     match unit {
@@ -136,6 +217,28 @@ const fn time_unit_multiple(unit: &TimeUnit) -> i64 {
     }
 }
 
+/// Casts a [`f16`] [`PrimitiveArray`] to `f32`, which is exact.
+pub fn f16_to_f32(from: &PrimitiveArray<f16>) -> PrimitiveArray<f32> {
+    unary(from, |x| x.to_f32(), DataType::Float32)
+}
+
+/// Casts a [`f16`] [`PrimitiveArray`] to `f64`, which is exact.
+pub fn f16_to_f64(from: &PrimitiveArray<f16>) -> PrimitiveArray<f64> {
+    unary(from, |x| x.to_f64(), DataType::Float64)
+}
+
+/// Casts a `f32` [`PrimitiveArray`] to [`f16`], rounding to the nearest value.
+/// Values too large to be represented become infinite.
+pub fn f32_to_f16(from: &PrimitiveArray<f32>) -> PrimitiveArray<f16> {
+    unary(from, f16::from_f32, DataType::Float16)
+}
+
+/// Casts a `f64` [`PrimitiveArray`] to [`f16`], rounding to the nearest value.
+/// Values too large to be represented become infinite.
+pub fn f64_to_f16(from: &PrimitiveArray<f64>) -> PrimitiveArray<f16> {
+    unary(from, f16::from_f64, DataType::Float16)
+}
+
 pub fn date32_to_date64(from: &PrimitiveArray<i32>) -> PrimitiveArray<i64> {
     unary(from, |x| x as i64 * MILLISECONDS_IN_DAY, DataType::Date64)
 }
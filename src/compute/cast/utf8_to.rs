@@ -9,8 +9,10 @@ use crate::{
     types::NativeType,
 };
 use crate::{
+    compute::arithmetics::decimal::try_max_value,
     error::{ArrowError, Result},
     temporal_conversions::EPOCH_DAYS_FROM_CE,
+    util::parse_decimal,
 };
 
 use super::utf8_to_timestamp_ns_scalar;
@@ -38,6 +40,39 @@ where
     Ok(Box::new(utf8_to_primitive::<O, T>(from, to)))
 }
 
+/// Casts a [`Utf8Array`] to a Decimal [`PrimitiveArray`] of precision `to_precision` and scale
+/// `to_scale`, making any uncastable value or value that does not fit in `to_precision` a Null.
+/// # Errors
+/// This function errors if `to_precision` is larger than 38.
+pub fn utf8_to_decimal<O: Offset>(
+    from: &Utf8Array<O>,
+    to_precision: usize,
+    to_scale: usize,
+) -> Result<PrimitiveArray<i128>> {
+    // validates the precision
+    try_max_value(to_precision)?;
+
+    let iter = from
+        .iter()
+        .map(|x| x.and_then(|x| parse_decimal(x.as_bytes(), to_precision, to_scale)));
+
+    Ok(PrimitiveArray::<i128>::from_trusted_len_iter(iter)
+        .to(DataType::Decimal(to_precision, to_scale)))
+}
+
+pub(super) fn utf8_to_decimal_dyn<O: Offset>(
+    from: &dyn Array,
+    to_precision: usize,
+    to_scale: usize,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(utf8_to_decimal::<O>(
+        from,
+        to_precision,
+        to_scale,
+    )?))
+}
+
 /// Casts a [`Utf8Array`] to a Date32 primitive, making any uncastable value a Null.
 pub fn utf8_to_date32<O: Offset>(from: &Utf8Array<O>) -> PrimitiveArray<i32> {
     let iter = from.iter().map(|x| {
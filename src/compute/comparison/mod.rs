@@ -25,7 +25,8 @@
 use crate::array::*;
 use crate::datatypes::{DataType, IntervalUnit};
 use crate::error::{ArrowError, Result};
-use crate::types::days_ms;
+use crate::scalar::*;
+use crate::types::{days_ms, f16};
 
 mod boolean;
 mod primitive;
@@ -101,7 +102,11 @@ pub fn compare(lhs: &dyn Array, rhs: &dyn Array, operator: Operator) -> Result<B
             let rhs = rhs.as_any().downcast_ref::<UInt64Array>().unwrap();
             primitive::compare(lhs, rhs, operator)
         }
-        DataType::Float16 => unreachable!(),
+        DataType::Float16 => {
+            let lhs = lhs.as_any().downcast_ref::<Float16Array>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<Float16Array>().unwrap();
+            primitive::compare(lhs, rhs, operator)
+        }
         DataType::Float32 => {
             let lhs = lhs.as_any().downcast_ref::<Float32Array>().unwrap();
             let rhs = rhs.as_any().downcast_ref::<Float32Array>().unwrap();
@@ -145,6 +150,113 @@ pub fn compare(lhs: &dyn Array, rhs: &dyn Array, operator: Operator) -> Result<B
     }
 }
 
+/// Downcasts `scalar` to `S`, erroring when the scalar's physical type does not match its
+/// logical type.
+fn downcast_scalar<S: Scalar + 'static>(scalar: &dyn Scalar) -> Result<&S> {
+    scalar.as_any().downcast_ref::<S>().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "A scalar of logical type {:?} must be a {}",
+            scalar.data_type(),
+            std::any::type_name::<S>()
+        ))
+    })
+}
+
+macro_rules! compare_primitive_scalar {
+    ($lhs:expr, $rhs:expr, $op:expr, $type:ty) => {{
+        let lhs = $lhs
+            .as_any()
+            .downcast_ref::<PrimitiveArray<$type>>()
+            .unwrap();
+        let rhs = downcast_scalar::<PrimitiveScalar<$type>>($rhs)?;
+        primitive::compare_scalar(lhs, rhs.value(), $op)
+    }};
+}
+
+macro_rules! compare_utf8_scalar {
+    ($lhs:expr, $rhs:expr, $op:expr, $type:ty) => {{
+        let lhs = $lhs.as_any().downcast_ref::<Utf8Array<$type>>().unwrap();
+        let rhs = downcast_scalar::<Utf8Scalar<$type>>($rhs)?;
+        Ok(utf8::compare_scalar(lhs, rhs.value(), $op))
+    }};
+}
+
+/// Compares all slots of `lhs` against the [`Scalar`] `rhs` using `operator`.
+/// When `rhs` is null, all slots of the result are null.
+/// # Errors
+/// Errors iff `lhs` and `rhs` have different logical types or the logical type is not
+/// supported (see [`can_compare`]).
+/// # Examples
+/// ```
+/// use arrow2::array::{BooleanArray, Int32Array};
+/// use arrow2::compute::comparison::{compare_scalar, Operator};
+/// use arrow2::datatypes::DataType;
+/// use arrow2::scalar::PrimitiveScalar;
+///
+/// let array = Int32Array::from(&[Some(1), None, Some(3)]);
+/// let scalar = PrimitiveScalar::new(DataType::Int32, Some(2i32));
+/// let result = compare_scalar(&array, &scalar, Operator::Gt).unwrap();
+/// assert_eq!(result, BooleanArray::from(&[Some(false), None, Some(true)]));
+/// ```
+pub fn compare_scalar(
+    lhs: &dyn Array,
+    rhs: &dyn Scalar,
+    operator: Operator,
+) -> Result<BooleanArray> {
+    let data_type = lhs.data_type();
+    if data_type != rhs.data_type() {
+        return Err(ArrowError::NotYetImplemented(
+            "Comparison is only supported for the same logical type".to_string(),
+        ));
+    }
+    if !can_compare(data_type) {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "Comparison between {:?} is not supported",
+            data_type
+        )));
+    }
+    if !rhs.is_valid() {
+        return Ok(BooleanArray::new_null(lhs.len()));
+    }
+    match data_type {
+        DataType::Boolean => {
+            let lhs = lhs.as_any().downcast_ref::<BooleanArray>().unwrap();
+            let rhs = downcast_scalar::<BooleanScalar>(rhs)?;
+            boolean::compare_scalar(lhs, rhs.value(), operator)
+        }
+        DataType::Int8 => compare_primitive_scalar!(lhs, rhs, operator, i8),
+        DataType::Int16 => compare_primitive_scalar!(lhs, rhs, operator, i16),
+        DataType::Int32
+        | DataType::Date32
+        | DataType::Time32(_)
+        | DataType::Interval(IntervalUnit::YearMonth) => {
+            compare_primitive_scalar!(lhs, rhs, operator, i32)
+        }
+        DataType::Int64
+        | DataType::Timestamp(_, None)
+        | DataType::Date64
+        | DataType::Time64(_)
+        | DataType::Duration(_) => compare_primitive_scalar!(lhs, rhs, operator, i64),
+        DataType::UInt8 => compare_primitive_scalar!(lhs, rhs, operator, u8),
+        DataType::UInt16 => compare_primitive_scalar!(lhs, rhs, operator, u16),
+        DataType::UInt32 => compare_primitive_scalar!(lhs, rhs, operator, u32),
+        DataType::UInt64 => compare_primitive_scalar!(lhs, rhs, operator, u64),
+        DataType::Float16 => compare_primitive_scalar!(lhs, rhs, operator, f16),
+        DataType::Float32 => compare_primitive_scalar!(lhs, rhs, operator, f32),
+        DataType::Float64 => compare_primitive_scalar!(lhs, rhs, operator, f64),
+        DataType::Interval(IntervalUnit::DayTime) => {
+            compare_primitive_scalar!(lhs, rhs, operator, days_ms)
+        }
+        DataType::Decimal(_, _) => compare_primitive_scalar!(lhs, rhs, operator, i128),
+        DataType::Utf8 => compare_utf8_scalar!(lhs, rhs, operator, i32),
+        DataType::LargeUtf8 => compare_utf8_scalar!(lhs, rhs, operator, i64),
+        _ => Err(ArrowError::NotYetImplemented(format!(
+            "Comparison between {:?} and a scalar is not supported",
+            data_type
+        ))),
+    }
+}
+
 pub use boolean::compare_scalar as boolean_compare_scalar;
 pub use primitive::compare_scalar as primitive_compare_scalar;
 pub(crate) use primitive::compare_values_op as primitive_compare_values_op;
@@ -183,6 +295,7 @@ pub fn can_compare(data_type: &DataType) -> bool {
             | DataType::UInt16
             | DataType::UInt32
             | DataType::UInt64
+            | DataType::Float16
             | DataType::Float32
             | DataType::Float64
             | DataType::Utf8
@@ -245,4 +358,47 @@ mod tests {
             }
         });
     }
+
+    #[test]
+    fn scalar() -> Result<()> {
+        let array = Utf8Array::<i32>::from([Some("a"), None, Some("c")]);
+        let scalar = Utf8Scalar::<i32>::new(Some("b"));
+        let result = compare_scalar(&array, &scalar, Operator::Lt)?;
+        assert_eq!(result, BooleanArray::from(&[Some(true), None, Some(false)]));
+
+        let array = BooleanArray::from(&[Some(true), Some(false)]);
+        let scalar = BooleanScalar::new(Some(true));
+        let result = compare_scalar(&array, &scalar, Operator::Eq)?;
+        assert_eq!(result, BooleanArray::from_slice([true, false]));
+
+        // a null scalar results in nulls
+        let scalar = BooleanScalar::new(None);
+        let result = compare_scalar(&array, &scalar, Operator::Eq)?;
+        assert_eq!(result, BooleanArray::from(&[None, None]));
+
+        // different logical types can't be compared
+        let scalar = PrimitiveScalar::new(DataType::Int32, Some(1i32));
+        assert!(compare_scalar(&array, &scalar, Operator::Eq).is_err());
+
+        // a scalar whose physical type does not match its logical type
+        #[derive(Debug)]
+        struct Other;
+
+        impl Scalar for Other {
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+
+            fn is_valid(&self) -> bool {
+                true
+            }
+
+            fn data_type(&self) -> &DataType {
+                &DataType::Int32
+            }
+        }
+        let array = Int32Array::from_slice([1, 2]);
+        assert!(compare_scalar(&array, &Other, Operator::Eq).is_err());
+        Ok(())
+    }
 }
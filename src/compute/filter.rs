@@ -24,7 +24,7 @@ use crate::{
 use crate::{array::*, bitmap::Bitmap, types::NativeType};
 use crate::{
     bitmap::{utils::SlicesIterator, MutableBitmap},
-    types::days_ms,
+    types::{days_ms, f16},
 };
 use crate::{buffer::MutableBuffer, error::Result};
 
@@ -146,6 +146,9 @@ pub fn build_filter(filter: &BooleanArray) -> Result<Filter> {
         DataType::Interval(IntervalUnit::DayTime) => {
             dyn_build_filter!(days_ms, array, filter_count, chunks)
         }
+        DataType::Float16 => {
+            dyn_build_filter!(f16, array, filter_count, chunks)
+        }
         DataType::Float32 => {
             dyn_build_filter!(f32, array, filter_count, chunks)
         }
@@ -232,6 +235,10 @@ pub fn filter(array: &dyn Array, filter: &BooleanArray) -> Result<Box<dyn Array>
             let array = array.as_any().downcast_ref().unwrap();
             Ok(Box::new(filter_primitive::<days_ms>(array, filter)))
         }
+        DataType::Float16 => {
+            let array = array.as_any().downcast_ref().unwrap();
+            Ok(Box::new(filter_primitive::<f16>(array, filter)))
+        }
         DataType::Float32 => {
             let array = array.as_any().downcast_ref().unwrap();
             Ok(Box::new(filter_primitive::<f32>(array, filter)))
@@ -0,0 +1,398 @@
+use num::ToPrimitive;
+
+use crate::array::{ord::build_compare, *};
+use crate::bitmap::Bitmap;
+use crate::compute::take::take;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+use super::Groups;
+
+/// Returns the validity of the groups given whether each group has a valid value.
+fn validity(is_valid: Vec<bool>) -> Option<Bitmap> {
+    let validity = is_valid.into_iter().collect::<Bitmap>();
+    if validity.null_count() == 0 {
+        None
+    } else {
+        Some(validity)
+    }
+}
+
+/// Errors unless `array` has one row per row of `groups`.
+fn check_length(array: &dyn Array, groups: &Groups) -> Result<()> {
+    if array.len() != groups.ids().len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The array to aggregate must have the same length as the grouped rows ({} != {})",
+            array.len(),
+            groups.ids().len()
+        )));
+    }
+    Ok(())
+}
+
+/// Returns the number of non-null values of each group.
+/// # Errors
+/// This function errors if `array` and `groups` have a different number of rows.
+pub fn count(array: &dyn Array, groups: &Groups) -> Result<PrimitiveArray<u64>> {
+    check_length(array, groups)?;
+    let mut counts = vec![0u64; groups.len()];
+    groups
+        .ids()
+        .iter()
+        .enumerate()
+        .filter(|(row, _)| array.is_valid(*row))
+        .for_each(|(_, id)| counts[*id as usize] += 1);
+    Ok(PrimitiveArray::<u64>::from_data(
+        DataType::UInt64,
+        counts.into(),
+        None,
+    ))
+}
+
+/// Addition used to sum the values of a group: integers wrap around on overflow, like in
+/// [`crate::compute::aggregate::sum`], and floating points follow IEEE 754.
+pub trait GroupSum: NativeType {
+    /// Returns `self + rhs`, wrapping around on overflow.
+    fn group_add(self, rhs: Self) -> Self;
+}
+
+macro_rules! group_sum_wrapping {
+    ($($ty:ty),*) => {
+        $(impl GroupSum for $ty {
+            #[inline]
+            fn group_add(self, rhs: Self) -> Self {
+                self.wrapping_add(rhs)
+            }
+        })*
+    };
+}
+
+group_sum_wrapping!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl GroupSum for f32 {
+    #[inline]
+    fn group_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+}
+
+impl GroupSum for f64 {
+    #[inline]
+    fn group_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+}
+
+/// Returns the sum of the values of each group.
+/// The sum of a group whose values are all null is null. Integer sums that overflow wrap around.
+/// # Errors
+/// This function errors if `array` and `groups` have a different number of rows.
+pub fn sum_primitive<T>(array: &PrimitiveArray<T>, groups: &Groups) -> Result<PrimitiveArray<T>>
+where
+    T: GroupSum,
+{
+    check_length(array, groups)?;
+    let mut sums = vec![T::default(); groups.len()];
+    let mut is_valid = vec![false; groups.len()];
+    groups
+        .ids()
+        .iter()
+        .zip(array.iter())
+        .for_each(|(id, value)| {
+            if let Some(value) = value {
+                let id = *id as usize;
+                sums[id] = sums[id].group_add(*value);
+                is_valid[id] = true;
+            }
+        });
+    Ok(PrimitiveArray::<T>::from_data(
+        array.data_type().clone(),
+        sums.into(),
+        validity(is_valid),
+    ))
+}
+
+/// Returns the arithmetic mean of the values of each group.
+/// The mean of a group whose values are all null is null.
+/// # Errors
+/// This function errors if `array` and `groups` have a different number of rows.
+pub fn mean_primitive<T>(array: &PrimitiveArray<T>, groups: &Groups) -> Result<PrimitiveArray<f64>>
+where
+    T: NativeType + ToPrimitive,
+{
+    check_length(array, groups)?;
+    let mut sums = vec![0.0f64; groups.len()];
+    let mut counts = vec![0usize; groups.len()];
+    groups
+        .ids()
+        .iter()
+        .zip(array.iter())
+        .for_each(|(id, value)| {
+            if let Some(value) = value {
+                let id = *id as usize;
+                sums[id] += value.to_f64().unwrap();
+                counts[id] += 1;
+            }
+        });
+    let is_valid = counts.iter().map(|count| *count > 0).collect();
+    let means = sums
+        .into_iter()
+        .zip(counts)
+        .map(|(sum, count)| if count > 0 { sum / count as f64 } else { 0.0 })
+        .collect::<Vec<_>>();
+    Ok(PrimitiveArray::<f64>::from_data(
+        DataType::Float64,
+        means.into(),
+        validity(is_valid),
+    ))
+}
+
+macro_rules! dyn_sum {
+    ($ty:ty, $array:expr, $groups:expr) => {{
+        let array = $array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<$ty>>()
+            .unwrap();
+        Box::new(sum_primitive::<$ty>(array, $groups)?)
+    }};
+}
+
+/// Returns the sum of the values of each group of `array`, whose type is the type of `array`.
+/// The sum of a group whose values are all null is null. Integer sums that overflow wrap around.
+/// # Errors
+/// This function errors if the type of `array` is not numeric or if `array` and `groups` have
+/// a different number of rows.
+pub fn sum(array: &dyn Array, groups: &Groups) -> Result<Box<dyn Array>> {
+    use DataType::*;
+    Ok(match array.data_type() {
+        Int8 => dyn_sum!(i8, array, groups),
+        Int16 => dyn_sum!(i16, array, groups),
+        Int32 => dyn_sum!(i32, array, groups),
+        Int64 | Duration(_) => dyn_sum!(i64, array, groups),
+        UInt8 => dyn_sum!(u8, array, groups),
+        UInt16 => dyn_sum!(u16, array, groups),
+        UInt32 => dyn_sum!(u32, array, groups),
+        UInt64 => dyn_sum!(u64, array, groups),
+        Float32 => dyn_sum!(f32, array, groups),
+        Float64 => dyn_sum!(f64, array, groups),
+        Decimal(_, _) => dyn_sum!(i128, array, groups),
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Grouped sum of type {:?} is not supported",
+                other
+            )))
+        }
+    })
+}
+
+macro_rules! dyn_mean {
+    ($ty:ty, $array:expr, $groups:expr) => {{
+        let array = $array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<$ty>>()
+            .unwrap();
+        mean_primitive::<$ty>(array, $groups)?
+    }};
+}
+
+/// Returns the arithmetic mean of the values of each group of `array`.
+/// The mean of a group whose values are all null is null.
+/// # Errors
+/// This function errors if `array` is not an integer or floating point array or if `array` and
+/// `groups` have a different number of rows.
+pub fn mean(array: &dyn Array, groups: &Groups) -> Result<PrimitiveArray<f64>> {
+    use DataType::*;
+    Ok(match array.data_type() {
+        Int8 => dyn_mean!(i8, array, groups),
+        Int16 => dyn_mean!(i16, array, groups),
+        Int32 => dyn_mean!(i32, array, groups),
+        Int64 => dyn_mean!(i64, array, groups),
+        UInt8 => dyn_mean!(u8, array, groups),
+        UInt16 => dyn_mean!(u16, array, groups),
+        UInt32 => dyn_mean!(u32, array, groups),
+        UInt64 => dyn_mean!(u64, array, groups),
+        Float32 => dyn_mean!(f32, array, groups),
+        Float64 => dyn_mean!(f64, array, groups),
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Grouped mean of type {:?} is not supported",
+                other
+            )))
+        }
+    })
+}
+
+/// Selects, for each group, the index of a non-null row of `array`. A row replaces the current
+/// selection of its group when `replace(row, current)` is true.
+/// Groups without non-null values select null.
+fn select<F: Fn(usize, usize) -> bool>(
+    array: &dyn Array,
+    groups: &Groups,
+    replace: F,
+) -> Result<PrimitiveArray<u32>> {
+    check_length(array, groups)?;
+    let mut selected = vec![None; groups.len()];
+    groups
+        .ids()
+        .iter()
+        .enumerate()
+        .filter(|(row, _)| array.is_valid(*row))
+        .for_each(|(row, id)| {
+            let current = &mut selected[*id as usize];
+            match current {
+                Some(index) if !replace(row, *index as usize) => {}
+                _ => *current = Some(row as u32),
+            }
+        });
+    Ok(PrimitiveArray::<u32>::from(selected).to(DataType::UInt32))
+}
+
+/// Returns the minimum value of each group of `array`, according to its natural order.
+/// For floating point arrays NaN values are considered to be greater than any other non-null
+/// value. The minimum of a group whose values are all null is null.
+/// # Errors
+/// This function errors if the type of `array` has no natural order or if `array` and `groups`
+/// have a different number of rows.
+pub fn min(array: &dyn Array, groups: &Groups) -> Result<Box<dyn Array>> {
+    let compare = build_compare(array, array)?;
+    let indices = select(array, groups, |row, current| {
+        compare(row, current) == std::cmp::Ordering::Less
+    })?;
+    take(array, &indices)
+}
+
+/// Returns the maximum value of each group of `array`, according to its natural order.
+/// For floating point arrays NaN values are considered to be greater than any other non-null
+/// value. The maximum of a group whose values are all null is null.
+/// # Errors
+/// This function errors if the type of `array` has no natural order or if `array` and `groups`
+/// have a different number of rows.
+pub fn max(array: &dyn Array, groups: &Groups) -> Result<Box<dyn Array>> {
+    let compare = build_compare(array, array)?;
+    let indices = select(array, groups, |row, current| {
+        compare(row, current) == std::cmp::Ordering::Greater
+    })?;
+    take(array, &indices)
+}
+
+/// Returns the first non-null value of each group of `array`, or null if all its values are null.
+/// # Errors
+/// This function errors if `array` and `groups` have a different number of rows.
+pub fn first(array: &dyn Array, groups: &Groups) -> Result<Box<dyn Array>> {
+    let indices = select(array, groups, |_, _| false)?;
+    take(array, &indices)
+}
+
+/// Returns the last non-null value of each group of `array`, or null if all its values are null.
+/// # Errors
+/// This function errors if `array` and `groups` have a different number of rows.
+pub fn last(array: &dyn Array, groups: &Groups) -> Result<Box<dyn Array>> {
+    let indices = select(array, groups, |_, _| true)?;
+    take(array, &indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::group_by;
+    use super::*;
+
+    fn groups() -> Groups {
+        let keys = Utf8Array::<i32>::from_slice(["a", "b", "a", "c", "b", "a"]);
+        group_by(&[&keys]).unwrap()
+    }
+
+    #[test]
+    fn sum_and_count() -> Result<()> {
+        let groups = groups();
+        let values = Int32Array::from([Some(1), Some(2), None, None, Some(5), Some(6)]);
+
+        let result = sum(&values, &groups)?;
+        let expected = Int32Array::from([Some(7), Some(7), None]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let result = count(&values, &groups)?;
+        assert_eq!(result, UInt64Array::from_slice([2, 2, 0]));
+        Ok(())
+    }
+
+    #[test]
+    fn sum_overflow() -> Result<()> {
+        let groups = groups();
+        let values = Int8Array::from_slice([i8::MAX, i8::MIN, 1, 0, -1, 1]);
+
+        let result = sum(&values, &groups)?;
+        let expected = Int8Array::from_slice([i8::MIN + 1, i8::MAX, 0]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+        Ok(())
+    }
+
+    #[test]
+    fn wrong_length() {
+        let groups = groups();
+        let values = Int32Array::from_slice([1, 2, 3]);
+        assert!(count(&values, &groups).is_err());
+        assert!(sum(&values, &groups).is_err());
+        assert!(mean(&values, &groups).is_err());
+        assert!(min(&values, &groups).is_err());
+        assert!(first(&values, &groups).is_err());
+    }
+
+    #[test]
+    fn mean_() -> Result<()> {
+        let groups = groups();
+        let values = Float32Array::from([Some(1.0), Some(2.0), Some(2.0), None, None, Some(6.0)]);
+
+        let result = mean(&values, &groups)?;
+        assert_eq!(result, Float64Array::from([Some(3.0), Some(2.0), None]));
+
+        let values = Utf8Array::<i32>::from_slice(["a", "b", "a", "c", "b", "a"]);
+        assert!(mean(&values, &groups).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn min_max() -> Result<()> {
+        let groups = groups();
+        let values = Float64Array::from([
+            Some(1.0),
+            Some(f64::NAN),
+            Some(-1.0),
+            None,
+            Some(5.0),
+            Some(6.0),
+        ]);
+
+        let result = min(&values, &groups)?;
+        let expected = Float64Array::from([Some(-1.0), Some(5.0), None]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let result = max(&values, &groups)?;
+        let result = result.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(result.value(0), 6.0);
+        assert!(result.value(1).is_nan());
+        assert!(result.is_null(2));
+
+        let values = Utf8Array::<i32>::from([Some("b"), None, Some("a"), Some("c"), None, None]);
+        let result = max(&values, &groups)?;
+        let expected = Utf8Array::<i32>::from([Some("b"), None, Some("c")]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+        Ok(())
+    }
+
+    #[test]
+    fn first_last() -> Result<()> {
+        let groups = groups();
+        let values =
+            BooleanArray::from([None, Some(true), Some(false), None, Some(false), Some(true)]);
+
+        let result = first(&values, &groups)?;
+        let expected = BooleanArray::from([Some(false), Some(true), None]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let result = last(&values, &groups)?;
+        let expected = BooleanArray::from([Some(true), Some(false), None]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+        Ok(())
+    }
+}
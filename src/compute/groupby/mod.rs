@@ -0,0 +1,414 @@
+//! Defines hash-based group-by kernels.
+//!
+//! Grouping maps the rows of one or more key columns to dense group ids via [`group_by`].
+//! The resulting [`Groups`] are then used by the grouped aggregations of this module
+//! (e.g. [`sum`]) to compute one value per group.
+//!
+//! # Example
+//! ```
+//! use arrow2::array::{Array, Int32Array, Utf8Array};
+//! use arrow2::compute::groupby::{group_by, sum};
+//! use arrow2::compute::take::take;
+//!
+//! let keys = Utf8Array::<i32>::from([Some("a"), Some("b"), Some("a"), None]);
+//! let values = Int32Array::from([Some(1), Some(2), Some(3), Some(4)]);
+//!
+//! let groups = group_by(&[&keys]).unwrap();
+//! assert_eq!(groups.ids(), &[0, 1, 0, 2]);
+//!
+//! // the key of each group
+//! let keys = take(&keys, groups.indices()).unwrap();
+//! assert_eq!(keys.as_ref(), &Utf8Array::<i32>::from([Some("a"), Some("b"), None]) as &dyn Array);
+//!
+//! let sums = sum(&values, &groups).unwrap();
+//! assert_eq!(sums.as_ref(), &Int32Array::from([Some(4), Some(2), Some(4)]) as &dyn Array);
+//! ```
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::array::*;
+use crate::bitmap::Bitmap;
+use crate::datatypes::{DataType, IntervalUnit};
+use crate::error::{ArrowError, Result};
+use crate::types::{days_ms, f16};
+
+mod aggregate;
+pub use aggregate::*;
+
+/// The code of a null key. Codes of valid keys are always smaller than the number of rows.
+const NULL_CODE: u32 = u32::MAX;
+
+/// The result of grouping rows by one or more key columns: every row is assigned to a dense
+/// group id in `[0, len)`, in the order in which the groups first appear.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Groups {
+    ids: Vec<u32>,
+    indices: PrimitiveArray<u32>,
+}
+
+impl Groups {
+    /// The group id of each row.
+    #[inline]
+    pub fn ids(&self) -> &[u32] {
+        &self.ids
+    }
+
+    /// The index of the first row of each group, which can be used to [`take`](crate::compute::take::take)
+    /// the keys of each group.
+    #[inline]
+    pub fn indices(&self) -> &PrimitiveArray<u32> {
+        &self.indices
+    }
+
+    /// The number of groups.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Whether there are no groups, which only happens when there are no rows.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Maps every value of `iter` to a code such that equal values have equal codes.
+/// Nulls are mapped to [`NULL_CODE`].
+fn hash_codes<K: Hash + Eq, I: Iterator<Item = Option<K>>>(iter: I) -> Vec<u32> {
+    let mut map = HashMap::<K, u32>::new();
+    iter.map(|value| match value {
+        Some(value) => {
+            let code = map.len() as u32;
+            *map.entry(value).or_insert(code)
+        }
+        None => NULL_CODE,
+    })
+    .collect()
+}
+
+macro_rules! primitive_codes {
+    ($array:expr, $type:ty) => {{
+        let array = $array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<$type>>()
+            .unwrap();
+        hash_codes(array.iter().map(|x| x.copied()))
+    }};
+}
+
+// floats are grouped by their bit representation, with all zeros and all NaNs being equal
+macro_rules! float_codes {
+    ($array:expr, $type:ty) => {
+        float_codes!($array, $type, $type, |x: &$type| *x)
+    };
+    // `$to_float` converts values of `$type` to the float `$float` whose bits are hashed
+    ($array:expr, $type:ty, $float:ty, $to_float:expr) => {{
+        let array = $array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<$type>>()
+            .unwrap();
+        hash_codes(array.iter().map(|x| {
+            x.map(|x| {
+                let x: $float = ($to_float)(x);
+                if x.is_nan() {
+                    <$float>::NAN.to_bits()
+                } else if x == 0.0 {
+                    (0.0 as $float).to_bits()
+                } else {
+                    x.to_bits()
+                }
+            })
+        }))
+    }};
+}
+
+macro_rules! dictionary_codes {
+    ($array:expr, $type:ty) => {{
+        let array = $array
+            .as_any()
+            .downcast_ref::<DictionaryArray<$type>>()
+            .unwrap();
+        // keys are mapped to the codes of their values, so that repeated values are equal
+        let values = codes(array.values().as_ref())?;
+        array
+            .keys()
+            .iter()
+            .map(|key| key.map(|key| values[*key as usize]).unwrap_or(NULL_CODE))
+            .collect()
+    }};
+}
+
+/// Returns a code for every row of `array` such that rows with equal values have equal codes.
+fn codes(array: &dyn Array) -> Result<Vec<u32>> {
+    use DataType::*;
+    Ok(match array.data_type() {
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            array
+                .iter()
+                .map(|x| x.map(|x| x as u32).unwrap_or(NULL_CODE))
+                .collect()
+        }
+        Int8 => primitive_codes!(array, i8),
+        Int16 => primitive_codes!(array, i16),
+        Int32 | Date32 | Time32(_) | Interval(IntervalUnit::YearMonth) => {
+            primitive_codes!(array, i32)
+        }
+        Int64 | Date64 | Time64(_) | Timestamp(_, _) | Duration(_) => {
+            primitive_codes!(array, i64)
+        }
+        Interval(IntervalUnit::DayTime) => primitive_codes!(array, days_ms),
+        Decimal(_, _) => primitive_codes!(array, i128),
+        UInt8 => primitive_codes!(array, u8),
+        UInt16 => primitive_codes!(array, u16),
+        UInt32 => primitive_codes!(array, u32),
+        UInt64 => primitive_codes!(array, u64),
+        Float16 => float_codes!(array, f16, f32, |x: &f16| x.to_f32()),
+        Float32 => float_codes!(array, f32),
+        Float64 => float_codes!(array, f64),
+        Utf8 => hash_codes(
+            array
+                .as_any()
+                .downcast_ref::<Utf8Array<i32>>()
+                .unwrap()
+                .iter(),
+        ),
+        LargeUtf8 => hash_codes(
+            array
+                .as_any()
+                .downcast_ref::<Utf8Array<i64>>()
+                .unwrap()
+                .iter(),
+        ),
+        Binary => hash_codes(
+            array
+                .as_any()
+                .downcast_ref::<BinaryArray<i32>>()
+                .unwrap()
+                .iter(),
+        ),
+        LargeBinary => hash_codes(
+            array
+                .as_any()
+                .downcast_ref::<BinaryArray<i64>>()
+                .unwrap()
+                .iter(),
+        ),
+        Dictionary(key_type, _) => match key_type.as_ref() {
+            Int8 => dictionary_codes!(array, i8),
+            Int16 => dictionary_codes!(array, i16),
+            Int32 => dictionary_codes!(array, i32),
+            Int64 => dictionary_codes!(array, i64),
+            UInt8 => dictionary_codes!(array, u8),
+            UInt16 => dictionary_codes!(array, u16),
+            UInt32 => dictionary_codes!(array, u32),
+            UInt64 => dictionary_codes!(array, u64),
+            _ => unreachable!(),
+        },
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Grouping by keys of type {:?} is not supported",
+                other
+            )))
+        }
+    })
+}
+
+fn dictionary_validity<K: DictionaryKey>(key: &dyn Array) -> Option<Bitmap> {
+    let key = key.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    let values = key.values();
+    if values.null_count() == 0 {
+        return key.keys().validity().clone();
+    }
+    Some(
+        key.keys()
+            .iter()
+            .map(|x| match x {
+                Some(x) => values.is_valid(x.to_usize().unwrap()),
+                None => false,
+            })
+            .collect(),
+    )
+}
+
+/// Returns the validity of a key column, where the slots of a dictionary whose value is null
+/// are null.
+pub(crate) fn key_validity(key: &dyn Array) -> Option<Bitmap> {
+    match key.data_type() {
+        DataType::Dictionary(key_type, _) => match key_type.as_ref() {
+            DataType::Int8 => dictionary_validity::<i8>(key),
+            DataType::Int16 => dictionary_validity::<i16>(key),
+            DataType::Int32 => dictionary_validity::<i32>(key),
+            DataType::Int64 => dictionary_validity::<i64>(key),
+            DataType::UInt8 => dictionary_validity::<u8>(key),
+            DataType::UInt16 => dictionary_validity::<u16>(key),
+            DataType::UInt32 => dictionary_validity::<u32>(key),
+            DataType::UInt64 => dictionary_validity::<u64>(key),
+            _ => key.validity().clone(),
+        },
+        _ => key.validity().clone(),
+    }
+}
+
+/// Groups the rows of `keys`, returning the dense group id of every row.
+/// Two rows belong to the same group iff all their keys are equal. Nulls are equal to each other
+/// and form their own group.
+///
+/// Supported key types:
+/// * Boolean
+/// * All primitive types
+/// * `[Large]Utf8` and `[Large]Binary`
+/// * `Dictionary` of the above, grouped by value
+/// # Errors
+/// This function errors if `keys` is empty, if the keys have different lengths or if the type of
+/// a key is not supported.
+pub fn group_by(keys: &[&dyn Array]) -> Result<Groups> {
+    let length = keys
+        .first()
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError("Group by requires at least one key".to_string())
+        })?
+        .len();
+    if keys.iter().any(|key| key.len() != length) {
+        return Err(ArrowError::InvalidArgumentError(
+            "All keys of a group by must have the same length".to_string(),
+        ));
+    }
+
+    let mut ids = vec![0u32; length];
+    let mut indices = vec![];
+    for key in keys {
+        let codes = codes(*key)?;
+
+        // the new group of a row is determined by its previous group and the code of this key
+        let mut map = HashMap::<(u32, u32), u32>::new();
+        indices.clear();
+        ids.iter_mut()
+            .zip(codes.iter())
+            .enumerate()
+            .for_each(|(row, (id, code))| {
+                let next = indices.len() as u32;
+                *id = *map.entry((*id, *code)).or_insert_with(|| {
+                    indices.push(row as u32);
+                    next
+                });
+            });
+    }
+
+    Ok(Groups {
+        ids,
+        indices: PrimitiveArray::<u32>::from_data(DataType::UInt32, indices.into(), None),
+    })
+}
+
+/// Checks if an array of type `data_type` can be used as a key of [`group_by`].
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::groupby::can_group_by;
+/// use arrow2::datatypes::DataType;
+///
+/// assert_eq!(can_group_by(&DataType::Utf8), true);
+/// assert_eq!(can_group_by(&DataType::Null), false);
+/// ```
+pub fn can_group_by(data_type: &DataType) -> bool {
+    use DataType::*;
+    match data_type {
+        Dictionary(_, values) => can_group_by(values),
+        _ => matches!(
+            data_type,
+            Boolean
+                | Int8
+                | Int16
+                | Int32
+                | Date32
+                | Time32(_)
+                | Interval(_)
+                | Int64
+                | Date64
+                | Time64(_)
+                | Timestamp(_, _)
+                | Duration(_)
+                | Decimal(_, _)
+                | UInt8
+                | UInt16
+                | UInt32
+                | UInt64
+                | Float16
+                | Float32
+                | Float64
+                | Utf8
+                | LargeUtf8
+                | Binary
+                | LargeBinary
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[test]
+    fn single_key() -> Result<()> {
+        let keys = Int32Array::from([Some(3), None, Some(1), Some(3), None]);
+        let groups = group_by(&[&keys])?;
+        assert_eq!(groups.ids(), &[0, 1, 2, 0, 1]);
+        assert_eq!(groups.indices(), &UInt32Array::from_slice([0, 1, 2]));
+        assert_eq!(groups.len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn multiple_keys() -> Result<()> {
+        let a = Utf8Array::<i32>::from_slice(["a", "a", "b", "a", "b"]);
+        let b = BooleanArray::from([Some(true), Some(false), Some(true), Some(true), None]);
+        let groups = group_by(&[&a, &b])?;
+        assert_eq!(groups.ids(), &[0, 1, 2, 0, 3]);
+        assert_eq!(groups.indices(), &UInt32Array::from_slice([0, 1, 2, 4]));
+        Ok(())
+    }
+
+    #[test]
+    fn floats() -> Result<()> {
+        let keys = Float64Array::from_slice([0.0, -0.0, f64::NAN, 1.0, -f64::NAN]);
+        let groups = group_by(&[&keys])?;
+        assert_eq!(groups.ids(), &[0, 0, 1, 2, 1]);
+        Ok(())
+    }
+
+    #[test]
+    fn dictionary() -> Result<()> {
+        // the dictionary contains repeated values, which belong to the same group
+        let values = Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "a"])) as Arc<dyn Array>;
+        let keys = Int8Array::from([Some(0), Some(1), Some(2), None]);
+        let array = DictionaryArray::<i8>::from_data(keys, values);
+        let groups = group_by(&[&array])?;
+        assert_eq!(groups.ids(), &[0, 1, 0, 2]);
+        Ok(())
+    }
+
+    #[test]
+    fn errors() {
+        assert!(group_by(&[]).is_err());
+
+        let a = Int32Array::from_slice([1, 2]);
+        let b = Int32Array::from_slice([1]);
+        assert!(group_by(&[&a, &b]).is_err());
+
+        let a = NullArray::from_data(2);
+        assert!(group_by(&[&a]).is_err());
+    }
+
+    #[test]
+    fn empty() -> Result<()> {
+        let keys = Int32Array::from_slice([]);
+        let groups = group_by(&[&keys])?;
+        assert!(groups.is_empty());
+        assert!(groups.ids().is_empty());
+        Ok(())
+    }
+}
@@ -83,7 +83,7 @@ macro_rules! hash_dyn {
 /// Returns the element-wise hash of an [`Array`]. Validity is preserved.
 /// Supported DataTypes:
 /// * Boolean types
-/// * All primitive types except `Float32` and `Float64`
+/// * All primitive types except `Float16`, `Float32` and `Float64`
 /// * `[Large]Utf8`;
 /// * `[Large]Binary`.
 /// # Errors
@@ -108,7 +108,6 @@ pub fn hash(array: &dyn Array) -> Result<PrimitiveArray<u64>> {
         DataType::UInt16 => hash_dyn!(u16, array),
         DataType::UInt32 => hash_dyn!(u32, array),
         DataType::UInt64 => hash_dyn!(u64, array),
-        DataType::Float16 => unreachable!(),
         DataType::Binary => hash_binary::<i32>(array.as_any().downcast_ref().unwrap()),
         DataType::LargeBinary => hash_binary::<i64>(array.as_any().downcast_ref().unwrap()),
         DataType::Utf8 => hash_utf8::<i32>(array.as_any().downcast_ref().unwrap()),
@@ -155,7 +154,6 @@ pub fn can_hash(data_type: &DataType) -> bool {
             | DataType::UInt16
             | DataType::UInt32
             | DataType::UInt64
-            | DataType::Float16
             | DataType::Binary
             | DataType::LargeBinary
             | DataType::Utf8
@@ -0,0 +1,265 @@
+//! Defines hash join kernels.
+//!
+//! The kernels of this module compute which rows of two tables match on one or more key columns
+//! and return the indices of the matching rows. The joined table can then be materialized by
+//! [`take`](crate::compute::take::take)-ing every column of each table with its indices.
+//!
+//! # Example
+//! ```
+//! use arrow2::array::{Int32Array, UInt32Array};
+//! use arrow2::compute::join::{join, JoinType};
+//!
+//! let left = Int32Array::from(&[Some(1), Some(2), None]);
+//! let right = Int32Array::from(&[Some(2), Some(3), None, Some(1)]);
+//!
+//! let (left_indices, right_indices) = join::<u32>(&[&left], &[&right], JoinType::Left).unwrap();
+//! assert_eq!(left_indices, UInt32Array::from(&[Some(1), Some(0), Some(2)]));
+//! assert_eq!(right_indices, UInt32Array::from(&[Some(0), Some(3), None]));
+//! ```
+use std::convert::TryFrom;
+
+use crate::array::*;
+use crate::bitmap::Bitmap;
+use crate::error::{ArrowError, Result};
+use crate::types::NaturalDataType;
+
+use super::concat::concatenate;
+use super::groupby::{group_by, key_validity};
+
+/// The type of a join.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JoinType {
+    /// Pairs of matching rows of both tables.
+    Inner,
+    /// [`JoinType::Inner`] plus the rows of the left table without a match.
+    Left,
+    /// [`JoinType::Inner`] plus the rows of the right table without a match.
+    Right,
+    /// [`JoinType::Inner`] plus the rows of either table without a match.
+    Full,
+    /// The rows of the left table with at least one match.
+    Semi,
+    /// The rows of the left table without a match.
+    Anti,
+}
+
+/// Returns whether all keys of each row are valid. Rows with a null key never match.
+fn keys_validity(keys: &[&dyn Array]) -> Option<Bitmap> {
+    keys.iter()
+        .filter_map(|key| key_validity(*key))
+        .fold(None, |acc, validity| match acc {
+            None => Some(validity),
+            Some(acc) => Some(&acc & &validity),
+        })
+}
+
+fn to_index<I: Index + TryFrom<usize>>(index: usize) -> Result<I> {
+    I::try_from(index).map_err(|_| {
+        ArrowError::InvalidArgumentError(
+            "The number of rows of a join exceeds the capacity of its index type".to_string(),
+        )
+    })
+}
+
+fn to_indices<I: Index + NaturalDataType + TryFrom<usize>>(
+    indices: Vec<Option<usize>>,
+) -> Result<PrimitiveArray<I>> {
+    let indices = indices
+        .into_iter()
+        .map(|index| index.map(to_index::<I>).transpose())
+        .collect::<Result<Vec<_>>>()?;
+    Ok(PrimitiveArray::<I>::from(indices).to(I::DATA_TYPE))
+}
+
+/// Joins the rows of two tables whose keys are `left` and `right` according to `join_type`,
+/// returning the indices of the joined rows of the left and right table respectively.
+///
+/// The keys of both tables are hashed together by [`group_by`], so that equal keys share a
+/// group id, and each row of `right` is matched with the rows of `left` of its group. Its cost
+/// is linear in the total number of rows of both tables, since all their keys are hashed.
+/// Matches are returned in the order of the rows of `right`, followed by the rows of `left`
+/// without a match for [`JoinType::Left`] and [`JoinType::Full`].
+/// The indices of the rows without a match in the other table are null.
+/// For [`JoinType::Semi`] and [`JoinType::Anti`] only the indices of the left table are
+/// returned and the indices of the right table are empty.
+///
+/// Like in SQL, a row with a null key does not match any row, including other rows with null keys.
+/// A dictionary key whose value is null is a null key.
+/// # Errors
+/// This function errors if the number of key columns of both tables differ, if their types
+/// differ or are not supported by [`group_by`], or if the number of rows does not fit in `I`.
+pub fn join<I: Index + NaturalDataType + TryFrom<usize>>(
+    left: &[&dyn Array],
+    right: &[&dyn Array],
+    join_type: JoinType,
+) -> Result<(PrimitiveArray<I>, PrimitiveArray<I>)> {
+    if left.len() != right.len() || left.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "A join requires the same, non-zero, number of keys on both sides".to_string(),
+        ));
+    }
+
+    // group the keys of both tables together so that equal keys share a group id
+    let keys = left
+        .iter()
+        .zip(right.iter())
+        .map(|(left, right)| concatenate(&[*left, *right]))
+        .collect::<Result<Vec<_>>>()?;
+    let keys = keys.iter().map(|key| key.as_ref()).collect::<Vec<_>>();
+    let groups = group_by(&keys)?;
+
+    let left_length = left[0].len();
+    let (left_ids, right_ids) = groups.ids().split_at(left_length);
+    let validity = keys_validity(&keys);
+    let is_valid = |row: usize| validity.as_ref().map(|x| x.get_bit(row)).unwrap_or(true);
+
+    // the hash table: the rows of the left table of each group
+    let mut table = vec![vec![]; groups.len()];
+    left_ids
+        .iter()
+        .enumerate()
+        .filter(|(row, _)| is_valid(*row))
+        .for_each(|(row, id)| table[*id as usize].push(row));
+
+    let mut matched = vec![false; left_length];
+    let mut left_indices = vec![];
+    let mut right_indices = vec![];
+    for (row, id) in right_ids.iter().enumerate() {
+        let rows = if is_valid(left_length + row) {
+            table[*id as usize].as_slice()
+        } else {
+            &[]
+        };
+        rows.iter().for_each(|left_row| matched[*left_row] = true);
+
+        match join_type {
+            JoinType::Inner | JoinType::Left | JoinType::Right | JoinType::Full => {
+                rows.iter().for_each(|left_row| {
+                    left_indices.push(Some(*left_row));
+                    right_indices.push(Some(row));
+                });
+                if rows.is_empty() && matches!(join_type, JoinType::Right | JoinType::Full) {
+                    left_indices.push(None);
+                    right_indices.push(Some(row));
+                }
+            }
+            JoinType::Semi | JoinType::Anti => {}
+        }
+    }
+
+    match join_type {
+        JoinType::Left | JoinType::Full => {
+            matched
+                .iter()
+                .enumerate()
+                .filter(|(_, matched)| !**matched)
+                .for_each(|(row, _)| {
+                    left_indices.push(Some(row));
+                    right_indices.push(None);
+                });
+        }
+        JoinType::Semi | JoinType::Anti => {
+            let is_semi = join_type == JoinType::Semi;
+            left_indices = matched
+                .iter()
+                .enumerate()
+                .filter(|(_, matched)| **matched == is_semi)
+                .map(|(row, _)| Some(row))
+                .collect();
+        }
+        JoinType::Inner | JoinType::Right => {}
+    }
+
+    Ok((to_indices(left_indices)?, to_indices(right_indices)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    fn test_join(join_type: JoinType, left: &[Option<u32>], right: &[Option<u32>]) -> Result<()> {
+        let l = Int32Array::from([Some(1), Some(2), None, Some(2), Some(4)]);
+        let r = Int32Array::from([Some(2), None, Some(3), Some(1)]);
+        let (left_indices, right_indices) = join::<u32>(&[&l], &[&r], join_type)?;
+        assert_eq!(left_indices, UInt32Array::from(left));
+        assert_eq!(right_indices, UInt32Array::from(right));
+        Ok(())
+    }
+
+    #[test]
+    fn inner() -> Result<()> {
+        test_join(
+            JoinType::Inner,
+            &[Some(1), Some(3), Some(0)],
+            &[Some(0), Some(0), Some(3)],
+        )
+    }
+
+    #[test]
+    fn left() -> Result<()> {
+        test_join(
+            JoinType::Left,
+            &[Some(1), Some(3), Some(0), Some(2), Some(4)],
+            &[Some(0), Some(0), Some(3), None, None],
+        )
+    }
+
+    #[test]
+    fn right() -> Result<()> {
+        test_join(
+            JoinType::Right,
+            &[Some(1), Some(3), None, None, Some(0)],
+            &[Some(0), Some(0), Some(1), Some(2), Some(3)],
+        )
+    }
+
+    #[test]
+    fn full() -> Result<()> {
+        test_join(
+            JoinType::Full,
+            &[Some(1), Some(3), None, None, Some(0), Some(2), Some(4)],
+            &[Some(0), Some(0), Some(1), Some(2), Some(3), None, None],
+        )
+    }
+
+    #[test]
+    fn semi_anti() -> Result<()> {
+        test_join(JoinType::Semi, &[Some(0), Some(1), Some(3)], &[])?;
+        test_join(JoinType::Anti, &[Some(2), Some(4)], &[])
+    }
+
+    #[test]
+    fn multiple_keys() -> Result<()> {
+        let l0 = Utf8Array::<i32>::from_slice(["a", "a", "b"]);
+        let l1 = BooleanArray::from([Some(true), Some(false), None]);
+        let r0 = Utf8Array::<i32>::from_slice(["b", "a", "a"]);
+        let r1 = BooleanArray::from([None, Some(false), Some(false)]);
+
+        let (left_indices, right_indices) = join::<u64>(&[&l0, &l1], &[&r0, &r1], JoinType::Inner)?;
+        assert_eq!(left_indices, UInt64Array::from_slice([1, 1]));
+        assert_eq!(right_indices, UInt64Array::from_slice([1, 2]));
+        Ok(())
+    }
+
+    #[test]
+    fn dictionary_null_values() -> Result<()> {
+        let values = Arc::new(Utf8Array::<i32>::from([Some("a"), None])) as Arc<dyn Array>;
+        let l = DictionaryArray::<i32>::from_data(Int32Array::from_slice([0, 1]), values.clone());
+        let r = DictionaryArray::<i32>::from_data(Int32Array::from_slice([1, 0]), values);
+
+        let (left_indices, right_indices) = join::<u32>(&[&l], &[&r], JoinType::Inner)?;
+        assert_eq!(left_indices, UInt32Array::from_slice([0]));
+        assert_eq!(right_indices, UInt32Array::from_slice([1]));
+        Ok(())
+    }
+
+    #[test]
+    fn errors() {
+        let l = Int32Array::from_slice([1]);
+        let r = Int64Array::from_slice([1]);
+        assert!(join::<u32>(&[&l], &[&r], JoinType::Inner).is_err());
+        assert!(join::<u32>(&[&l], &[], JoinType::Inner).is_err());
+    }
+}
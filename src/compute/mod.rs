@@ -17,8 +17,10 @@ pub mod comparison;
 pub mod concat;
 pub mod contains;
 pub mod filter;
+pub mod groupby;
 pub mod hash;
 pub mod if_then_else;
+pub mod join;
 pub mod length;
 pub mod limit;
 pub mod nullif;
@@ -27,6 +29,7 @@ pub mod sort;
 pub mod substring;
 pub mod take;
 pub mod temporal;
+pub mod unique;
 mod utils;
 pub mod window;
 
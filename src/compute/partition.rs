@@ -17,6 +17,7 @@
 
 //! Defines partition kernel for `ArrayRef`
 
+use crate::compute::sort::row;
 use crate::compute::sort::{build_compare, Compare, SortColumn};
 use crate::error::{ArrowError, Result};
 use std::cmp::Ordering;
@@ -60,21 +61,30 @@ impl<'a> LexicographicalPartitionIterator<'a> {
             ));
         };
 
-        let comparators = columns
+        let comparator: Compare = if columns
             .iter()
-            .map(|x| build_compare(x.values, x.options.unwrap_or_default()))
-            .collect::<Result<Vec<_>>>()?;
+            .all(|column| row::can_encode(column.values.data_type()))
+        {
+            // fast path: compare rows encoded as bytes
+            let rows = row::encode(columns)?;
+            Box::new(move |a_idx: usize, b_idx: usize| rows.row(a_idx).cmp(rows.row(b_idx)))
+        } else {
+            let comparators = columns
+                .iter()
+                .map(|x| build_compare(x.values, x.options.unwrap_or_default()))
+                .collect::<Result<Vec<_>>>()?;
 
-        let comparator = Box::new(move |a_idx: usize, b_idx: usize| -> Ordering {
-            for comparator in comparators.iter() {
-                match comparator(a_idx, b_idx) {
-                    Ordering::Equal => continue,
-                    other => return other,
+            Box::new(move |a_idx: usize, b_idx: usize| -> Ordering {
+                for comparator in comparators.iter() {
+                    match comparator(a_idx, b_idx) {
+                        Ordering::Equal => continue,
+                        other => return other,
+                    }
                 }
-            }
 
-            Ordering::Equal
-        });
+                Ordering::Equal
+            })
+        };
 
         let value_indices = (0..num_rows).collect::<Vec<usize>>();
         Ok(Self {
@@ -17,15 +17,11 @@
 
 use std::cmp::Ordering;
 
+use crate::array::{ord, Array, Index, PrimitiveArray};
 use crate::compute::take;
 use crate::error::{ArrowError, Result};
-use crate::{
-    array::{ord, Array, PrimitiveArray},
-    buffer::MutableBuffer,
-    datatypes::DataType,
-};
 
-use super::{sort_to_indices, SortOptions};
+use super::{row, sort_to_indices, sort_with_limit, SortOptions};
 
 type IsValid<'a> = Box<dyn Fn(usize) -> bool + 'a>;
 
@@ -36,9 +32,10 @@ pub struct SortColumn<'a> {
     pub options: Option<SortOptions>,
 }
 
-/// Sort a list of [`Array`] using [`SortOptions`] provided for each array.
+/// Sort a list of [`Array`] using [`SortOptions`] provided for each array, returning at most
+/// `limit` rows.
 /// # Implementaqtion
-/// The sort is stable and lexicographical on values.
+/// The sort is stable and lexicographical on values. Rows are gathered with indices of type `I`.
 ///
 /// Returns an [`ArrowError`] if any of the array type is either unsupported by
 /// `lexsort_to_indices` or `take`.
@@ -54,7 +51,7 @@ pub struct SortColumn<'a> {
 /// let int64 = Int64Array::from(&[None, Some(-2), Some(89), Some(-64), Some(101)]);
 /// let utf8 = Utf8Array::<i32>::from(&vec![Some("hello"), Some("world"), Some(","), Some("foobar"), Some("!")]);
 ///
-/// let sorted_columns = lexsort(&vec![
+/// let sorted_columns = lexsort::<i32>(&vec![
 ///     SortColumn {
 ///         values: &int64,
 ///         options: None,
@@ -66,14 +63,17 @@ pub struct SortColumn<'a> {
 ///             nulls_first: false,
 ///         }),
 ///     },
-/// ]).unwrap();
+/// ], None).unwrap();
 ///
 /// let sorted = sorted_columns[0].as_any().downcast_ref::<Int64Array>().unwrap();
 /// assert_eq!(sorted.value(1), -64);
 /// assert!(sorted.is_null(0));
 /// ```
-pub fn lexsort(columns: &[SortColumn]) -> Result<Vec<Box<dyn Array>>> {
-    let indices = lexsort_to_indices(columns)?;
+pub fn lexsort<I: Index>(
+    columns: &[SortColumn],
+    limit: Option<usize>,
+) -> Result<Vec<Box<dyn Array>>> {
+    let indices = lexsort_to_indices::<I>(columns, limit)?;
     columns
         .iter()
         .map(|c| take::take(c.values, &indices))
@@ -135,9 +135,12 @@ pub(crate) fn build_compare(array: &dyn Array, sort_option: SortOptions) -> Resu
     })
 }
 
-/// Sort elements lexicographically from a list of `ArrayRef` into an unsigned integer
-/// [`Int32Array`] of indices.
-pub fn lexsort_to_indices(columns: &[SortColumn]) -> Result<PrimitiveArray<i32>> {
+/// Sort elements lexicographically from a list of `ArrayRef` into an array of indices of
+/// type `I`, returning at most `limit` indices.
+pub fn lexsort_to_indices<I: Index>(
+    columns: &[SortColumn],
+    limit: Option<usize>,
+) -> Result<PrimitiveArray<I>> {
     if columns.is_empty() {
         return Err(ArrowError::InvalidArgumentError(
             "Sort requires at least one column".to_string(),
@@ -146,7 +149,7 @@ pub fn lexsort_to_indices(columns: &[SortColumn]) -> Result<PrimitiveArray<i32>>
     if columns.len() == 1 {
         // fallback to non-lexical sort
         let column = &columns[0];
-        return sort_to_indices(column.values, &column.options.unwrap_or_default());
+        return sort_to_indices(column.values, &column.options.unwrap_or_default(), limit);
     }
 
     let row_count = columns[0].values.len();
@@ -155,35 +158,55 @@ pub fn lexsort_to_indices(columns: &[SortColumn]) -> Result<PrimitiveArray<i32>>
             "lexical sort columns have different row counts".to_string(),
         ));
     };
+    if I::from_usize(row_count).is_none() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot sort {} rows with indices of type {:?}",
+            row_count,
+            I::DATA_TYPE
+        )));
+    }
 
-    // map arrays to comparators
-    let comparators = columns
+    let lex_comparator: Compare = if columns
         .iter()
-        .map(|column| -> Result<Compare> {
-            build_compare(column.values, column.options.unwrap_or_default())
-        })
-        .collect::<Result<Vec<Compare>>>()?;
+        .all(|column| row::can_encode(column.values.data_type()))
+    {
+        // fast path: compare rows encoded as bytes
+        let rows = row::encode(columns)?;
+        Box::new(move |a_idx: usize, b_idx: usize| rows.row(a_idx).cmp(rows.row(b_idx)))
+    } else {
+        // map arrays to comparators
+        let comparators = columns
+            .iter()
+            .map(|column| -> Result<Compare> {
+                build_compare(column.values, column.options.unwrap_or_default())
+            })
+            .collect::<Result<Vec<Compare>>>()?;
 
-    let lex_comparator = |a_idx: &i32, b_idx: &i32| -> Ordering {
-        let a_idx = *a_idx as usize;
-        let b_idx = *b_idx as usize;
-        for comparator in comparators.iter() {
-            match comparator(a_idx, b_idx) {
-                Ordering::Equal => continue,
-                other => return other,
+        Box::new(move |a_idx: usize, b_idx: usize| -> Ordering {
+            for comparator in comparators.iter() {
+                match comparator(a_idx, b_idx) {
+                    Ordering::Equal => continue,
+                    other => return other,
+                }
             }
-        }
 
-        Ordering::Equal
+            Ordering::Equal
+        })
     };
 
-    // Safety: `0..row_count` is TrustedLen
-    let mut values =
-        unsafe { MutableBuffer::<i32>::from_trusted_len_iter_unchecked(0..row_count as i32) };
-    values.sort_unstable_by(lex_comparator);
+    let mut values = (0..row_count)
+        .map(|index| I::from_usize(index).unwrap())
+        .collect::<Vec<_>>();
+    sort_with_limit(
+        &mut values,
+        |a, b| lex_comparator(a.to_usize(), b.to_usize()),
+        |index| index.to_usize(),
+        false,
+        limit,
+    );
 
-    Ok(PrimitiveArray::<i32>::from_data(
-        DataType::Int32,
+    Ok(PrimitiveArray::<I>::from_data(
+        I::DATA_TYPE,
         values.into(),
         None,
     ))
@@ -196,7 +219,7 @@ mod tests {
     use super::*;
 
     fn test_lex_sort_arrays(input: Vec<SortColumn>, expected: Vec<Box<dyn Array>>) {
-        let sorted = lexsort(&input).unwrap();
+        let sorted = lexsort::<i32>(&input, None).unwrap();
         assert_eq!(sorted, expected);
     }
 
@@ -395,4 +418,26 @@ mod tests {
         test_lex_sort_arrays(input, expected);
     }
     */
+
+    #[test]
+    fn test_lex_sort_stable_with_limit() {
+        let c1 = Int64Array::from([Some(1), Some(0), Some(1), Some(0), Some(1)]);
+        let c2 = Utf8Array::<i32>::from([Some("a"), Some("b"), Some("a"), Some("b"), Some("a")]);
+        let input = vec![
+            SortColumn {
+                values: &c1,
+                options: None,
+            },
+            SortColumn {
+                values: &c2,
+                options: None,
+            },
+        ];
+
+        let indices = lexsort_to_indices::<u64>(&input, None).unwrap();
+        assert_eq!(indices, UInt64Array::from_slice([1, 3, 0, 2, 4]));
+
+        let indices = lexsort_to_indices::<u64>(&input, Some(3)).unwrap();
+        assert_eq!(indices, UInt64Array::from_slice([1, 3, 0]));
+    }
 }
@@ -17,7 +17,7 @@
 
 //! Defines sort kernel for `ArrayRef`
 
-use std::cmp::{Ordering, Reverse};
+use std::cmp::Ordering;
 
 use crate::array::ord;
 use crate::compute::take;
@@ -25,15 +25,13 @@ use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::{
     array::*,
-    buffer::Buffer,
-    types::{days_ms, NativeType},
+    buffer::MutableBuffer,
+    types::{days_ms, f16},
 };
 
-use crate::buffer::MutableBuffer;
-use num::ToPrimitive;
-
 mod lex_sort;
 mod primitive;
+pub mod row;
 
 pub(crate) use lex_sort::{build_compare, Compare};
 pub use lex_sort::{lexsort, lexsort_to_indices, SortColumn};
@@ -44,174 +42,226 @@ macro_rules! dyn_sort {
             .as_any()
             .downcast_ref::<PrimitiveArray<$ty>>()
             .unwrap();
-        Ok(Box::new(primitive::sort_by::<$ty, _>(
+        return Ok(Box::new(primitive::sort_by::<$ty, _>(
             &array, $cmp, $options,
-        )))
+        )));
     }};
 }
 
-/// Sort the `ArrayRef` using `SortOptions`.
+/// Sort the `ArrayRef` using `SortOptions`, returning at most `limit` values.
 ///
 /// Performs a stable sort on values and indices. Nulls are ordered according to the `nulls_first` flag in `options`.
-/// Floats are sorted using IEEE 754 totalOrder
+/// Floats are sorted using IEEE 754 totalOrder.
+/// When `limit` is set, only the first `limit` values are sorted (a top-k).
 ///
 /// Returns an `ArrowError::ComputeError(String)` if the array type is either unsupported by `sort_to_indices` or `take`.
 ///
-pub fn sort(values: &dyn Array, options: &SortOptions) -> Result<Box<dyn Array>> {
-    match values.data_type() {
-        DataType::Int8 => dyn_sort!(i8, values, ord::total_cmp, options),
-        DataType::Int16 => dyn_sort!(i16, values, ord::total_cmp, options),
-        DataType::Int32
-        | DataType::Date32
-        | DataType::Time32(_)
-        | DataType::Interval(IntervalUnit::YearMonth) => {
-            dyn_sort!(i32, values, ord::total_cmp, options)
+pub fn sort(
+    values: &dyn Array,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> Result<Box<dyn Array>> {
+    if limit.is_none() {
+        match values.data_type() {
+            DataType::Int8 => dyn_sort!(i8, values, ord::total_cmp, options),
+            DataType::Int16 => dyn_sort!(i16, values, ord::total_cmp, options),
+            DataType::Int32
+            | DataType::Date32
+            | DataType::Time32(_)
+            | DataType::Interval(IntervalUnit::YearMonth) => {
+                dyn_sort!(i32, values, ord::total_cmp, options)
+            }
+            DataType::Int64
+            | DataType::Date64
+            | DataType::Time64(_)
+            | DataType::Timestamp(_, None)
+            | DataType::Duration(_) => dyn_sort!(i64, values, ord::total_cmp, options),
+            DataType::UInt8 => dyn_sort!(u8, values, ord::total_cmp, options),
+            DataType::UInt16 => dyn_sort!(u16, values, ord::total_cmp, options),
+            DataType::UInt32 => dyn_sort!(u32, values, ord::total_cmp, options),
+            DataType::UInt64 => dyn_sort!(u64, values, ord::total_cmp, options),
+            DataType::Float16 => dyn_sort!(f16, values, ord::total_cmp_f16, options),
+            DataType::Float32 => dyn_sort!(f32, values, ord::total_cmp_f32, options),
+            DataType::Float64 => dyn_sort!(f64, values, ord::total_cmp_f64, options),
+            DataType::Interval(IntervalUnit::DayTime) => {
+                dyn_sort!(days_ms, values, ord::total_cmp, options)
+            }
+            _ => {}
         }
-        DataType::Int64
-        | DataType::Date64
-        | DataType::Time64(_)
-        | DataType::Timestamp(_, None)
-        | DataType::Duration(_) => dyn_sort!(i64, values, ord::total_cmp, options),
-        DataType::UInt8 => dyn_sort!(u8, values, ord::total_cmp, options),
-        DataType::UInt16 => dyn_sort!(u16, values, ord::total_cmp, options),
-        DataType::UInt32 => dyn_sort!(u32, values, ord::total_cmp, options),
-        DataType::UInt64 => dyn_sort!(u64, values, ord::total_cmp, options),
-        DataType::Float32 => dyn_sort!(f32, values, ord::total_cmp_f32, options),
-        DataType::Float64 => dyn_sort!(f64, values, ord::total_cmp_f64, options),
-        DataType::Interval(IntervalUnit::DayTime) => {
-            dyn_sort!(days_ms, values, ord::total_cmp, options)
+    }
+    let indices = sort_to_indices::<u64>(values, options, limit)?;
+    take::take(values, &indices)
+}
+
+// partition indices into valid and null indices
+fn partition_validity<I: Index>(array: &dyn Array) -> (Vec<I>, Vec<I>) {
+    // Soundness: `sort_to_indices` checks that every index fits in `I`
+    let indices = (0..array.len()).map(|index| I::from_usize(index).unwrap());
+    indices.partition(|index| array.is_valid(index.to_usize()))
+}
+
+/// Sorts `values` by `cmp` (reversed when `descending`), keeping at most `limit` of them.
+/// When `limit` is smaller than the number of values, the values are partially sorted.
+/// In both cases, the sort is stable: equal values are ordered by `index`.
+fn sort_with_limit<T, F, G>(
+    values: &mut Vec<T>,
+    mut cmp: F,
+    index: G,
+    descending: bool,
+    limit: Option<usize>,
+) where
+    F: FnMut(&T, &T) -> Ordering,
+    G: Fn(&T) -> usize,
+{
+    let mut cmp = |lhs: &T, rhs: &T| {
+        let ordering = cmp(lhs, rhs);
+        if descending {
+            ordering.reverse()
+        } else {
+            ordering
         }
-        _ => {
-            let indices = sort_to_indices(values, options)?;
-            take::take(values, &indices)
+    };
+    match limit {
+        Some(limit) if limit < values.len() => {
+            // partial sorts are not stable: break ties with the index
+            let mut cmp =
+                |lhs: &T, rhs: &T| cmp(lhs, rhs).then_with(|| index(lhs).cmp(&index(rhs)));
+            values.select_nth_unstable_by(limit, &mut cmp);
+            values.truncate(limit);
+            values.sort_unstable_by(cmp);
         }
+        _ => values.sort_by(cmp),
     }
 }
 
-// partition indices into valid and null indices
-fn partition_validity(array: &dyn Array) -> (Vec<i32>, Vec<i32>) {
-    let indices = 0..(array.len().to_i32().unwrap());
-    indices.partition(|index| array.is_valid(*index as usize))
+/// Returns the indices of the sorted `valids` and of the `nulls` according to `options`,
+/// up to `limit` indices.
+fn concatenate_indices<I: Index, V: Iterator<Item = I>>(
+    valids: V,
+    nulls: Vec<I>,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> PrimitiveArray<I> {
+    let limit = limit.unwrap_or(usize::MAX);
+    let values: MutableBuffer<I> = if options.nulls_first {
+        nulls.into_iter().chain(valids).take(limit).collect()
+    } else {
+        valids.chain(nulls).take(limit).collect()
+    };
+    PrimitiveArray::<I>::from_data(I::DATA_TYPE, values.into(), None)
 }
 
 macro_rules! dyn_sort_indices {
-    ($ty:ty, $array:expr, $cmp:expr, $options:expr) => {{
+    ($index:ty, $ty:ty, $array:expr, $cmp:expr, $options:expr, $limit:expr) => {{
         let array = $array
             .as_any()
             .downcast_ref::<PrimitiveArray<$ty>>()
             .unwrap();
-        Ok(primitive::indices_sorted_by::<$ty, _>(
-            &array, $cmp, $options,
+        Ok(primitive::indices_sorted_by::<$index, $ty, _>(
+            &array, $cmp, $options, $limit,
         ))
     }};
 }
 
-/// Sort elements from `ArrayRef` into an unsigned integer (`UInt32Array`) of indices.
-/// For floating point arrays any NaN values are considered to be greater than any other non-null value
-pub fn sort_to_indices(values: &dyn Array, options: &SortOptions) -> Result<Int32Array> {
+/// Sort elements from `ArrayRef` into an array of indices of type `I`, returning at most
+/// `limit` indices.
+/// For floating point arrays any NaN values are considered to be greater than any other non-null value.
+/// # Errors
+/// Errors if the type of `values` can't be sorted (see [`can_sort`]) or if its length
+/// does not fit in `I`.
+pub fn sort_to_indices<I: Index>(
+    values: &dyn Array,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> Result<PrimitiveArray<I>> {
+    if I::from_usize(values.len()).is_none() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot sort an array of length {} with indices of type {:?}",
+            values.len(),
+            I::DATA_TYPE
+        )));
+    }
     match values.data_type() {
         DataType::Boolean => {
             let (v, n) = partition_validity(values);
-            Ok(sort_boolean(values, v, n, &options))
+            Ok(sort_boolean(values, v, n, options, limit))
         }
-        DataType::Int8 => dyn_sort_indices!(i8, values, ord::total_cmp, options),
-        DataType::Int16 => dyn_sort_indices!(i16, values, ord::total_cmp, options),
+        DataType::Int8 => dyn_sort_indices!(I, i8, values, ord::total_cmp, options, limit),
+        DataType::Int16 => dyn_sort_indices!(I, i16, values, ord::total_cmp, options, limit),
         DataType::Int32
         | DataType::Date32
         | DataType::Time32(_)
         | DataType::Interval(IntervalUnit::YearMonth) => {
-            dyn_sort_indices!(i32, values, ord::total_cmp, options)
+            dyn_sort_indices!(I, i32, values, ord::total_cmp, options, limit)
         }
         DataType::Int64
         | DataType::Date64
         | DataType::Time64(_)
         | DataType::Timestamp(_, None)
-        | DataType::Duration(_) => dyn_sort_indices!(i64, values, ord::total_cmp, options),
-        DataType::UInt8 => dyn_sort_indices!(u8, values, ord::total_cmp, options),
-        DataType::UInt16 => dyn_sort_indices!(u16, values, ord::total_cmp, options),
-        DataType::UInt32 => dyn_sort_indices!(u32, values, ord::total_cmp, options),
-        DataType::UInt64 => dyn_sort_indices!(u64, values, ord::total_cmp, options),
-        DataType::Float32 => dyn_sort_indices!(f32, values, ord::total_cmp_f32, options),
-        DataType::Float64 => dyn_sort_indices!(f64, values, ord::total_cmp_f64, options),
-        DataType::Interval(IntervalUnit::DayTime) => {
-            dyn_sort_indices!(days_ms, values, ord::total_cmp, options)
+        | DataType::Duration(_) => {
+            dyn_sort_indices!(I, i64, values, ord::total_cmp, options, limit)
         }
-        DataType::Utf8 => {
-            let (v, n) = partition_validity(values);
-            Ok(sort_utf8::<i32>(values, v, n, &options))
+        DataType::UInt8 => dyn_sort_indices!(I, u8, values, ord::total_cmp, options, limit),
+        DataType::UInt16 => dyn_sort_indices!(I, u16, values, ord::total_cmp, options, limit),
+        DataType::UInt32 => dyn_sort_indices!(I, u32, values, ord::total_cmp, options, limit),
+        DataType::UInt64 => dyn_sort_indices!(I, u64, values, ord::total_cmp, options, limit),
+        DataType::Float16 => {
+            dyn_sort_indices!(I, f16, values, ord::total_cmp_f16, options, limit)
         }
-        DataType::LargeUtf8 => {
-            let (v, n) = partition_validity(values);
-            Ok(sort_utf8::<i64>(values, v, n, &options))
+        DataType::Float32 => {
+            dyn_sort_indices!(I, f32, values, ord::total_cmp_f32, options, limit)
         }
-        DataType::List(field) => {
-            let (v, n) = partition_validity(values);
-            match field.data_type() {
-                DataType::Int8 => Ok(sort_list::<i32, i8>(values, v, n, &options)),
-                DataType::Int16 => Ok(sort_list::<i32, i16>(values, v, n, &options)),
-                DataType::Int32 => Ok(sort_list::<i32, i32>(values, v, n, &options)),
-                DataType::Int64 => Ok(sort_list::<i32, i64>(values, v, n, &options)),
-                DataType::UInt8 => Ok(sort_list::<i32, u8>(values, v, n, &options)),
-                DataType::UInt16 => Ok(sort_list::<i32, u16>(values, v, n, &options)),
-                DataType::UInt32 => Ok(sort_list::<i32, u32>(values, v, n, &options)),
-                DataType::UInt64 => Ok(sort_list::<i32, u64>(values, v, n, &options)),
-                t => Err(ArrowError::NotYetImplemented(format!(
-                    "Sort not supported for list type {:?}",
-                    t
-                ))),
-            }
+        DataType::Float64 => {
+            dyn_sort_indices!(I, f64, values, ord::total_cmp_f64, options, limit)
+        }
+        DataType::Interval(IntervalUnit::DayTime) => {
+            dyn_sort_indices!(I, days_ms, values, ord::total_cmp, options, limit)
         }
-        DataType::LargeList(field) => {
+        DataType::Utf8 => {
             let (v, n) = partition_validity(values);
-            match field.data_type() {
-                DataType::Int8 => Ok(sort_list::<i64, i8>(values, v, n, &options)),
-                DataType::Int16 => Ok(sort_list::<i64, i16>(values, v, n, &options)),
-                DataType::Int32 => Ok(sort_list::<i64, i32>(values, v, n, &options)),
-                DataType::Int64 => Ok(sort_list::<i64, i64>(values, v, n, &options)),
-                DataType::UInt8 => Ok(sort_list::<i64, u8>(values, v, n, &options)),
-                DataType::UInt16 => Ok(sort_list::<i64, u16>(values, v, n, &options)),
-                DataType::UInt32 => Ok(sort_list::<i64, u32>(values, v, n, &options)),
-                DataType::UInt64 => Ok(sort_list::<i64, u64>(values, v, n, &options)),
-                t => Err(ArrowError::NotYetImplemented(format!(
-                    "Sort not supported for list type {:?}",
-                    t
-                ))),
-            }
+            Ok(sort_utf8::<I, i32>(values, v, n, options, limit))
         }
-        DataType::FixedSizeList(field, _) => {
+        DataType::LargeUtf8 => {
             let (v, n) = partition_validity(values);
-            match field.data_type() {
-                DataType::Int8 => Ok(sort_list::<i32, i8>(values, v, n, &options)),
-                DataType::Int16 => Ok(sort_list::<i32, i16>(values, v, n, &options)),
-                DataType::Int32 => Ok(sort_list::<i32, i32>(values, v, n, &options)),
-                DataType::Int64 => Ok(sort_list::<i32, i64>(values, v, n, &options)),
-                DataType::UInt8 => Ok(sort_list::<i32, u8>(values, v, n, &options)),
-                DataType::UInt16 => Ok(sort_list::<i32, u16>(values, v, n, &options)),
-                DataType::UInt32 => Ok(sort_list::<i32, u32>(values, v, n, &options)),
-                DataType::UInt64 => Ok(sort_list::<i32, u64>(values, v, n, &options)),
-                t => Err(ArrowError::NotYetImplemented(format!(
-                    "Sort not supported for list type {:?}",
-                    t
-                ))),
-            }
+            Ok(sort_utf8::<I, i64>(values, v, n, options, limit))
         }
         DataType::Dictionary(key_type, value_type) if *value_type.as_ref() == DataType::Utf8 => {
             let (v, n) = partition_validity(values);
             match key_type.as_ref() {
-                DataType::Int8 => Ok(sort_string_dictionary::<i8>(values, v, n, &options)),
-                DataType::Int16 => Ok(sort_string_dictionary::<i16>(values, v, n, &options)),
-                DataType::Int32 => Ok(sort_string_dictionary::<i32>(values, v, n, &options)),
-                DataType::Int64 => Ok(sort_string_dictionary::<i64>(values, v, n, &options)),
-                DataType::UInt8 => Ok(sort_string_dictionary::<u8>(values, v, n, &options)),
-                DataType::UInt16 => Ok(sort_string_dictionary::<u16>(values, v, n, &options)),
-                DataType::UInt32 => Ok(sort_string_dictionary::<u32>(values, v, n, &options)),
-                DataType::UInt64 => Ok(sort_string_dictionary::<u64>(values, v, n, &options)),
+                DataType::Int8 => Ok(sort_string_dictionary::<I, i8>(
+                    values, v, n, options, limit,
+                )),
+                DataType::Int16 => Ok(sort_string_dictionary::<I, i16>(
+                    values, v, n, options, limit,
+                )),
+                DataType::Int32 => Ok(sort_string_dictionary::<I, i32>(
+                    values, v, n, options, limit,
+                )),
+                DataType::Int64 => Ok(sort_string_dictionary::<I, i64>(
+                    values, v, n, options, limit,
+                )),
+                DataType::UInt8 => Ok(sort_string_dictionary::<I, u8>(
+                    values, v, n, options, limit,
+                )),
+                DataType::UInt16 => Ok(sort_string_dictionary::<I, u16>(
+                    values, v, n, options, limit,
+                )),
+                DataType::UInt32 => Ok(sort_string_dictionary::<I, u32>(
+                    values, v, n, options, limit,
+                )),
+                DataType::UInt64 => Ok(sort_string_dictionary::<I, u64>(
+                    values, v, n, options, limit,
+                )),
                 t => Err(ArrowError::NotYetImplemented(format!(
                     "Sort not supported for dictionary key type {:?}",
                     t
                 ))),
             }
         }
+        t if can_sort(t) => {
+            let (v, n) = partition_validity(values);
+            sort_dyn(values, v, n, options, limit)
+        }
         t => Err(ArrowError::NotYetImplemented(format!(
             "Sort not supported for data type {:?}",
             t
@@ -230,6 +280,9 @@ pub fn sort_to_indices(values: &dyn Array, options: &SortOptions) -> Result<Int3
 /// assert_eq!(can_sort(&data_type), true);
 ///
 /// let data_type = DataType::LargeBinary;
+/// assert_eq!(can_sort(&data_type), true);
+///
+/// let data_type = DataType::Null;
 /// assert_eq!(can_sort(&data_type), false)
 /// ```
 pub fn can_sort(data_type: &DataType) -> bool {
@@ -250,24 +303,20 @@ pub fn can_sort(data_type: &DataType) -> bool {
         | DataType::UInt16
         | DataType::UInt32
         | DataType::UInt64
+        | DataType::Float16
         | DataType::Float32
         | DataType::Float64
+        | DataType::Decimal(_, _)
         | DataType::Utf8
-        | DataType::LargeUtf8 => true,
+        | DataType::LargeUtf8
+        | DataType::Binary
+        | DataType::LargeBinary
+        | DataType::FixedSizeBinary(_) => true,
         DataType::List(field) | DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
-            matches!(
-                field.data_type(),
-                DataType::Int8
-                    | DataType::Int16
-                    | DataType::Int32
-                    | DataType::Int64
-                    | DataType::UInt8
-                    | DataType::UInt16
-                    | DataType::UInt32
-                    | DataType::UInt64
-            )
+            can_sort(field.data_type())
         }
-        DataType::Dictionary(key_type, value_type) if *value_type.as_ref() == DataType::Utf8 => {
+        DataType::Struct(fields) => fields.iter().all(|field| can_sort(field.data_type())),
+        DataType::Dictionary(key_type, value_type) => {
             matches!(
                 key_type.as_ref(),
                 DataType::Int8
@@ -278,7 +327,7 @@ pub fn can_sort(data_type: &DataType) -> bool {
                     | DataType::UInt16
                     | DataType::UInt32
                     | DataType::UInt64
-            )
+            ) && can_sort(value_type.as_ref())
         }
         _ => false,
     }
@@ -304,12 +353,13 @@ impl Default for SortOptions {
 }
 
 /// Sort primitive values
-fn sort_boolean(
+fn sort_boolean<I: Index>(
     values: &dyn Array,
-    value_indices: Vec<i32>,
-    null_indices: Vec<i32>,
+    value_indices: Vec<I>,
+    null_indices: Vec<I>,
     options: &SortOptions,
-) -> Int32Array {
+    limit: Option<usize>,
+) -> PrimitiveArray<I> {
     let values = values
         .as_any()
         .downcast_ref::<BooleanArray>()
@@ -319,40 +369,35 @@ fn sort_boolean(
     // create tuples that are used for sorting
     let mut valids = value_indices
         .into_iter()
-        .map(|index| (index, values.value(index as usize)))
-        .collect::<Vec<(i32, bool)>>();
+        .map(|index| (index, values.value(index.to_usize())))
+        .collect::<Vec<(I, bool)>>();
 
     let mut nulls = null_indices;
 
-    if !descending {
-        valids.sort_by(|a, b| a.1.cmp(&b.1));
-    } else {
-        valids.sort_by(|a, b| a.1.cmp(&b.1).reverse());
+    sort_with_limit(
+        &mut valids,
+        |a, b| a.1.cmp(&b.1),
+        |a| a.0.to_usize(),
+        descending,
+        limit,
+    );
+    if descending {
         // reverse to keep a stable ordering
         nulls.reverse();
     }
 
-    let mut values = MutableBuffer::<i32>::with_capacity(values.len());
-
-    if options.nulls_first {
-        values.extend_from_slice(nulls.as_slice());
-        valids.iter().for_each(|x| values.push(x.0));
-    } else {
-        // nulls last
-        valids.iter().for_each(|x| values.push(x.0));
-        values.extend_from_slice(nulls.as_slice());
-    }
-
-    Int32Array::from_data(DataType::Int32, values.into(), None)
+    let valids = valids.into_iter().map(|tuple| tuple.0);
+    concatenate_indices(valids, nulls, options, limit)
 }
 
 /// Sort strings
-fn sort_utf8<O: Offset>(
+fn sort_utf8<I: Index, O: Offset>(
     values: &dyn Array,
-    value_indices: Vec<i32>,
-    null_indices: Vec<i32>,
+    value_indices: Vec<I>,
+    null_indices: Vec<I>,
     options: &SortOptions,
-) -> Int32Array {
+    limit: Option<usize>,
+) -> PrimitiveArray<I> {
     let values = values.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
 
     sort_string_helper(
@@ -360,17 +405,19 @@ fn sort_utf8<O: Offset>(
         value_indices,
         null_indices,
         options,
-        |array, idx| array.value(idx as usize),
+        limit,
+        |array, idx| array.value(idx.to_usize()),
     )
 }
 
 /// Sort dictionary encoded strings
-fn sort_string_dictionary<T: DictionaryKey>(
+fn sort_string_dictionary<I: Index, T: DictionaryKey>(
     values: &dyn Array,
-    value_indices: Vec<i32>,
-    null_indices: Vec<i32>,
+    value_indices: Vec<I>,
+    null_indices: Vec<I>,
     options: &SortOptions,
-) -> Int32Array {
+    limit: Option<usize>,
+) -> PrimitiveArray<I> {
     let values: &DictionaryArray<T> = values
         .as_any()
         .downcast_ref::<DictionaryArray<T>>()
@@ -386,8 +433,9 @@ fn sort_string_dictionary<T: DictionaryKey>(
         value_indices,
         null_indices,
         options,
+        limit,
         |array: &PrimitiveArray<T>, idx| -> &str {
-            let key: T = array.value(idx as usize);
+            let key: T = array.value(idx.to_usize());
             dict.value(key.to_usize().unwrap())
         },
     )
@@ -395,110 +443,71 @@ fn sort_string_dictionary<T: DictionaryKey>(
 
 /// shared implementation between dictionary encoded and plain string arrays
 #[inline]
-fn sort_string_helper<'a, A: Array, F>(
+fn sort_string_helper<'a, I: Index, A: Array, F>(
     values: &'a A,
-    value_indices: Vec<i32>,
-    null_indices: Vec<i32>,
+    value_indices: Vec<I>,
+    null_indices: Vec<I>,
     options: &SortOptions,
+    limit: Option<usize>,
     value_fn: F,
-) -> Int32Array
+) -> PrimitiveArray<I>
 where
-    F: Fn(&'a A, i32) -> &str,
+    F: Fn(&'a A, I) -> &str,
 {
     let mut valids = value_indices
         .into_iter()
-        .map(|index| (index, value_fn(&values, index)))
-        .collect::<Vec<(i32, &str)>>();
+        .map(|index| (index, value_fn(values, index)))
+        .collect::<Vec<(I, &str)>>();
     let mut nulls = null_indices;
-    if !options.descending {
-        valids.sort_by_key(|a| a.1);
-    } else {
-        valids.sort_by_key(|a| Reverse(a.1));
+    sort_with_limit(
+        &mut valids,
+        |a, b| a.1.cmp(b.1),
+        |a| a.0.to_usize(),
+        options.descending,
+        limit,
+    );
+    if options.descending {
         nulls.reverse();
     }
 
-    let valids = valids.iter().map(|tuple| tuple.0);
-
-    let values = if options.nulls_first {
-        let values = nulls.into_iter().chain(valids);
-        Buffer::<i32>::from_trusted_len_iter(values)
-    } else {
-        let values = valids.chain(nulls.into_iter());
-        Buffer::<i32>::from_trusted_len_iter(values)
-    };
-
-    PrimitiveArray::<i32>::from_data(DataType::Int32, values, None)
+    let valids = valids.into_iter().map(|tuple| tuple.0);
+    concatenate_indices(valids, nulls, options, limit)
 }
 
-fn sort_list<O, T>(
+/// Sort values of any type supported by [`ord::build_compare`], such as binary, nested
+/// and dictionary arrays.
+fn sort_dyn<I: Index>(
     values: &dyn Array,
-    value_indices: Vec<i32>,
-    null_indices: Vec<i32>,
+    value_indices: Vec<I>,
+    null_indices: Vec<I>,
     options: &SortOptions,
-) -> Int32Array
-where
-    O: Offset,
-    T: NativeType + std::cmp::PartialOrd,
-{
-    let mut valids: Vec<(i32, Box<dyn Array>)> = values
-        .as_any()
-        .downcast_ref::<FixedSizeListArray>()
-        .map_or_else(
-            || {
-                let values = values.as_any().downcast_ref::<ListArray<O>>().unwrap();
-                value_indices
-                    .iter()
-                    .copied()
-                    .map(|index| (index, values.value(index as usize)))
-                    .collect()
-            },
-            |values| {
-                value_indices
-                    .iter()
-                    .copied()
-                    .map(|index| (index, values.value(index as usize)))
-                    .collect()
-            },
-        );
-
-    if !options.descending {
-        valids.sort_by(|a, b| cmp_array(a.1.as_ref(), b.1.as_ref()))
-    } else {
-        valids.sort_by(|a, b| cmp_array(a.1.as_ref(), b.1.as_ref()).reverse())
-    }
-
-    let values = valids.iter().map(|tuple| tuple.0);
-
-    let values = if options.nulls_first {
-        let mut buffer = MutableBuffer::<i32>::from_trusted_len_iter(null_indices.into_iter());
-        values.for_each(|x| buffer.push(x));
-        buffer.into()
-    } else {
-        let mut buffer = MutableBuffer::<i32>::from_trusted_len_iter(values);
-        null_indices.iter().for_each(|x| buffer.push(*x));
-        buffer.into()
-    };
-
-    PrimitiveArray::<i32>::from_data(DataType::Int32, values, None)
-}
-
-/// Compare two `Array`s based on the ordering defined in [ord](crate::array::ord).
-fn cmp_array(a: &dyn Array, b: &dyn Array) -> Ordering {
-    let cmp_op = ord::build_compare(a, b).unwrap();
-    let length = a.len().max(b.len());
-
-    for i in 0..length {
-        let result = cmp_op(i, i);
-        if result != Ordering::Equal {
-            return result;
-        }
-    }
-    Ordering::Equal
+    limit: Option<usize>,
+) -> Result<PrimitiveArray<I>> {
+    let comparator = ord::build_compare(values, values)?;
+
+    let mut valids = value_indices;
+    sort_with_limit(
+        &mut valids,
+        |a, b| comparator(a.to_usize(), b.to_usize()),
+        |a| a.to_usize(),
+        options.descending,
+        limit,
+    );
+
+    Ok(concatenate_indices(
+        valids.into_iter(),
+        null_indices,
+        options,
+        limit,
+    ))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::buffer::Buffer;
+    use crate::types::NativeType;
+    use std::sync::Arc;
 
     fn test_sort_to_indices_boolean_arrays(
         data: &[Option<bool>],
@@ -507,7 +516,7 @@ mod tests {
     ) {
         let output = BooleanArray::from(data);
         let expected = Int32Array::from_slice(expected_data);
-        let output = sort_to_indices(&output, &options).unwrap();
+        let output = sort_to_indices::<i32>(&output, &options, None).unwrap();
         assert_eq!(output, expected)
     }
 
@@ -521,7 +530,7 @@ mod tests {
     {
         let input = PrimitiveArray::<T>::from(data).to(data_type.clone());
         let expected = PrimitiveArray::<T>::from(expected_data).to(data_type);
-        let output = sort(&input, &options).unwrap();
+        let output = sort(&input, &options, None).unwrap();
         assert_eq!(expected, output.as_ref())
     }
 
@@ -532,7 +541,7 @@ mod tests {
     ) {
         let input = Utf8Array::<i32>::from(&data.to_vec());
         let expected = Int32Array::from_slice(expected_data);
-        let output = sort_to_indices(&input, &options).unwrap();
+        let output = sort_to_indices::<i32>(&input, &options, None).unwrap();
         assert_eq!(output, expected)
     }
 
@@ -543,7 +552,7 @@ mod tests {
     ) {
         let input = Utf8Array::<i32>::from(&data.to_vec());
         let expected = Utf8Array::<i32>::from(&expected_data.to_vec());
-        let output = sort(&input, &options).unwrap();
+        let output = sort(&input, &options, None).unwrap();
         assert_eq!(expected, output.as_ref())
     }
 
@@ -560,7 +569,7 @@ mod tests {
         expected.try_extend(expected_data.iter().copied()).unwrap();
         let expected = expected.into_arc();
 
-        let output = sort(input.as_ref(), &options).unwrap();
+        let output = sort(input.as_ref(), &options, None).unwrap();
         assert_eq!(expected.as_ref(), output.as_ref())
     }
 
@@ -577,7 +586,7 @@ mod tests {
         // for FixedSizedList
         if let Some(length) = fixed_length {
             let input = Arc::new(build_fixed_size_list_nullable(data.clone(), length));
-            let sorted = sort(&(input as ArrayRef), options).unwrap();
+            let sorted = sort(&(input as ArrayRef), options, None).unwrap();
             let expected = Arc::new(build_fixed_size_list_nullable(
                 expected_data.clone(),
                 length,
@@ -588,7 +597,7 @@ mod tests {
 
         // for List
         let input = Arc::new(build_generic_list_nullable::<i32, T>(data.clone()));
-        let sorted = sort(&(input as ArrayRef), options).unwrap();
+        let sorted = sort(&(input as ArrayRef), options, None).unwrap();
         let expected =
             Arc::new(build_generic_list_nullable::<i32, T>(expected_data.clone()))
                 as ArrayRef;
@@ -597,7 +606,7 @@ mod tests {
 
         // for LargeList
         let input = Arc::new(build_generic_list_nullable::<i64, T>(data));
-        let sorted = sort(&(input as ArrayRef), options).unwrap();
+        let sorted = sort(&(input as ArrayRef), options, None).unwrap();
         let expected =
             Arc::new(build_generic_list_nullable::<i64, T>(expected_data)) as ArrayRef;
 
@@ -605,7 +614,7 @@ mod tests {
     }
 
     fn test_lex_sort_arrays(input: Vec<SortColumn>, expected_output: Vec<ArrayRef>) {
-        let sorted = lexsort(&input).unwrap();
+        let sorted = lexsort::<i32>(&input, None).unwrap();
 
         for (result, expected) in sorted.iter().zip(expected_output.iter()) {
             assert_eq!(result, expected);
@@ -689,6 +698,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sort_f16() {
+        use crate::types::f16;
+        let f = |x: f32| Some(f16::from_f32(x));
+        test_sort_primitive_arrays::<f16>(
+            &[None, f(0.0), f(2.0), f(-1.0), None],
+            DataType::Float16,
+            SortOptions {
+                descending: false,
+                nulls_first: true,
+            },
+            &[None, None, f(-1.0), f(0.0), f(2.0)],
+        );
+        test_sort_primitive_arrays::<f16>(
+            &[None, f(0.0), f(2.0), f(-1.0), None],
+            DataType::Float16,
+            SortOptions {
+                descending: true,
+                nulls_first: false,
+            },
+            &[f(2.0), f(0.0), f(-1.0), None, None],
+        );
+    }
+
     #[test]
     fn test_sort_to_indices_strings() {
         test_sort_to_indices_string_arrays(
@@ -1048,7 +1081,7 @@ mod tests {
             },
         ];
         assert!(
-            lexsort(&input).is_err(),
+            lexsort::<i32>(&input, None).is_err(),
             "lexsort should reject columns with different row counts"
         );
     }
@@ -1100,14 +1133,109 @@ mod tests {
                     descending: true,
                     nulls_first: true,
                 };
-                assert!(sort(array.as_ref(), &options).is_ok());
+                assert!(sort(array.as_ref(), &options, None).is_ok());
             } else {
                 let options = SortOptions {
                     descending: true,
                     nulls_first: true,
                 };
-                assert!(sort(array.as_ref(), &options).is_err());
+                assert!(sort(array.as_ref(), &options, None).is_err());
             }
         });
     }
+
+    #[test]
+    fn binary() {
+        let input = BinaryArray::<i32>::from([Some(b"bb".as_ref()), None, Some(b"a"), Some(b"")]);
+        let options = SortOptions::default();
+        let output = sort_to_indices::<i32>(&input, &options, None).unwrap();
+        assert_eq!(output, Int32Array::from_slice([1, 3, 2, 0]));
+
+        let input = FixedSizeBinaryArray::from_iter(vec![Some(b"bb"), Some(b"ab"), None], 2);
+        let output = sort_to_indices::<i32>(&input, &options, None).unwrap();
+        assert_eq!(output, Int32Array::from_slice([2, 1, 0]));
+    }
+
+    #[test]
+    fn list_of_strings() {
+        let values = Utf8Array::<i32>::from([Some("b"), Some("a"), Some("b"), None, Some("a")]);
+        // [["b", "a"], ["b"], null, [null], ["a"]]
+        let input = ListArray::<i32>::from_data(
+            ListArray::<i32>::default_datatype(DataType::Utf8),
+            Buffer::from([0, 2, 3, 3, 4, 5]),
+            Arc::new(values),
+            Some([true, true, false, true, true].iter().copied().collect()),
+        );
+        let options = SortOptions {
+            descending: false,
+            nulls_first: false,
+        };
+        let output = sort_to_indices::<i32>(&input, &options, None).unwrap();
+        assert_eq!(output, Int32Array::from_slice([3, 4, 1, 0, 2]));
+    }
+
+    #[test]
+    fn struct_() {
+        let a = Int32Array::from([Some(2), Some(1), Some(2), Some(1)]);
+        let b = Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("a")]);
+        let fields = vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ];
+        let input = StructArray::from_data(fields, vec![Arc::new(a), Arc::new(b)], None);
+
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let output = sort_to_indices::<i32>(&input, &options, None).unwrap();
+        assert_eq!(output, Int32Array::from_slice([0, 2, 1, 3]));
+    }
+
+    #[test]
+    fn unsorted_dictionary() {
+        let mut input = MutableDictionaryArray::<i32, MutablePrimitiveArray<i64>>::new();
+        input
+            .try_extend(vec![Some(3), Some(1), None, Some(2), Some(1)])
+            .unwrap();
+        let input: DictionaryArray<i32> = input.into();
+
+        let options = SortOptions::default();
+        let output = sort_to_indices::<i32>(&input, &options, None).unwrap();
+        assert_eq!(output, Int32Array::from_slice([2, 1, 4, 3, 0]));
+    }
+
+    #[test]
+    fn u64_indices() {
+        let input = Utf8Array::<i32>::from([Some("c"), None, Some("a")]);
+        let output = sort_to_indices::<u64>(&input, &SortOptions::default(), None).unwrap();
+        assert_eq!(output, UInt64Array::from_slice([1, 2, 0]));
+    }
+
+    #[test]
+    fn limit() {
+        let input = Int32Array::from([Some(3), None, Some(1), Some(2), Some(1), None]);
+
+        let options = SortOptions {
+            descending: false,
+            nulls_first: false,
+        };
+        let output = sort(&input, &options, Some(3)).unwrap();
+        assert_eq!(
+            output.as_ref(),
+            &Int32Array::from([Some(1), Some(1), Some(2)]) as &dyn Array
+        );
+
+        let options = SortOptions {
+            descending: true,
+            nulls_first: true,
+        };
+        let output = sort_to_indices::<i32>(&input, &options, Some(3)).unwrap();
+        assert_eq!(output, Int32Array::from_slice([1, 5, 0]));
+
+        // ties are kept in their original order
+        let input = BinaryArray::<i32>::from_slice([b"b", b"a", b"b", b"a"]);
+        let output = sort_to_indices::<i32>(&input, &SortOptions::default(), Some(3)).unwrap();
+        assert_eq!(output, Int32Array::from_slice([1, 3, 0]));
+    }
 }
@@ -16,118 +16,43 @@
 // under the License.
 
 use crate::{
-    array::{Array, PrimitiveArray},
-    buffer::MutableBuffer,
-    datatypes::DataType,
+    array::{Index, PrimitiveArray},
     types::NativeType,
 };
 
-use super::super::SortOptions;
+use super::super::{concatenate_indices, partition_validity, sort_with_limit, SortOptions};
 
-/// # Safety
-/// `indices[i] < values.len()` for all i
-#[inline]
-unsafe fn sort_inner<T, F>(indices: &mut [i32], values: &[T], mut cmp: F, descending: bool)
-where
-    T: NativeType,
-    F: FnMut(&T, &T) -> std::cmp::Ordering,
-{
-    if descending {
-        indices.sort_by(|lhs, rhs| {
-            let lhs = values.get_unchecked(*lhs as usize);
-            let rhs = values.get_unchecked(*rhs as usize);
-            cmp(lhs, rhs).reverse()
-        })
-    } else {
-        indices.sort_by(|lhs, rhs| {
-            let lhs = values.get_unchecked(*lhs as usize);
-            let rhs = values.get_unchecked(*rhs as usize);
-            cmp(lhs, rhs)
-        })
-    }
-}
-
-pub fn indices_sorted_by<T, F>(
+/// Returns the indices that sort `array` by `cmp`, up to `limit` of them.
+/// The caller must ensure that every index of `array` fits in `I`.
+pub fn indices_sorted_by<I, T, F>(
     array: &PrimitiveArray<T>,
     cmp: F,
     options: &SortOptions,
-) -> PrimitiveArray<i32>
+    limit: Option<usize>,
+) -> PrimitiveArray<I>
 where
+    I: Index,
     T: NativeType,
     F: Fn(&T, &T) -> std::cmp::Ordering,
 {
-    let descending = options.descending;
     let values = array.values();
-    let validity = array.validity();
-
-    if let Some(validity) = validity {
-        let mut indices = MutableBuffer::<i32>::from_len_zeroed(array.len());
-
-        if options.nulls_first {
-            let mut nulls = 0;
-            let mut valids = 0;
-            validity
-                .iter()
-                .zip(0..array.len() as i32)
-                .for_each(|(x, index)| {
-                    if x {
-                        indices[validity.null_count() + valids] = index;
-                        valids += 1;
-                    } else {
-                        indices[nulls] = index;
-                        nulls += 1;
-                    }
-                });
-            // Soundness:
-            // all indices in `indices` are by construction `< array.len() == values.len()`
-            unsafe {
-                sort_inner(
-                    &mut indices.as_mut_slice()[validity.null_count()..],
-                    values,
-                    cmp,
-                    options.descending,
-                )
-            }
-        } else {
-            let last_valid_index = array.len() - validity.null_count();
-            let mut nulls = 0;
-            let mut valids = 0;
-            validity
-                .iter()
-                .zip(0..array.len() as i32)
-                .for_each(|(x, index)| {
-                    if x {
-                        indices[valids] = index;
-                        valids += 1;
-                    } else {
-                        indices[last_valid_index + nulls] = index;
-                        nulls += 1;
-                    }
-                });
-
-            // Soundness:
-            // all indices in `indices` are by construction `< array.len() == values.len()`
-            unsafe {
-                sort_inner(
-                    &mut indices.as_mut_slice()[..last_valid_index],
-                    values,
-                    cmp,
-                    options.descending,
-                )
-            };
-        }
-
-        PrimitiveArray::<i32>::from_data(DataType::Int32, indices.into(), None)
-    } else {
-        let mut indices =
-            unsafe { MutableBuffer::from_trusted_len_iter_unchecked(0..values.len() as i32) };
-
-        // Soundness:
-        // indices are by construction `< values.len()`
-        unsafe { sort_inner(&mut indices, values, cmp, descending) };
+    let (mut valids, nulls) = partition_validity::<I>(array);
+
+    // Soundness:
+    // all indices in `valids` are by construction `< array.len() == values.len()`
+    sort_with_limit(
+        &mut valids,
+        |lhs, rhs| unsafe {
+            let lhs = values.get_unchecked(lhs.to_usize());
+            let rhs = values.get_unchecked(rhs.to_usize());
+            cmp(lhs, rhs)
+        },
+        |index| index.to_usize(),
+        options.descending,
+        limit,
+    );
 
-        PrimitiveArray::<i32>::from_data(DataType::Int32, indices.into(), None)
-    }
+    concatenate_indices(valids.into_iter(), nulls, options, limit)
 }
 
 #[cfg(test)]
@@ -135,14 +60,15 @@ mod tests {
     use super::*;
     use crate::array::ord;
     use crate::array::*;
+    use crate::datatypes::DataType;
 
     fn test<T>(data: &[Option<T>], data_type: DataType, options: SortOptions, expected_data: &[i32])
     where
         T: NativeType + std::cmp::Ord,
     {
         let input = PrimitiveArray::<T>::from(data).to(data_type);
-        let expected = Int32Array::from_slice(&expected_data);
-        let output = indices_sorted_by(&input, ord::total_cmp, &options);
+        let expected = Int32Array::from_slice(expected_data);
+        let output = indices_sorted_by::<i32, _, _>(&input, ord::total_cmp, &options, None);
         assert_eq!(output, expected)
     }
 
@@ -197,4 +123,19 @@ mod tests {
             &[2, 1, 4, 3, 0, 5],
         );
     }
+
+    #[test]
+    fn limit() {
+        let input = Int8Array::from(&[None, Some(3), Some(5), Some(2), Some(3), None]);
+        let options = SortOptions {
+            descending: false,
+            nulls_first: false,
+        };
+
+        let output = indices_sorted_by::<u64, _, _>(&input, ord::total_cmp, &options, Some(3));
+        assert_eq!(output, UInt64Array::from_slice([3, 1, 4]));
+
+        let output = indices_sorted_by::<u64, _, _>(&input, ord::total_cmp, &options, Some(10));
+        assert_eq!(output, UInt64Array::from_slice([3, 1, 4, 2, 0, 5]));
+    }
 }
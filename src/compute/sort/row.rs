@@ -0,0 +1,654 @@
+//! Order-preserving encoding of rows of multiple columns into bytes.
+//!
+//! Comparing two encoded rows with `memcmp` (i.e. comparing `&[u8]`) yields the same order as
+//! comparing them column by column according to each column's [`SortOptions`], which is much
+//! faster than comparing them via [`ord::build_compare`](crate::array::ord::build_compare).
+//!
+//! # Example
+//! ```
+//! use arrow2::array::{Int32Array, Utf8Array};
+//! use arrow2::compute::sort::{row, SortColumn, SortOptions};
+//!
+//! let a = Int32Array::from(&[Some(1), None, Some(1)]);
+//! let b = Utf8Array::<i32>::from_slice(["b", "a", "a"]);
+//! let columns = [
+//!     SortColumn { values: &a, options: None },
+//!     SortColumn {
+//!         values: &b,
+//!         options: Some(SortOptions { descending: true, nulls_first: true }),
+//!     },
+//! ];
+//! let rows = row::encode(&columns).unwrap();
+//! // nulls first
+//! assert!(rows.row(1) < rows.row(0));
+//! // "b" comes before "a" when descending
+//! assert!(rows.row(0) < rows.row(2));
+//! ```
+use crate::array::*;
+use crate::datatypes::{DataType, IntervalUnit};
+use crate::error::{ArrowError, Result};
+use crate::types::{days_ms, f16, NativeType};
+
+use super::{SortColumn, SortOptions};
+
+/// Marker of a valid value.
+const VALID: u8 = 1;
+/// Size of the blocks in which variable-length values are encoded.
+const BLOCK_SIZE: usize = 32;
+/// Marker of a block followed by more blocks.
+const BLOCK_CONTINUATION: u8 = 0xFF;
+
+/// Rows of one or more columns encoded as bytes, such that the order of two rows is the order
+/// of their bytes. See the [module level documentation](self) for details.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rows {
+    values: Vec<u8>,
+    offsets: Vec<usize>,
+}
+
+impl Rows {
+    /// Returns the number of rows
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Returns whether there are no rows
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the encoded row at `index`
+    /// # Panics
+    /// iff `index >= self.len()`
+    #[inline]
+    pub fn row(&self, index: usize) -> &[u8] {
+        &self.values[self.offsets[index]..self.offsets[index + 1]]
+    }
+
+    /// Returns an iterator over the encoded rows
+    pub fn iter(&self) -> impl Iterator<Item = &[u8]> + '_ {
+        self.offsets
+            .windows(2)
+            .map(move |w| &self.values[w[0]..w[1]])
+    }
+}
+
+/// Types whose values can be encoded in an order-preserving manner
+trait Encode: NativeType {
+    /// Appends `self` to `buffer` such that the order of the bytes is the order of the values
+    fn encode(&self, buffer: &mut Vec<u8>);
+}
+
+macro_rules! encode_unsigned {
+    ($ty:ty) => {
+        impl Encode for $ty {
+            #[inline]
+            fn encode(&self, buffer: &mut Vec<u8>) {
+                buffer.extend_from_slice(&self.to_be_bytes())
+            }
+        }
+    };
+}
+
+macro_rules! encode_signed {
+    ($ty:ty, $unsigned:ty) => {
+        impl Encode for $ty {
+            #[inline]
+            fn encode(&self, buffer: &mut Vec<u8>) {
+                // flipping the sign bit orders negative values before positive ones
+                let value = (*self as $unsigned) ^ (1 << (<$unsigned>::BITS - 1));
+                buffer.extend_from_slice(&value.to_be_bytes())
+            }
+        }
+    };
+}
+
+encode_unsigned!(u8);
+encode_unsigned!(u16);
+encode_unsigned!(u32);
+encode_unsigned!(u64);
+encode_signed!(i8, u8);
+encode_signed!(i16, u16);
+encode_signed!(i32, u32);
+encode_signed!(i64, u64);
+encode_signed!(i128, u128);
+
+// floats are encoded following IEEE 754 totalOrder, like `ord::total_cmp_f32`
+impl Encode for f16 {
+    #[inline]
+    fn encode(&self, buffer: &mut Vec<u8>) {
+        let mut value = self.to_bits() as i16;
+        value ^= (((value >> 15) as u16) >> 1) as i16;
+        value.encode(buffer)
+    }
+}
+
+impl Encode for f32 {
+    #[inline]
+    fn encode(&self, buffer: &mut Vec<u8>) {
+        let mut value = self.to_bits() as i32;
+        value ^= (((value >> 31) as u32) >> 1) as i32;
+        value.encode(buffer)
+    }
+}
+
+impl Encode for f64 {
+    #[inline]
+    fn encode(&self, buffer: &mut Vec<u8>) {
+        let mut value = self.to_bits() as i64;
+        value ^= (((value >> 63) as u64) >> 1) as i64;
+        value.encode(buffer)
+    }
+}
+
+impl Encode for days_ms {
+    #[inline]
+    fn encode(&self, buffer: &mut Vec<u8>) {
+        self.days().encode(buffer);
+        self.milliseconds().encode(buffer);
+    }
+}
+
+/// Appends `value` to `buffer` such that the order of the bytes is the order of the values
+/// and no encoded value is a prefix of another.
+/// Values are split in blocks of [`BLOCK_SIZE`] bytes, the last padded with zeros, each followed
+/// by [`BLOCK_CONTINUATION`] or, for the last block, by its (unpadded) length.
+fn encode_bytes(value: &[u8], buffer: &mut Vec<u8>) {
+    if value.is_empty() {
+        buffer.push(0);
+        return;
+    }
+    buffer.push(1);
+    let mut chunks = value.chunks(BLOCK_SIZE).peekable();
+    while let Some(chunk) = chunks.next() {
+        buffer.extend_from_slice(chunk);
+        if chunks.peek().is_some() {
+            buffer.push(BLOCK_CONTINUATION);
+        } else {
+            buffer.resize(buffer.len() + BLOCK_SIZE - chunk.len(), 0);
+            buffer.push(chunk.len() as u8);
+        }
+    }
+}
+
+#[inline]
+fn null_sentinel(options: &SortOptions) -> u8 {
+    if options.nulls_first {
+        VALID - 1
+    } else {
+        VALID + 1
+    }
+}
+
+/// Appends to each of `rows` its null sentinel or its value, encoded by `encode_value`.
+fn encode_with<F: Fn(usize, &mut Vec<u8>)>(
+    array: &dyn Array,
+    options: &SortOptions,
+    rows: &mut [Vec<u8>],
+    encode_value: F,
+) {
+    let null = null_sentinel(options);
+    rows.iter_mut().enumerate().for_each(|(index, row)| {
+        if array.is_null(index) {
+            row.push(null);
+        } else {
+            row.push(VALID);
+            let start = row.len();
+            encode_value(index, row);
+            if options.descending {
+                row[start..].iter_mut().for_each(|byte| *byte = !*byte);
+            }
+        }
+    })
+}
+
+fn encode_primitive<T: Encode>(array: &dyn Array, options: &SortOptions, rows: &mut [Vec<u8>]) {
+    let values = array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap()
+        .values();
+    encode_with(array, options, rows, |index, row| values[index].encode(row))
+}
+
+fn encode_dictionary<K: DictionaryKey>(
+    array: &dyn Array,
+    options: &SortOptions,
+    rows: &mut [Vec<u8>],
+) -> Result<()> {
+    let array = array.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+
+    // each value is encoded once and then copied to the rows that use it
+    let mut values = vec![vec![]; array.values().len()];
+    encode_array(array.values().as_ref(), options, &mut values)?;
+
+    let null = null_sentinel(options);
+    rows.iter_mut()
+        .zip(array.keys().iter())
+        .for_each(|(row, key)| match key {
+            Some(key) => row.extend_from_slice(&values[key.to_usize().unwrap()]),
+            None => row.push(null),
+        });
+    Ok(())
+}
+
+/// Items of nested types are ordered like `ord::build_compare` does: ascending and with nulls
+/// first. `descending` inverts the whole encoded value.
+const NESTED_OPTIONS: SortOptions = SortOptions {
+    descending: false,
+    nulls_first: true,
+};
+
+fn encode_list<O: Offset>(
+    array: &dyn Array,
+    options: &SortOptions,
+    rows: &mut [Vec<u8>],
+) -> Result<()> {
+    let array = array.as_any().downcast_ref::<ListArray<O>>().unwrap();
+
+    let mut items = vec![vec![]; array.values().len()];
+    encode_array(array.values().as_ref(), &NESTED_OPTIONS, &mut items)?;
+
+    let offsets = array.offsets().as_slice();
+    encode_with(array, options, rows, |index, row| {
+        let start = offsets[index].to_usize();
+        let end = offsets[index + 1].to_usize();
+        // every item is preceded by a marker so that shorter lists are ordered first
+        items[start..end].iter().for_each(|item| {
+            row.push(1);
+            row.extend_from_slice(item);
+        });
+        row.push(0);
+    });
+    Ok(())
+}
+
+fn encode_fixed_size_list(
+    array: &dyn Array,
+    options: &SortOptions,
+    rows: &mut [Vec<u8>],
+) -> Result<()> {
+    let size = *FixedSizeListArray::get_child_and_size(array.data_type()).1 as usize;
+    let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+
+    let mut items = vec![vec![]; array.values().len()];
+    encode_array(array.values().as_ref(), &NESTED_OPTIONS, &mut items)?;
+
+    encode_with(array, options, rows, |index, row| {
+        items[index * size..(index + 1) * size]
+            .iter()
+            .for_each(|item| row.extend_from_slice(item));
+    });
+    Ok(())
+}
+
+fn encode_struct(array: &dyn Array, options: &SortOptions, rows: &mut [Vec<u8>]) -> Result<()> {
+    let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+
+    let mut fields = vec![vec![]; array.len()];
+    for values in array.values() {
+        encode_array(values.as_ref(), &NESTED_OPTIONS, &mut fields)?;
+    }
+
+    encode_with(array, options, rows, |index, row| {
+        row.extend_from_slice(&fields[index])
+    });
+    Ok(())
+}
+
+macro_rules! dyn_dict {
+    ($key:ty, $array:expr, $options:expr, $rows:expr) => {
+        encode_dictionary::<$key>($array, $options, $rows)
+    };
+}
+
+/// Appends the encoding of each slot of `array` to the corresponding item of `rows`.
+fn encode_array(array: &dyn Array, options: &SortOptions, rows: &mut [Vec<u8>]) -> Result<()> {
+    use DataType::*;
+    match array.data_type() {
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            encode_with(array, options, rows, |index, row| {
+                row.push(array.value(index) as u8)
+            })
+        }
+        Int8 => encode_primitive::<i8>(array, options, rows),
+        Int16 => encode_primitive::<i16>(array, options, rows),
+        Int32 | Date32 | Time32(_) | Interval(IntervalUnit::YearMonth) => {
+            encode_primitive::<i32>(array, options, rows)
+        }
+        Int64 | Date64 | Time64(_) | Timestamp(_, None) | Duration(_) => {
+            encode_primitive::<i64>(array, options, rows)
+        }
+        UInt8 => encode_primitive::<u8>(array, options, rows),
+        UInt16 => encode_primitive::<u16>(array, options, rows),
+        UInt32 => encode_primitive::<u32>(array, options, rows),
+        UInt64 => encode_primitive::<u64>(array, options, rows),
+        Float16 => encode_primitive::<f16>(array, options, rows),
+        Float32 => encode_primitive::<f32>(array, options, rows),
+        Float64 => encode_primitive::<f64>(array, options, rows),
+        Interval(IntervalUnit::DayTime) => encode_primitive::<days_ms>(array, options, rows),
+        Decimal(_, _) => encode_primitive::<i128>(array, options, rows),
+        Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            encode_with(array, options, rows, |index, row| {
+                encode_bytes(array.value(index).as_bytes(), row)
+            })
+        }
+        LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            encode_with(array, options, rows, |index, row| {
+                encode_bytes(array.value(index).as_bytes(), row)
+            })
+        }
+        Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            encode_with(array, options, rows, |index, row| {
+                encode_bytes(array.value(index), row)
+            })
+        }
+        LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            encode_with(array, options, rows, |index, row| {
+                encode_bytes(array.value(index), row)
+            })
+        }
+        FixedSizeBinary(_) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            encode_with(array, options, rows, |index, row| {
+                row.extend_from_slice(array.value(index))
+            })
+        }
+        List(_) => encode_list::<i32>(array, options, rows)?,
+        LargeList(_) => encode_list::<i64>(array, options, rows)?,
+        FixedSizeList(_, _) => encode_fixed_size_list(array, options, rows)?,
+        Struct(_) => encode_struct(array, options, rows)?,
+        Dictionary(key_type, _) => match key_type.as_ref() {
+            Int8 => dyn_dict!(i8, array, options, rows)?,
+            Int16 => dyn_dict!(i16, array, options, rows)?,
+            Int32 => dyn_dict!(i32, array, options, rows)?,
+            Int64 => dyn_dict!(i64, array, options, rows)?,
+            UInt8 => dyn_dict!(u8, array, options, rows)?,
+            UInt16 => dyn_dict!(u16, array, options, rows)?,
+            UInt32 => dyn_dict!(u32, array, options, rows)?,
+            UInt64 => dyn_dict!(u64, array, options, rows)?,
+            t => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Row encoding not supported for dictionary key type {:?}",
+                    t
+                )))
+            }
+        },
+        t => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Row encoding not supported for data type {:?}",
+                t
+            )))
+        }
+    };
+    Ok(())
+}
+
+/// Checks if arrays of type `data_type` can be encoded into [`Rows`]
+pub fn can_encode(data_type: &DataType) -> bool {
+    use DataType::*;
+    match data_type {
+        Boolean
+        | Int8
+        | Int16
+        | Int32
+        | Date32
+        | Time32(_)
+        | Interval(_)
+        | Int64
+        | Date64
+        | Time64(_)
+        | Timestamp(_, None)
+        | Duration(_)
+        | UInt8
+        | UInt16
+        | UInt32
+        | UInt64
+        | Float16
+        | Float32
+        | Float64
+        | Decimal(_, _)
+        | Utf8
+        | LargeUtf8
+        | Binary
+        | LargeBinary
+        | FixedSizeBinary(_) => true,
+        List(field) | LargeList(field) | FixedSizeList(field, _) => can_encode(field.data_type()),
+        Struct(fields) => fields.iter().all(|field| can_encode(field.data_type())),
+        Dictionary(key_type, value_type) => {
+            matches!(
+                key_type.as_ref(),
+                Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64
+            ) && can_encode(value_type.as_ref())
+        }
+        _ => false,
+    }
+}
+
+/// Encodes the rows of `columns` into [`Rows`], whose order is the lexicographical order of
+/// the columns, each ordered according to its [`SortOptions`].
+/// # Errors
+/// Errors if `columns` is empty, if the columns have different lengths or if the type of any
+/// of them can't be encoded (see [`can_encode`]).
+pub fn encode(columns: &[SortColumn]) -> Result<Rows> {
+    if columns.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Row encoding requires at least one column".to_string(),
+        ));
+    }
+    let length = columns[0].values.len();
+    if columns.iter().any(|column| column.values.len() != length) {
+        return Err(ArrowError::InvalidArgumentError(
+            "Row encoding requires columns of the same length".to_string(),
+        ));
+    }
+
+    let mut rows = vec![vec![]; length];
+    for column in columns {
+        encode_array(
+            column.values,
+            &column.options.unwrap_or_default(),
+            &mut rows,
+        )?;
+    }
+
+    let mut offsets = Vec::with_capacity(length + 1);
+    offsets.push(0);
+    let mut values = Vec::with_capacity(rows.iter().map(|row| row.len()).sum());
+    for row in rows {
+        values.extend_from_slice(&row);
+        offsets.push(values.len());
+    }
+    Ok(Rows { values, offsets })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::array::ord::build_compare;
+    use crate::buffer::Buffer;
+    use crate::compute::sort::build_compare as build_sort_compare;
+    use crate::datatypes::Field;
+
+    /// checks that the order of the rows is the order of the comparator of `array`
+    fn check(array: &dyn Array) {
+        for options in [
+            SortOptions {
+                descending: false,
+                nulls_first: false,
+            },
+            SortOptions {
+                descending: false,
+                nulls_first: true,
+            },
+            SortOptions {
+                descending: true,
+                nulls_first: false,
+            },
+            SortOptions {
+                descending: true,
+                nulls_first: true,
+            },
+        ] {
+            let rows = encode(&[SortColumn {
+                values: array,
+                options: Some(options),
+            }])
+            .unwrap();
+            let cmp = build_sort_compare(array, options).unwrap();
+            for i in 0..array.len() {
+                for j in 0..array.len() {
+                    assert_eq!(
+                        rows.row(i).cmp(rows.row(j)),
+                        cmp(i, j),
+                        "{:?} {} {}",
+                        options,
+                        i,
+                        j
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn primitive() {
+        check(&Int32Array::from([
+            Some(1),
+            None,
+            Some(-1),
+            Some(i32::MIN),
+            Some(0),
+        ]));
+        check(&UInt64Array::from([Some(1), None, Some(u64::MAX), Some(0)]));
+        check(&Int128Array::from([Some(1), None, Some(-1)]).to(DataType::Decimal(5, 2)));
+        check(&Float64Array::from([
+            Some(1.0),
+            None,
+            Some(-0.0),
+            Some(0.0),
+            Some(f64::NAN),
+            Some(-f64::NAN),
+            Some(f64::NEG_INFINITY),
+            Some(-1.5),
+        ]));
+        check(&BooleanArray::from([Some(true), None, Some(false)]));
+    }
+
+    #[test]
+    fn bytes() {
+        let long = "a".repeat(BLOCK_SIZE);
+        let longer = "a".repeat(BLOCK_SIZE + 1);
+        check(&Utf8Array::<i32>::from([
+            Some("a"),
+            None,
+            Some(""),
+            Some("a\0"),
+            Some("b"),
+            Some(long.as_str()),
+            Some(longer.as_str()),
+            Some("ab"),
+        ]));
+        check(&BinaryArray::<i64>::from([
+            Some(b"".as_ref()),
+            Some(b"\0"),
+            None,
+            Some(b"\xff"),
+        ]));
+    }
+
+    #[test]
+    fn dictionary() {
+        let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+        array
+            .try_extend(vec![Some("c"), Some("a"), None, Some("b"), Some("a")])
+            .unwrap();
+        let array: DictionaryArray<i32> = array.into();
+        check(&array);
+    }
+
+    #[test]
+    fn nested() {
+        let values = Int32Array::from([Some(1), Some(2), Some(1), None, Some(1)]);
+        // [[1, 2], [1, null], [1], null, []]
+        let array = ListArray::<i32>::from_data(
+            ListArray::<i32>::default_datatype(DataType::Int32),
+            Buffer::from([0, 2, 4, 5, 5, 5]),
+            Arc::new(values),
+            Some([true, true, true, false, true].iter().copied().collect()),
+        );
+        check(&array);
+
+        let a = Int32Array::from([Some(2), Some(1), Some(2), Some(1)]);
+        let b = Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("a")]);
+        let fields = vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ];
+        let array = StructArray::from_data(
+            fields,
+            vec![Arc::new(a), Arc::new(b)],
+            Some([true, false, true, true].iter().copied().collect()),
+        );
+        check(&array);
+    }
+
+    #[test]
+    fn multiple_columns() {
+        let a = Int32Array::from([Some(1), Some(1), Some(0)]);
+        let b = Utf8Array::<i32>::from_slice(["b", "a", "c"]);
+        let columns = [
+            SortColumn {
+                values: &a,
+                options: None,
+            },
+            SortColumn {
+                values: &b,
+                options: None,
+            },
+        ];
+        let rows = encode(&columns).unwrap();
+        assert_eq!(rows.len(), 3);
+
+        let cmp_a = build_compare(&a, &a).unwrap();
+        let cmp_b = build_compare(&b, &b).unwrap();
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = cmp_a(i, j).then_with(|| cmp_b(i, j));
+                assert_eq!(rows.row(i).cmp(rows.row(j)), expected);
+            }
+        }
+        assert_eq!(rows.iter().count(), 3);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(encode(&[]).is_err());
+
+        let a = Int32Array::from_slice([1]);
+        let b = NullArray::from_data(1);
+        let columns = [
+            SortColumn {
+                values: &a,
+                options: None,
+            },
+            SortColumn {
+                values: &b,
+                options: None,
+            },
+        ];
+        assert!(encode(&columns).is_err());
+    }
+}
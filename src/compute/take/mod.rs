@@ -21,6 +21,7 @@ use crate::{
     array::{new_empty_array, Array, NullArray, PrimitiveArray},
     datatypes::{DataType, IntervalUnit},
     error::Result,
+    types::f16,
 };
 
 pub use crate::array::Index;
@@ -80,7 +81,7 @@ pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result
         DataType::UInt16 => downcast_take!(u16, values, indices),
         DataType::UInt32 => downcast_take!(u32, values, indices),
         DataType::UInt64 => downcast_take!(u64, values, indices),
-        DataType::Float16 => unreachable!(),
+        DataType::Float16 => downcast_take!(f16, values, indices),
         DataType::Float32 => downcast_take!(f32, values, indices),
         DataType::Float64 => downcast_take!(f64, values, indices),
         DataType::Decimal(_, _) => downcast_take!(i128, values, indices),
@@ -0,0 +1,210 @@
+//! Defines kernels to compute the distinct values of an array, their frequencies and to
+//! dictionary-encode arrays.
+//!
+//! These kernels support every type supported by [`group_by`](super::groupby::group_by), which
+//! includes every type supported by [`can_hash`](super::hash::can_hash).
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::datatypes::{DataType, Field};
+use crate::error::{ArrowError, Result};
+
+use super::groupby::{group_by, key_validity};
+use super::take::take;
+
+/// Returns the distinct values of `array`, in the order in which they first appear.
+/// Null is returned as a value when `array` contains nulls.
+/// # Errors
+/// This function errors if the type of `array` can't be hashed.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array};
+/// use arrow2::compute::unique::unique;
+///
+/// let array = Int32Array::from(&[Some(2), None, Some(1), Some(2), None]);
+/// let result = unique(&array).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from(&[Some(2), None, Some(1)]) as &dyn Array);
+/// ```
+pub fn unique(array: &dyn Array) -> Result<Box<dyn Array>> {
+    let groups = group_by(&[array])?;
+    take(array, groups.indices())
+}
+
+/// Returns the number of distinct values of `array`, where null counts as a value.
+/// This equals the length of [`unique`].
+/// # Errors
+/// This function errors if the type of `array` can't be hashed.
+pub fn n_unique(array: &dyn Array) -> Result<usize> {
+    Ok(group_by(&[array])?.len())
+}
+
+/// Returns the distinct values of `array` and the number of times each of them appears, as a
+/// [`StructArray`] with the fields `values` and `counts` (`UInt64`).
+/// Values are returned in the order in which they first appear and null is counted as a value.
+/// # Errors
+/// This function errors if the type of `array` can't be hashed.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Utf8Array, UInt64Array};
+/// use arrow2::compute::unique::value_counts;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("a"), Some("b"), None, Some("a")]);
+/// let result = value_counts(&array).unwrap();
+/// let values = Utf8Array::<i32>::from(&[Some("a"), Some("b"), None]);
+/// assert_eq!(result.values()[0].as_ref(), &values as &dyn Array);
+/// assert_eq!(result.values()[1].as_ref(), &UInt64Array::from_slice(&[2, 1, 1]) as &dyn Array);
+/// ```
+pub fn value_counts(array: &dyn Array) -> Result<StructArray> {
+    let groups = group_by(&[array])?;
+    let values = take(array, groups.indices())?;
+
+    let mut counts = vec![0u64; groups.len()];
+    groups.ids().iter().for_each(|id| counts[*id as usize] += 1);
+    let counts = PrimitiveArray::<u64>::from_data(DataType::UInt64, counts.into(), None);
+
+    let fields = vec![
+        Field::new("values", array.data_type().clone(), true),
+        Field::new("counts", DataType::UInt64, false),
+    ];
+    Ok(StructArray::from_data(
+        fields,
+        vec![values.into(), Arc::new(counts)],
+        None,
+    ))
+}
+
+/// Dictionary-encodes `array`: the values of the result are the distinct non-null values of
+/// `array`, in the order in which they first appear, and its keys point to them. Nulls of
+/// `array`, including slots of dictionaries whose value is null, are null keys.
+/// # Errors
+/// This function errors if the type of `array` can't be hashed or if the number of distinct
+/// values does not fit in `K`.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int8Array, Utf8Array};
+/// use arrow2::compute::unique::dictionary_encode;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("a"), None, Some("b"), Some("a")]);
+/// let result = dictionary_encode::<i8>(&array).unwrap();
+/// assert_eq!(result.keys(), &Int8Array::from(&[Some(0), None, Some(1), Some(0)]));
+/// let values = Utf8Array::<i32>::from_slice(&["a", "b"]);
+/// assert_eq!(result.values().as_ref(), &values as &dyn Array);
+/// ```
+pub fn dictionary_encode<K: DictionaryKey>(array: &dyn Array) -> Result<DictionaryArray<K>> {
+    let groups = group_by(&[array])?;
+    // slots of a dictionary whose value is null are null
+    let validity = key_validity(array);
+    let is_null = |row: usize| validity.as_ref().map(|x| !x.get_bit(row)).unwrap_or(false);
+
+    // the key of each group; the group of nulls (if any) has no key
+    let mut indices = vec![];
+    let keys_of_groups = groups
+        .indices()
+        .values()
+        .iter()
+        .map(|index| {
+            if is_null(*index as usize) {
+                return Ok(None);
+            }
+            let key = K::from_usize(indices.len()).ok_or(ArrowError::KeyOverflowError)?;
+            indices.push(*index);
+            Ok(Some(key))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let values = take(
+        array,
+        &PrimitiveArray::<u32>::from_data(DataType::UInt32, indices.into(), None),
+    )?;
+    let keys = groups
+        .ids()
+        .iter()
+        .map(|id| keys_of_groups[*id as usize])
+        .collect::<Vec<_>>();
+    let keys = PrimitiveArray::<K>::from(keys).to(K::DATA_TYPE);
+
+    Ok(DictionaryArray::<K>::from_data(keys, values.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unique_() -> Result<()> {
+        let array = Float64Array::from([Some(1.0), Some(f64::NAN), Some(1.0), Some(f64::NAN)]);
+        let result = unique(&array)?;
+        let result = result.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result.value(0), 1.0);
+        assert!(result.value(1).is_nan());
+
+        assert_eq!(n_unique(&array)?, 2);
+        assert_eq!(n_unique(&Int32Array::from([Some(1), None, None]))?, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn value_counts_() -> Result<()> {
+        let array = BooleanArray::from([Some(true), None, Some(true), Some(false), None]);
+        let result = value_counts(&array)?;
+
+        let values = BooleanArray::from([Some(true), None, Some(false)]);
+        let counts = UInt64Array::from_slice([2, 2, 1]);
+        assert_eq!(result.values()[0].as_ref(), &values as &dyn Array);
+        assert_eq!(result.values()[1].as_ref(), &counts as &dyn Array);
+        Ok(())
+    }
+
+    #[test]
+    fn dictionary_encode_() -> Result<()> {
+        let array = Int64Array::from([None, Some(3), Some(1), Some(3)]);
+        let result = dictionary_encode::<u8>(&array)?;
+
+        assert_eq!(
+            result.keys(),
+            &UInt8Array::from([None, Some(0), Some(1), Some(0)])
+        );
+        let values = Int64Array::from_slice([3, 1]);
+        assert_eq!(result.values().as_ref(), &values as &dyn Array);
+        assert_eq!(
+            result.data_type(),
+            &DataType::Dictionary(Box::new(DataType::UInt8), Box::new(DataType::Int64))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn dictionary_encode_null_value() -> Result<()> {
+        // a valid key pointing to a null value is null
+        let values = Arc::new(Utf8Array::<i32>::from([None, Some("a")])) as Arc<dyn Array>;
+        let keys = Int8Array::from([Some(0), Some(1), None, Some(0)]);
+        let array = DictionaryArray::<i8>::from_data(keys, values);
+        let result = dictionary_encode::<u8>(&array)?;
+
+        assert_eq!(
+            result.keys(),
+            &UInt8Array::from([None, Some(0), None, None])
+        );
+        let values = DictionaryArray::<i8>::from_data(
+            Int8Array::from_slice([1]),
+            Arc::new(Utf8Array::<i32>::from([None, Some("a")])),
+        );
+        assert_eq!(result.values().as_ref(), &values as &dyn Array);
+        Ok(())
+    }
+
+    #[test]
+    fn dictionary_encode_overflow() {
+        let array = Int32Array::from_slice((0..300).collect::<Vec<_>>());
+        assert!(dictionary_encode::<u8>(&array).is_err());
+        assert!(dictionary_encode::<u16>(&array).is_ok());
+    }
+
+    #[test]
+    fn not_hashable() {
+        let array = NullArray::from_data(2);
+        assert!(unique(&array).is_err());
+        assert!(value_counts(&array).is_err());
+    }
+}
@@ -6,6 +6,7 @@
 //! * [`Schema`]
 //! * [`TimeUnit`]
 //! * [`IntervalUnit`]
+//! * [`UnionMode`]
 mod field;
 mod schema;
 
@@ -23,6 +24,8 @@ pub use schema::Schema;
 /// Currently the Rust implementation supports the following  nested types:
 ///  - `List<T>`
 ///  - `Struct<T, U, V, ...>`
+///  - `Union<T, U, V, ...>`
+///  - `Map<K, V>`
 ///
 /// Nested types can themselves be nested within other arrays.
 /// For more information on these types please see
@@ -103,7 +106,26 @@ pub enum DataType {
     /// A nested datatype that contains a number of sub-fields.
     Struct(Vec<Field>),
     /// A nested datatype that can represent slots of differing types.
-    Union(Vec<Field>),
+    /// The second parameter are the optional type ids of each field (when `None`, the
+    /// type id of a field equals its position) and the third is whether the union
+    /// is sparse or dense.
+    Union(Vec<Field>, Option<Vec<i32>>, UnionMode),
+    /// A nested type that is represented as
+    ///
+    /// List<entries: Struct<key: K, value: V>>
+    ///
+    /// In this layout, the keys and values are each respectively contiguous. We do
+    /// not constrain the key and value types, so the application is responsible
+    /// for ensuring that the keys are hashable and unique. Whether the keys are sorted
+    /// may be set in the metadata for this field.
+    ///
+    /// In a field with Map type, the field has a child Struct field, which then
+    /// has two children: key type and the second the value type. The names of the
+    /// child fields may be respectively "entries", "key", and "value", but this is
+    /// not enforced.
+    ///
+    /// The second parameter is whether the keys are sorted.
+    Map(Box<Field>, bool),
     /// A dictionary encoded array (`key_type`, `value_type`), where
     /// each array element is an index of `key_type` into an
     /// associated dictionary of `value_type`.
@@ -151,6 +173,37 @@ pub enum IntervalUnit {
     DayTime,
 }
 
+/// Mode of [`DataType::Union`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum UnionMode {
+    /// Dense union: every slot has an offset into the child of its type.
+    Dense,
+    /// Sparse union: every child has the same length as the union.
+    Sparse,
+}
+
+impl UnionMode {
+    /// Constructs a [`UnionMode::Sparse`] if the input bool is true,
+    /// or otherwise constructs a [`UnionMode::Dense`]
+    pub fn sparse(is_sparse: bool) -> Self {
+        if is_sparse {
+            Self::Sparse
+        } else {
+            Self::Dense
+        }
+    }
+
+    /// Returns whether the mode is sparse
+    pub fn is_sparse(&self) -> bool {
+        matches!(self, Self::Sparse)
+    }
+
+    /// Returns whether the mode is dense
+    pub fn is_dense(&self) -> bool {
+        matches!(self, Self::Dense)
+    }
+}
+
 impl DataType {
     /// Compares the datatype with another, ignoring nested field names
     /// and metadata.
@@ -160,6 +213,11 @@ impl DataType {
             | (DataType::LargeList(a), DataType::LargeList(b)) => {
                 a.is_nullable() == b.is_nullable() && a.data_type().equals_datatype(b.data_type())
             }
+            (DataType::Map(a, a_sorted), DataType::Map(b, b_sorted)) => {
+                a_sorted == b_sorted
+                    && a.is_nullable() == b.is_nullable()
+                    && a.data_type().equals_datatype(b.data_type())
+            }
             (DataType::FixedSizeList(a, a_size), DataType::FixedSizeList(b, b_size)) => {
                 a_size == b_size
                     && a.is_nullable() == b.is_nullable()
@@ -172,6 +230,15 @@ impl DataType {
                             && a.data_type().equals_datatype(b.data_type())
                     })
             }
+            (DataType::Union(a, a_ids, a_mode), DataType::Union(b, b_ids, b_mode)) => {
+                a_ids == b_ids
+                    && a_mode == b_mode
+                    && a.len() == b.len()
+                    && a.iter().zip(b).all(|(a, b)| {
+                        a.is_nullable() == b.is_nullable()
+                            && a.data_type().equals_datatype(b.data_type())
+                    })
+            }
             _ => self == other,
         }
     }
@@ -20,9 +20,12 @@
 use super::ffi::ArrowArrayRef;
 use crate::array::{BooleanArray, FromFfi};
 use crate::error::{ArrowError, Result};
-use crate::types::days_ms;
+use crate::types::{days_ms, f16};
 use crate::{
-    array::{Array, BinaryArray, ListArray, PrimitiveArray, StructArray, Utf8Array},
+    array::{
+        Array, BinaryArray, DictionaryArray, ListArray, MapArray, PrimitiveArray, StructArray,
+        UnionArray, Utf8Array,
+    },
     datatypes::{DataType, IntervalUnit},
 };
 
@@ -56,7 +59,7 @@ pub fn try_from<A: ArrowArrayRef>(array: A) -> Result<Box<dyn Array>> {
         DataType::UInt16 => Box::new(PrimitiveArray::<u16>::try_from_ffi(array)?),
         DataType::UInt32 => Box::new(PrimitiveArray::<u32>::try_from_ffi(array)?),
         DataType::UInt64 => Box::new(PrimitiveArray::<u64>::try_from_ffi(array)?),
-        DataType::Float16 => unreachable!(),
+        DataType::Float16 => Box::new(PrimitiveArray::<f16>::try_from_ffi(array)?),
         DataType::Float32 => Box::new(PrimitiveArray::<f32>::try_from_ffi(array)?),
         DataType::Float64 => Box::new(PrimitiveArray::<f64>::try_from_ffi(array)?),
         DataType::Utf8 => Box::new(Utf8Array::<i32>::try_from_ffi(array)?),
@@ -66,6 +69,24 @@ pub fn try_from<A: ArrowArrayRef>(array: A) -> Result<Box<dyn Array>> {
         DataType::List(_) => Box::new(ListArray::<i32>::try_from_ffi(array)?),
         DataType::LargeList(_) => Box::new(ListArray::<i64>::try_from_ffi(array)?),
         DataType::Struct(_) => Box::new(StructArray::try_from_ffi(array)?),
+        DataType::Union(_, _, _) => Box::new(UnionArray::try_from_ffi(array)?),
+        DataType::Map(_, _) => Box::new(MapArray::try_from_ffi(array)?),
+        DataType::Dictionary(key_type, _) => match key_type.as_ref() {
+            DataType::Int8 => Box::new(DictionaryArray::<i8>::try_from_ffi(array)?),
+            DataType::Int16 => Box::new(DictionaryArray::<i16>::try_from_ffi(array)?),
+            DataType::Int32 => Box::new(DictionaryArray::<i32>::try_from_ffi(array)?),
+            DataType::Int64 => Box::new(DictionaryArray::<i64>::try_from_ffi(array)?),
+            DataType::UInt8 => Box::new(DictionaryArray::<u8>::try_from_ffi(array)?),
+            DataType::UInt16 => Box::new(DictionaryArray::<u16>::try_from_ffi(array)?),
+            DataType::UInt32 => Box::new(DictionaryArray::<u32>::try_from_ffi(array)?),
+            DataType::UInt64 => Box::new(DictionaryArray::<u64>::try_from_ffi(array)?),
+            _ => {
+                return Err(ArrowError::Ffi(format!(
+                    "Dictionary key type {:?} is not supported",
+                    key_type
+                )))
+            }
+        },
         data_type => {
             return Err(ArrowError::NotYetImplemented(format!(
                 "Reading DataType \"{}\" is not yet supported.",
@@ -168,4 +189,115 @@ mod tests {
 
         test_round_trip(array)
     }
+
+    #[test]
+    fn test_list_list() -> Result<()> {
+        let data = vec![
+            Some(vec![
+                Some(vec![Some(1i32), Some(2)]),
+                None,
+                Some(vec![Some(3)]),
+            ]),
+            None,
+            Some(vec![Some(vec![]), Some(vec![Some(4), None, Some(6)])]),
+        ];
+
+        let mut array =
+            MutableListArray::<i32, MutableListArray<i32, MutablePrimitiveArray<i32>>>::new();
+        array.try_extend(data)?;
+
+        let array: ListArray<i32> = array.into();
+
+        test_round_trip(array)
+    }
+
+    #[test]
+    fn test_struct_list() -> Result<()> {
+        let data = vec![Some(vec![Some(1i32), Some(2)]), None, Some(vec![None])];
+        let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+        list.try_extend(data)?;
+        let list: ListArray<i32> = list.into();
+
+        let fields = vec![
+            crate::datatypes::Field::new("a", list.data_type().clone(), true),
+            crate::datatypes::Field::new("b", DataType::Utf8, false),
+        ];
+        let values = vec![
+            Arc::new(list) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])) as Arc<dyn Array>,
+        ];
+        let array = StructArray::from_data(fields, values, Some([true, false, true].into()));
+
+        test_round_trip(array)
+    }
+
+    #[test]
+    fn test_dictionary() -> Result<()> {
+        let data = vec![Some("a"), None, Some("b"), Some("a")];
+
+        let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+        array.try_extend(data)?;
+
+        let array: DictionaryArray<i32> = array.into();
+
+        test_round_trip(array)
+    }
+
+    #[test]
+    // the C structs are not `Send + Sync`; they are only shared within this test
+    #[allow(clippy::arc_with_non_send_sync)]
+    fn test_dictionary_invalid_key() -> Result<()> {
+        let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+        array.try_extend(vec![Some("a"), None])?;
+        let array: DictionaryArray<i32> = array.into();
+        let array = ffi::export_to_c(Arc::new(array))?;
+
+        // a producer declaring float keys
+        let data_type = DataType::Dictionary(Box::new(DataType::Float32), Box::new(DataType::Utf8));
+        let field = crate::datatypes::Field::new("", data_type, true);
+        let array = Arc::new(ffi::ArrowArray {
+            array: array.array,
+            schema: Arc::new(ffi::ffi::Ffi_ArrowSchema::try_new(field)?),
+        });
+
+        let error = try_from(array).unwrap_err();
+        assert!(error.to_string().contains("Dictionary key type"));
+        Ok(())
+    }
+
+    fn union_fields() -> (Vec<crate::datatypes::Field>, Vec<Arc<dyn Array>>) {
+        let fields = vec![
+            crate::datatypes::Field::new("a", DataType::Int32, true),
+            crate::datatypes::Field::new("b", DataType::Utf8, true),
+        ];
+        let values = vec![
+            Arc::new(Int32Array::from(&[Some(1), None, Some(2)])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("b"), Some("c")])) as Arc<dyn Array>,
+        ];
+        (fields, values)
+    }
+
+    #[test]
+    fn test_sparse_union() -> Result<()> {
+        let (fields, values) = union_fields();
+        let data_type = DataType::Union(fields, None, crate::datatypes::UnionMode::Sparse);
+        let array = UnionArray::from_data(data_type, vec![0, 1, 0].into(), values, None);
+
+        test_round_trip(array)
+    }
+
+    #[test]
+    fn test_dense_union() -> Result<()> {
+        let (fields, values) = union_fields();
+        let data_type =
+            DataType::Union(fields, Some(vec![3, 5]), crate::datatypes::UnionMode::Dense);
+        let array = UnionArray::from_data(
+            data_type,
+            vec![3, 5, 3].into(),
+            values,
+            Some(vec![0, 2, 2].into()),
+        );
+
+        test_round_trip(array)
+    }
 }
@@ -29,7 +29,7 @@ use crate::{
         bytes::{Bytes, Deallocation},
         Buffer,
     },
-    datatypes::{DataType, Field, TimeUnit},
+    datatypes::{DataType, Field, TimeUnit, UnionMode},
     error::{ArrowError, Result},
     types::NativeType,
 };
@@ -38,6 +38,7 @@ use crate::{
 struct SchemaPrivateData {
     field: Field,
     children_ptr: Box<[*mut Ffi_ArrowSchema]>,
+    dictionary: Option<*mut Ffi_ArrowSchema>,
 }
 
 /// ABI-compatible struct for `ArrowSchema` from C Data Interface
@@ -71,13 +72,16 @@ unsafe extern "C" fn c_release_schema(schema: *mut Ffi_ArrowSchema) {
     for child in private.children_ptr.iter() {
         let _ = Box::from_raw(*child);
     }
+    if let Some(dictionary) = private.dictionary {
+        let _ = Box::from_raw(dictionary);
+    }
 
     schema.release = None;
 }
 
 impl Ffi_ArrowSchema {
     /// create a new [`Ffi_ArrowSchema`]. This fails if the fields' [`DataType`] is not supported.
-    fn try_new(field: Field) -> Result<Ffi_ArrowSchema> {
+    pub(super) fn try_new(field: Field) -> Result<Ffi_ArrowSchema> {
         let format = to_format(field.data_type())?;
         let name = field.name().clone();
 
@@ -89,24 +93,39 @@ impl Ffi_ArrowSchema {
             DataType::LargeList(field) => {
                 vec![Box::new(Ffi_ArrowSchema::try_new(field.as_ref().clone())?)]
             }
-            DataType::Struct(fields) => fields
+            DataType::Map(field, _) => {
+                vec![Box::new(Ffi_ArrowSchema::try_new(field.as_ref().clone())?)]
+            }
+            DataType::Struct(fields) | DataType::Union(fields, _, _) => fields
                 .iter()
                 .map(|field| Ok(Box::new(Ffi_ArrowSchema::try_new(field.clone())?)))
                 .collect::<Result<Vec<_>>>()?,
             _ => vec![],
         };
+        // the values of a dictionary-encoded array are described by the schema's dictionary
+        let dictionary = if let DataType::Dictionary(_, values) = field.data_type() {
+            let field = Field::new("", values.as_ref().clone(), true);
+            Some(Box::new(Ffi_ArrowSchema::try_new(field)?))
+        } else {
+            None
+        };
         // note: this cannot be done along with the above because the above is fallible and this op leaks.
         let children_ptr = children_vec
             .into_iter()
             .map(Box::into_raw)
             .collect::<Box<_>>();
         let n_children = children_ptr.len() as i64;
+        let dictionary = dictionary.map(Box::into_raw);
 
-        let flags = field.is_nullable() as i64 * 2;
+        let mut flags = field.is_nullable() as i64 * 2;
+        if let DataType::Map(_, true) = field.data_type() {
+            flags += 4;
+        }
 
         let mut private = Box::new(SchemaPrivateData {
             field,
             children_ptr,
+            dictionary,
         });
 
         // <https://arrow.apache.org/docs/format/CDataInterface.html#c.ArrowSchema>
@@ -117,14 +136,14 @@ impl Ffi_ArrowSchema {
             flags,
             n_children,
             children: private.children_ptr.as_mut_ptr(),
-            dictionary: std::ptr::null_mut(),
+            dictionary: dictionary.unwrap_or(std::ptr::null_mut()),
             release: Some(c_release_schema),
             private_data: Box::into_raw(private) as *mut ::std::os::raw::c_void,
         })
     }
 
     /// create an empty [Ffi_ArrowSchema]
-    fn empty() -> Self {
+    pub(super) fn empty() -> Self {
         Self {
             format: std::ptr::null_mut(),
             name: std::ptr::null_mut(),
@@ -163,6 +182,15 @@ impl Ffi_ArrowSchema {
     pub fn nullable(&self) -> bool {
         (self.flags / 2) & 1 == 1
     }
+
+    pub fn map_keys_sorted(&self) -> bool {
+        (self.flags / 4) & 1 == 1
+    }
+
+    /// returns the schema of the values of a dictionary-encoded array, if any.
+    pub fn dictionary(&self) -> Option<&Self> {
+        unsafe { self.dictionary.as_ref() }
+    }
 }
 
 impl Drop for Ffi_ArrowSchema {
@@ -175,7 +203,7 @@ impl Drop for Ffi_ArrowSchema {
 }
 
 /// See https://arrow.apache.org/docs/format/CDataInterface.html#data-type-description-format-strings
-fn to_field(schema: &Ffi_ArrowSchema) -> Result<Field> {
+pub(super) fn to_field(schema: &Ffi_ArrowSchema) -> Result<Field> {
     let data_type = match schema.format() {
         "n" => DataType::Null,
         "b" => DataType::Boolean,
@@ -208,6 +236,10 @@ fn to_field(schema: &Ffi_ArrowSchema) -> Result<Field> {
             let child = schema.child(0);
             DataType::LargeList(Box::new(to_field(child)?))
         }
+        "+m" => {
+            let child = schema.child(0);
+            DataType::Map(Box::new(to_field(child)?), schema.map_keys_sorted())
+        }
         "+s" => {
             let children = (0..schema.n_children as usize)
                 .map(|x| to_field(schema.child(x)))
@@ -215,17 +247,63 @@ fn to_field(schema: &Ffi_ArrowSchema) -> Result<Field> {
             DataType::Struct(children)
         }
         other => {
-            return Err(ArrowError::Ffi(format!(
-                "The datatype \"{}\" is still not supported in Rust implementation",
-                other
-            )))
+            let parts = other.splitn(2, ':').collect::<Vec<_>>();
+            match parts.as_slice() {
+                ["+us", ids] => to_union(schema, ids, UnionMode::Sparse)?,
+                ["+ud", ids] => to_union(schema, ids, UnionMode::Dense)?,
+                _ => {
+                    return Err(ArrowError::Ffi(format!(
+                        "The datatype \"{}\" is still not supported in Rust implementation",
+                        other
+                    )))
+                }
+            }
         }
     };
+    // the format of a dictionary-encoded array is the format of its keys
+    let data_type = if let Some(dictionary) = schema.dictionary() {
+        let values = to_field(dictionary)?;
+        DataType::Dictionary(Box::new(data_type), Box::new(values.data_type().clone()))
+    } else {
+        data_type
+    };
     Ok(Field::new(schema.name(), data_type, schema.nullable()))
 }
 
+/// Returns the [`DataType::Union`] of a schema whose format is `+us:ids` or `+ud:ids`
+fn to_union(schema: &Ffi_ArrowSchema, ids: &str, mode: UnionMode) -> Result<DataType> {
+    let fields = (0..schema.n_children as usize)
+        .map(|x| to_field(schema.child(x)))
+        .collect::<Result<Vec<_>>>()?;
+    let ids = ids
+        .split(',')
+        .map(|x| {
+            x.parse::<i32>()
+                .map_err(|_| ArrowError::Ffi("The union's type ids must be integers".to_string()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    // type ids equal to the fields' positions are represented by `None`
+    let ids = if ids.iter().enumerate().all(|(i, x)| i as i32 == *x) {
+        None
+    } else {
+        Some(ids)
+    };
+    Ok(DataType::Union(fields, ids, mode))
+}
+
 /// the inverse of [to_datatype]
 fn to_format(data_type: &DataType) -> Result<String> {
+    if let DataType::Union(fields, ids, mode) = data_type {
+        let ids = match ids {
+            Some(ids) => ids.iter().map(|x| x.to_string()).collect::<Vec<_>>(),
+            None => (0..fields.len()).map(|x| x.to_string()).collect::<Vec<_>>(),
+        };
+        let mode = if mode.is_sparse() { "+us" } else { "+ud" };
+        return Ok(format!("{}:{}", mode, ids.join(",")));
+    }
+    if let DataType::Dictionary(keys, _) = data_type {
+        return to_format(keys);
+    }
     Ok(match data_type {
         DataType::Null => "n",
         DataType::Boolean => "b",
@@ -253,6 +331,7 @@ fn to_format(data_type: &DataType) -> Result<String> {
         DataType::List(_) => "+l",
         DataType::LargeList(_) => "+L",
         DataType::Struct(_) => "+s",
+        DataType::Map(_, _) => "+m",
         z => {
             return Err(ArrowError::Ffi(format!(
                 "The datatype \"{:?}\" is still not supported in Rust implementation",
@@ -307,6 +386,9 @@ unsafe extern "C" fn c_release_array(array: *mut Ffi_ArrowArray) {
     for child in private.children_ptr.iter() {
         let _ = Box::from_raw(*child);
     }
+    if let Some(dictionary) = private.dictionary_ptr {
+        let _ = Box::from_raw(dictionary);
+    }
 
     array.release = None;
 }
@@ -316,6 +398,7 @@ struct PrivateData {
     array: Arc<dyn Array>,
     buffers_ptr: Box<[*const std::os::raw::c_void]>,
     children_ptr: Box<[*mut Ffi_ArrowArray]>,
+    dictionary_ptr: Option<*mut Ffi_ArrowArray>,
 }
 
 impl Ffi_ArrowArray {
@@ -323,8 +406,8 @@ impl Ffi_ArrowArray {
     /// # Safety
     /// This method releases `buffers`. Consumers of this struct *must* call `release` before
     /// releasing this struct, or contents in `buffers` leak.
-    fn new(array: Arc<dyn Array>) -> Self {
-        let (buffers, children) = buffers_children(array.as_ref());
+    pub(super) fn new(array: Arc<dyn Array>) -> Self {
+        let (buffers, children, dictionary) = buffers_children(array.as_ref());
 
         let buffers_ptr = buffers
             .iter()
@@ -342,6 +425,9 @@ impl Ffi_ArrowArray {
             .collect::<Box<_>>();
         let n_children = children_ptr.len() as i64;
 
+        let dictionary_ptr =
+            dictionary.map(|array| Box::into_raw(Box::new(Ffi_ArrowArray::new(array))));
+
         let length = array.len() as i64;
         let null_count = array.null_count() as i64;
 
@@ -349,6 +435,7 @@ impl Ffi_ArrowArray {
             array,
             buffers_ptr,
             children_ptr,
+            dictionary_ptr,
         });
 
         Self {
@@ -359,14 +446,14 @@ impl Ffi_ArrowArray {
             n_children,
             buffers: private_data.buffers_ptr.as_mut_ptr(),
             children: private_data.children_ptr.as_mut_ptr(),
-            dictionary: std::ptr::null_mut(),
+            dictionary: private_data.dictionary_ptr.unwrap_or(std::ptr::null_mut()),
             release: Some(c_release_array),
             private_data: Box::into_raw(private_data) as *mut ::std::os::raw::c_void,
         }
     }
 
     // create an empty `Ffi_ArrowArray`, which can be used to import data into
-    fn empty() -> Self {
+    pub(super) fn empty() -> Self {
         Self {
             length: 0,
             null_count: 0,
@@ -381,6 +468,11 @@ impl Ffi_ArrowArray {
         }
     }
 
+    /// whether the array was released, which is how the end of a C stream is marked
+    pub(super) fn is_released(&self) -> bool {
+        self.release.is_none()
+    }
+
     /// the length of the array
     pub fn len(&self) -> usize {
         self.length as usize
@@ -476,7 +568,8 @@ fn buffer_len(array: &Ffi_ArrowArray, data_type: &DataType, i: usize) -> Result<
         | (DataType::Binary, 1)
         | (DataType::LargeBinary, 1)
         | (DataType::List(_), 1)
-        | (DataType::LargeList(_), 1) => {
+        | (DataType::LargeList(_), 1)
+        | (DataType::Map(_, _), 1) => {
             // the len of the offset buffer (buffer 1) equals length + 1
             array.length as usize + 1
         }
@@ -505,6 +598,26 @@ fn buffer_len(array: &Ffi_ArrowArray, data_type: &DataType, i: usize) -> Result<
     })
 }
 
+fn create_dictionary(
+    array: &Ffi_ArrowArray,
+    schema: &Ffi_ArrowSchema,
+    parent: Arc<ArrowArray>,
+) -> Result<Option<ArrowArrayChild<'static>>> {
+    if schema.dictionary.is_null() {
+        return Ok(None);
+    }
+    if array.dictionary.is_null() {
+        return Err(ArrowError::Ffi(
+            "The array of a dictionary-encoded type must have a dictionary".to_string(),
+        ));
+    }
+    unsafe {
+        let array = &*array.dictionary;
+        let schema = &*schema.dictionary;
+        Ok(Some(ArrowArrayChild::from_raw(array, schema, parent)))
+    }
+}
+
 fn create_child(
     array: &Ffi_ArrowArray,
     schema: &Ffi_ArrowSchema,
@@ -548,13 +661,14 @@ pub trait ArrowArrayRef {
     /// The caller must guarantee that the buffer `index` corresponds to a bitmap.
     /// This function assumes that the bitmap created from FFI is valid; this is impossible to prove.
     unsafe fn buffer<T: NativeType>(&self, index: usize) -> Result<Buffer<T>> {
-        // +1 to ignore null bitmap
-        create_buffer::<T>(
-            self.array(),
-            &self.data_type()?,
-            self.deallocation(),
-            index + 1,
-        )
+        let data_type = self.data_type()?;
+        // +1 to ignore null bitmap, which unions do not have
+        let index = if let DataType::Union(_, _, _) = data_type {
+            index
+        } else {
+            index + 1
+        };
+        create_buffer::<T>(self.array(), &data_type, self.deallocation(), index)
     }
 
     /// # Safety
@@ -569,6 +683,12 @@ pub trait ArrowArrayRef {
         create_child(self.array(), self.schema(), self.parent().clone(), index)
     }
 
+    /// returns the values of a dictionary-encoded array, or `None` if the array is not
+    /// dictionary-encoded.
+    fn dictionary(&self) -> Result<Option<ArrowArrayChild>> {
+        create_dictionary(self.array(), self.schema(), self.parent().clone())
+    }
+
     fn parent(&self) -> &Arc<ArrowArray>;
     fn array(&self) -> &Ffi_ArrowArray;
     fn schema(&self) -> &Ffi_ArrowSchema;
@@ -596,10 +716,15 @@ pub trait ArrowArrayRef {
 /// Furthermore, this struct assumes that the incoming data agrees with the C data interface.
 #[derive(Debug)]
 pub struct ArrowArray {
-    array: Arc<Ffi_ArrowArray>,
-    schema: Arc<Ffi_ArrowSchema>,
+    pub(super) array: Arc<Ffi_ArrowArray>,
+    pub(super) schema: Arc<Ffi_ArrowSchema>,
 }
 
+// Safety: an imported array is immutable and the C data interface allows its release callback
+// to be called from any thread.
+unsafe impl Send for ArrowArray {}
+unsafe impl Sync for ArrowArray {}
+
 impl ArrowArrayRef for Arc<ArrowArray> {
     /// the data_type as declared in the schema
     fn data_type(&self) -> Result<DataType> {
@@ -643,10 +768,6 @@ impl<'a> ArrowArrayRef for ArrowArrayChild<'a> {
     fn schema(&self) -> &Ffi_ArrowSchema {
         self.schema
     }
-
-    fn child(&self, _: usize) -> Result<ArrowArrayChild> {
-        todo!()
-    }
 }
 
 impl<'a> ArrowArrayChild<'a> {
@@ -3,6 +3,8 @@
 mod array;
 #[allow(clippy::module_inception)]
 mod ffi;
+mod stream;
 
 pub use array::try_from;
 pub use ffi::{create_empty, export_to_c, ArrowArray, ArrowArrayRef};
+pub use stream::{export_iterator, ArrowArrayStreamReader, Ffi_ArrowArrayStream};
@@ -0,0 +1,360 @@
+//! Contains the [C Stream Interface](https://arrow.apache.org/docs/format/CStreamInterface.html):
+//! export of an iterator of [`RecordBatch`]es to an `ArrowArrayStream` and import of an
+//! `ArrowArrayStream` as an iterator of [`RecordBatch`]es.
+use std::{
+    ffi::{CStr, CString},
+    os::raw::{c_char, c_int, c_void},
+    sync::Arc,
+};
+
+use super::ffi::{to_field, ArrowArray, Ffi_ArrowArray, Ffi_ArrowSchema};
+use crate::{
+    array::{Array, StructArray},
+    datatypes::{DataType, Field, Schema},
+    error::{ArrowError, Result},
+    record_batch::{RecordBatch, RecordBatchReader},
+};
+
+/// errno-compatible codes returned by the callbacks of an exported stream
+const EINVAL: c_int = 22;
+const EIO: c_int = 5;
+
+/// ABI-compatible struct for `ArrowArrayStream` from the C Stream Interface
+/// See <https://arrow.apache.org/docs/format/CStreamInterface.html#structure-definition>
+#[repr(C)]
+#[derive(Debug)]
+pub struct Ffi_ArrowArrayStream {
+    get_schema: Option<
+        unsafe extern "C" fn(arg1: *mut Ffi_ArrowArrayStream, out: *mut Ffi_ArrowSchema) -> c_int,
+    >,
+    get_next: Option<
+        unsafe extern "C" fn(arg1: *mut Ffi_ArrowArrayStream, out: *mut Ffi_ArrowArray) -> c_int,
+    >,
+    get_last_error: Option<unsafe extern "C" fn(arg1: *mut Ffi_ArrowArrayStream) -> *const c_char>,
+    release: Option<unsafe extern "C" fn(arg1: *mut Ffi_ArrowArrayStream)>,
+    private_data: *mut c_void,
+}
+
+impl Drop for Ffi_ArrowArrayStream {
+    fn drop(&mut self) {
+        match self.release {
+            None => (),
+            Some(release) => unsafe { release(self) },
+        };
+    }
+}
+
+impl Ffi_ArrowArrayStream {
+    /// Creates an empty [`Ffi_ArrowArrayStream`], which can be used to import a stream into
+    /// from a producer.
+    pub fn empty() -> Self {
+        Self {
+            get_schema: None,
+            get_next: None,
+            get_last_error: None,
+            release: None,
+            private_data: std::ptr::null_mut(),
+        }
+    }
+}
+
+struct PrivateData {
+    iter: Box<dyn Iterator<Item = Result<RecordBatch>>>,
+    schema: Arc<Schema>,
+    error: Option<CString>,
+}
+
+impl PrivateData {
+    fn set_error(&mut self, error: ArrowError, code: c_int) -> c_int {
+        // the message of the error can't contain nul bytes
+        let message = error.to_string().replace('\0', "");
+        self.error = Some(CString::new(message).unwrap());
+        code
+    }
+}
+
+unsafe fn private_data<'a>(stream: *mut Ffi_ArrowArrayStream) -> &'a mut PrivateData {
+    &mut *((*stream).private_data as *mut PrivateData)
+}
+
+unsafe extern "C" fn get_schema(
+    stream: *mut Ffi_ArrowArrayStream,
+    out: *mut Ffi_ArrowSchema,
+) -> c_int {
+    if stream.is_null() || out.is_null() {
+        return EINVAL;
+    }
+    let private = private_data(stream);
+
+    // the batches are exported as struct arrays whose fields are the schema's fields
+    let field = Field::new("", DataType::Struct(private.schema.fields().clone()), false);
+    match Ffi_ArrowSchema::try_new(field) {
+        Ok(schema) => {
+            std::ptr::write(out, schema);
+            0
+        }
+        Err(error) => private.set_error(error, EINVAL),
+    }
+}
+
+unsafe extern "C" fn get_next(
+    stream: *mut Ffi_ArrowArrayStream,
+    out: *mut Ffi_ArrowArray,
+) -> c_int {
+    if stream.is_null() || out.is_null() {
+        return EINVAL;
+    }
+    let private = private_data(stream);
+
+    match private.iter.next() {
+        Some(Ok(batch)) => {
+            let array = Arc::new(StructArray::from(batch)) as Arc<dyn Array>;
+            std::ptr::write(out, Ffi_ArrowArray::new(array));
+            0
+        }
+        Some(Err(error)) => private.set_error(error, EIO),
+        // a released array marks the end of the stream
+        None => {
+            std::ptr::write(out, Ffi_ArrowArray::empty());
+            0
+        }
+    }
+}
+
+unsafe extern "C" fn get_last_error(stream: *mut Ffi_ArrowArrayStream) -> *const c_char {
+    if stream.is_null() {
+        return std::ptr::null();
+    }
+    private_data(stream)
+        .error
+        .as_ref()
+        .map(|error| error.as_ptr())
+        .unwrap_or(std::ptr::null())
+}
+
+unsafe extern "C" fn release(stream: *mut Ffi_ArrowArrayStream) {
+    if stream.is_null() {
+        return;
+    }
+    let stream = &mut *stream;
+
+    // take ownership of `private_data`, therefore dropping it
+    let _ = Box::from_raw(stream.private_data as *mut PrivateData);
+
+    stream.release = None;
+}
+
+/// Exports an iterator of [`RecordBatch`]es whose schema is `schema` to the C stream interface.
+/// Each batch is exported as a struct array whose fields are the fields of `schema`. Errors of the
+/// iterator are reported to the consumer via `get_last_error`.
+pub fn export_iterator(
+    iter: Box<dyn Iterator<Item = Result<RecordBatch>>>,
+    schema: Arc<Schema>,
+) -> Ffi_ArrowArrayStream {
+    let private = Box::new(PrivateData {
+        iter,
+        schema,
+        error: None,
+    });
+
+    Ffi_ArrowArrayStream {
+        get_schema: Some(get_schema),
+        get_next: Some(get_next),
+        get_last_error: Some(get_last_error),
+        release: Some(release),
+        private_data: Box::into_raw(private) as *mut c_void,
+    }
+}
+
+/// An iterator of [`RecordBatch`]es imported from the C stream interface.
+#[derive(Debug)]
+pub struct ArrowArrayStreamReader {
+    stream: Box<Ffi_ArrowArrayStream>,
+    schema: Arc<Schema>,
+    ffi_schema: Arc<Ffi_ArrowSchema>,
+}
+
+/// Returns the error of a callback of `stream` that returned `code`.
+unsafe fn last_error(stream: &mut Ffi_ArrowArrayStream, code: c_int) -> ArrowError {
+    let message = stream
+        .get_last_error
+        .map(|get_last_error| get_last_error(stream))
+        .filter(|message| !message.is_null())
+        .map(|message| CStr::from_ptr(message).to_string_lossy().into_owned())
+        .unwrap_or_else(|| "unknown error".to_string());
+    ArrowError::Ffi(format!(
+        "The C stream failed with code {}: {}",
+        code, message
+    ))
+}
+
+impl ArrowArrayStreamReader {
+    /// Creates a new [`ArrowArrayStreamReader`] from a stream filled by a producer, reading its
+    /// schema.
+    /// # Errors
+    /// If the stream was released, if its schema can't be read or if it is not a struct.
+    /// # Safety
+    /// `stream` must be a valid `ArrowArrayStream` as defined by the C stream interface.
+    pub unsafe fn try_new(mut stream: Box<Ffi_ArrowArrayStream>) -> Result<Self> {
+        if stream.release.is_none() {
+            return Err(ArrowError::Ffi(
+                "The C stream was already released".to_string(),
+            ));
+        }
+        let get_schema = stream.get_schema.ok_or_else(|| {
+            ArrowError::Ffi("The C stream must have a `get_schema` callback".to_string())
+        })?;
+
+        let mut ffi_schema = Ffi_ArrowSchema::empty();
+        let code = get_schema(stream.as_mut(), &mut ffi_schema);
+        if code != 0 {
+            return Err(last_error(stream.as_mut(), code));
+        }
+
+        let fields = match to_field(&ffi_schema)?.data_type() {
+            DataType::Struct(fields) => fields.clone(),
+            _ => {
+                return Err(ArrowError::Ffi(
+                    "The schema of a C stream must be a struct".to_string(),
+                ))
+            }
+        };
+
+        // the schema is shared by the `ArrowArray` of every batch, which is `Send + Sync`
+        #[allow(clippy::arc_with_non_send_sync)]
+        let ffi_schema = Arc::new(ffi_schema);
+
+        Ok(Self {
+            stream,
+            schema: Arc::new(Schema::new(fields)),
+            ffi_schema,
+        })
+    }
+
+    /// Returns the schema of the stream
+    pub fn schema(&self) -> &Arc<Schema> {
+        &self.schema
+    }
+
+    fn maybe_next(&mut self) -> Result<Option<RecordBatch>> {
+        let get_next = self.stream.get_next.ok_or_else(|| {
+            ArrowError::Ffi("The C stream must have a `get_next` callback".to_string())
+        })?;
+
+        let mut array = Ffi_ArrowArray::empty();
+        let code = unsafe { get_next(self.stream.as_mut(), &mut array) };
+        if code != 0 {
+            return Err(unsafe { last_error(self.stream.as_mut(), code) });
+        }
+        if array.is_released() {
+            return Ok(None);
+        }
+
+        // `ArrowArray` owns its C array through an `Arc`; it is only accessed through the
+        // `ArrowArray`, which is `Send + Sync`
+        #[allow(clippy::arc_with_non_send_sync)]
+        let array = Arc::new(ArrowArray {
+            array: Arc::new(array),
+            schema: self.ffi_schema.clone(),
+        });
+        let array = super::try_from(array)?;
+        let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+        RecordBatch::try_new(self.schema.clone(), array.values().to_vec()).map(Some)
+    }
+}
+
+impl Iterator for ArrowArrayStreamReader {
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.maybe_next().transpose()
+    }
+}
+
+impl RecordBatchReader for ArrowArrayStreamReader {
+    fn schema(&self) -> &Schema {
+        self.schema.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::*;
+
+    fn batches() -> Result<(Arc<Schema>, Vec<RecordBatch>)> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, false),
+        ]));
+        let batch1 = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(&[Some(1), None, Some(3)])),
+                Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])),
+            ],
+        )?;
+        let batch2 = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(&[None, Some(5)])),
+                Arc::new(Utf8Array::<i32>::from_slice(["d", "e"])),
+            ],
+        )?;
+        Ok((schema, vec![batch1, batch2]))
+    }
+
+    #[test]
+    fn round_trip() -> Result<()> {
+        let (schema, expected) = batches()?;
+
+        let iter = Box::new(expected.clone().into_iter().map(Ok));
+        let stream = Box::new(export_iterator(iter, schema.clone()));
+
+        let reader = unsafe { ArrowArrayStreamReader::try_new(stream) }?;
+        assert_eq!(reader.schema(), &schema);
+
+        let result = reader.collect::<Result<Vec<_>>>()?;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn empty() -> Result<()> {
+        let (schema, _) = batches()?;
+
+        let stream = Box::new(export_iterator(
+            Box::new(std::iter::empty()),
+            schema.clone(),
+        ));
+
+        let mut reader = unsafe { ArrowArrayStreamReader::try_new(stream) }?;
+        assert_eq!(reader.schema(), &schema);
+        assert!(reader.next().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn last_error() -> Result<()> {
+        let (schema, expected) = batches()?;
+
+        let iter = vec![
+            Ok(expected[0].clone()),
+            Err(ArrowError::Other("the producer failed".to_string())),
+        ];
+        let stream = Box::new(export_iterator(Box::new(iter.into_iter()), schema));
+
+        let mut reader = unsafe { ArrowArrayStreamReader::try_new(stream) }?;
+        assert_eq!(reader.next().unwrap()?, expected[0]);
+
+        let error = reader.next().unwrap().unwrap_err();
+        assert!(error.to_string().contains("the producer failed"));
+        Ok(())
+    }
+
+    #[test]
+    fn released() {
+        let stream = Box::new(Ffi_ArrowArrayStream::empty());
+        assert!(unsafe { ArrowArrayStreamReader::try_new(stream) }.is_err());
+    }
+}
@@ -10,6 +10,7 @@ use crate::{
     record_batch::RecordBatch,
     temporal_conversions::EPOCH_DAYS_FROM_CE,
     types::{NativeType, NaturalDataType},
+    util::parse_decimal,
 };
 
 fn deserialize_primitive<T, F>(
@@ -19,7 +20,7 @@ fn deserialize_primitive<T, F>(
     op: F,
 ) -> Arc<dyn Array>
 where
-    T: NativeType + NaturalDataType + lexical_core::FromLexical,
+    T: NativeType + NaturalDataType,
     F: Fn(&[u8]) -> Option<T>,
 {
     let iter = rows.iter().map(|row| match row.get(column) {
@@ -149,6 +150,9 @@ pub fn deserialize_column(
                     .map(|x| x.timestamp_nanos() / 1_000_000_000)
             })
         }
+        Decimal(precision, scale) => deserialize_primitive(rows, column, datatype, |bytes| {
+            parse_decimal(bytes, precision, scale)
+        }),
         Utf8 => deserialize_utf8::<i32>(rows, column),
         LargeUtf8 => deserialize_utf8::<i64>(rows, column),
         other => {
@@ -178,4 +178,33 @@ sed do eiusmod tempor|-556132.25|1||2019-04-18T02:45:55.555000000|11:46:03 PM
         );
         Ok(())
     }
+
+    #[test]
+    fn test_decimal_round_trip() -> Result<()> {
+        use crate::io::csv::read::{deserialize_column, ReaderBuilder};
+
+        let data_type = DataType::Decimal(5, 2);
+        let array = Int128Array::from(&[Some(12345), None, Some(-5)]).to(data_type.clone());
+        let schema = Schema::new(vec![Field::new("c1", data_type.clone(), true)]);
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(array.clone())])?;
+
+        let write = Cursor::new(Vec::<u8>::new());
+        let mut writer = WriterBuilder::new().from_writer(write);
+        write_batch(&mut writer, &batch, &SerializeOptions::default())?;
+        let buffer = writer.into_inner().unwrap().into_inner();
+        assert_eq!(
+            "123.45\n\"\"\n-0.05\n",
+            String::from_utf8(buffer.clone()).unwrap()
+        );
+
+        let mut reader = ReaderBuilder::new()
+            .has_headers(false)
+            .from_reader(Cursor::new(buffer));
+        let rows = reader
+            .byte_records()
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let result = deserialize_column(&rows, 0, data_type, 0)?;
+        assert_eq!(array, result.as_ref());
+        Ok(())
+    }
 }
@@ -1,5 +1,5 @@
 use crate::temporal_conversions;
-use crate::util::lexical_to_bytes;
+use crate::util::{decimal_to_string, lexical_to_bytes};
 use crate::{
     array::{Array, BinaryArray, BooleanArray, PrimitiveArray, Utf8Array},
     datatypes::{DataType, TimeUnit},
@@ -57,6 +57,7 @@ macro_rules! dyn_date {
 /// * numeric types (i.e. floats, int, uint)
 /// * times and dates
 /// * naive timestamps (timestamps without timezone information)
+/// * decimals
 /// # Error
 /// This function errors if any of the logical types in `batch` is not supported.
 pub fn new_serializer<'a>(
@@ -214,6 +215,17 @@ pub fn new_serializer<'a>(
                     .map(|x| x.map(|x| x.to_vec()).unwrap_or_default()),
             )
         }
+        DataType::Decimal(_, scale) => {
+            let scale = *scale;
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i128>>()
+                .unwrap();
+            Box::new(array.iter().map(move |x| {
+                x.map(|x| decimal_to_string(*x, scale).into_bytes())
+                    .unwrap_or_default()
+            }))
+        }
         _ => todo!(),
     })
 }
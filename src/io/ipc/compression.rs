@@ -14,6 +14,22 @@ pub fn decompress_zstd(input_buf: &[u8], output_buf: &mut [u8]) -> Result<()> {
     decoder.read_exact(output_buf).map_err(|e| e.into())
 }
 
+#[cfg(feature = "io_ipc_compression")]
+pub fn compress_lz4(input_buf: &[u8], output_buf: &mut Vec<u8>) -> Result<()> {
+    use std::io::Write;
+    let mut encoder = lz4::EncoderBuilder::new().build(output_buf)?;
+    encoder.write_all(input_buf)?;
+    encoder.finish().1.map_err(|e| e.into())
+}
+
+#[cfg(feature = "io_ipc_compression")]
+pub fn compress_zstd(input_buf: &[u8], output_buf: &mut Vec<u8>) -> Result<()> {
+    use std::io::Write;
+    let mut encoder = zstd::Encoder::new(output_buf, 0)?;
+    encoder.write_all(input_buf)?;
+    encoder.finish().map(|_| ()).map_err(|e| e.into())
+}
+
 #[cfg(not(feature = "io_ipc_compression"))]
 pub fn decompress_lz4(_input_buf: &[u8], _output_buf: &mut [u8]) -> Result<()> {
     use crate::error::ArrowError;
@@ -25,3 +41,15 @@ pub fn decompress_zstd(_input_buf: &[u8], _output_buf: &mut [u8]) -> Result<()>
     use crate::error::ArrowError;
     Err(ArrowError::Ipc("The crate was compiled without IPC compression. Use `io_ipc_compression` to read compressed IPC.".to_string()))
 }
+
+#[cfg(not(feature = "io_ipc_compression"))]
+pub fn compress_lz4(_input_buf: &[u8], _output_buf: &mut Vec<u8>) -> Result<()> {
+    use crate::error::ArrowError;
+    Err(ArrowError::Ipc("The crate was compiled without IPC compression. Use `io_ipc_compression` to write compressed IPC.".to_string()))
+}
+
+#[cfg(not(feature = "io_ipc_compression"))]
+pub fn compress_zstd(_input_buf: &[u8], _output_buf: &mut Vec<u8>) -> Result<()> {
+    use crate::error::ArrowError;
+    Err(ArrowError::Ipc("The crate was compiled without IPC compression. Use `io_ipc_compression` to write compressed IPC.".to_string()))
+}
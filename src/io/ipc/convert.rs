@@ -17,7 +17,7 @@
 
 //! Utilities for converting between IPC types and native Arrow types
 
-use crate::datatypes::{DataType, Field, IntervalUnit, Schema, TimeUnit};
+use crate::datatypes::{DataType, Field, IntervalUnit, Schema, TimeUnit, UnionMode};
 use crate::endianess::is_native_little_endian;
 
 mod ipc {
@@ -262,6 +262,14 @@ pub(crate) fn get_data_type(field: ipc::Field, may_be_dictionary: bool) -> DataT
             let fsl = field.type_as_fixed_size_list().unwrap();
             DataType::FixedSizeList(Box::new(children.get(0).into()), fsl.listSize())
         }
+        ipc::Type::Map => {
+            let children = field.children().unwrap();
+            if children.len() != 1 {
+                panic!("expect a map to have one child")
+            }
+            let map = field.type_as_map().unwrap();
+            DataType::Map(Box::new(children.get(0).into()), map.keysSorted())
+        }
         ipc::Type::Struct_ => {
             let mut fields = vec![];
             if let Some(children) = field.children() {
@@ -272,6 +280,22 @@ pub(crate) fn get_data_type(field: ipc::Field, may_be_dictionary: bool) -> DataT
 
             DataType::Struct(fields)
         }
+        ipc::Type::Union => {
+            let type_ = field.type_as_union().unwrap();
+            let mode = UnionMode::sparse(type_.mode() == ipc::UnionMode::Sparse);
+            let ids = type_
+                .typeIds()
+                .map(|x| (0..x.len()).map(|i| x.get(i)).collect());
+
+            let fields = if let Some(children) = field.children() {
+                (0..children.len())
+                    .map(|i| children.get(i).into())
+                    .collect()
+            } else {
+                vec![]
+            };
+            DataType::Union(fields, ids, mode)
+        }
         ipc::Type::Decimal => {
             let fsb = field.type_as_decimal().unwrap();
             DataType::Decimal(fsb.precision() as usize, fsb.scale() as usize)
@@ -587,6 +611,39 @@ pub(crate) fn get_fb_field_type<'a>(
                 children: Some(fbb.create_vector(&children[..])),
             }
         }
+        Map(field, keys_sorted) => {
+            let child = build_field(fbb, field);
+            let mut builder = ipc::MapBuilder::new(fbb);
+            builder.add_keysSorted(*keys_sorted);
+            FbFieldType {
+                type_type: ipc::Type::Map,
+                type_: builder.finish().as_union_value(),
+                children: Some(fbb.create_vector(&[child])),
+            }
+        }
+        Union(fields, ids, mode) => {
+            let children = fields
+                .iter()
+                .map(|field| build_field(fbb, field))
+                .collect::<Vec<_>>();
+
+            let ids = ids.as_ref().map(|ids| fbb.create_vector(ids));
+
+            let mut builder = ipc::UnionBuilder::new(fbb);
+            builder.add_mode(if mode.is_sparse() {
+                ipc::UnionMode::Sparse
+            } else {
+                ipc::UnionMode::Dense
+            });
+            if let Some(ids) = ids {
+                builder.add_typeIds(ids);
+            }
+            FbFieldType {
+                type_type: ipc::Type::Union,
+                type_: builder.finish().as_union_value(),
+                children: Some(fbb.create_vector(&children)),
+            }
+        }
         Dictionary(_, value_type) => {
             // In this library, the dictionary "type" is a logical construct. Here we
             // pass through to the value type, as we've already captured the index
@@ -790,6 +847,45 @@ mod tests {
                     true,
                 ),
                 Field::new("decimal<usize, usize>", DataType::Decimal(10, 6), false),
+                Field::new(
+                    "map<utf8, int32>",
+                    DataType::Map(
+                        Box::new(Field::new(
+                            "entries",
+                            DataType::Struct(vec![
+                                Field::new("key", DataType::Utf8, false),
+                                Field::new("value", DataType::Int32, true),
+                            ]),
+                            false,
+                        )),
+                        true,
+                    ),
+                    true,
+                ),
+                Field::new(
+                    "sparse union<int32, utf8>",
+                    DataType::Union(
+                        vec![
+                            Field::new("a", DataType::Int32, true),
+                            Field::new("b", DataType::Utf8, true),
+                        ],
+                        None,
+                        UnionMode::Sparse,
+                    ),
+                    false,
+                ),
+                Field::new(
+                    "dense union<int32, utf8>",
+                    DataType::Union(
+                        vec![
+                            Field::new("a", DataType::Int32, true),
+                            Field::new("b", DataType::Utf8, true),
+                        ],
+                        Some(vec![2, 5]),
+                        UnionMode::Dense,
+                    ),
+                    false,
+                ),
             ],
             md,
         );
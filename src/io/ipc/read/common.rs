@@ -25,7 +25,7 @@ use crate::error::{ArrowError, Result};
 use crate::record_batch::RecordBatch;
 
 use super::super::gen;
-use super::deserialize::read;
+use super::deserialize::{read, BufferReader};
 
 type ArrayRef = Arc<dyn Array>;
 
@@ -37,6 +37,28 @@ pub fn read_record_batch<R: Read + Seek>(
     dictionaries: &[Option<ArrayRef>],
     reader: &mut R,
     block_offset: u64,
+    version: gen::Schema::MetadataVersion,
+) -> Result<RecordBatch> {
+    read_record_batch_from(
+        batch,
+        schema,
+        is_little_endian,
+        dictionaries,
+        &mut BufferReader::new(reader, None),
+        block_offset,
+        version,
+    )
+}
+
+/// Like [`read_record_batch`], but reads its buffers from a [`BufferReader`].
+pub(super) fn read_record_batch_from<R: Read + Seek>(
+    batch: gen::Message::RecordBatch,
+    schema: Arc<Schema>,
+    is_little_endian: bool,
+    dictionaries: &[Option<ArrayRef>],
+    reader: &mut BufferReader<R>,
+    block_offset: u64,
+    version: gen::Schema::MetadataVersion,
 ) -> Result<RecordBatch> {
     let buffers = batch
         .buffers()
@@ -66,6 +88,7 @@ pub fn read_record_batch<R: Read + Seek>(
                 block_offset,
                 is_little_endian,
                 batch.compression(),
+                version,
             )
         })
         .collect::<Result<Vec<_>>>()?;
@@ -82,6 +105,28 @@ pub fn read_dictionary<R: Read + Seek>(
     dictionaries_by_field: &mut [Option<ArrayRef>],
     reader: &mut R,
     block_offset: u64,
+    version: gen::Schema::MetadataVersion,
+) -> Result<()> {
+    read_dictionary_from(
+        batch,
+        schema,
+        is_little_endian,
+        dictionaries_by_field,
+        &mut BufferReader::new(reader, None),
+        block_offset,
+        version,
+    )
+}
+
+/// Like [`read_dictionary`], but reads its buffers from a [`BufferReader`].
+pub(super) fn read_dictionary_from<R: Read + Seek>(
+    batch: gen::Message::DictionaryBatch,
+    schema: &Schema,
+    is_little_endian: bool,
+    dictionaries_by_field: &mut [Option<ArrayRef>],
+    reader: &mut BufferReader<R>,
+    block_offset: u64,
+    version: gen::Schema::MetadataVersion,
 ) -> Result<()> {
     if batch.isDelta() {
         return Err(ArrowError::NotYetImplemented(
@@ -106,13 +151,14 @@ pub fn read_dictionary<R: Read + Seek>(
                 metadata: HashMap::new(),
             });
             // Read a single column
-            let record_batch = read_record_batch(
+            let record_batch = read_record_batch_from(
                 batch.data().unwrap(),
                 schema,
                 is_little_endian,
                 dictionaries_by_field,
                 reader,
                 block_offset,
+                version,
             )?;
             Some(record_batch.column(0).clone())
         }
@@ -20,22 +20,26 @@
 //! The `FileReader` and `StreamReader` have similar interfaces,
 //! however the `FileReader` expects a reader that supports `Seek`ing
 
-use std::{collections::VecDeque, convert::TryInto};
+use std::{any::Any, collections::VecDeque, convert::TryInto, ptr::NonNull};
 use std::{
     io::{Read, Seek, SeekFrom},
     sync::Arc,
 };
 
-use crate::buffer::Buffer;
+use crate::buffer::{
+    bytes::{Bytes, Deallocation},
+    Buffer,
+};
 use crate::datatypes::{DataType, IntervalUnit};
 use crate::endianess::is_native_little_endian;
 use crate::error::{ArrowError, Result};
 use crate::io::ipc::gen::Message::{BodyCompression, CompressionType};
+use crate::io::ipc::gen::Schema::MetadataVersion;
 use crate::{
     array::*,
     bitmap::Bitmap,
     buffer::MutableBuffer,
-    types::{days_ms, NativeType},
+    types::{days_ms, f16, NativeType},
 };
 
 use super::super::compression;
@@ -43,6 +47,55 @@ use super::super::gen;
 
 type Node<'a> = (&'a gen::Message::FieldNode, &'a Option<Arc<dyn Array>>);
 
+/// The reader of the buffers of IPC messages. Buffers are copied from `reader` unless the
+/// messages are in `memory`, in which case they are borrowed from it whenever they are
+/// uncompressed, in native endianness and aligned. Reads and seeks are those of `reader`.
+pub struct BufferReader<'a, R: Read + Seek> {
+    reader: &'a mut R,
+    memory: Option<Memory<'a>>,
+}
+
+/// A region of memory, `data`, kept alive by `owner`.
+#[derive(Clone)]
+pub struct Memory<'a> {
+    pub owner: Arc<dyn Any + Send + Sync>,
+    pub data: &'a [u8],
+}
+
+impl<'a, R: Read + Seek> BufferReader<'a, R> {
+    /// `reader` must read `memory` when `memory` is `Some`.
+    pub fn new(reader: &'a mut R, memory: Option<Memory<'a>>) -> Self {
+        Self { reader, memory }
+    }
+
+    /// Returns the [`Bytes`] of `length` slots starting at byte `offset` of the memory, borrowed
+    /// from it, or `None` if the reader is not backed by memory or if the slots are out of bounds
+    /// or not aligned for `T`.
+    fn borrow_bytes<T: NativeType>(&self, offset: usize, length: usize) -> Option<Bytes<T>> {
+        let memory = self.memory.as_ref()?;
+        let end = offset.checked_add(length.checked_mul(std::mem::size_of::<T>())?)?;
+        let data = memory.data.get(offset..end)?;
+        if data.as_ptr() as usize % std::mem::align_of::<T>() != 0 {
+            return None;
+        }
+        let ptr = NonNull::new(data.as_ptr() as *mut T)?;
+        // Safety: the region is valid for `length` slots of `T`, aligned, and kept alive by `owner`.
+        Some(unsafe { Bytes::new(ptr, length, Deallocation::Foreign(memory.owner.clone())) })
+    }
+}
+
+impl<R: Read + Seek> Read for BufferReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.reader.read(buf)
+    }
+}
+
+impl<R: Read + Seek> Seek for BufferReader<'_, R> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        self.reader.seek(pos)
+    }
+}
+
 fn read_big_endian<T: NativeType, R: Read + Seek>(
     reader: &mut R,
     bytes: usize,
@@ -126,6 +179,10 @@ fn read_compressed_buffer<T: NativeType, R: Read + Seek>(
     // it is undefined behavior to call read_exact on un-initialized, https://doc.rust-lang.org/std/io/trait.Read.html#tymethod.read
     // see also https://github.com/MaikKlein/ash/issues/354#issue-781730580
     let mut buffer = MutableBuffer::<T>::from_len_zeroed(length);
+    if buffer_length == 0 {
+        // empty buffers are not compressed
+        return Ok(buffer);
+    }
 
     // decompress first
     // todo: move this allocation to an external buffer for re-use
@@ -166,19 +223,30 @@ fn read_compressed_buffer<T: NativeType, R: Read + Seek>(
 fn read_buffer<T: NativeType, R: Read + Seek>(
     buf: &mut VecDeque<&gen::Schema::Buffer>,
     length: usize, // in slots
-    reader: &mut R,
+    reader: &mut BufferReader<R>,
     block_offset: u64,
     is_little_endian: bool,
     compression: Option<BodyCompression>,
 ) -> Result<Buffer<T>> {
     let buf = buf.pop_front().unwrap();
 
-    reader.seek(SeekFrom::Start(block_offset + buf.offset() as u64))?;
+    let offset = block_offset + buf.offset() as u64;
 
     let buffer_length = buf.length() as usize;
 
     let bytes = length * std::mem::size_of::<T>();
 
+    if compression.is_none()
+        && is_little_endian == is_native_little_endian()
+        && bytes <= buffer_length
+    {
+        if let Some(bytes) = reader.borrow_bytes(offset as usize, length) {
+            return Ok(Buffer::from_bytes(bytes));
+        }
+    }
+
+    reader.seek(SeekFrom::Start(offset))?;
+
     if let Some(compression) = compression {
         Ok(
             read_compressed_buffer(reader, buffer_length, length, is_little_endian, compression)?
@@ -214,6 +282,10 @@ fn read_compressed_bitmap<R: Read + Seek>(
     reader: &mut R,
 ) -> Result<MutableBuffer<u8>> {
     let mut buffer = MutableBuffer::<u8>::from_len_zeroed((length + 7) / 8);
+    if bytes == 0 {
+        // empty buffers are not compressed
+        return Ok(buffer);
+    }
     match compression.codec() {
         CompressionType::LZ4_FRAME => {
             // decompress first
@@ -244,17 +316,25 @@ fn read_compressed_bitmap<R: Read + Seek>(
 fn read_bitmap<R: Read + Seek>(
     buf: &mut VecDeque<&gen::Schema::Buffer>,
     length: usize,
-    reader: &mut R,
+    reader: &mut BufferReader<R>,
     block_offset: u64,
     _: bool,
     compression: Option<BodyCompression>,
 ) -> Result<Bitmap> {
     let buf = buf.pop_front().unwrap();
 
-    reader.seek(SeekFrom::Start(block_offset + buf.offset() as u64))?;
+    let offset = block_offset + buf.offset() as u64;
 
     let bytes = buf.length() as usize;
 
+    if compression.is_none() && length <= bytes * 8 {
+        if let Some(bytes) = reader.borrow_bytes(offset as usize, bytes) {
+            return Ok(Bitmap::from_bytes(bytes, length));
+        }
+    }
+
+    reader.seek(SeekFrom::Start(offset))?;
+
     let buffer = if let Some(compression) = compression {
         read_compressed_bitmap(length, bytes, compression, reader)
     } else {
@@ -267,7 +347,7 @@ fn read_bitmap<R: Read + Seek>(
 fn read_validity<R: Read + Seek>(
     buffers: &mut VecDeque<&gen::Schema::Buffer>,
     field_node: &gen::Message::FieldNode,
-    reader: &mut R,
+    reader: &mut BufferReader<R>,
     block_offset: u64,
     is_little_endian: bool,
     compression: Option<BodyCompression>,
@@ -291,7 +371,7 @@ fn read_primitive<T: NativeType, R: Read + Seek>(
     field_nodes: &mut VecDeque<Node>,
     data_type: DataType,
     buffers: &mut VecDeque<&gen::Schema::Buffer>,
-    reader: &mut R,
+    reader: &mut BufferReader<R>,
     block_offset: u64,
     is_little_endian: bool,
     compression: Option<BodyCompression>,
@@ -327,9 +407,10 @@ where
 fn read_boolean<R: Read + Seek>(
     field_nodes: &mut VecDeque<Node>,
     buffers: &mut VecDeque<&gen::Schema::Buffer>,
-    reader: &mut R,
+    reader: &mut BufferReader<R>,
     block_offset: u64,
     is_little_endian: bool,
+    compression: Option<BodyCompression>,
 ) -> Result<Arc<dyn Array>> {
     let field_node = field_nodes.pop_front().unwrap().0;
 
@@ -340,7 +421,7 @@ fn read_boolean<R: Read + Seek>(
         reader,
         block_offset,
         is_little_endian,
-        None,
+        compression,
     )?;
 
     let values = read_bitmap(
@@ -349,7 +430,7 @@ fn read_boolean<R: Read + Seek>(
         reader,
         block_offset,
         is_little_endian,
-        None,
+        compression,
     )?;
 
     let array = BooleanArray::from_data(values, validity);
@@ -359,7 +440,7 @@ fn read_boolean<R: Read + Seek>(
 fn read_utf8<O: Offset, R: Read + Seek>(
     field_nodes: &mut VecDeque<Node>,
     buffers: &mut VecDeque<&gen::Schema::Buffer>,
-    reader: &mut R,
+    reader: &mut BufferReader<R>,
     block_offset: u64,
     is_little_endian: bool,
     compression: Option<BodyCompression>,
@@ -405,7 +486,7 @@ where
 fn read_binary<O: Offset, R: Read + Seek>(
     field_nodes: &mut VecDeque<Node>,
     buffers: &mut VecDeque<&gen::Schema::Buffer>,
-    reader: &mut R,
+    reader: &mut BufferReader<R>,
     block_offset: u64,
     is_little_endian: bool,
     compression: Option<BodyCompression>,
@@ -452,7 +533,7 @@ fn read_fixed_size_binary<R: Read + Seek>(
     field_nodes: &mut VecDeque<Node>,
     data_type: DataType,
     buffers: &mut VecDeque<&gen::Schema::Buffer>,
-    reader: &mut R,
+    reader: &mut BufferReader<R>,
     block_offset: u64,
     is_little_endian: bool,
     compression: Option<BodyCompression>,
@@ -490,10 +571,11 @@ fn read_list<O: Offset, R: Read + Seek>(
     field_nodes: &mut VecDeque<Node>,
     data_type: DataType,
     buffers: &mut VecDeque<&gen::Schema::Buffer>,
-    reader: &mut R,
+    reader: &mut BufferReader<R>,
     block_offset: u64,
     is_little_endian: bool,
     compression: Option<BodyCompression>,
+    version: MetadataVersion,
 ) -> Result<Arc<dyn Array>>
 where
     Vec<u8>: TryInto<O::Bytes>,
@@ -530,20 +612,71 @@ where
         block_offset,
         is_little_endian,
         compression,
+        version,
     )?;
     Ok(Arc::new(ListArray::from_data(
         data_type, offsets, values, validity,
     )))
 }
 
+fn read_map<R: Read + Seek>(
+    field_nodes: &mut VecDeque<Node>,
+    data_type: DataType,
+    buffers: &mut VecDeque<&gen::Schema::Buffer>,
+    reader: &mut BufferReader<R>,
+    block_offset: u64,
+    is_little_endian: bool,
+    compression: Option<BodyCompression>,
+    version: MetadataVersion,
+) -> Result<Arc<dyn Array>> {
+    let field_node = field_nodes.pop_front().unwrap().0;
+
+    let validity = read_validity(
+        buffers,
+        &field_node,
+        reader,
+        block_offset,
+        is_little_endian,
+        compression,
+    )?;
+
+    let offsets = read_buffer::<i32, _>(
+        buffers,
+        1 + field_node.length() as usize,
+        reader,
+        block_offset,
+        is_little_endian,
+        compression,
+    )
+    // Older versions of the IPC format sometimes do not report an offset
+    .or_else(|_| Result::Ok(MutableBuffer::<i32>::from(&[0i32]).into()))?;
+
+    let value_data_type = MapArray::get_field(&data_type).data_type().clone();
+
+    let field = read(
+        field_nodes,
+        value_data_type,
+        buffers,
+        reader,
+        block_offset,
+        is_little_endian,
+        compression,
+        version,
+    )?;
+    Ok(Arc::new(MapArray::from_data(
+        data_type, offsets, field, validity,
+    )))
+}
+
 fn read_fixed_size_list<R: Read + Seek>(
     field_nodes: &mut VecDeque<Node>,
     data_type: DataType,
     buffers: &mut VecDeque<&gen::Schema::Buffer>,
-    reader: &mut R,
+    reader: &mut BufferReader<R>,
     block_offset: u64,
     is_little_endian: bool,
     compression: Option<BodyCompression>,
+    version: MetadataVersion,
 ) -> Result<Arc<dyn Array>> {
     let field_node = field_nodes.pop_front().unwrap().0;
 
@@ -566,6 +699,7 @@ fn read_fixed_size_list<R: Read + Seek>(
         block_offset,
         is_little_endian,
         compression,
+        version,
     )?;
     Ok(Arc::new(FixedSizeListArray::from_data(
         data_type, values, validity,
@@ -576,10 +710,11 @@ fn read_struct<R: Read + Seek>(
     field_nodes: &mut VecDeque<Node>,
     data_type: DataType,
     buffers: &mut VecDeque<&gen::Schema::Buffer>,
-    reader: &mut R,
+    reader: &mut BufferReader<R>,
     block_offset: u64,
     is_little_endian: bool,
     compression: Option<BodyCompression>,
+    version: MetadataVersion,
 ) -> Result<Arc<dyn Array>> {
     let field_node = field_nodes.pop_front().unwrap().0;
 
@@ -605,6 +740,7 @@ fn read_struct<R: Read + Seek>(
                 block_offset,
                 is_little_endian,
                 compression,
+                version,
             )
         })
         .collect::<Result<Vec<_>>>()?;
@@ -616,14 +752,77 @@ fn read_struct<R: Read + Seek>(
     )))
 }
 
+fn read_union<R: Read + Seek>(
+    field_nodes: &mut VecDeque<Node>,
+    data_type: DataType,
+    buffers: &mut VecDeque<&gen::Schema::Buffer>,
+    reader: &mut BufferReader<R>,
+    block_offset: u64,
+    is_little_endian: bool,
+    compression: Option<BodyCompression>,
+    version: MetadataVersion,
+) -> Result<Arc<dyn Array>> {
+    let field_node = field_nodes.pop_front().unwrap().0;
+
+    // unions do not have a validity bitmap since V5, but older versions write one
+    if version != MetadataVersion::V5 {
+        let _ = buffers.pop_front().unwrap();
+    };
+
+    let types = read_buffer(
+        buffers,
+        field_node.length() as usize,
+        reader,
+        block_offset,
+        is_little_endian,
+        compression,
+    )?;
+
+    let offsets = if UnionArray::is_sparse(&data_type) {
+        None
+    } else {
+        Some(read_buffer(
+            buffers,
+            field_node.length() as usize,
+            reader,
+            block_offset,
+            is_little_endian,
+            compression,
+        )?)
+    };
+
+    let fields = UnionArray::get_fields(&data_type);
+
+    let fields = fields
+        .iter()
+        .map(|field| {
+            read(
+                field_nodes,
+                field.data_type().clone(),
+                buffers,
+                reader,
+                block_offset,
+                is_little_endian,
+                compression,
+                version,
+            )
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Arc::new(UnionArray::try_new(
+        data_type, types, fields, offsets,
+    )?))
+}
+
 /// Reads the correct number of buffers based on list type and null_count, and creates a
 /// list array ref
 pub fn read_dictionary<T: DictionaryKey, R: Read + Seek>(
     field_nodes: &mut VecDeque<Node>,
     buffers: &mut VecDeque<&gen::Schema::Buffer>,
-    reader: &mut R,
+    reader: &mut BufferReader<R>,
     block_offset: u64,
     is_little_endian: bool,
+    compression: Option<BodyCompression>,
 ) -> Result<Arc<dyn Array>>
 where
     Vec<u8>: TryInto<T::Bytes>,
@@ -637,7 +836,7 @@ where
         reader,
         block_offset,
         is_little_endian,
-        None,
+        compression,
     )?;
 
     Ok(Arc::new(DictionaryArray::<T>::from_data(
@@ -650,19 +849,25 @@ pub fn read<R: Read + Seek>(
     field_nodes: &mut VecDeque<Node>,
     data_type: DataType,
     buffers: &mut VecDeque<&gen::Schema::Buffer>,
-    reader: &mut R,
+    reader: &mut BufferReader<R>,
     block_offset: u64,
     is_little_endian: bool,
     compression: Option<BodyCompression>,
+    version: MetadataVersion,
 ) -> Result<Arc<dyn Array>> {
     match data_type {
         DataType::Null => {
             let array = read_null(field_nodes);
             Ok(Arc::new(array))
         }
-        DataType::Boolean => {
-            read_boolean(field_nodes, buffers, reader, block_offset, is_little_endian)
-        }
+        DataType::Boolean => read_boolean(
+            field_nodes,
+            buffers,
+            reader,
+            block_offset,
+            is_little_endian,
+            compression,
+        ),
         DataType::Int8 => read_primitive::<i8, _>(
             field_nodes,
             data_type,
@@ -770,7 +975,16 @@ pub fn read<R: Read + Seek>(
             compression,
         )
         .map(|x| Arc::new(x) as Arc<dyn Array>),
-        DataType::Float16 => unreachable!(),
+        DataType::Float16 => read_primitive::<f16, _>(
+            field_nodes,
+            data_type,
+            buffers,
+            reader,
+            block_offset,
+            is_little_endian,
+            compression,
+        )
+        .map(|x| Arc::new(x) as Arc<dyn Array>),
         DataType::Float32 => read_primitive::<f32, _>(
             field_nodes,
             data_type,
@@ -855,6 +1069,7 @@ pub fn read<R: Read + Seek>(
             block_offset,
             is_little_endian,
             compression,
+            version,
         ),
         DataType::LargeList(_) => read_list::<i64, _>(
             field_nodes,
@@ -864,6 +1079,7 @@ pub fn read<R: Read + Seek>(
             block_offset,
             is_little_endian,
             compression,
+            version,
         ),
         DataType::FixedSizeList(_, _) => read_fixed_size_list(
             field_nodes,
@@ -873,6 +1089,7 @@ pub fn read<R: Read + Seek>(
             block_offset,
             is_little_endian,
             compression,
+            version,
         ),
         DataType::Struct(_) => read_struct(
             field_nodes,
@@ -882,6 +1099,7 @@ pub fn read<R: Read + Seek>(
             block_offset,
             is_little_endian,
             compression,
+            version,
         ),
         DataType::Dictionary(ref key_type, _) => match key_type.as_ref() {
             DataType::Int8 => read_dictionary::<i8, _>(
@@ -890,6 +1108,7 @@ pub fn read<R: Read + Seek>(
                 reader,
                 block_offset,
                 is_little_endian,
+                compression,
             ),
             DataType::Int16 => read_dictionary::<i16, _>(
                 field_nodes,
@@ -897,6 +1116,7 @@ pub fn read<R: Read + Seek>(
                 reader,
                 block_offset,
                 is_little_endian,
+                compression,
             ),
             DataType::Int32 => read_dictionary::<i32, _>(
                 field_nodes,
@@ -904,6 +1124,7 @@ pub fn read<R: Read + Seek>(
                 reader,
                 block_offset,
                 is_little_endian,
+                compression,
             ),
             DataType::Int64 => read_dictionary::<i64, _>(
                 field_nodes,
@@ -911,6 +1132,7 @@ pub fn read<R: Read + Seek>(
                 reader,
                 block_offset,
                 is_little_endian,
+                compression,
             ),
             DataType::UInt8 => read_dictionary::<u8, _>(
                 field_nodes,
@@ -918,6 +1140,7 @@ pub fn read<R: Read + Seek>(
                 reader,
                 block_offset,
                 is_little_endian,
+                compression,
             ),
             DataType::UInt16 => read_dictionary::<u16, _>(
                 field_nodes,
@@ -925,6 +1148,7 @@ pub fn read<R: Read + Seek>(
                 reader,
                 block_offset,
                 is_little_endian,
+                compression,
             ),
             DataType::UInt32 => read_dictionary::<u32, _>(
                 field_nodes,
@@ -932,6 +1156,7 @@ pub fn read<R: Read + Seek>(
                 reader,
                 block_offset,
                 is_little_endian,
+                compression,
             ),
             DataType::UInt64 => read_dictionary::<u64, _>(
                 field_nodes,
@@ -939,9 +1164,29 @@ pub fn read<R: Read + Seek>(
                 reader,
                 block_offset,
                 is_little_endian,
+                compression,
             ),
             _ => unreachable!(),
         },
-        DataType::Union(_) => unimplemented!(),
+        DataType::Map(_, _) => read_map(
+            field_nodes,
+            data_type,
+            buffers,
+            reader,
+            block_offset,
+            is_little_endian,
+            compression,
+            version,
+        ),
+        DataType::Union(_, _, _) => read_union(
+            field_nodes,
+            data_type,
+            buffers,
+            reader,
+            block_offset,
+            is_little_endian,
+            compression,
+            version,
+        ),
     }
 }
@@ -0,0 +1,207 @@
+//! Zero-copy reading of Arrow IPC files that are in memory, e.g. memory-mapped.
+//!
+//! The buffers of the arrays read from such files borrow from the file's memory whenever
+//! they are uncompressed, in native endianness and aligned; they are copied otherwise.
+use std::io::Cursor;
+use std::sync::Arc;
+
+use crate::datatypes::Schema;
+use crate::error::Result;
+use crate::record_batch::{RecordBatch, RecordBatchReader};
+
+use super::deserialize::{BufferReader, Memory};
+use super::reader::{read_batch_from, read_file_metadata_from, FileMetadata};
+
+/// Calls `f` with a [`BufferReader`] of `data` that borrows buffers from it.
+/// `T` may be unsized, e.g. `[u8]`, so that `Arc<[u8]>` can back the memory.
+fn read_memory<'a, T, F, O>(data: &'a Arc<T>, f: F) -> Result<O>
+where
+    T: ?Sized + AsRef<[u8]> + Send + Sync + 'static,
+    F: FnOnce(&mut BufferReader<Cursor<&'a [u8]>>) -> Result<O>,
+{
+    let bytes: &[u8] = (**data).as_ref();
+    let memory = Memory {
+        // `Arc<T>` is sized even when `T` is not
+        owner: Arc::new(data.clone()),
+        data: bytes,
+    };
+    let mut cursor = Cursor::new(bytes);
+    f(&mut BufferReader::new(&mut cursor, Some(memory)))
+}
+
+/// Reads the metadata of the Arrow IPC file `data`. Its dictionaries borrow from `data`.
+pub fn read_file_metadata_mmap<T: ?Sized + AsRef<[u8]> + Send + Sync + 'static>(
+    data: &Arc<T>,
+) -> Result<FileMetadata> {
+    read_memory(data, read_file_metadata_from)
+}
+
+/// Reads the record batch at position `block` of the Arrow IPC file `data`, whose metadata is
+/// `metadata`. The batch's arrays borrow from `data`.
+pub fn read_batch_mmap<T: ?Sized + AsRef<[u8]> + Send + Sync + 'static>(
+    data: &Arc<T>,
+    metadata: &FileMetadata,
+    block: usize,
+) -> Result<Option<RecordBatch>> {
+    read_memory(data, |reader| read_batch_from(reader, metadata, block))
+}
+
+/// Arrow File reader of a file in memory, such as a memory-mapped file. Its record batches
+/// borrow from the memory instead of copying from it.
+pub struct MmapFileReader<T: ?Sized + AsRef<[u8]> + Send + Sync + 'static> {
+    data: Arc<T>,
+    metadata: FileMetadata,
+    current_block: usize,
+}
+
+impl<T: ?Sized + AsRef<[u8]> + Send + Sync + 'static> MmapFileReader<T> {
+    /// Creates a new reader of `data`, whose metadata was read with [`read_file_metadata_mmap`].
+    pub fn new(data: Arc<T>, metadata: FileMetadata) -> Self {
+        Self {
+            data,
+            metadata,
+            current_block: 0,
+        }
+    }
+
+    /// Return the schema of the file
+    pub fn schema(&self) -> &Arc<Schema> {
+        self.metadata.schema()
+    }
+}
+
+impl<T: ?Sized + AsRef<[u8]> + Send + Sync + 'static> Iterator for MmapFileReader<T> {
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current_block < self.metadata.total_blocks {
+            let block = self.current_block;
+            self.current_block += 1;
+            read_batch_mmap(&self.data, &self.metadata, block).transpose()
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: ?Sized + AsRef<[u8]> + Send + Sync + 'static> RecordBatchReader for MmapFileReader<T> {
+    fn schema(&self) -> &Schema {
+        self.metadata.schema()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::*;
+    use crate::buffer::Buffer;
+    use crate::datatypes::{DataType, Field};
+    use crate::io::ipc::common::tests::read_gzip_json;
+    use crate::io::ipc::write::FileWriter;
+
+    use super::*;
+
+    fn test_file(version: &str, file_name: &str) -> Result<()> {
+        let testdata = crate::util::test_util::arrow_test_data();
+        let data = Arc::new(std::fs::read(format!(
+            "{}/arrow-ipc-stream/integration/{}/{}.arrow_file",
+            testdata, version, file_name
+        ))?);
+
+        let metadata = read_file_metadata_mmap(&data)?;
+        let reader = MmapFileReader::new(data, metadata);
+
+        // read expected JSON output
+        let (schema, batches) = read_gzip_json(version, file_name);
+
+        assert_eq!(&schema, reader.schema().as_ref());
+
+        batches.iter().zip(reader).try_for_each(|(lhs, rhs)| {
+            assert_eq!(lhs, &rhs?);
+            Result::Ok(())
+        })?;
+        Ok(())
+    }
+
+    #[test]
+    fn read_generated_100_primitive() -> Result<()> {
+        test_file("1.0.0-littleendian", "generated_primitive")?;
+        test_file("1.0.0-bigendian", "generated_primitive")
+    }
+
+    #[test]
+    fn read_generated_100_dictionary() -> Result<()> {
+        test_file("1.0.0-littleendian", "generated_dictionary")?;
+        test_file("1.0.0-bigendian", "generated_dictionary")
+    }
+
+    #[test]
+    fn read_generated_100_nested() -> Result<()> {
+        test_file("1.0.0-littleendian", "generated_nested")?;
+        test_file("1.0.0-bigendian", "generated_nested")
+    }
+
+    #[test]
+    fn read_generated_200_compression_lz4() -> Result<()> {
+        test_file("2.0.0-compression", "generated_lz4")
+    }
+
+    /// Memory whose start is aligned, as the memory of a memory-mapped file is.
+    struct Aligned(Buffer<u8>);
+
+    impl AsRef<[u8]> for Aligned {
+        fn as_ref(&self) -> &[u8] {
+            &self.0
+        }
+    }
+
+    fn write_batch() -> Result<(RecordBatch, Vec<u8>)> {
+        let array = Int32Array::from(&[Some(1), None, Some(3)]);
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+        let batch = RecordBatch::try_new(Arc::new(schema.clone()), vec![Arc::new(array)])?;
+
+        let mut data = vec![];
+        {
+            let mut writer = FileWriter::try_new(&mut data, &schema)?;
+            writer.write(&batch)?;
+            writer.finish()?;
+        }
+        Ok((batch, data))
+    }
+
+    #[test]
+    fn zero_copy() -> Result<()> {
+        let (batch, data) = write_batch()?;
+        let data = Arc::new(Aligned(Buffer::from(data)));
+
+        let metadata = read_file_metadata_mmap(&data)?;
+        let result = read_batch_mmap(&data, &metadata, 0)?.unwrap();
+        assert_eq!(result, batch);
+
+        let values = result
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap()
+            .values();
+        let bytes: &[u8] = &data.0;
+        let range = bytes.as_ptr() as usize..bytes.as_ptr() as usize + bytes.len();
+        assert!(range.contains(&(values.as_ptr() as usize)));
+
+        // the batch keeps the memory alive
+        drop(data);
+        assert_eq!(result, batch);
+        Ok(())
+    }
+
+    #[test]
+    fn unsized_memory() -> Result<()> {
+        let (batch, data) = write_batch()?;
+        let data: Arc<[u8]> = data.into();
+
+        let metadata = read_file_metadata_mmap(&data)?;
+        let reader = MmapFileReader::new(data, metadata);
+        let batches = reader.collect::<Result<Vec<_>>>()?;
+        assert_eq!(batches, vec![batch]);
+        Ok(())
+    }
+}
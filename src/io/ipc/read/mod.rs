@@ -17,9 +17,15 @@
 
 mod common;
 mod deserialize;
+mod mmap;
 mod reader;
 mod stream;
+#[cfg(feature = "io_ipc_async")]
+mod stream_async;
 
 pub use common::{read_dictionary, read_record_batch};
+pub use mmap::{read_batch_mmap, read_file_metadata_mmap, MmapFileReader};
 pub use reader::{read_file_metadata, FileMetadata, FileReader};
-pub use stream::{read_stream_metadata, StreamMetadata, StreamReader};
+pub use stream::{read_next, read_stream_metadata, StreamMetadata, StreamReader};
+#[cfg(feature = "io_ipc_async")]
+pub use stream_async::{read_next_async, read_stream_metadata_async, AsyncStreamReader};
@@ -26,6 +26,7 @@ use crate::record_batch::{RecordBatch, RecordBatchReader};
 use super::super::{convert, gen};
 use super::super::{ARROW_MAGIC, CONTINUATION_MARKER};
 use super::common::*;
+use super::deserialize::BufferReader;
 
 type ArrayRef = Arc<dyn Array>;
 
@@ -40,7 +41,7 @@ pub struct FileMetadata {
     blocks: Vec<gen::File::Block>,
 
     /// The total number of blocks, which may contain record batches and other types
-    total_blocks: usize,
+    pub(super) total_blocks: usize,
 
     /// Optional dictionaries for each schema field.
     ///
@@ -69,6 +70,13 @@ pub struct FileReader<'a, R: Read + Seek> {
 
 /// Read the IPC file's metadata
 pub fn read_file_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetadata> {
+    read_file_metadata_from(&mut BufferReader::new(reader, None))
+}
+
+/// Like [`read_file_metadata`], but reads the dictionaries' buffers from a [`BufferReader`].
+pub(super) fn read_file_metadata_from<R: Read + Seek>(
+    reader: &mut BufferReader<R>,
+) -> Result<FileMetadata> {
     // check if header and footer contain correct magic bytes
     let mut magic_buffer: [u8; 6] = [0; 6];
     reader.read_exact(&mut magic_buffer)?;
@@ -133,13 +141,14 @@ pub fn read_file_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetadata
             gen::Message::MessageHeader::DictionaryBatch => {
                 let block_offset = block.offset() as u64 + block.metaDataLength() as u64;
                 let batch = message.header_as_dictionary_batch().unwrap();
-                read_dictionary(
+                read_dictionary_from(
                     batch,
                     &schema,
                     is_little_endian,
                     &mut dictionaries_by_field,
                     reader,
                     block_offset,
+                    message.version(),
                 )?;
             }
             t => {
@@ -160,11 +169,20 @@ pub fn read_file_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetadata
     })
 }
 
-/// Read the IPC file's metadata
+/// Reads the record batch at position `block` of the IPC file
 pub fn read_batch<R: Read + Seek>(
     reader: &mut R,
     metadata: &FileMetadata,
     block: usize,
+) -> Result<Option<RecordBatch>> {
+    read_batch_from(&mut BufferReader::new(reader, None), metadata, block)
+}
+
+/// Like [`read_batch`], but reads the batch's buffers from a [`BufferReader`].
+pub(super) fn read_batch_from<R: Read + Seek>(
+    reader: &mut BufferReader<R>,
+    metadata: &FileMetadata,
+    block: usize,
 ) -> Result<Option<RecordBatch>> {
     let block = metadata.blocks[block];
 
@@ -200,13 +218,14 @@ pub fn read_batch<R: Read + Seek>(
             let batch = message.header_as_record_batch().ok_or_else(|| {
                 ArrowError::Ipc("Unable to read IPC message as record batch".to_string())
             })?;
-            read_record_batch(
+            read_record_batch_from(
                 batch,
                 metadata.schema.clone(),
                 metadata.is_little_endian,
                 &metadata.dictionaries_by_field,
                 reader,
                 block.offset() as u64 + block.metaDataLength() as u64,
+                message.version(),
             )
             .map(Some)
         }
@@ -364,6 +383,18 @@ mod tests {
         test_file("1.0.0-bigendian", "generated_decimal")
     }
 
+    #[test]
+    fn read_generated_100_map() -> Result<()> {
+        test_file("1.0.0-littleendian", "generated_map")?;
+        test_file("1.0.0-bigendian", "generated_map")
+    }
+
+    #[test]
+    fn read_generated_100_union() -> Result<()> {
+        test_file("1.0.0-littleendian", "generated_union")?;
+        test_file("1.0.0-bigendian", "generated_union")
+    }
+
     #[test]
     fn read_generated_100_interval() -> Result<()> {
         test_file("1.0.0-littleendian", "generated_interval")?;
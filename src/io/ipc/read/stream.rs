@@ -38,6 +38,13 @@ pub struct StreamMetadata {
     is_little_endian: bool,
 }
 
+impl StreamMetadata {
+    /// The schema of the stream
+    pub fn schema(&self) -> &Arc<Schema> {
+        &self.schema
+    }
+}
+
 /// Reads the metadata of the stream
 pub fn read_stream_metadata<R: Read>(reader: &mut R) -> Result<StreamMetadata> {
     // determine metadata length
@@ -55,7 +62,12 @@ pub fn read_stream_metadata<R: Read>(reader: &mut R) -> Result<StreamMetadata> {
     let mut meta_buffer = vec![0; meta_len as usize];
     reader.read_exact(&mut meta_buffer)?;
 
-    let message = gen::Message::root_as_message(meta_buffer.as_slice())
+    deserialize_stream_metadata(&meta_buffer)
+}
+
+/// Deserializes the metadata of the stream from the flatbuffers message of its schema
+pub(super) fn deserialize_stream_metadata(meta: &[u8]) -> Result<StreamMetadata> {
+    let message = gen::Message::root_as_message(meta)
         .map_err(|err| ArrowError::Ipc(format!("Unable to get root as message: {:?}", err)))?;
     // message header is a Schema, so read it
     let ipc_schema: gen::Schema::Schema = message
@@ -110,31 +122,67 @@ pub fn read_next<R: Read>(
     let mut meta_buffer = vec![0; meta_len as usize];
     reader.read_exact(&mut meta_buffer)?;
 
-    let vecs = &meta_buffer.to_vec();
-    let message = gen::Message::root_as_message(vecs)
+    let body_length = match message_body_length(&meta_buffer)? {
+        Some(body_length) => body_length,
+        None => return Ok(None),
+    };
+    // read the block that makes up the message into a buffer
+    let mut body = vec![0; body_length];
+    reader.read_exact(&mut body)?;
+
+    match read_message(&meta_buffer, body, metadata, dictionaries_by_field)? {
+        Some(batch) => Ok(Some(batch)),
+        // read the next message until we encounter a RecordBatch
+        None => read_next(reader, metadata, dictionaries_by_field),
+    }
+}
+
+/// Returns the length of the body of the flatbuffers message `meta`, or `None` when the message
+/// marks the end of the stream.
+pub(super) fn message_body_length(meta: &[u8]) -> Result<Option<usize>> {
+    let message = gen::Message::root_as_message(meta)
         .map_err(|err| ArrowError::Ipc(format!("Unable to get root as message: {:?}", err)))?;
 
     match message.header_type() {
         gen::Message::MessageHeader::Schema => Err(ArrowError::Ipc(
             "Not expecting a schema when messages are read".to_string(),
         )),
+        gen::Message::MessageHeader::RecordBatch | gen::Message::MessageHeader::DictionaryBatch => {
+            Ok(Some(message.bodyLength() as usize))
+        }
+        gen::Message::MessageHeader::NONE => Ok(None),
+        t => Err(ArrowError::Ipc(format!(
+            "Reading types other than record batches not yet supported, unable to read {:?} ",
+            t
+        ))),
+    }
+}
+
+/// Reads the flatbuffers message `meta` whose body is `body`. Dictionary batches are read into
+/// `dictionaries_by_field`, in which case `None` is returned.
+pub(super) fn read_message(
+    meta: &[u8],
+    body: Vec<u8>,
+    metadata: &StreamMetadata,
+    dictionaries_by_field: &mut Vec<Option<ArrayRef>>,
+) -> Result<Option<RecordBatch>> {
+    let message = gen::Message::root_as_message(meta)
+        .map_err(|err| ArrowError::Ipc(format!("Unable to get root as message: {:?}", err)))?;
+    let mut reader = std::io::Cursor::new(body);
+
+    match message.header_type() {
         gen::Message::MessageHeader::RecordBatch => {
             let batch = message.header_as_record_batch().ok_or_else(|| {
                 ArrowError::Ipc("Unable to read IPC message as record batch".to_string())
             })?;
-            // read the block that makes up the record batch into a buffer
-            let mut buf = vec![0; message.bodyLength() as usize];
-            reader.read_exact(&mut buf)?;
-
-            let mut reader = std::io::Cursor::new(buf);
-
             read_record_batch(
                 batch,
                 metadata.schema.clone(),
                 metadata.is_little_endian,
-                &dictionaries_by_field,
+                dictionaries_by_field,
                 &mut reader,
                 0,
+                message.version(),
             )
             .map(Some)
         }
@@ -142,25 +190,17 @@ pub fn read_next<R: Read>(
             let batch = message.header_as_dictionary_batch().ok_or_else(|| {
                 ArrowError::Ipc("Unable to read IPC message as dictionary batch".to_string())
             })?;
-            // read the block that makes up the dictionary batch into a buffer
-            let mut buf = vec![0; message.bodyLength() as usize];
-            reader.read_exact(&mut buf)?;
-
-            let mut dict_reader = std::io::Cursor::new(buf);
-
             read_dictionary(
                 batch,
                 &metadata.schema,
                 metadata.is_little_endian,
                 dictionaries_by_field,
-                &mut dict_reader,
+                &mut reader,
                 0,
+                message.version(),
             )?;
-
-            // read the next message until we encounter a RecordBatch
-            read_next(reader, metadata, dictionaries_by_field)
+            Ok(None)
         }
-        gen::Message::MessageHeader::NONE => Ok(None),
         t => Err(ArrowError::Ipc(format!(
             "Reading types other than record batches not yet supported, unable to read {:?} ",
             t
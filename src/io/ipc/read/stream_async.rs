@@ -0,0 +1,194 @@
+//! Async reader of the Arrow IPC stream format.
+use std::sync::Arc;
+
+use futures::{io::AsyncRead, AsyncReadExt, Stream};
+
+use crate::array::*;
+use crate::datatypes::Schema;
+use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
+
+use super::super::CONTINUATION_MARKER;
+use super::stream::{
+    deserialize_stream_metadata, message_body_length, read_message, StreamMetadata,
+};
+
+type ArrayRef = Arc<dyn Array>;
+
+/// Reads the length of the next message, skipping the continuation marker if present.
+/// Returns `None` if the reader is at its end.
+async fn read_message_length<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Option<usize>> {
+    let mut meta_size: [u8; 4] = [0; 4];
+    match reader.read_exact(&mut meta_size).await {
+        Ok(()) => (),
+        Err(e) => {
+            return if e.kind() == std::io::ErrorKind::UnexpectedEof {
+                // Handle EOF without the "0xFFFFFFFF 0x00000000"
+                // valid according to:
+                // https://arrow.apache.org/docs/format/Columnar.html#ipc-streaming-format
+                Ok(None)
+            } else {
+                Err(ArrowError::from(e))
+            };
+        }
+    }
+
+    // If a continuation marker is encountered, skip over it and read
+    // the size from the next four bytes.
+    if meta_size == CONTINUATION_MARKER {
+        reader.read_exact(&mut meta_size).await?;
+    }
+    Ok(Some(i32::from_le_bytes(meta_size) as usize))
+}
+
+/// Reads the metadata of the stream
+pub async fn read_stream_metadata_async<R: AsyncRead + Unpin>(
+    reader: &mut R,
+) -> Result<StreamMetadata> {
+    let meta_len = read_message_length(reader).await?.ok_or_else(|| {
+        ArrowError::Ipc("The stream ended before its schema was read".to_string())
+    })?;
+
+    let mut meta_buffer = vec![0; meta_len];
+    reader.read_exact(&mut meta_buffer).await?;
+
+    deserialize_stream_metadata(&meta_buffer)
+}
+
+/// Reads the next item. Like [`super::read_next`], dictionary batches are read into
+/// `dictionaries_by_field` until a record batch is found.
+pub async fn read_next_async<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    metadata: &StreamMetadata,
+    dictionaries_by_field: &mut Vec<Option<ArrayRef>>,
+) -> Result<Option<RecordBatch>> {
+    loop {
+        let meta_len = match read_message_length(reader).await? {
+            // the stream has ended
+            None | Some(0) => return Ok(None),
+            Some(meta_len) => meta_len,
+        };
+
+        let mut meta_buffer = vec![0; meta_len];
+        reader.read_exact(&mut meta_buffer).await?;
+
+        let body_length = match message_body_length(&meta_buffer)? {
+            Some(body_length) => body_length,
+            None => return Ok(None),
+        };
+        // read the block that makes up the message into a buffer
+        let mut body = vec![0; body_length];
+        reader.read_exact(&mut body).await?;
+
+        if let Some(batch) = read_message(&meta_buffer, body, metadata, dictionaries_by_field)? {
+            return Ok(Some(batch));
+        }
+    }
+}
+
+/// Async Arrow stream reader
+pub struct AsyncStreamReader<R: AsyncRead + Unpin> {
+    reader: R,
+    metadata: StreamMetadata,
+    dictionaries_by_field: Vec<Option<ArrayRef>>,
+}
+
+impl<R: AsyncRead + Unpin> AsyncStreamReader<R> {
+    /// Creates a new reader of the messages after the schema of the stream, whose metadata
+    /// was read with [`read_stream_metadata_async`].
+    pub fn new(reader: R, metadata: StreamMetadata) -> Self {
+        let fields = metadata.schema().fields().len();
+        Self {
+            reader,
+            metadata,
+            dictionaries_by_field: vec![None; fields],
+        }
+    }
+
+    /// Return the schema of the stream
+    pub fn schema(&self) -> &Arc<Schema> {
+        self.metadata.schema()
+    }
+
+    /// Reads the next [`RecordBatch`], returning `None` when the stream has ended.
+    pub async fn next_batch(&mut self) -> Result<Option<RecordBatch>> {
+        read_next_async(
+            &mut self.reader,
+            &self.metadata,
+            &mut self.dictionaries_by_field,
+        )
+        .await
+    }
+
+    /// Converts this reader into a [`Stream`] of [`RecordBatch`]es. The stream ends after the
+    /// first error.
+    pub fn into_stream(self) -> impl Stream<Item = Result<RecordBatch>> {
+        futures::stream::unfold(Some(self), |reader| async move {
+            let mut reader = reader?;
+            match reader.next_batch().await {
+                Ok(Some(batch)) => Some((Ok(batch), Some(reader))),
+                Ok(None) => None,
+                Err(e) => Some((Err(e), None)),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{executor::block_on, TryStreamExt};
+
+    use super::*;
+
+    use crate::io::ipc::common::tests::read_gzip_json;
+
+    fn test_file(version: &str, file_name: &str) -> Result<()> {
+        let testdata = crate::util::test_util::arrow_test_data();
+        let data = std::fs::read(format!(
+            "{}/arrow-ipc-stream/integration/{}/{}.stream",
+            testdata, version, file_name
+        ))?;
+
+        let (schema, batches) = block_on(async {
+            let mut reader = futures::io::Cursor::new(data);
+            let metadata = read_stream_metadata_async(&mut reader).await?;
+            let reader = AsyncStreamReader::new(reader, metadata);
+            let schema = reader.schema().clone();
+            let batches = reader.into_stream().try_collect::<Vec<_>>().await?;
+            Result::Ok((schema, batches))
+        })?;
+
+        // read expected JSON output
+        let (expected_schema, expected_batches) = read_gzip_json(version, file_name);
+
+        assert_eq!(schema.as_ref(), &expected_schema);
+        assert_eq!(batches, expected_batches);
+        Ok(())
+    }
+
+    #[test]
+    fn read_generated_100_primitive() -> Result<()> {
+        test_file("1.0.0-littleendian", "generated_primitive")
+    }
+
+    #[test]
+    fn read_generated_100_primitive_no_batches() -> Result<()> {
+        test_file("1.0.0-littleendian", "generated_primitive_no_batches")
+    }
+
+    #[test]
+    fn read_generated_100_dictionary() -> Result<()> {
+        test_file("1.0.0-littleendian", "generated_dictionary")
+    }
+
+    #[test]
+    fn read_generated_100_nested() -> Result<()> {
+        test_file("1.0.0-littleendian", "generated_nested")
+    }
+
+    #[test]
+    fn read_generated_014_primitive() -> Result<()> {
+        // streams of the legacy format have no continuation markers
+        test_file("0.14.1", "generated_primitive")
+    }
+}
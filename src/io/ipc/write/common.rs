@@ -18,6 +18,7 @@
 use std::io::Write;
 use std::{collections::HashMap, sync::Arc};
 
+use super::super::compression;
 use super::super::gen;
 use super::super::CONTINUATION_MARKER;
 use super::{write, write_dictionary};
@@ -29,6 +30,15 @@ use crate::error::{ArrowError, Result};
 use crate::record_batch::RecordBatch;
 use crate::{array::DictionaryArray, datatypes::*};
 
+/// Compression codec of the buffers of an IPC message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    /// LZ4 frame format
+    Lz4,
+    /// ZSTD
+    Zstd,
+}
+
 /// IPC write options used to control the behaviour of the writer
 #[derive(Debug)]
 pub struct IpcWriteOptions {
@@ -46,6 +56,9 @@ pub struct IpcWriteOptions {
     /// version 2.0.0: V4, with legacy format enabled
     /// version 4.0.0: V5
     metadata_version: gen::Schema::MetadataVersion,
+    /// The compression of the buffers of record batches and dictionary batches.
+    /// Compression is only supported on metadata version 5.
+    compression: Option<Compression>,
 }
 
 impl IpcWriteOptions {
@@ -70,6 +83,7 @@ impl IpcWriteOptions {
                 alignment,
                 write_legacy_ipc_format,
                 metadata_version,
+                compression: None,
             }),
             gen::Schema::MetadataVersion::V5 => {
                 if write_legacy_ipc_format {
@@ -81,6 +95,7 @@ impl IpcWriteOptions {
                         alignment,
                         write_legacy_ipc_format,
                         metadata_version,
+                        compression: None,
                     })
                 }
             }
@@ -88,9 +103,26 @@ impl IpcWriteOptions {
         }
     }
 
+    /// Sets the compression of the buffers of record batches and dictionary batches.
+    /// # Errors
+    /// This function errors if `compression` is set and the metadata version is lower than 5.
+    pub fn try_with_compression(mut self, compression: Option<Compression>) -> Result<Self> {
+        if compression.is_some() && self.metadata_version < gen::Schema::MetadataVersion::V5 {
+            return Err(ArrowError::InvalidArgumentError(
+                "Compressed IPC is only supported on metadata version 5".to_string(),
+            ));
+        }
+        self.compression = compression;
+        Ok(self)
+    }
+
     pub fn metadata_version(&self) -> &gen::Schema::MetadataVersion {
         &self.metadata_version
     }
+
+    pub fn compression(&self) -> Option<Compression> {
+        self.compression
+    }
 }
 
 impl Default for IpcWriteOptions {
@@ -99,6 +131,7 @@ impl Default for IpcWriteOptions {
             alignment: 8,
             write_legacy_ipc_format: false,
             metadata_version: gen::Schema::MetadataVersion::V5,
+            compression: None,
         }
     }
 }
@@ -110,6 +143,16 @@ pub fn encoded_batch(
 ) -> Result<(Vec<EncodedData>, EncodedData)> {
     // TODO: handle nested dictionaries
     let schema = batch.schema();
+    if write_options.metadata_version < gen::Schema::MetadataVersion::V5
+        && schema
+            .fields()
+            .iter()
+            .any(|field| contains_union(field.data_type()))
+    {
+        return Err(ArrowError::InvalidArgumentError(
+            "Writing unions is only supported on IPC metadata version 5".to_string(),
+        ));
+    }
     let mut encoded_dictionaries = Vec::with_capacity(schema.fields().len());
 
     for (i, field) in schema.fields().iter().enumerate() {
@@ -128,19 +171,37 @@ pub fn encoded_batch(
                     column.as_ref(),
                     write_options,
                     is_native_little_endian(),
-                ));
+                )?);
             }
         }
     }
 
-    let encoded_message = record_batch_to_bytes(batch, write_options);
+    let encoded_message = record_batch_to_bytes(batch, write_options)?;
 
     Ok((encoded_dictionaries, encoded_message))
 }
 
+/// Whether `data_type` is or contains a [`DataType::Union`].
+/// Before V5, unions have a validity buffer, which this writer does not write.
+fn contains_union(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Union(_, _, _) => true,
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _)
+        | DataType::Map(field, _) => contains_union(field.data_type()),
+        DataType::Struct(fields) => fields.iter().any(|field| contains_union(field.data_type())),
+        DataType::Dictionary(_, values) => contains_union(values),
+        _ => false,
+    }
+}
+
 /// Write a `RecordBatch` into two sets of bytes, one for the header (gen::Schema::Message) and the
 /// other for the batch's data
-fn record_batch_to_bytes(batch: &RecordBatch, write_options: &IpcWriteOptions) -> EncodedData {
+fn record_batch_to_bytes(
+    batch: &RecordBatch,
+    write_options: &IpcWriteOptions,
+) -> Result<EncodedData> {
     let mut fbb = FlatBufferBuilder::new();
 
     let mut nodes: Vec<gen::Message::FieldNode> = vec![];
@@ -157,6 +218,10 @@ fn record_batch_to_bytes(batch: &RecordBatch, write_options: &IpcWriteOptions) -
             is_native_little_endian(),
         )
     }
+    let (buffers, arrow_data) = compress_buffers(buffers, arrow_data, write_options.compression)?;
+    let compression = write_options
+        .compression
+        .map(|compression| body_compression(&mut fbb, compression));
 
     // write data
     let buffers = fbb.create_vector(&buffers);
@@ -167,6 +232,9 @@ fn record_batch_to_bytes(batch: &RecordBatch, write_options: &IpcWriteOptions) -
         batch_builder.add_length(batch.num_rows() as i64);
         batch_builder.add_nodes(nodes);
         batch_builder.add_buffers(buffers);
+        if let Some(compression) = compression {
+            batch_builder.add_compression(compression);
+        }
         let b = batch_builder.finish();
         b.as_union_value()
     };
@@ -180,10 +248,10 @@ fn record_batch_to_bytes(batch: &RecordBatch, write_options: &IpcWriteOptions) -
     fbb.finish(root, None);
     let finished_data = fbb.finished_data();
 
-    EncodedData {
+    Ok(EncodedData {
         ipc_message: finished_data.to_vec(),
         arrow_data,
-    }
+    })
 }
 
 /// Write dictionary values into two sets of bytes, one for the header (gen::Schema::Message) and the
@@ -193,7 +261,7 @@ fn dictionary_batch_to_bytes(
     array: &dyn Array,
     write_options: &IpcWriteOptions,
     is_little_endian: bool,
-) -> EncodedData {
+) -> Result<EncodedData> {
     let mut fbb = FlatBufferBuilder::new();
 
     let mut nodes: Vec<gen::Message::FieldNode> = vec![];
@@ -209,6 +277,10 @@ fn dictionary_batch_to_bytes(
         is_little_endian,
         false,
     );
+    let (buffers, arrow_data) = compress_buffers(buffers, arrow_data, write_options.compression)?;
+    let compression = write_options
+        .compression
+        .map(|compression| body_compression(&mut fbb, compression));
 
     // write data
     let buffers = fbb.create_vector(&buffers);
@@ -219,6 +291,9 @@ fn dictionary_batch_to_bytes(
         batch_builder.add_length(length as i64);
         batch_builder.add_nodes(nodes);
         batch_builder.add_buffers(buffers);
+        if let Some(compression) = compression {
+            batch_builder.add_compression(compression);
+        }
         batch_builder.finish()
     };
 
@@ -241,10 +316,61 @@ fn dictionary_batch_to_bytes(
     fbb.finish(root, None);
     let finished_data = fbb.finished_data();
 
-    EncodedData {
+    Ok(EncodedData {
         ipc_message: finished_data.to_vec(),
         arrow_data,
-    }
+    })
+}
+
+/// Compresses each buffer in `arrow_data` with `compression`, returning the new buffers and data.
+/// Each compressed buffer is prefixed by its uncompressed length as a little-endian `i64` and
+/// padded to 8 bytes. Empty buffers are left empty.
+fn compress_buffers(
+    buffers: Vec<gen::Schema::Buffer>,
+    arrow_data: Vec<u8>,
+    compression: Option<Compression>,
+) -> Result<(Vec<gen::Schema::Buffer>, Vec<u8>)> {
+    let codec = if let Some(codec) = compression {
+        codec
+    } else {
+        return Ok((buffers, arrow_data));
+    };
+
+    let mut compressed = Vec::with_capacity(arrow_data.len());
+    let buffers = buffers
+        .iter()
+        .map(|buffer| {
+            let start = compressed.len();
+            if buffer.length() == 0 {
+                return Ok(gen::Schema::Buffer::new(start as i64, 0));
+            }
+            let data = &arrow_data[buffer.offset() as usize..][..buffer.length() as usize];
+
+            compressed.extend_from_slice(&(data.len() as i64).to_le_bytes());
+            match codec {
+                Compression::Lz4 => compression::compress_lz4(data, &mut compressed)?,
+                Compression::Zstd => compression::compress_zstd(data, &mut compressed)?,
+            }
+            let length = compressed.len() - start;
+            compressed.extend_from_slice(&vec![0u8; pad_to_8(length as u32)]);
+            Ok(gen::Schema::Buffer::new(start as i64, length as i64))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok((buffers, compressed))
+}
+
+fn body_compression<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    compression: Compression,
+) -> flatbuffers::WIPOffset<gen::Message::BodyCompression<'a>> {
+    let codec = match compression {
+        Compression::Lz4 => gen::Message::CompressionType::LZ4_FRAME,
+        Compression::Zstd => gen::Message::CompressionType::ZSTD,
+    };
+    let mut builder = gen::Message::BodyCompressionBuilder::new(fbb);
+    builder.add_codec(codec);
+    builder.add_method(gen::Message::BodyCompressionMethod::BUFFER);
+    builder.finish()
 }
 
 /// Keeps track of dictionaries that have been written, to avoid emitting the same dictionary
@@ -19,10 +19,14 @@ pub mod common;
 mod schema;
 mod serialize;
 mod stream;
+#[cfg(feature = "io_ipc_async")]
+mod stream_async;
 mod writer;
 
 pub use super::gen::Schema::MetadataVersion;
 pub use schema::schema_to_bytes;
 pub use serialize::{write, write_dictionary};
 pub use stream::StreamWriter;
+#[cfg(feature = "io_ipc_async")]
+pub use stream_async::StreamSink;
 pub use writer::FileWriter;
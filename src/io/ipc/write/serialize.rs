@@ -18,14 +18,15 @@
 use crate::{
     array::{
         Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey, FixedSizeBinaryArray,
-        FixedSizeListArray, ListArray, Offset, PrimitiveArray, StructArray, Utf8Array,
+        FixedSizeListArray, ListArray, MapArray, Offset, PrimitiveArray, StructArray, UnionArray,
+        Utf8Array,
     },
     bitmap::Bitmap,
     datatypes::{DataType, IntervalUnit},
     endianess::is_native_little_endian,
     io::ipc::gen::Message,
     trusted_len::TrustedLen,
-    types::{days_ms, NativeType},
+    types::{days_ms, f16, NativeType},
 };
 
 use crate::io::ipc::gen::Schema;
@@ -214,6 +215,47 @@ fn write_list<O: Offset>(
     );
 }
 
+fn write_map(
+    array: &dyn Array,
+    buffers: &mut Vec<Schema::Buffer>,
+    arrow_data: &mut Vec<u8>,
+    nodes: &mut Vec<Message::FieldNode>,
+    offset: &mut i64,
+    is_little_endian: bool,
+) {
+    let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+    let offsets = array.offsets();
+    let validity = array.validity();
+
+    write_bitmap(validity, offsets.len() - 1, buffers, arrow_data, offset);
+
+    let first = *offsets.first().unwrap();
+    let last = *offsets.last().unwrap();
+    if first == 0 {
+        write_buffer(offsets, buffers, arrow_data, offset, is_little_endian);
+    } else {
+        write_buffer_from_iter(
+            offsets.iter().map(|x| *x - first),
+            buffers,
+            arrow_data,
+            offset,
+            is_little_endian,
+        );
+    }
+
+    write(
+        array
+            .field()
+            .slice(first as usize, last as usize - first as usize)
+            .as_ref(),
+        buffers,
+        arrow_data,
+        nodes,
+        offset,
+        is_little_endian,
+    );
+}
+
 pub fn write_struct(
     array: &dyn Array,
     buffers: &mut Vec<Schema::Buffer>,
@@ -236,6 +278,34 @@ pub fn write_struct(
     });
 }
 
+/// Writes a [`UnionArray`] following metadata version V5, i.e. without a validity buffer.
+fn write_union(
+    array: &dyn Array,
+    buffers: &mut Vec<Schema::Buffer>,
+    arrow_data: &mut Vec<u8>,
+    nodes: &mut Vec<Message::FieldNode>,
+    offset: &mut i64,
+    is_little_endian: bool,
+) {
+    let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+
+    write_buffer(array.types(), buffers, arrow_data, offset, is_little_endian);
+
+    if let Some(offsets) = array.offsets() {
+        write_buffer(offsets, buffers, arrow_data, offset, is_little_endian);
+    }
+    array.fields().iter().for_each(|array| {
+        write(
+            array.as_ref(),
+            buffers,
+            arrow_data,
+            nodes,
+            offset,
+            is_little_endian,
+        )
+    });
+}
+
 fn write_fixed_size_list(
     array: &dyn Array,
     buffers: &mut Vec<Schema::Buffer>,
@@ -424,7 +494,9 @@ pub fn write(
         DataType::UInt64 => {
             write_primitive::<u64>(array, buffers, arrow_data, offset, is_little_endian)
         }
-        DataType::Float16 => unreachable!(),
+        DataType::Float16 => {
+            write_primitive::<f16>(array, buffers, arrow_data, offset, is_little_endian)
+        }
         DataType::Float32 => {
             write_primitive::<f32>(array, buffers, arrow_data, offset, is_little_endian)
         }
@@ -467,7 +539,12 @@ pub fn write(
                 true,
             );
         }
-        DataType::Union(_) => unimplemented!(),
+        DataType::Map(_, _) => {
+            write_map(array, buffers, arrow_data, nodes, offset, is_little_endian);
+        }
+        DataType::Union(_, _, _) => {
+            write_union(array, buffers, arrow_data, nodes, offset, is_little_endian);
+        }
     }
 }
 
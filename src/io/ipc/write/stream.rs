@@ -114,6 +114,7 @@ mod tests {
     use std::io::Cursor;
 
     use super::super::super::gen;
+    use super::super::common::Compression;
     use super::*;
 
     use crate::io::ipc::read::StreamReader;
@@ -123,14 +124,24 @@ mod tests {
     };
 
     fn test_file(version: &str, file_name: &str) {
+        test_file_with_compression(version, file_name, None)
+    }
+
+    fn test_file_with_compression(
+        version: &str,
+        file_name: &str,
+        compression: Option<Compression>,
+    ) {
         let (schema, batches) = read_arrow_stream(version, file_name);
 
         let mut result = Vec::<u8>::new();
 
         // write IPC version 5
         {
-            let options =
-                IpcWriteOptions::try_new(8, false, gen::Schema::MetadataVersion::V5).unwrap();
+            let options = IpcWriteOptions::try_new(8, false, gen::Schema::MetadataVersion::V5)
+                .unwrap()
+                .try_with_compression(compression)
+                .unwrap();
             let mut writer =
                 StreamWriter::try_new_with_options(&mut result, &schema, options).unwrap();
             for batch in batches {
@@ -160,6 +171,24 @@ mod tests {
         test_file("1.0.0-littleendian", "generated_primitive");
     }
 
+    #[test]
+    fn write_100_primitive_lz4() {
+        test_file_with_compression(
+            "1.0.0-littleendian",
+            "generated_primitive",
+            Some(Compression::Lz4),
+        );
+    }
+
+    #[test]
+    fn write_100_dictionary_zstd() {
+        test_file_with_compression(
+            "1.0.0-littleendian",
+            "generated_dictionary",
+            Some(Compression::Zstd),
+        );
+    }
+
     #[test]
     fn write_100_datetime() {
         test_file("1.0.0-littleendian", "generated_datetime");
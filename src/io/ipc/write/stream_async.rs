@@ -0,0 +1,225 @@
+//! Async writer of the Arrow IPC stream format.
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::{io::AsyncWrite, ready, Sink};
+
+use super::common::{
+    encoded_batch, write_continuation, write_message, DictionaryTracker, EncodedData,
+    IpcWriteOptions,
+};
+use super::schema_to_bytes;
+
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
+
+/// A [`Sink`] of [`RecordBatch`]es that writes them to an [`AsyncWrite`] in the Arrow IPC stream
+/// format.
+///
+/// Each batch is encoded to an in-memory buffer exactly like [`super::StreamWriter`] does, which
+/// is then written to the writer when the sink is flushed or when the next batch is sent.
+/// Closing the sink writes the end-of-stream marker and closes the writer.
+pub struct StreamSink<W: AsyncWrite + Unpin> {
+    /// The object to write to
+    writer: W,
+    /// IPC write options
+    write_options: IpcWriteOptions,
+    /// Keeps track of dictionaries that have been written
+    dictionary_tracker: DictionaryTracker,
+    /// Encoded messages not yet written to `writer`
+    buffer: Vec<u8>,
+    /// The number of bytes of `buffer` already written to `writer`
+    written: usize,
+    /// Whether the end-of-stream marker has been encoded
+    finished: bool,
+}
+
+impl<W: AsyncWrite + Unpin> StreamSink<W> {
+    /// Creates a new [`StreamSink`] whose first message is `schema`.
+    pub fn try_new(writer: W, schema: &Schema, write_options: IpcWriteOptions) -> Result<Self> {
+        let mut buffer = vec![];
+        let encoded_message = EncodedData {
+            ipc_message: schema_to_bytes(schema, *write_options.metadata_version()),
+            arrow_data: vec![],
+        };
+        write_message(&mut buffer, encoded_message, &write_options)?;
+        Ok(Self {
+            writer,
+            write_options,
+            dictionary_tracker: DictionaryTracker::new(false),
+            buffer,
+            written: 0,
+            finished: false,
+        })
+    }
+
+    /// Consumes the sink, returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Writes the pending messages to the writer.
+    fn poll_write_buffer(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        while self.written < self.buffer.len() {
+            let written =
+                ready!(Pin::new(&mut self.writer).poll_write(cx, &self.buffer[self.written..]))?;
+            if written == 0 {
+                return Poll::Ready(Err(ArrowError::from(std::io::Error::from(
+                    std::io::ErrorKind::WriteZero,
+                ))));
+            }
+            self.written += written;
+        }
+        self.buffer.clear();
+        self.written = 0;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<W: AsyncWrite + Unpin> Sink<RecordBatch> for StreamSink<W> {
+    type Error = ArrowError;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.get_mut().poll_write_buffer(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, batch: RecordBatch) -> Result<()> {
+        let this = self.get_mut();
+        if this.finished {
+            return Err(ArrowError::Ipc(
+                "Cannot write record batch to stream writer as it is closed".to_string(),
+            ));
+        }
+
+        let (encoded_dictionaries, encoded_message) =
+            encoded_batch(&batch, &mut this.dictionary_tracker, &this.write_options)?;
+
+        for encoded_dictionary in encoded_dictionaries {
+            write_message(&mut this.buffer, encoded_dictionary, &this.write_options)?;
+        }
+
+        write_message(&mut this.buffer, encoded_message, &this.write_options)?;
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_write_buffer(cx))?;
+        Pin::new(&mut this.writer)
+            .poll_flush(cx)
+            .map_err(ArrowError::from)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        let this = self.get_mut();
+        if !this.finished {
+            write_continuation(&mut this.buffer, &this.write_options, 0)?;
+            this.finished = true;
+        }
+        ready!(this.poll_write_buffer(cx))?;
+        Pin::new(&mut this.writer)
+            .poll_close(cx)
+            .map_err(ArrowError::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{executor::block_on, SinkExt};
+
+    use super::super::super::gen;
+    use super::super::StreamWriter;
+    use super::*;
+
+    use crate::io::ipc::common::tests::{read_arrow_stream, read_gzip_json};
+    use crate::io::ipc::read::{read_stream_metadata, StreamReader};
+
+    fn write_async(schema: &Schema, batches: Vec<RecordBatch>) -> Vec<u8> {
+        block_on(async {
+            let options =
+                IpcWriteOptions::try_new(8, false, gen::Schema::MetadataVersion::V5).unwrap();
+            let mut sink =
+                StreamSink::try_new(futures::io::Cursor::new(vec![]), schema, options).unwrap();
+            for batch in batches {
+                sink.feed(batch).await.unwrap();
+            }
+            sink.close().await.unwrap();
+            sink.into_inner().into_inner()
+        })
+    }
+
+    fn test_file(version: &str, file_name: &str) {
+        let (schema, batches) = read_arrow_stream(version, file_name);
+
+        let result = write_async(&schema, batches.clone());
+
+        // the same bytes as the sync writer
+        let mut expected = vec![];
+        {
+            let options =
+                IpcWriteOptions::try_new(8, false, gen::Schema::MetadataVersion::V5).unwrap();
+            let mut writer =
+                StreamWriter::try_new_with_options(&mut expected, &schema, options).unwrap();
+            for batch in &batches {
+                writer.write(batch).unwrap();
+            }
+            writer.finish().unwrap();
+        }
+        assert_eq!(result, expected);
+
+        let mut reader = std::io::Cursor::new(result);
+        let metadata = read_stream_metadata(&mut reader).unwrap();
+        let reader = StreamReader::new(reader, metadata);
+
+        let schema = reader.schema().clone();
+
+        // read expected JSON output
+        let (expected_schema, expected_batches) = read_gzip_json(version, file_name);
+
+        assert_eq!(schema.as_ref(), &expected_schema);
+
+        let batches = reader.collect::<Result<Vec<_>>>().unwrap();
+
+        assert_eq!(batches, expected_batches);
+    }
+
+    #[test]
+    fn write_100_primitive() {
+        test_file("1.0.0-littleendian", "generated_primitive");
+    }
+
+    #[test]
+    fn write_100_dictionary() {
+        test_file("1.0.0-littleendian", "generated_dictionary");
+    }
+
+    #[test]
+    fn write_100_nested() {
+        test_file("1.0.0-littleendian", "generated_nested");
+    }
+
+    #[test]
+    fn write_100_no_batches() {
+        test_file("1.0.0-littleendian", "generated_primitive_no_batches");
+    }
+
+    #[test]
+    fn round_trip_async() -> Result<()> {
+        use futures::TryStreamExt;
+
+        use crate::io::ipc::read::{read_stream_metadata_async, AsyncStreamReader};
+
+        let (schema, batches) = read_arrow_stream("1.0.0-littleendian", "generated_dictionary");
+        let data = write_async(&schema, batches.clone());
+
+        let result = block_on(async {
+            let mut reader = futures::io::Cursor::new(data);
+            let metadata = read_stream_metadata_async(&mut reader).await?;
+            let reader = AsyncStreamReader::new(reader, metadata);
+            reader.into_stream().try_collect::<Vec<_>>().await
+        })?;
+        assert_eq!(result, batches);
+        Ok(())
+    }
+}
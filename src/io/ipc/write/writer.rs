@@ -169,6 +169,7 @@ mod tests {
 
     use super::*;
 
+    use super::super::common::Compression;
     use crate::error::Result;
     use crate::io::ipc::{
         common::tests::read_gzip_json,
@@ -176,11 +177,19 @@ mod tests {
     };
 
     fn test_round_trip(batch: RecordBatch) -> Result<()> {
+        test_round_trip_with_compression(batch, None)
+    }
+
+    fn test_round_trip_with_compression(
+        batch: RecordBatch,
+        compression: Option<Compression>,
+    ) -> Result<()> {
         let mut result = Vec::<u8>::new();
 
         // write IPC version 5
         {
-            let options = IpcWriteOptions::try_new(8, false, gen::Schema::MetadataVersion::V5)?;
+            let options = IpcWriteOptions::try_new(8, false, gen::Schema::MetadataVersion::V5)?
+                .try_with_compression(compression)?;
             let mut writer =
                 FileWriter::try_new_with_options(&mut result, batch.schema(), options)?;
             writer.write(&batch)?;
@@ -204,13 +213,22 @@ mod tests {
     }
 
     fn test_file(version: &str, file_name: &str) -> Result<()> {
+        test_file_with_compression(version, file_name, None)
+    }
+
+    fn test_file_with_compression(
+        version: &str,
+        file_name: &str,
+        compression: Option<Compression>,
+    ) -> Result<()> {
         let (schema, batches) = read_gzip_json(version, file_name);
 
         let mut result = Vec::<u8>::new();
 
         // write IPC version 5
         {
-            let options = IpcWriteOptions::try_new(8, false, gen::Schema::MetadataVersion::V5)?;
+            let options = IpcWriteOptions::try_new(8, false, gen::Schema::MetadataVersion::V5)?
+                .try_with_compression(compression)?;
             let mut writer = FileWriter::try_new_with_options(&mut result, &schema, options)?;
             for batch in batches {
                 writer.write(&batch)?;
@@ -240,6 +258,42 @@ mod tests {
         test_file("1.0.0-bigendian", "generated_primitive")
     }
 
+    #[test]
+    fn write_100_primitive_lz4() -> Result<()> {
+        test_file_with_compression(
+            "1.0.0-littleendian",
+            "generated_primitive",
+            Some(Compression::Lz4),
+        )
+    }
+
+    #[test]
+    fn write_100_primitive_zstd() -> Result<()> {
+        test_file_with_compression(
+            "1.0.0-littleendian",
+            "generated_primitive",
+            Some(Compression::Zstd),
+        )
+    }
+
+    #[test]
+    fn write_100_dictionary_lz4() -> Result<()> {
+        test_file_with_compression(
+            "1.0.0-littleendian",
+            "generated_dictionary",
+            Some(Compression::Lz4),
+        )
+    }
+
+    #[test]
+    fn write_100_nested_zstd() -> Result<()> {
+        test_file_with_compression(
+            "1.0.0-littleendian",
+            "generated_nested",
+            Some(Compression::Zstd),
+        )
+    }
+
     #[test]
     fn write_100_datetime() -> Result<()> {
         test_file("1.0.0-littleendian", "generated_datetime")?;
@@ -330,6 +384,18 @@ mod tests {
         test_file("1.0.0-bigendian", "generated_decimal")
     }
 
+    #[test]
+    fn write_100_map() -> Result<()> {
+        test_file("1.0.0-littleendian", "generated_map")?;
+        test_file("1.0.0-bigendian", "generated_map")
+    }
+
+    #[test]
+    fn write_100_union() -> Result<()> {
+        test_file("1.0.0-littleendian", "generated_union")?;
+        test_file("1.0.0-bigendian", "generated_union")
+    }
+
     #[test]
     fn write_sliced_utf8() -> Result<()> {
         use crate::array::{Array, Utf8Array};
@@ -340,6 +406,24 @@ mod tests {
         test_round_trip(batch)
     }
 
+    #[test]
+    fn write_boolean_and_dictionary_lz4() -> Result<()> {
+        use crate::array::{
+            Array, BooleanArray, MutableDictionaryArray, MutableUtf8Array, TryExtend,
+        };
+        use std::sync::Arc;
+
+        let boolean = BooleanArray::from(&[Some(true), None, Some(false), Some(true)]);
+        let mut dictionary = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+        dictionary.try_extend(vec![Some("a"), Some("b"), None, Some("a")])?;
+
+        let batch = RecordBatch::try_from_iter(vec![
+            ("a", Arc::new(boolean) as Arc<dyn Array>),
+            ("b", dictionary.into_arc()),
+        ])?;
+        test_round_trip_with_compression(batch, Some(Compression::Lz4))
+    }
+
     #[test]
     fn write_sliced_list() -> Result<()> {
         use crate::array::{MutableListArray, MutablePrimitiveArray, TryExtend};
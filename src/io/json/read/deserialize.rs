@@ -33,6 +33,7 @@ use crate::{
     buffer::MutableBuffer,
     datatypes::{DataType, IntervalUnit},
     types::NativeType,
+    util::parse_decimal,
 };
 
 /// A function that converts a &Value into an optional tuple of a byte slice and a Value.
@@ -90,6 +91,19 @@ fn read_primitive<T: NativeType + NaturalDataType + NumCast>(
     PrimitiveArray::from_trusted_len_iter(iter).to(data_type)
 }
 
+fn read_decimal(rows: &[&Value], data_type: DataType) -> PrimitiveArray<i128> {
+    let (precision, scale) = match data_type {
+        DataType::Decimal(precision, scale) => (precision, scale),
+        _ => unreachable!(),
+    };
+    let iter = rows.iter().map(|row| match row {
+        Value::String(v) => parse_decimal(v.as_bytes(), precision, scale),
+        Value::Number(v) => parse_decimal(v.to_string().as_bytes(), precision, scale),
+        _ => None,
+    });
+    PrimitiveArray::from_trusted_len_iter(iter).to(data_type)
+}
+
 fn read_boolean(rows: &[&Value]) -> BooleanArray {
     let iter = rows.iter().map(|row| match row {
         Value::Bool(v) => Some(v),
@@ -232,6 +246,7 @@ pub fn read(rows: &[&Value], data_type: DataType) -> Arc<dyn Array> {
         DataType::Float16 => unreachable!(),
         DataType::Float32 => Arc::new(read_primitive::<f32>(rows, data_type)),
         DataType::Float64 => Arc::new(read_primitive::<f64>(rows, data_type)),
+        DataType::Decimal(_, _) => Arc::new(read_decimal(rows, data_type)),
         DataType::Utf8 => Arc::new(read_utf8::<i32>(rows)),
         DataType::LargeUtf8 => Arc::new(read_utf8::<i64>(rows)),
         DataType::List(_) => Arc::new(read_list::<i32>(rows, data_type)),
@@ -255,7 +270,7 @@ pub fn read(rows: &[&Value], data_type: DataType) -> Arc<dyn Array> {
         /*
         DataType::FixedSizeBinary(_) => Box::new(FixedSizeBinaryArray::new_empty(data_type)),
         DataType::FixedSizeList(_, _) => Box::new(FixedSizeListArray::new_empty(data_type)),
-        DataType::Union(_) => unimplemented!(),
+        DataType::Union(_, _, _) => unimplemented!(),
         DataType::Decimal(_, _) => Box::new(PrimitiveArray::<i128>::new_empty(data_type)),
         */
     }
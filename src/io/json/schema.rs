@@ -25,7 +25,7 @@ use serde_json::{json, Value};
 
 use crate::error::ArrowError;
 
-use crate::datatypes::{DataType, Field, IntervalUnit, Schema, TimeUnit};
+use crate::datatypes::{DataType, Field, IntervalUnit, Schema, TimeUnit, UnionMode};
 
 pub trait ToJson {
     /// Generate a JSON representation
@@ -56,9 +56,18 @@ impl ToJson for DataType {
                 json!({"name": "fixedsizebinary", "byteWidth": byte_width})
             }
             DataType::Struct(_) => json!({"name": "struct"}),
-            DataType::Union(_) => json!({"name": "union"}),
+            DataType::Union(_, type_ids, mode) => {
+                let mode = if mode.is_sparse() { "SPARSE" } else { "DENSE" };
+                match type_ids {
+                    Some(type_ids) => {
+                        json!({"name": "union", "mode": mode, "typeIds": type_ids})
+                    }
+                    None => json!({"name": "union", "mode": mode}),
+                }
+            }
             DataType::List(_) => json!({ "name": "list"}),
             DataType::LargeList(_) => json!({ "name": "largelist"}),
+            DataType::Map(_, keys_sorted) => json!({"name": "map", "keysSorted": keys_sorted}),
             DataType::FixedSizeList(_, length) => {
                 json!({"name":"fixedsizelist", "listSize": length})
             }
@@ -122,9 +131,11 @@ impl ToJson for Field {
     fn to_json(&self) -> Value {
         let children: Vec<Value> = match self.data_type() {
             DataType::Struct(fields) => fields.iter().map(|f| f.to_json()).collect(),
+            DataType::Union(fields, _, _) => fields.iter().map(|f| f.to_json()).collect(),
             DataType::List(field) => vec![field.to_json()],
             DataType::LargeList(field) => vec![field.to_json()],
             DataType::FixedSizeList(field, _) => vec![field.to_json()],
+            DataType::Map(field, _) => vec![field.to_json()],
             _ => vec![],
         };
         match self.data_type() {
@@ -309,10 +320,49 @@ impl TryFrom<&Value> for DataType {
                         ))
                     }
                 }
+                Some(s) if s == "map" => {
+                    let keys_sorted = match map.get("keysSorted") {
+                        Some(Value::Bool(keys_sorted)) => *keys_sorted,
+                        _ => {
+                            return Err(ArrowError::Schema(
+                                "Expecting a keysSorted for map".to_string(),
+                            ))
+                        }
+                    };
+                    // return a map with any type as its child isn't defined in the map
+                    Ok(DataType::Map(Box::new(default_field), keys_sorted))
+                }
                 Some(s) if s == "struct" => {
                     // return an empty `struct` type as its children aren't defined in the map
                     Ok(DataType::Struct(vec![]))
                 }
+                Some(s) if s == "union" => {
+                    let mode = match map.get("mode") {
+                        Some(Value::String(mode)) if mode == "SPARSE" => UnionMode::Sparse,
+                        Some(Value::String(mode)) if mode == "DENSE" => UnionMode::Dense,
+                        _ => {
+                            return Err(ArrowError::Schema(
+                                "union requires a mode of SPARSE or DENSE".to_string(),
+                            ))
+                        }
+                    };
+                    let type_ids = match map.get("typeIds") {
+                        Some(Value::Array(ids)) => Some(
+                            ids.iter()
+                                .map(|id| {
+                                    id.as_i64().map(|x| x as i32).ok_or_else(|| {
+                                        ArrowError::Schema(
+                                            "union typeIds must be integers".to_string(),
+                                        )
+                                    })
+                                })
+                                .collect::<Result<Vec<_>, ArrowError>>()?,
+                        ),
+                        _ => None,
+                    };
+                    // return an empty `union` type as its children aren't defined in the map
+                    Ok(DataType::Union(vec![], type_ids, mode))
+                }
                 Some(other) => Err(ArrowError::Schema(format!(
                     "invalid or unsupported type name: {} in {:?}",
                     other, value
@@ -417,42 +467,48 @@ impl TryFrom<&Value> for Field {
 
                 // if data_type is a struct or list, get its children
                 let data_type = match data_type {
-                    DataType::List(_) | DataType::LargeList(_) | DataType::FixedSizeList(_, _) => {
-                        match map.get("children") {
-                            Some(Value::Array(values)) => {
-                                if values.len() != 1 {
-                                    return Err(ArrowError::Schema(
-                                    "Field 'children' must have one element for a list data type".to_string(),
+                    DataType::List(_)
+                    | DataType::LargeList(_)
+                    | DataType::FixedSizeList(_, _)
+                    | DataType::Map(_, _) => match map.get("children") {
+                        Some(Value::Array(values)) => {
+                            if values.len() != 1 {
+                                return Err(ArrowError::Schema(
+                                    "Field 'children' must have one element for a list data type"
+                                        .to_string(),
                                 ));
+                            }
+                            match data_type {
+                                DataType::List(_) => {
+                                    DataType::List(Box::new(Self::try_from(&values[0])?))
                                 }
-                                match data_type {
-                                    DataType::List(_) => {
-                                        DataType::List(Box::new(Self::try_from(&values[0])?))
-                                    }
-                                    DataType::LargeList(_) => {
-                                        DataType::LargeList(Box::new(Self::try_from(&values[0])?))
-                                    }
-                                    DataType::FixedSizeList(_, int) => DataType::FixedSizeList(
-                                        Box::new(Self::try_from(&values[0])?),
-                                        int,
-                                    ),
-                                    _ => unreachable!(
-                                        "Data type should be a list, largelist or fixedsizelist"
-                                    ),
+                                DataType::LargeList(_) => {
+                                    DataType::LargeList(Box::new(Self::try_from(&values[0])?))
                                 }
-                            }
-                            Some(_) => {
-                                return Err(ArrowError::Schema(
-                                    "Field 'children' must be an array".to_string(),
-                                ))
-                            }
-                            None => {
-                                return Err(ArrowError::Schema(
-                                    "Field missing 'children' attribute".to_string(),
-                                ));
+                                DataType::FixedSizeList(_, int) => DataType::FixedSizeList(
+                                    Box::new(Self::try_from(&values[0])?),
+                                    int,
+                                ),
+                                DataType::Map(_, keys_sorted) => DataType::Map(
+                                    Box::new(Self::try_from(&values[0])?),
+                                    keys_sorted,
+                                ),
+                                _ => unreachable!(
+                                    "Data type should be a list, largelist, fixedsizelist or map"
+                                ),
                             }
                         }
-                    }
+                        Some(_) => {
+                            return Err(ArrowError::Schema(
+                                "Field 'children' must be an array".to_string(),
+                            ))
+                        }
+                        None => {
+                            return Err(ArrowError::Schema(
+                                "Field missing 'children' attribute".to_string(),
+                            ));
+                        }
+                    },
                     DataType::Struct(mut fields) => match map.get("children") {
                         Some(Value::Array(values)) => {
                             let struct_fields: Result<Vec<Field>, _> =
@@ -471,6 +527,24 @@ impl TryFrom<&Value> for Field {
                             ));
                         }
                     },
+                    DataType::Union(mut fields, type_ids, mode) => match map.get("children") {
+                        Some(Value::Array(values)) => {
+                            let union_fields: Result<Vec<Field>, _> =
+                                values.iter().map(Field::try_from).collect();
+                            fields.append(&mut union_fields?);
+                            DataType::Union(fields, type_ids, mode)
+                        }
+                        Some(_) => {
+                            return Err(ArrowError::Schema(
+                                "Field 'children' must be an array".to_string(),
+                            ))
+                        }
+                        None => {
+                            return Err(ArrowError::Schema(
+                                "Field missing 'children' attribute".to_string(),
+                            ));
+                        }
+                    },
                     _ => data_type,
                 };
 
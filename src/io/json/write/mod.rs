@@ -261,4 +261,29 @@ mod tests {
 "#
         );
     }
+
+    #[test]
+    fn decimal_round_trip() {
+        let data_type = DataType::Decimal(5, 2);
+        let schema = Arc::new(Schema::new(vec![Field::new("c1", data_type.clone(), true)]));
+        let a = Int128Array::from(&[Some(12345), None, Some(-5)]).to(data_type);
+        let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(a)]).unwrap();
+
+        let mut buf = Vec::new();
+        {
+            let mut writer = LineDelimitedWriter::new(&mut buf);
+            writer.write_batches(std::slice::from_ref(&batch)).unwrap();
+        }
+
+        assert_eq!(
+            String::from_utf8(buf.clone()).unwrap(),
+            r#"{"c1":"123.45"}
+{"c1":null}
+{"c1":"-0.05"}
+"#
+        );
+
+        let mut reader = crate::io::json::Reader::new(buf.as_slice(), schema, 1024, None);
+        assert_eq!(reader.next().unwrap().unwrap(), batch);
+    }
 }
@@ -18,7 +18,9 @@
 use serde_json::map::Map;
 use serde_json::{Number, Value};
 
-use crate::{array::*, datatypes::*, record_batch::RecordBatch, types::NativeType};
+use crate::{
+    array::*, datatypes::*, record_batch::RecordBatch, types::NativeType, util::decimal_to_string,
+};
 
 trait JsonSerializable {
     fn into_json_value(self) -> Option<Value>;
@@ -102,6 +104,25 @@ where
     array.iter().map(to_json).collect()
 }
 
+/// Decimals are written as strings so that no precision is lost, e.g. `"-1.05"`.
+fn decimal_array_to_json(array: &dyn Array) -> Vec<Value> {
+    let scale = match array.data_type() {
+        DataType::Decimal(_, scale) => *scale,
+        _ => unreachable!(),
+    };
+    let array = array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i128>>()
+        .unwrap();
+    array
+        .iter()
+        .map(|x| {
+            x.map(|x| Value::String(decimal_to_string(*x, scale)))
+                .unwrap_or(Value::Null)
+        })
+        .collect()
+}
+
 fn struct_array_to_jsonmap_array(array: &StructArray, row_count: usize) -> Vec<Map<String, Value>> {
     // {"a": [1, 2, 3], "b": [a, b, c], "c": {"a": [1, 2, 3]}}
     // [
@@ -177,6 +198,7 @@ fn write_array(array: &dyn Array) -> Value {
         DataType::UInt64 => primitive_array_to_json::<u64>(array),
         DataType::Float32 => primitive_array_to_json::<f32>(array),
         DataType::Float64 => primitive_array_to_json::<f64>(array),
+        DataType::Decimal(_, _) => decimal_array_to_json(array),
         DataType::List(_) => array
             .as_any()
             .downcast_ref::<ListArray<i32>>()
@@ -262,6 +284,14 @@ fn set_column_for_json_rows(
         DataType::UInt64 => set_column_by_primitive_type::<u64>(rows, row_count, array, col_name),
         DataType::Float32 => set_column_by_primitive_type::<f32>(rows, row_count, array, col_name),
         DataType::Float64 => set_column_by_primitive_type::<f64>(rows, row_count, array, col_name),
+        DataType::Decimal(_, _) => {
+            rows.iter_mut()
+                .zip(decimal_array_to_json(array))
+                .take(row_count)
+                .for_each(|(row, value)| {
+                    row.insert(col_name.to_string(), value);
+                });
+        }
         DataType::Utf8 => {
             let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
             rows.iter_mut()
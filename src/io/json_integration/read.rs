@@ -27,7 +27,7 @@ use crate::{
     datatypes::{DataType, Field, IntervalUnit, Schema},
     error::{ArrowError, Result},
     record_batch::RecordBatch,
-    types::{days_ms, NativeType},
+    types::{days_ms, f16, NativeType},
 };
 
 use super::{ArrowJsonBatch, ArrowJsonColumn, ArrowJsonDictionaryBatch};
@@ -105,6 +105,18 @@ fn to_decimal(json_col: &ArrowJsonColumn, data_type: DataType) -> PrimitiveArray
     PrimitiveArray::<i128>::from_data(data_type, values, validity)
 }
 
+fn to_float16(json_col: &ArrowJsonColumn, data_type: DataType) -> PrimitiveArray<f16> {
+    let validity = to_validity(&json_col.validity);
+    let values = json_col
+        .data
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|value| f16::from_f64(value.as_f64().unwrap()))
+        .collect();
+    PrimitiveArray::<f16>::from_data(data_type, values, validity)
+}
+
 fn to_primitive<T: NativeType + NumCast>(
     json_col: &ArrowJsonColumn,
     data_type: DataType,
@@ -259,6 +271,7 @@ pub fn to_array(
         DataType::UInt16 => Ok(Arc::new(to_primitive::<u16>(json_col, data_type.clone()))),
         DataType::UInt32 => Ok(Arc::new(to_primitive::<u32>(json_col, data_type.clone()))),
         DataType::UInt64 => Ok(Arc::new(to_primitive::<u64>(json_col, data_type.clone()))),
+        DataType::Float16 => Ok(Arc::new(to_float16(json_col, data_type.clone()))),
         DataType::Float32 => Ok(Arc::new(to_primitive::<f32>(json_col, data_type.clone()))),
         DataType::Float64 => Ok(Arc::new(to_primitive::<f64>(json_col, data_type.clone()))),
         DataType::Binary => Ok(to_binary::<i32>(json_col)),
@@ -298,6 +311,16 @@ pub fn to_array(
                 validity,
             )))
         }
+        DataType::Map(child_field, _) => {
+            let validity = to_validity(&json_col.validity);
+
+            let children = &json_col.children.as_ref().unwrap()[0];
+            let field = to_array(child_field, children, dictionaries)?;
+            let offsets = to_offsets::<i32>(json_col.offset.as_ref());
+
+            let array = MapArray::from_data(data_type.clone(), offsets, field, validity);
+            Ok(Arc::new(array))
+        }
         DataType::Struct(fields) => {
             let validity = to_validity(&json_col.validity);
 
@@ -321,10 +344,32 @@ pub fn to_array(
             DataType::UInt64 => to_dictionary::<u64>(field, json_col, dictionaries),
             _ => unreachable!(),
         },
-        DataType::Float16 => unreachable!(),
-        DataType::Union(_) => Err(ArrowError::NotYetImplemented(
-            "Union not supported".to_string(),
-        )),
+        DataType::Union(fields, _, mode) => {
+            let types = json_col
+                .type_id
+                .as_ref()
+                .map(|x| {
+                    x.iter()
+                        .map(|value| value.as_i64().unwrap() as i8)
+                        .collect::<Buffer<i8>>()
+                })
+                .unwrap_or_default();
+
+            let fields = fields
+                .iter()
+                .zip(json_col.children.as_ref().unwrap())
+                .map(|(field, col)| to_array(field, col, dictionaries))
+                .collect::<Result<Vec<_>>>()?;
+
+            let offsets = if mode.is_sparse() {
+                None
+            } else {
+                Some(to_offsets::<i32>(json_col.offset.as_ref()))
+            };
+
+            let array = UnionArray::try_new(data_type.clone(), types, fields, offsets)?;
+            Ok(Arc::new(array))
+        }
     }
 }
 
@@ -49,6 +49,9 @@ mod tests {
     }
 
     pub fn pyarrow_nested_nullable(column: usize) -> Box<dyn Array> {
+        if column == 7 {
+            return pyarrow_nested_nested();
+        }
         let offsets = Buffer::<i32>::from([0, 2, 2, 5, 8, 8, 11, 11, 12]);
 
         let values = match column {
@@ -189,6 +192,164 @@ mod tests {
         }
     }
 
+    // [[[0, 1]], None, [[2, None], [3]], [[4, 5], [6]], [], [[7], None, [9]], None, [[10]]]
+    fn pyarrow_nested_nested() -> Box<dyn Array> {
+        let values = Arc::new(PrimitiveArray::<i64>::from(&[
+            Some(0),
+            Some(1),
+            Some(2),
+            None,
+            Some(3),
+            Some(4),
+            Some(5),
+            Some(6),
+            Some(7),
+            Some(9),
+            Some(10),
+        ]));
+        let inner_data_type = DataType::List(Box::new(Field::new("item", DataType::Int64, true)));
+        let inner = Arc::new(ListArray::<i32>::from_data(
+            inner_data_type.clone(),
+            Buffer::from([0, 2, 4, 5, 7, 8, 9, 9, 10, 11]),
+            values,
+            Some(Bitmap::from([
+                true, true, true, true, true, true, false, true, true,
+            ])),
+        ));
+        Box::new(ListArray::<i32>::from_data(
+            DataType::List(Box::new(Field::new("item", inner_data_type, true))),
+            Buffer::from([0, 1, 1, 3, 5, 5, 8, 8, 9]),
+            inner,
+            Some(Bitmap::from([
+                true, false, true, true, true, true, false, true,
+            ])),
+        ))
+    }
+
+    pub fn pyarrow_struct(column: usize) -> Box<dyn Array> {
+        let fields = vec![
+            Field::new("f1", DataType::Utf8, true),
+            Field::new("f2", DataType::Boolean, true),
+        ];
+        match column {
+            0 => {
+                let string = Arc::new(Utf8Array::<i32>::from(&[
+                    Some("Hello"),
+                    None,
+                    Some("aa"),
+                    Some(""),
+                    None,
+                    Some("abc"),
+                    None,
+                    None,
+                    Some("def"),
+                    Some("aaa"),
+                ]));
+                let boolean = Arc::new(BooleanArray::from(&[
+                    Some(true),
+                    None,
+                    Some(false),
+                    Some(false),
+                    None,
+                    Some(true),
+                    None,
+                    None,
+                    Some(true),
+                    Some(true),
+                ]));
+                Box::new(StructArray::from_data(
+                    fields,
+                    vec![string, boolean],
+                    Some(Bitmap::from([
+                        true, false, true, true, true, true, false, true, true, true,
+                    ])),
+                ))
+            }
+            1 => {
+                let string = Arc::new(Utf8Array::<i32>::from(&[
+                    Some("Hello"),
+                    Some("aa"),
+                    None,
+                    None,
+                    Some("abc"),
+                    Some("def"),
+                    Some("aaa"),
+                    None,
+                ]));
+                let boolean = Arc::new(BooleanArray::from(&[
+                    Some(true),
+                    Some(false),
+                    None,
+                    None,
+                    Some(true),
+                    Some(false),
+                    Some(true),
+                    None,
+                ]));
+                let values = Arc::new(StructArray::from_data(
+                    fields.clone(),
+                    vec![string, boolean],
+                    Some(Bitmap::from([
+                        true, true, false, true, true, true, true, false,
+                    ])),
+                ));
+                Box::new(ListArray::<i32>::from_data(
+                    DataType::List(Box::new(Field::new("item", DataType::Struct(fields), true))),
+                    Buffer::from([0, 1, 1, 1, 3, 4, 6, 6, 6, 7, 8]),
+                    values,
+                    Some(Bitmap::from([
+                        true, false, true, true, true, true, false, true, true, true,
+                    ])),
+                ))
+            }
+            2 => {
+                let list_data_type =
+                    DataType::List(Box::new(Field::new("item", DataType::Int64, true)));
+                let list = Arc::new(ListArray::<i32>::from_data(
+                    list_data_type.clone(),
+                    Buffer::from([0, 2, 2, 2, 2, 4, 5, 5, 8, 8, 9]),
+                    Arc::new(PrimitiveArray::<i64>::from(&[
+                        Some(0),
+                        Some(1),
+                        Some(2),
+                        None,
+                        Some(3),
+                        Some(4),
+                        Some(5),
+                        Some(6),
+                        Some(7),
+                    ])),
+                    Some(Bitmap::from([
+                        true, false, false, true, true, true, false, true, false, true,
+                    ])),
+                ));
+                let boolean = Arc::new(BooleanArray::from(&[
+                    Some(true),
+                    None,
+                    Some(false),
+                    None,
+                    Some(true),
+                    Some(false),
+                    None,
+                    Some(true),
+                    None,
+                    Some(true),
+                ]));
+                Box::new(StructArray::from_data(
+                    vec![
+                        Field::new("f1", list_data_type, true),
+                        Field::new("f2", DataType::Boolean, true),
+                    ],
+                    vec![list, boolean],
+                    Some(Bitmap::from([
+                        true, false, true, true, true, true, false, true, true, true,
+                    ])),
+                ))
+            }
+            _ => unreachable!(),
+        }
+    }
+
     pub fn pyarrow_nullable(column: usize) -> Box<dyn Array> {
         let i64_values = &[
             Some(0),
@@ -1,152 +1,46 @@
 use parquet2::{
-    encoding::{bitpacking, hybrid_rle, uleb128, Encoding},
+    encoding::Encoding,
     metadata::{ColumnChunkMetaData, ColumnDescriptor},
-    read::{levels, BinaryPageDict, Page, PageHeader, StreamingIterator},
+    read::{BinaryPageDict, Page, StreamingIterator},
 };
 
 use crate::{
     array::{Array, BinaryArray, Offset, Utf8Array},
-    bitmap::{utils::BitmapIter, MutableBitmap},
+    bitmap::MutableBitmap,
     buffer::MutableBuffer,
     datatypes::DataType,
     error::{ArrowError, Result},
 };
 
+use super::super::encodings;
 use super::super::utils;
 
-/// Assumptions: No rep levels
-#[allow(clippy::too_many_arguments)]
-fn read_dict_buffer<O: Offset>(
+/// Extends `offsets`, `values` and `validity` with a page of `length` slots whose non-null
+/// values are `new_values`.
+fn extend<'a, O: Offset, I: Iterator<Item = &'a [u8]>>(
+    is_optional: bool,
     validity_buffer: &[u8],
-    indices_buffer: &[u8],
-    length: u32,
-    dict: &BinaryPageDict,
-    offsets: &mut MutableBuffer<O>,
-    values: &mut MutableBuffer<u8>,
-    validity: &mut MutableBitmap,
-) {
-    let length = length as usize;
-    let dict_values = dict.values();
-    let dict_offsets = dict.offsets();
-    let mut last_offset = *offsets.as_mut_slice().last().unwrap();
-
-    // SPEC: Data page format: the bit width used to encode the entry ids stored as 1 byte (max bit width = 32),
-    // SPEC: followed by the values encoded using RLE/Bit packed described above (with the given bit width).
-    let bit_width = indices_buffer[0];
-    let indices_buffer = &indices_buffer[1..];
-
-    let (_, consumed) = uleb128::decode(&indices_buffer);
-    let indices_buffer = &indices_buffer[consumed..];
-
-    let non_null_indices_len = indices_buffer.len() * 8 / bit_width as usize;
-
-    let mut indices = bitpacking::Decoder::new(indices_buffer, bit_width, non_null_indices_len);
-
-    let validity_iterator = hybrid_rle::Decoder::new(&validity_buffer, 1);
-
-    for run in validity_iterator {
-        match run {
-            hybrid_rle::HybridEncoded::Bitpacked(packed) => {
-                let remaining = length - (offsets.len() - 1);
-                let len = std::cmp::min(packed.len() * 8, remaining);
-                for is_valid in BitmapIter::new(packed, 0, len) {
-                    if is_valid {
-                        let index = indices.next().unwrap() as usize;
-                        let dict_offset_i = dict_offsets[index] as usize;
-                        let dict_offset_ip1 = dict_offsets[index + 1] as usize;
-                        let length = dict_offset_ip1 - dict_offset_i;
-                        last_offset += O::from_usize(length).unwrap();
-                        values.extend_from_slice(&dict_values[dict_offset_i..dict_offset_ip1]);
-                    };
-                    offsets.push(last_offset);
-                }
-                validity.extend_from_slice(packed, 0, len);
-            }
-            hybrid_rle::HybridEncoded::Rle(value, additional) => {
-                let is_set = value[0] == 1;
-                validity.extend_constant(additional, is_set);
-                if is_set {
-                    (0..additional).for_each(|_| {
-                        let index = indices.next().unwrap() as usize;
-                        let dict_offset_i = dict_offsets[index] as usize;
-                        let dict_offset_ip1 = dict_offsets[index + 1] as usize;
-                        let length = dict_offset_ip1 - dict_offset_i;
-                        last_offset += O::from_usize(length).unwrap();
-                        offsets.push(last_offset);
-                        values.extend_from_slice(&dict_values[dict_offset_i..dict_offset_ip1]);
-                    })
-                } else {
-                    offsets.extend_constant(additional, last_offset)
-                }
-            }
-        }
-    }
-}
-
-fn read_optional<O: Offset>(
-    validity_buffer: &[u8],
-    values_buffer: &[u8],
     length: usize,
+    new_values: I,
     offsets: &mut MutableBuffer<O>,
     values: &mut MutableBuffer<u8>,
     validity: &mut MutableBitmap,
-) {
+) -> Result<()> {
     let mut last_offset = *offsets.as_mut_slice().last().unwrap();
-
-    // values_buffer: first 4 bytes are len, remaining is values
-    let mut values_iterator = utils::BinaryIter::new(values_buffer);
-
-    let validity_iterator = hybrid_rle::Decoder::new(&validity_buffer, 1);
-
-    for run in validity_iterator {
-        match run {
-            hybrid_rle::HybridEncoded::Bitpacked(packed) => {
-                // the pack may contain more items than needed.
-                let remaining = length - (offsets.len() - 1);
-                let len = std::cmp::min(packed.len() * 8, remaining);
-                for is_valid in BitmapIter::new(packed, 0, len) {
-                    if is_valid {
-                        let value = values_iterator.next().unwrap();
-                        last_offset += O::from_usize(value.len()).unwrap();
-                        values.extend_from_slice(value);
-                    }
-                    offsets.push(last_offset);
-                }
-                validity.extend_from_slice(packed, 0, len);
-            }
-            hybrid_rle::HybridEncoded::Rle(value, additional) => {
-                let is_set = value[0] == 1;
-                validity.extend_constant(additional, is_set);
-                if is_set {
-                    (0..additional).for_each(|_| {
-                        let value = values_iterator.next().unwrap();
-                        last_offset += O::from_usize(value.len()).unwrap();
-                        offsets.push(last_offset);
-                        values.extend_from_slice(value)
-                    })
-                } else {
-                    offsets.extend_constant(additional, last_offset)
-                }
+    encodings::extend_page(
+        is_optional,
+        validity_buffer,
+        length,
+        validity,
+        new_values,
+        |value| {
+            if let Some(value) = value {
+                last_offset += O::from_usize(value.len()).unwrap();
+                values.extend_from_slice(value);
             }
-        }
-    }
-}
-
-pub(super) fn read_required<O: Offset>(
-    buffer: &[u8],
-    _length: usize,
-    offsets: &mut MutableBuffer<O>,
-    values: &mut MutableBuffer<u8>,
-) {
-    let mut last_offset = *offsets.as_mut_slice().last().unwrap();
-
-    let values_iterator = utils::BinaryIter::new(buffer);
-
-    for value in values_iterator {
-        last_offset += O::from_usize(value.len()).unwrap();
-        values.extend_from_slice(value);
-        offsets.push(last_offset);
-    }
+            offsets.push(last_offset);
+        },
+    )
 }
 
 fn extend_from_page<O: Offset>(
@@ -156,93 +50,75 @@ fn extend_from_page<O: Offset>(
     values: &mut MutableBuffer<u8>,
     validity: &mut MutableBitmap,
 ) -> Result<()> {
-    assert_eq!(descriptor.max_rep_level(), 0);
-    assert!(descriptor.max_def_level() <= 1);
+    let (version, validity_buffer, values_buffer) = utils::split_buffer(page, descriptor)?;
     let is_optional = descriptor.max_def_level() == 1;
-    match page.header() {
-        PageHeader::V1(header) => {
-            assert_eq!(header.definition_level_encoding, Encoding::Rle);
-
-            let (_, validity_buffer, values_buffer) =
-                levels::split_buffer_v1(page.buffer(), false, is_optional);
-
-            match (&page.encoding(), page.dictionary_page(), is_optional) {
-                (Encoding::PlainDictionary, Some(dict), true) => read_dict_buffer::<O>(
-                    validity_buffer,
-                    values_buffer,
-                    page.num_values() as u32,
-                    dict.as_any().downcast_ref().unwrap(),
-                    offsets,
-                    values,
-                    validity,
-                ),
-                (Encoding::Plain, None, true) => read_optional::<O>(
-                    validity_buffer,
-                    values_buffer,
-                    page.num_values(),
-                    offsets,
-                    values,
-                    validity,
-                ),
-                (Encoding::Plain, None, false) => {
-                    read_required::<O>(page.buffer(), page.num_values(), offsets, values)
-                }
-                _ => {
-                    return Err(utils::not_implemented(
-                        &page.encoding(),
-                        is_optional,
-                        page.dictionary_page().is_some(),
-                        "V1",
-                        "Binary",
-                    ))
-                }
-            }
+    let length = page.num_values();
+
+    match (page.encoding(), page.dictionary_page()) {
+        (Encoding::PlainDictionary, Some(dict)) | (Encoding::RleDictionary, Some(dict)) => {
+            let dict = dict
+                .as_any()
+                .downcast_ref::<BinaryPageDict>()
+                .ok_or_else(|| utils::invalid_dictionary("binary"))?;
+            let dict_values = dict.values();
+            let dict_offsets = dict.offsets();
+            let indices = encodings::dict_indices(values_buffer, length)?;
+            encodings::check_indices(&indices, dict_offsets.len().saturating_sub(1))?;
+            let new_values = indices.into_iter().map(|index| {
+                let index = index as usize;
+                &dict_values[dict_offsets[index] as usize..dict_offsets[index + 1] as usize]
+            });
+            extend(
+                is_optional,
+                validity_buffer,
+                length,
+                new_values,
+                offsets,
+                values,
+                validity,
+            )
         }
-        PageHeader::V2(header) => {
-            let def_level_buffer_length = header.definition_levels_byte_length as usize;
-
-            match (&page.encoding(), page.dictionary_page(), is_optional) {
-                (Encoding::PlainDictionary, Some(dict), true) => {
-                    let (_, validity_buffer, values_buffer) =
-                        levels::split_buffer_v2(page.buffer(), 0, def_level_buffer_length);
-                    read_dict_buffer::<O>(
-                        validity_buffer,
-                        values_buffer,
-                        page.num_values() as u32,
-                        dict.as_any().downcast_ref().unwrap(),
-                        offsets,
-                        values,
-                        validity,
-                    )
-                }
-                (Encoding::Plain, None, true) => {
-                    let (_, validity_buffer, values_buffer) =
-                        levels::split_buffer_v2(page.buffer(), 0, def_level_buffer_length);
-                    read_optional::<O>(
-                        validity_buffer,
-                        values_buffer,
-                        page.num_values(),
-                        offsets,
-                        values,
-                        validity,
-                    )
-                }
-                (Encoding::Plain, None, false) => {
-                    read_required::<O>(page.buffer(), page.num_values(), offsets, values)
-                }
-                _ => {
-                    return Err(utils::not_implemented(
-                        &page.encoding(),
-                        is_optional,
-                        page.dictionary_page().is_some(),
-                        "V2",
-                        "Binary",
-                    ))
-                }
-            }
+        (Encoding::Plain, None) => extend(
+            is_optional,
+            validity_buffer,
+            length,
+            utils::BinaryIter::new(values_buffer),
+            offsets,
+            values,
+            validity,
+        ),
+        (Encoding::DeltaLengthByteArray, None) => {
+            let new_values = encodings::delta_length_byte_array(values_buffer)?;
+            extend(
+                is_optional,
+                validity_buffer,
+                length,
+                new_values.into_iter(),
+                offsets,
+                values,
+                validity,
+            )
+        }
+        (Encoding::DeltaByteArray, None) => {
+            let new_values = encodings::delta_byte_array(values_buffer)?;
+            extend(
+                is_optional,
+                validity_buffer,
+                length,
+                new_values.iter().map(|x| x.as_slice()),
+                offsets,
+                values,
+                validity,
+            )
         }
-    };
-    Ok(())
+        _ => Err(utils::not_implemented(
+            &page.encoding(),
+            is_optional,
+            page.dictionary_page().is_some(),
+            version,
+            "Binary",
+        )),
+    }
 }
 
 pub fn iter_to_array<O, I, E>(
@@ -1,17 +1,14 @@
-use std::sync::Arc;
-
 use parquet2::{
     encoding::Encoding,
     metadata::{ColumnChunkMetaData, ColumnDescriptor},
     read::{
-        levels::{get_bit_width, split_buffer_v1, split_buffer_v2, RLEDecoder},
+        levels::{split_buffer_v1, split_buffer_v2},
         Page, PageHeader, StreamingIterator,
     },
 };
 
 use super::super::nested_utils::*;
-use super::super::utils;
-use super::basic::read_required;
+use super::super::{is_type_nullable, utils};
 use crate::{
     array::{Array, BinaryArray, Offset, Utf8Array},
     bitmap::MutableBitmap,
@@ -23,6 +20,7 @@ use crate::{
 fn read_values<'a, O, D, G>(
     def_levels: D,
     max_def: u32,
+    min_def: u32,
     mut new_values: G,
     offsets: &mut MutableBuffer<O>,
     values: &mut MutableBuffer<u8>,
@@ -38,7 +36,7 @@ fn read_values<'a, O, D, G>(
             values.extend_from_slice(v);
             offsets.push(*offsets.last().unwrap() + O::from_usize(v.len()).unwrap());
             validity.push(true);
-        } else if def == max_def - 1 {
+        } else if def >= min_def {
             offsets.push(*offsets.last().unwrap());
             validity.push(false);
         }
@@ -53,64 +51,43 @@ fn read<O: Offset>(
     additional: usize,
     rep_level_encoding: (&Encoding, i16),
     def_level_encoding: (&Encoding, i16),
-    is_nullable: bool,
     nested: &mut Vec<Box<dyn Nested>>,
     offsets: &mut MutableBuffer<O>,
     values: &mut MutableBuffer<u8>,
     validity: &mut MutableBitmap,
-) {
-    let max_rep_level = rep_level_encoding.1 as u32;
+) -> Result<()> {
     let max_def_level = def_level_encoding.1 as u32;
 
     match (rep_level_encoding.0, def_level_encoding.0) {
         (Encoding::Rle, Encoding::Rle) => {
-            let rep_levels = RLEDecoder::new(
-                rep_levels,
-                get_bit_width(rep_level_encoding.1),
-                additional as u32,
+            let def_levels_iter = levels(def_levels, def_level_encoding.1, additional);
+            let new_values = utils::BinaryIter::new(values_buffer);
+            read_values(
+                def_levels_iter,
+                max_def_level,
+                min_def_level(nested),
+                new_values,
+                offsets,
+                values,
+                validity,
             );
-            if is_nullable {
-                let def_levels = RLEDecoder::new(
-                    def_levels,
-                    get_bit_width(def_level_encoding.1),
-                    additional as u32,
-                );
-                let new_values = utils::BinaryIter::new(values_buffer);
-                read_values(
-                    def_levels,
-                    max_def_level,
-                    new_values,
-                    offsets,
-                    values,
-                    validity,
-                )
-            } else {
-                read_required(values_buffer, additional, offsets, values)
-            }
 
-            let def_levels = RLEDecoder::new(
-                def_levels,
-                get_bit_width(def_level_encoding.1),
-                additional as u32,
-            );
+            let rep_levels = levels(rep_levels, rep_level_encoding.1, additional);
+            let def_levels = levels(def_levels, def_level_encoding.1, additional);
 
-            extend_offsets(
-                rep_levels,
-                def_levels,
-                is_nullable,
-                max_rep_level,
-                max_def_level,
-                nested,
-            )
+            extend_offsets(rep_levels, def_levels, nested);
+            Ok(())
         }
-        _ => todo!(),
+        (rep, def) => Err(ArrowError::NotYetImplemented(format!(
+            "Decoding nested parquet pages with repetition levels encoded as {:?} and definition levels encoded as {:?}",
+            rep, def
+        ))),
     }
 }
 
 fn extend_from_page<O: Offset>(
     page: &Page,
     descriptor: &ColumnDescriptor,
-    is_nullable: bool,
     nested: &mut Vec<Box<dyn Nested>>,
     offsets: &mut MutableBuffer<O>,
     values: &mut MutableBuffer<u8>,
@@ -143,17 +120,16 @@ fn extend_from_page<O: Offset>(
                             &header.definition_level_encoding,
                             descriptor.max_def_level(),
                         ),
-                        is_nullable,
                         nested,
                         offsets,
                         values,
                         validity,
-                    )
+                    )?
                 }
                 _ => {
                     return Err(utils::not_implemented(
                         &page.encoding(),
-                        is_nullable,
+                        is_type_nullable(descriptor.type_()),
                         page.dictionary_page().is_some(),
                         "V1",
                         "primitive",
@@ -177,17 +153,16 @@ fn extend_from_page<O: Offset>(
                     additional,
                     (&Encoding::Rle, descriptor.max_rep_level()),
                     (&Encoding::Rle, descriptor.max_def_level()),
-                    is_nullable,
                     nested,
                     offsets,
                     values,
                     validity,
-                )
+                )?
             }
             _ => {
                 return Err(utils::not_implemented(
                     &page.encoding(),
-                    is_nullable,
+                    is_type_nullable(descriptor.type_()),
                     page.dictionary_page().is_some(),
                     "V2",
                     "primitive",
@@ -198,10 +173,13 @@ fn extend_from_page<O: Offset>(
     Ok(())
 }
 
+/// Reads the leaf column `metadata` of a nested field into a [`BinaryArray`] or [`Utf8Array`]
+/// of `data_type`, extending `nested` with its levels.
 pub fn iter_to_array<O, I, E>(
     mut iter: I,
     metadata: &ColumnChunkMetaData,
-    data_type: DataType,
+    data_type: &DataType,
+    nested: &mut Vec<Box<dyn Nested>>,
 ) -> Result<Box<dyn Array>>
 where
     O: Offset,
@@ -215,44 +193,28 @@ where
     offsets.push(O::default());
     let mut validity = MutableBitmap::with_capacity(capacity);
 
-    let (mut nested, is_nullable) = init_nested(metadata.descriptor().base_type(), capacity);
-
     while let Some(page) = iter.next() {
         extend_from_page(
             page.as_ref().map_err(|x| x.clone())?,
             metadata.descriptor(),
-            is_nullable,
-            &mut nested,
+            nested,
             &mut offsets,
             &mut values,
             &mut validity,
         )?
     }
 
-    let inner_data_type = match data_type {
-        DataType::List(ref inner) => inner.data_type(),
-        DataType::LargeList(ref inner) => inner.data_type(),
-        _ => {
-            return Err(ArrowError::NotYetImplemented(format!(
-                "Read nested datatype {:?}",
-                data_type
-            )))
-        }
-    };
-
-    let values = match inner_data_type {
-        DataType::LargeBinary | DataType::Binary => Arc::new(BinaryArray::from_data(
+    Ok(match data_type {
+        DataType::LargeBinary | DataType::Binary => Box::new(BinaryArray::from_data(
             offsets.into(),
             values.into(),
             validity.into(),
-        )) as Arc<dyn Array>,
-        DataType::LargeUtf8 | DataType::Utf8 => Arc::new(Utf8Array::from_data(
+        )),
+        DataType::LargeUtf8 | DataType::Utf8 => Box::new(Utf8Array::from_data(
             offsets.into(),
             values.into(),
             validity.into(),
-        )) as Arc<dyn Array>,
+        )),
         _ => unreachable!(),
-    };
-
-    create_list(data_type, &mut nested, values)
+    })
 }
@@ -4,64 +4,49 @@ use crate::{
     error::{ArrowError, Result},
 };
 
+use super::super::encodings;
 use super::super::utils;
 use parquet2::{
-    encoding::{hybrid_rle, Encoding},
+    encoding::Encoding,
     metadata::{ColumnChunkMetaData, ColumnDescriptor},
-    read::{levels, Page, PageHeader, StreamingIterator},
+    read::{Page, StreamingIterator},
 };
 
-pub(super) fn read_required(buffer: &[u8], additional: usize, values: &mut MutableBitmap) {
+pub(super) fn read_required(
+    buffer: &[u8],
+    additional: usize,
+    values: &mut MutableBitmap,
+) -> Result<()> {
+    if buffer.len() * 8 < additional {
+        return Err(ArrowError::ExternalFormat(format!(
+            "Invalid parquet page: a required page of {} booleans has {} bytes",
+            additional,
+            buffer.len()
+        )));
+    }
     // in PLAIN, booleans are LSB bitpacked and thus we can read them as if they were a bitmap.
     values.extend_from_slice(buffer, 0, additional);
+    Ok(())
 }
 
-fn read_optional(
+/// Extends `values` and `validity` with a page of `length` slots whose non-null values are
+/// `new_values`.
+fn extend<I: Iterator<Item = bool>>(
+    is_optional: bool,
     validity_buffer: &[u8],
-    values_buffer: &[u8],
     length: usize,
+    new_values: I,
     values: &mut MutableBitmap,
     validity: &mut MutableBitmap,
-) {
-    let validity_iterator = hybrid_rle::Decoder::new(&validity_buffer, 1);
-
-    // in PLAIN, booleans are LSB bitpacked and thus we can read them as if they were a bitmap.
-    // note that `values_buffer` contains only non-null values.
-    // thus, at this point, it is not known how many values this buffer contains
-    // values_len is the upper bound. The actual number depends on how many nulls there is.
-    let values_len = values_buffer.len() * 8;
-    let mut values_iterator = BitmapIter::new(values_buffer, 0, values_len);
-
-    for run in validity_iterator {
-        match run {
-            hybrid_rle::HybridEncoded::Bitpacked(packed_validity) => {
-                // the pack may contain more items than needed.
-                let remaining = length - values.len();
-                let len = std::cmp::min(packed_validity.len() * 8, remaining);
-                for is_valid in BitmapIter::new(packed_validity, 0, len) {
-                    let value = if is_valid {
-                        values_iterator.next().unwrap()
-                    } else {
-                        false
-                    };
-                    values.push(value);
-                }
-                validity.extend_from_slice(packed_validity, 0, len);
-            }
-            hybrid_rle::HybridEncoded::Rle(value, additional) => {
-                let is_set = value[0] == 1;
-                validity.extend_constant(additional, is_set);
-                if is_set {
-                    (0..additional).for_each(|_| {
-                        let value = values_iterator.next().unwrap();
-                        values.push(value)
-                    })
-                } else {
-                    values.extend_constant(additional, false)
-                }
-            }
-        }
-    }
+) -> Result<()> {
+    encodings::extend_page(
+        is_optional,
+        validity_buffer,
+        length,
+        validity,
+        new_values,
+        |value| values.push(value.unwrap_or_default()),
+    )
 }
 
 pub fn iter_to_array<I, E>(mut iter: I, metadata: &ColumnChunkMetaData) -> Result<BooleanArray>
@@ -91,67 +76,41 @@ fn extend_from_page(
     values: &mut MutableBitmap,
     validity: &mut MutableBitmap,
 ) -> Result<()> {
-    assert_eq!(descriptor.max_rep_level(), 0);
-    assert!(descriptor.max_def_level() <= 1);
+    let (version, validity_buffer, values_buffer) = utils::split_buffer(page, descriptor)?;
     let is_optional = descriptor.max_def_level() == 1;
-    match page.header() {
-        PageHeader::V1(header) => {
-            assert_eq!(header.definition_level_encoding, Encoding::Rle);
 
-            match (&page.encoding(), page.dictionary_page(), is_optional) {
-                (Encoding::Plain, None, true) => {
-                    let (_, validity_buffer, values_buffer) =
-                        levels::split_buffer_v1(page.buffer(), false, is_optional);
-                    read_optional(
-                        validity_buffer,
-                        values_buffer,
-                        page.num_values(),
-                        values,
-                        validity,
-                    )
-                }
-                (Encoding::Plain, None, false) => {
-                    read_required(page.buffer(), page.num_values(), values)
-                }
-                _ => {
-                    return Err(utils::not_implemented(
-                        &page.encoding(),
-                        is_optional,
-                        page.dictionary_page().is_some(),
-                        "V1",
-                        "Boolean",
-                    ))
-                }
-            }
+    match (page.encoding(), page.dictionary_page(), is_optional) {
+        (Encoding::Plain, None, true) => {
+            // note that `values_buffer` contains only non-null values and thus its number of
+            // values is not known: `values_buffer.len() * 8` is an upper bound.
+            let new_values = BitmapIter::new(values_buffer, 0, values_buffer.len() * 8);
+            extend(
+                is_optional,
+                validity_buffer,
+                page.num_values(),
+                new_values,
+                values,
+                validity,
+            )
         }
-        PageHeader::V2(header) => {
-            let def_level_buffer_length = header.definition_levels_byte_length as usize;
-            match (page.encoding(), page.dictionary_page(), is_optional) {
-                (Encoding::Plain, None, true) => {
-                    let (_, validity_buffer, values_buffer) =
-                        levels::split_buffer_v2(page.buffer(), 0, def_level_buffer_length);
-                    read_optional(
-                        validity_buffer,
-                        values_buffer,
-                        page.num_values(),
-                        values,
-                        validity,
-                    )
-                }
-                (Encoding::Plain, None, false) => {
-                    read_required(page.buffer(), page.num_values(), values)
-                }
-                _ => {
-                    return Err(utils::not_implemented(
-                        &page.encoding(),
-                        is_optional,
-                        page.dictionary_page().is_some(),
-                        "V2",
-                        "Boolean",
-                    ))
-                }
-            }
+        (Encoding::Plain, None, false) => read_required(values_buffer, page.num_values(), values),
+        (Encoding::Rle, None, _) => {
+            let new_values = encodings::rle_booleans(values_buffer, page.num_values())?;
+            extend(
+                is_optional,
+                validity_buffer,
+                page.num_values(),
+                new_values.into_iter(),
+                values,
+                validity,
+            )
         }
-    };
-    Ok(())
+        _ => Err(utils::not_implemented(
+            &page.encoding(),
+            is_optional,
+            page.dictionary_page().is_some(),
+            version,
+            "Boolean",
+        )),
+    }
 }
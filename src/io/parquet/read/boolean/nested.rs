@@ -1,27 +1,24 @@
-use std::sync::Arc;
-
 use parquet2::{
     encoding::Encoding,
     metadata::{ColumnChunkMetaData, ColumnDescriptor},
     read::{
-        levels::{get_bit_width, split_buffer_v1, split_buffer_v2, RLEDecoder},
+        levels::{split_buffer_v1, split_buffer_v2},
         Page, PageHeader, StreamingIterator,
     },
 };
 
 use super::super::nested_utils::*;
-use super::super::utils;
-use super::basic::read_required;
+use super::super::{is_type_nullable, utils};
 use crate::{
     array::{Array, BooleanArray},
     bitmap::{utils::BitmapIter, MutableBitmap},
-    datatypes::DataType,
     error::{ArrowError, Result},
 };
 
 fn read_values<D, G>(
     def_levels: D,
     max_def: u32,
+    min_def: u32,
     mut new_values: G,
     values: &mut MutableBitmap,
     validity: &mut MutableBitmap,
@@ -33,7 +30,7 @@ fn read_values<D, G>(
         if def == max_def {
             values.push(new_values.next().unwrap());
             validity.push(true);
-        } else if def == max_def - 1 {
+        } else if def >= min_def {
             values.push(false);
             validity.push(false);
         }
@@ -48,56 +45,41 @@ fn read(
     additional: usize,
     rep_level_encoding: (&Encoding, i16),
     def_level_encoding: (&Encoding, i16),
-    is_nullable: bool,
     nested: &mut Vec<Box<dyn Nested>>,
     values: &mut MutableBitmap,
     validity: &mut MutableBitmap,
-) {
-    let max_rep_level = rep_level_encoding.1 as u32;
+) -> Result<()> {
     let max_def_level = def_level_encoding.1 as u32;
 
     match (rep_level_encoding.0, def_level_encoding.0) {
         (Encoding::Rle, Encoding::Rle) => {
-            let rep_levels = RLEDecoder::new(
-                rep_levels,
-                get_bit_width(rep_level_encoding.1),
-                additional as u32,
+            let def_levels_iter = levels(def_levels, def_level_encoding.1, additional);
+            let new_values = BitmapIter::new(values_buffer, 0, values_buffer.len() * 8);
+            read_values(
+                def_levels_iter,
+                max_def_level,
+                min_def_level(nested),
+                new_values,
+                values,
+                validity,
             );
-            if is_nullable {
-                let def_levels = RLEDecoder::new(
-                    def_levels,
-                    get_bit_width(def_level_encoding.1),
-                    additional as u32,
-                );
-                let new_values = BitmapIter::new(values_buffer, 0, additional);
-                read_values(def_levels, max_def_level, new_values, values, validity)
-            } else {
-                read_required(values_buffer, additional, values)
-            }
 
-            let def_levels = RLEDecoder::new(
-                def_levels,
-                get_bit_width(def_level_encoding.1),
-                additional as u32,
-            );
+            let rep_levels = levels(rep_levels, rep_level_encoding.1, additional);
+            let def_levels = levels(def_levels, def_level_encoding.1, additional);
 
-            extend_offsets(
-                rep_levels,
-                def_levels,
-                is_nullable,
-                max_rep_level,
-                max_def_level,
-                nested,
-            )
+            extend_offsets(rep_levels, def_levels, nested);
+            Ok(())
         }
-        _ => todo!(),
+        (rep, def) => Err(ArrowError::NotYetImplemented(format!(
+            "Decoding nested parquet pages with repetition levels encoded as {:?} and definition levels encoded as {:?}",
+            rep, def
+        ))),
     }
 }
 
 fn extend_from_page(
     page: &Page,
     descriptor: &ColumnDescriptor,
-    is_nullable: bool,
     nested: &mut Vec<Box<dyn Nested>>,
     values: &mut MutableBitmap,
     validity: &mut MutableBitmap,
@@ -129,16 +111,15 @@ fn extend_from_page(
                             &header.definition_level_encoding,
                             descriptor.max_def_level(),
                         ),
-                        is_nullable,
                         nested,
                         values,
                         validity,
-                    )
+                    )?
                 }
                 _ => {
                     return Err(utils::not_implemented(
                         &page.encoding(),
-                        is_nullable,
+                        is_type_nullable(descriptor.type_()),
                         page.dictionary_page().is_some(),
                         "V1",
                         "primitive",
@@ -162,16 +143,15 @@ fn extend_from_page(
                     additional,
                     (&Encoding::Rle, descriptor.max_rep_level()),
                     (&Encoding::Rle, descriptor.max_def_level()),
-                    is_nullable,
                     nested,
                     values,
                     validity,
-                )
+                )?
             }
             _ => {
                 return Err(utils::not_implemented(
                     &page.encoding(),
-                    is_nullable,
+                    is_type_nullable(descriptor.type_()),
                     page.dictionary_page().is_some(),
                     "V2",
                     "primitive",
@@ -182,10 +162,12 @@ fn extend_from_page(
     Ok(())
 }
 
+/// Reads the leaf column `metadata` of a nested field into a [`BooleanArray`], extending
+/// `nested` with its levels.
 pub fn iter_to_array<I, E>(
     mut iter: I,
     metadata: &ColumnChunkMetaData,
-    data_type: DataType,
+    nested: &mut Vec<Box<dyn Nested>>,
 ) -> Result<Box<dyn Array>>
 where
     ArrowError: From<E>,
@@ -196,20 +178,18 @@ where
     let mut values = MutableBitmap::with_capacity(capacity);
     let mut validity = MutableBitmap::with_capacity(capacity);
 
-    let (mut nested, is_nullable) = init_nested(metadata.descriptor().base_type(), capacity);
-
     while let Some(page) = iter.next() {
         extend_from_page(
             page.as_ref().map_err(|x| x.clone())?,
             metadata.descriptor(),
-            is_nullable,
-            &mut nested,
+            nested,
             &mut values,
             &mut validity,
         )?
     }
 
-    let values = Arc::new(BooleanArray::from_data(values.into(), validity.into()));
-
-    create_list(data_type, &mut nested, values)
+    Ok(Box::new(BooleanArray::from_data(
+        values.into(),
+        validity.into(),
+    )))
 }
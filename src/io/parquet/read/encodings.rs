@@ -0,0 +1,390 @@
+//! Decoders of parquet encodings of values. Every decoder validates its input and returns an
+//! error on malformed pages instead of panicking.
+use std::convert::{TryFrom, TryInto};
+
+use parquet2::types::NativeType;
+
+use crate::bitmap::MutableBitmap;
+use crate::error::{ArrowError, Result};
+
+fn out_of_spec(message: &str) -> ArrowError {
+    ArrowError::ExternalFormat(format!("Invalid parquet page: {}", message))
+}
+
+/// Decodes an unsigned LEB128 integer, returning it and the number of bytes it used.
+fn uleb128(buffer: &[u8]) -> Result<(u64, usize)> {
+    let mut value = 0u64;
+    for (i, byte) in buffer.iter().enumerate() {
+        if i == 10 {
+            break;
+        }
+        value |= ((byte & 0x7f) as u64) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Ok((value, i + 1));
+        }
+    }
+    Err(out_of_spec("unterminated ULEB128 integer"))
+}
+
+/// Decodes the ULEB128 integer of `buffer` at `offset`, advancing it.
+fn next_uleb128(buffer: &[u8], offset: &mut usize) -> Result<u64> {
+    let (value, consumed) = uleb128(&buffer[*offset..])?;
+    *offset += consumed;
+    Ok(value)
+}
+
+/// Decodes the zigzag ULEB128 integer of `buffer` at `offset`, advancing it.
+fn next_zigzag(buffer: &[u8], offset: &mut usize) -> Result<i64> {
+    let value = next_uleb128(buffer, offset)?;
+    Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+}
+
+/// Unpacks the `index`th integer of `bit_width` bits of the LSB bit-packed `buffer`.
+#[inline]
+fn unpack(buffer: &[u8], bit_width: usize, index: usize) -> u64 {
+    let start = index * bit_width;
+    let mut value = 0u64;
+    let mut read = 0;
+    while read < bit_width {
+        let bit = start + read;
+        let offset = bit % 8;
+        let take = std::cmp::min(8 - offset, bit_width - read);
+        let bits = (buffer[bit / 8] as u64 >> offset) & ((1 << take) - 1);
+        value |= bits << read;
+        read += take;
+    }
+    value
+}
+
+/// Decodes up to `length` integers of `bit_width` bits encoded with the RLE / bit-packing
+/// hybrid encoding. Fewer values are returned when `buffer` ends before `length` values.
+pub fn hybrid_rle(mut buffer: &[u8], bit_width: usize, length: usize) -> Result<Vec<u32>> {
+    if bit_width > 32 {
+        return Err(out_of_spec("hybrid RLE bit width larger than 32"));
+    }
+    let mut values = Vec::with_capacity(length);
+    while values.len() < length && !buffer.is_empty() {
+        let (header, consumed) = uleb128(buffer)?;
+        buffer = &buffer[consumed..];
+        let remaining = length - values.len();
+        if header & 1 == 1 {
+            // bit-packed run of groups of 8 values. The last run may be truncated.
+            let groups = (header >> 1) as usize;
+            let bytes = std::cmp::min(groups.saturating_mul(bit_width), buffer.len());
+            let count = (bytes * 8)
+                .checked_div(bit_width)
+                .unwrap_or_else(|| groups.saturating_mul(8));
+            let packed = &buffer[..bytes];
+            values.extend(
+                (0..std::cmp::min(count, remaining)).map(|i| unpack(packed, bit_width, i) as u32),
+            );
+            buffer = &buffer[bytes..];
+        } else {
+            let width = (bit_width + 7) / 8;
+            if buffer.len() < width {
+                return Err(out_of_spec("RLE run without a value"));
+            }
+            let mut bytes = [0u8; 4];
+            bytes[..width].copy_from_slice(&buffer[..width]);
+            let value = u32::from_le_bytes(bytes);
+            let count = std::cmp::min((header >> 1) as usize, remaining);
+            values.extend(std::iter::repeat(value).take(count));
+            buffer = &buffer[width..];
+        }
+    }
+    Ok(values)
+}
+
+/// Decodes up to `length` dictionary indices of a data page, stored as a byte with their bit
+/// width followed by them in the RLE / bit-packing hybrid encoding.
+pub fn dict_indices(buffer: &[u8], length: usize) -> Result<Vec<u32>> {
+    let (bit_width, buffer) = buffer
+        .split_first()
+        .ok_or_else(|| out_of_spec("dictionary-encoded page without a bit width"))?;
+    hybrid_rle(buffer, *bit_width as usize, length)
+}
+
+/// Validates that all `indices` are within a dictionary of `length` values.
+pub fn check_indices(indices: &[u32], length: usize) -> Result<()> {
+    if indices.iter().any(|index| *index as usize >= length) {
+        Err(out_of_spec("dictionary index out of bounds"))
+    } else {
+        Ok(())
+    }
+}
+
+/// Decodes `length` RLE-encoded booleans, stored as their byte length (4 bytes) followed by them
+/// in the RLE / bit-packing hybrid encoding with a bit width of 1.
+pub fn rle_booleans(buffer: &[u8], length: usize) -> Result<Vec<bool>> {
+    if buffer.len() < 4 {
+        return Err(out_of_spec("RLE-encoded booleans without a length"));
+    }
+    let bytes = u32::from_le_bytes(buffer[..4].try_into().unwrap()) as usize;
+    let buffer = buffer[4..]
+        .get(..bytes)
+        .ok_or_else(|| out_of_spec("RLE-encoded booleans longer than the page"))?;
+    Ok(hybrid_rle(buffer, 1, length)?
+        .into_iter()
+        .map(|x| x == 1)
+        .collect())
+}
+
+/// Decodes the `DELTA_BINARY_PACKED`-encoded integers at the start of `buffer`, returning them
+/// and the number of bytes they used.
+pub fn delta_bitpacked(buffer: &[u8]) -> Result<(Vec<i64>, usize)> {
+    let mut offset = 0;
+    let block_size = next_uleb128(buffer, &mut offset)? as usize;
+    let num_miniblocks = next_uleb128(buffer, &mut offset)? as usize;
+    let total = next_uleb128(buffer, &mut offset)? as usize;
+    let first = next_zigzag(buffer, &mut offset)?;
+    if num_miniblocks == 0 || block_size % num_miniblocks != 0 {
+        return Err(out_of_spec("invalid delta-encoding block size"));
+    }
+    let values_per_miniblock = block_size / num_miniblocks;
+    if values_per_miniblock % 8 != 0 || values_per_miniblock.checked_mul(64).is_none() {
+        return Err(out_of_spec("invalid delta-encoding miniblock size"));
+    }
+
+    let mut values = Vec::with_capacity(std::cmp::min(total, buffer.len()));
+    if total == 0 {
+        return Ok((values, offset));
+    }
+    values.push(first);
+    let mut last = first;
+    while values.len() < total {
+        let min_delta = next_zigzag(buffer, &mut offset)?;
+        let bit_widths = offset
+            .checked_add(num_miniblocks)
+            .and_then(|end| buffer.get(offset..end))
+            .ok_or_else(|| out_of_spec("delta-encoded block without bit widths"))?;
+        offset += num_miniblocks;
+        for bit_width in bit_widths {
+            if values.len() == total {
+                // the remaining miniblocks have bit widths but no data
+                break;
+            }
+            let bit_width = *bit_width as usize;
+            if bit_width > 64 {
+                return Err(out_of_spec("delta-encoding bit width larger than 64"));
+            }
+            let bytes = values_per_miniblock * bit_width / 8;
+            let packed = offset
+                .checked_add(bytes)
+                .and_then(|end| buffer.get(offset..end))
+                .ok_or_else(|| out_of_spec("delta-encoded miniblock longer than the page"))?;
+            offset += bytes;
+            let count = std::cmp::min(values_per_miniblock, total - values.len());
+            for i in 0..count {
+                let delta = unpack(packed, bit_width, i) as i64;
+                last = last.wrapping_add(min_delta).wrapping_add(delta);
+                values.push(last);
+            }
+        }
+    }
+    Ok((values, offset))
+}
+
+/// Decodes `DELTA_LENGTH_BYTE_ARRAY`-encoded values: the delta-encoded lengths of the values
+/// followed by their concatenated bytes.
+pub fn delta_length_byte_array(buffer: &[u8]) -> Result<Vec<&[u8]>> {
+    let (lengths, mut offset) = delta_bitpacked(buffer)?;
+    lengths
+        .into_iter()
+        .map(|length| {
+            let value = usize::try_from(length)
+                .ok()
+                .and_then(|length| buffer.get(offset..offset.checked_add(length)?))
+                .ok_or_else(|| out_of_spec("delta-length-encoded value longer than the page"))?;
+            offset += value.len();
+            Ok(value)
+        })
+        .collect()
+}
+
+/// Decodes `DELTA_BYTE_ARRAY`-encoded values: the delta-encoded lengths of the prefix each value
+/// shares with the previous one followed by the `DELTA_LENGTH_BYTE_ARRAY`-encoded suffixes.
+pub fn delta_byte_array(buffer: &[u8]) -> Result<Vec<Vec<u8>>> {
+    let (prefixes, offset) = delta_bitpacked(buffer)?;
+    let suffixes = delta_length_byte_array(&buffer[offset..])?;
+    if prefixes.len() != suffixes.len() {
+        return Err(out_of_spec(
+            "delta-encoded prefixes and suffixes of different lengths",
+        ));
+    }
+    let mut values: Vec<Vec<u8>> = Vec::with_capacity(prefixes.len());
+    for (prefix, suffix) in prefixes.into_iter().zip(suffixes) {
+        let previous = values.last().map(|x| x.as_slice()).unwrap_or(&[]);
+        let prefix = usize::try_from(prefix)
+            .ok()
+            .and_then(|prefix| previous.get(..prefix))
+            .ok_or_else(|| out_of_spec("delta-encoded prefix longer than the previous value"))?;
+        let mut value = Vec::with_capacity(prefix.len() + suffix.len());
+        value.extend_from_slice(prefix);
+        value.extend_from_slice(suffix);
+        values.push(value);
+    }
+    Ok(values)
+}
+
+/// Decodes `BYTE_STREAM_SPLIT`-encoded values, where the `i`th byte of every value is stored in
+/// the `i`th of `size_of::<T>()` streams.
+pub fn byte_stream_split<T: NativeType>(buffer: &[u8]) -> Result<Vec<T>> {
+    let size = std::mem::size_of::<T>();
+    if buffer.len() % size != 0 {
+        return Err(out_of_spec(
+            "byte-stream-split page not a multiple of the type's size",
+        ));
+    }
+    let length = buffer.len() / size;
+    let mut bytes = vec![0u8; size];
+    (0..length)
+        .map(|i| {
+            bytes
+                .iter_mut()
+                .enumerate()
+                .for_each(|(stream, byte)| *byte = buffer[stream * length + i]);
+            match bytes.as_slice().try_into() {
+                Ok(bytes) => Ok(T::from_le_bytes(bytes)),
+                Err(_) => Err(out_of_spec("byte-stream-split value of an invalid size")),
+            }
+        })
+        .collect()
+}
+
+/// Converts a decoded `DELTA_BINARY_PACKED` integer to the physical type `T` (`i32` or `i64`).
+pub fn from_i64<T: NativeType>(value: i64) -> Result<T> {
+    match value.to_le_bytes()[..std::mem::size_of::<T>()].try_into() {
+        Ok(bytes) => Ok(T::from_le_bytes(bytes)),
+        Err(_) => Err(out_of_spec("delta-encoded value of an invalid size")),
+    }
+}
+
+/// Extends a page of `length` slots with `values`. When `is_optional`, `validity_buffer` holds
+/// the RLE / bit-packing hybrid encoded validity of the slots, which extends `validity`, and
+/// `push` is called with `None` for null slots. `values` only contains the non-null values.
+pub fn extend_page<T, I, F>(
+    is_optional: bool,
+    validity_buffer: &[u8],
+    length: usize,
+    validity: &mut MutableBitmap,
+    mut values: I,
+    mut push: F,
+) -> Result<()>
+where
+    I: Iterator<Item = T>,
+    F: FnMut(Option<T>),
+{
+    let mut next = || {
+        values
+            .next()
+            .ok_or_else(|| out_of_spec("the page contains fewer values than its number of rows"))
+    };
+    if is_optional {
+        let is_valid = hybrid_rle(validity_buffer, 1, length)?;
+        if is_valid.len() != length {
+            return Err(out_of_spec(
+                "the page contains fewer definition levels than its number of rows",
+            ));
+        }
+        for is_valid in is_valid {
+            let is_valid = is_valid == 1;
+            validity.push(is_valid);
+            push(if is_valid { Some(next()?) } else { None });
+        }
+    } else {
+        for _ in 0..length {
+            push(Some(next()?));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hybrid_rle_runs() -> Result<()> {
+        // an RLE run of 3 times 4 followed by a bit-packed run of 8 values of 3 bits
+        let buffer = [6, 4, 3, 0b10001000, 0b11000110, 0b11111010];
+        let values = hybrid_rle(&buffer, 3, 11)?;
+        assert_eq!(values, vec![4, 4, 4, 0, 1, 2, 3, 4, 5, 6, 7]);
+        // fewer values when the buffer ends
+        assert_eq!(hybrid_rle(&buffer[..2], 3, 11)?, vec![4, 4, 4]);
+        Ok(())
+    }
+
+    #[test]
+    fn delta_bitpacked_values() -> Result<()> {
+        // block size of 128 values in 4 miniblocks, 5 values, first value 1;
+        // min delta 1 and deltas [0, 1, 0, 2] in a single miniblock of 2 bits
+        let mut buffer = vec![128, 1, 4, 5, 2, 2, 2, 0, 0, 0];
+        buffer.extend_from_slice(&[0b10000100]);
+        buffer.extend_from_slice(&[0; 7]);
+        buffer.push(42);
+        let (values, consumed) = delta_bitpacked(&buffer)?;
+        assert_eq!(values, vec![1, 2, 4, 5, 8]);
+        assert_eq!(consumed, buffer.len() - 1);
+        Ok(())
+    }
+
+    #[test]
+    fn delta_bitpacked_invalid() {
+        // missing the miniblock
+        assert!(delta_bitpacked(&[128, 1, 4, 5, 2, 2, 2, 0, 0, 0]).is_err());
+        // zero miniblocks
+        assert!(delta_bitpacked(&[128, 1, 0, 5, 2]).is_err());
+    }
+
+    #[test]
+    fn delta_byte_arrays() -> Result<()> {
+        // prefix lengths [0, 2] and suffix lengths [3, 1]: "abc", "ab" + "d"
+        let mut buffer = vec![128, 1, 4, 2, 0, 4, 0, 0, 0, 0];
+        buffer.extend_from_slice(&[128, 1, 4, 2, 6, 3, 0, 0, 0, 0]);
+        buffer.extend_from_slice(b"abcd");
+        let values = delta_byte_array(&buffer)?;
+        assert_eq!(values, vec![b"abc".to_vec(), b"abd".to_vec()]);
+        Ok(())
+    }
+
+    #[test]
+    fn byte_stream_split_f32() -> Result<()> {
+        let expected = [1.0f32, -2.5, 3.25];
+        let mut buffer = vec![0; 12];
+        for (i, value) in expected.iter().enumerate() {
+            for (stream, byte) in value.to_le_bytes().iter().enumerate() {
+                buffer[stream * 3 + i] = *byte;
+            }
+        }
+        assert_eq!(byte_stream_split::<f32>(&buffer)?, expected.to_vec());
+        assert!(byte_stream_split::<f32>(&buffer[..11]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn rle_boolean_values() -> Result<()> {
+        // a bit-packed run of 8 values followed by an RLE run of 3 trues
+        let buffer = [4, 0, 0, 0, 3, 0b00000101, 6, 1];
+        let values = rle_booleans(&buffer, 11)?;
+        let mut expected = vec![true, false, true, false, false, false, false, false];
+        expected.extend_from_slice(&[true; 3]);
+        assert_eq!(values, expected);
+        assert!(rle_booleans(&buffer[..6], 11).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn extend_optional() -> Result<()> {
+        let mut validity = MutableBitmap::new();
+        let mut result = vec![];
+        // bit-packed validity [true, false, true]
+        extend_page(true, &[3, 0b101], 3, &mut validity, [1, 2].iter(), |x| {
+            result.push(x.copied())
+        })?;
+        assert_eq!(result, vec![Some(1), None, Some(2)]);
+
+        let error = extend_page(true, &[3, 0b111], 3, &mut validity, [1, 2].iter(), |_| {});
+        assert!(error.is_err());
+        Ok(())
+    }
+}
@@ -2,12 +2,16 @@ use std::io::{Read, Seek};
 
 mod binary;
 mod boolean;
+pub(crate) mod encodings;
 mod fixed_size_binary;
 mod nested_utils;
+pub mod parallel;
+pub mod predicate;
 mod primitive;
 mod record_batch;
 pub mod schema;
 pub mod statistics;
+pub mod stream;
 mod utils;
 
 use crate::{
@@ -16,6 +20,7 @@ use crate::{
     error::{ArrowError, Result},
 };
 
+use nested_utils::{check_levels, create_nested, init_nested, Nested};
 pub use record_batch::RecordReader;
 pub use schema::{get_schema, is_type_nullable, FileMetaData};
 
@@ -61,26 +66,9 @@ fn page_iter_i64<I: StreamingIterator<Item = std::result::Result<Page, ParquetEr
     metadata: &ColumnChunkMetaData,
     data_type: DataType,
 ) -> Result<Box<dyn Array>> {
-    if metadata.descriptor().max_rep_level() > 0 {
-        let inner = match data_type {
-            DataType::List(ref inner) => inner.data_type(),
-            DataType::LargeList(ref inner) => inner.data_type(),
-            _ => unimplemented!(),
-        };
-
-        match inner {
-            DataType::UInt64 => {
-                primitive::iter_to_array_nested(iter, metadata, data_type, |x: i64| x as u64)
-            }
-            _ => primitive::iter_to_array_nested(iter, metadata, data_type, |x: i64| x as i64),
-        }
-    } else {
-        match data_type {
-            DataType::UInt64 => {
-                primitive::iter_to_array(iter, metadata, data_type, |x: i64| x as u64)
-            }
-            _ => primitive::iter_to_array(iter, metadata, data_type, |x: i64| x as i64),
-        }
+    match data_type {
+        DataType::UInt64 => primitive::iter_to_array(iter, metadata, data_type, |x: i64| x as u64),
+        _ => primitive::iter_to_array(iter, metadata, data_type, |x: i64| x as i64),
     }
 }
 
@@ -90,31 +78,13 @@ fn page_iter_i32<I: StreamingIterator<Item = std::result::Result<Page, ParquetEr
     data_type: DataType,
 ) -> Result<Box<dyn Array>> {
     use DataType::*;
-
-    if metadata.descriptor().max_rep_level() > 0 {
-        let inner = match data_type {
-            List(ref inner) => inner.data_type(),
-            LargeList(ref inner) => inner.data_type(),
-            _ => unimplemented!(),
-        };
-
-        match inner {
-            UInt8 => primitive::iter_to_array_nested(iter, metadata, data_type, |x: i32| x as u8),
-            UInt16 => primitive::iter_to_array_nested(iter, metadata, data_type, |x: i32| x as u16),
-            UInt32 => primitive::iter_to_array_nested(iter, metadata, data_type, |x: i32| x as u32),
-            Int8 => primitive::iter_to_array_nested(iter, metadata, data_type, |x: i32| x as i8),
-            Int16 => primitive::iter_to_array_nested(iter, metadata, data_type, |x: i32| x as i16),
-            _ => primitive::iter_to_array_nested(iter, metadata, data_type, |x: i32| x),
-        }
-    } else {
-        match data_type {
-            UInt8 => primitive::iter_to_array(iter, metadata, data_type, |x: i32| x as u8),
-            UInt16 => primitive::iter_to_array(iter, metadata, data_type, |x: i32| x as u16),
-            UInt32 => primitive::iter_to_array(iter, metadata, data_type, |x: i32| x as u32),
-            Int8 => primitive::iter_to_array(iter, metadata, data_type, |x: i32| x as i8),
-            Int16 => primitive::iter_to_array(iter, metadata, data_type, |x: i32| x as i16),
-            _ => primitive::iter_to_array(iter, metadata, data_type, |x: i32| x),
-        }
+    match data_type {
+        UInt8 => primitive::iter_to_array(iter, metadata, data_type, |x: i32| x as u8),
+        UInt16 => primitive::iter_to_array(iter, metadata, data_type, |x: i32| x as u16),
+        UInt32 => primitive::iter_to_array(iter, metadata, data_type, |x: i32| x as u32),
+        Int8 => primitive::iter_to_array(iter, metadata, data_type, |x: i32| x as i8),
+        Int16 => primitive::iter_to_array(iter, metadata, data_type, |x: i32| x as i16),
+        _ => primitive::iter_to_array(iter, metadata, data_type, |x: i32| x),
     }
 }
 
@@ -124,35 +94,13 @@ fn page_iter_byte_array<I: StreamingIterator<Item = std::result::Result<Page, Pa
     data_type: DataType,
 ) -> Result<Box<dyn Array>> {
     use DataType::*;
-
-    if metadata.descriptor().max_rep_level() > 0 {
-        let inner = match data_type {
-            List(ref inner) => inner.data_type(),
-            LargeList(ref inner) => inner.data_type(),
-            _ => unimplemented!(),
-        };
-
-        match inner {
-            Binary | Utf8 => binary::iter_to_array_nested::<i32, _, _>(iter, metadata, data_type),
-            LargeBinary | LargeUtf8 => {
-                binary::iter_to_array_nested::<i64, _, _>(iter, metadata, data_type)
-            }
-            other => Err(ArrowError::NotYetImplemented(format!(
-                "Can't read {:?} from parquet",
-                other
-            ))),
-        }
-    } else {
-        match data_type {
-            Binary | Utf8 => binary::iter_to_array::<i32, _, _>(iter, metadata, &data_type),
-            LargeBinary | LargeUtf8 => {
-                binary::iter_to_array::<i64, _, _>(iter, metadata, &data_type)
-            }
-            other => Err(ArrowError::NotYetImplemented(format!(
-                "Can't read {:?} from parquet",
-                other
-            ))),
-        }
+    match data_type {
+        Binary | Utf8 => binary::iter_to_array::<i32, _, _>(iter, metadata, &data_type),
+        LargeBinary | LargeUtf8 => binary::iter_to_array::<i64, _, _>(iter, metadata, &data_type),
+        other => Err(ArrowError::NotYetImplemented(format!(
+            "Can't read {:?} from parquet",
+            other
+        ))),
     }
 }
 
@@ -213,31 +161,112 @@ pub fn page_iter_to_array<I: StreamingIterator<Item = std::result::Result<Page,
                 p, c, l
             ))),
         },
-        _ => {
-            // get all primitives in the type_ and their max rep levels
-            match metadata.descriptor().type_() {
-                ParquetType::PrimitiveType {
-                    physical_type,
-                    converted_type,
-                    logical_type,
-                    ..
-                } => match (physical_type, converted_type, logical_type) {
-                    (PhysicalType::Boolean, None, None) => {
-                        boolean::iter_to_array_nested(iter, metadata, data_type)
-                    }
-                    (PhysicalType::Int64, _, _) => page_iter_i64(iter, metadata, data_type),
-                    (PhysicalType::Int32, _, _) => page_iter_i32(iter, metadata, data_type),
-                    (PhysicalType::ByteArray, _, _) => {
-                        page_iter_byte_array(iter, metadata, data_type)
-                    }
-                    _ => todo!(),
-                },
-                _ => unreachable!(),
+        ParquetType::GroupType { .. } => {
+            let is_nullable = is_type_nullable(metadata.descriptor().base_type());
+            let capacity = metadata.num_values() as usize;
+            let mut leaves = init_nested(&data_type, is_nullable, capacity);
+            if leaves.len() != 1 {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The data type {:?} is stored in {} parquet columns and can't be read from a single column",
+                    data_type,
+                    leaves.len()
+                )));
             }
+            let (leaf_type, mut nested) = leaves.pop().unwrap();
+            let array = page_iter_to_leaf(iter, metadata, leaf_type, &mut nested)?;
+            create_nested(&data_type, vec![(array, nested)])
+        }
+    }
+}
+
+/// Reads the leaf column `metadata` of a nested field into an [`Array`] of `data_type`,
+/// extending `nested` with its repetition and definition levels.
+fn page_iter_to_leaf<I: StreamingIterator<Item = std::result::Result<Page, ParquetError>>>(
+    iter: &mut I,
+    metadata: &ColumnChunkMetaData,
+    data_type: DataType,
+    nested: &mut Vec<Box<dyn Nested>>,
+) -> Result<Box<dyn Array>> {
+    use DataType::*;
+    check_levels(nested, metadata.descriptor())?;
+
+    let physical_type = match metadata.descriptor().type_() {
+        ParquetType::PrimitiveType { physical_type, .. } => physical_type,
+        ParquetType::GroupType { .. } => unreachable!(),
+    };
+    match (physical_type, &data_type) {
+        (PhysicalType::Boolean, Boolean) => boolean::iter_to_array_nested(iter, metadata, nested),
+        (PhysicalType::Int32, UInt8) => {
+            primitive::iter_to_array_nested(iter, metadata, data_type, nested, |x: i32| x as u8)
+        }
+        (PhysicalType::Int32, UInt16) => {
+            primitive::iter_to_array_nested(iter, metadata, data_type, nested, |x: i32| x as u16)
+        }
+        (PhysicalType::Int32, UInt32) => {
+            primitive::iter_to_array_nested(iter, metadata, data_type, nested, |x: i32| x as u32)
         }
+        (PhysicalType::Int32, Int8) => {
+            primitive::iter_to_array_nested(iter, metadata, data_type, nested, |x: i32| x as i8)
+        }
+        (PhysicalType::Int32, Int16) => {
+            primitive::iter_to_array_nested(iter, metadata, data_type, nested, |x: i32| x as i16)
+        }
+        (PhysicalType::Int32, Int32)
+        | (PhysicalType::Int32, Date32)
+        | (PhysicalType::Int32, Time32(_)) => {
+            primitive::iter_to_array_nested(iter, metadata, data_type, nested, |x: i32| x)
+        }
+        (PhysicalType::Int64, UInt64) => {
+            primitive::iter_to_array_nested(iter, metadata, data_type, nested, |x: i64| x as u64)
+        }
+        (PhysicalType::Int64, Int64)
+        | (PhysicalType::Int64, Date64)
+        | (PhysicalType::Int64, Time64(_))
+        | (PhysicalType::Int64, Timestamp(_, _))
+        | (PhysicalType::Int64, Duration(_)) => {
+            primitive::iter_to_array_nested(iter, metadata, data_type, nested, |x: i64| x)
+        }
+        (PhysicalType::Float, Float32) => {
+            primitive::iter_to_array_nested(iter, metadata, data_type, nested, |x: f32| x)
+        }
+        (PhysicalType::Double, Float64) => {
+            primitive::iter_to_array_nested(iter, metadata, data_type, nested, |x: f64| x)
+        }
+        (PhysicalType::ByteArray, Binary) | (PhysicalType::ByteArray, Utf8) => {
+            binary::iter_to_array_nested::<i32, _, _>(iter, metadata, &data_type, nested)
+        }
+        (PhysicalType::ByteArray, LargeBinary) | (PhysicalType::ByteArray, LargeUtf8) => {
+            binary::iter_to_array_nested::<i64, _, _>(iter, metadata, &data_type, nested)
+        }
+        (p, other) => Err(ArrowError::NotYetImplemented(format!(
+            "Reading nested parquet columns of physical type {:?} to {:?}",
+            p, other
+        ))),
     }
 }
 
+/// Reads a nested field of `data_type` from its leaf columns. `columns` is called once per
+/// leaf, in order, with the leaf's data type and its nested state, and must return the leaf
+/// read with [`page_iter_to_leaf`].
+fn read_nested<F>(
+    data_type: &DataType,
+    is_nullable: bool,
+    capacity: usize,
+    mut columns: F,
+) -> Result<Box<dyn Array>>
+where
+    F: FnMut(usize, DataType, &mut Vec<Box<dyn Nested>>) -> Result<Box<dyn Array>>,
+{
+    let leaves = init_nested(data_type, is_nullable, capacity)
+        .into_iter()
+        .enumerate()
+        .map(|(index, (leaf_type, mut nested))| {
+            columns(index, leaf_type, &mut nested).map(|array| (array, nested))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    create_nested(data_type, leaves)
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs::File;
@@ -274,18 +303,23 @@ mod tests {
             ("basic", true) => pyarrow_required(column),
             ("basic", false) => pyarrow_nullable(column),
             ("nested", false) => pyarrow_nested_nullable(column),
+            ("struct", false) => pyarrow_struct(column),
             _ => unreachable!(),
         };
 
         let expected_statistics = match (type_, required) {
-            ("basic", true) => pyarrow_required_statistics(column),
-            ("basic", false) => pyarrow_nullable_statistics(column),
-            ("nested", false) => pyarrow_nested_nullable_statistics(column),
+            ("basic", true) => Some(pyarrow_required_statistics(column)),
+            ("basic", false) => Some(pyarrow_nullable_statistics(column)),
+            ("nested", false) if column < 7 => Some(pyarrow_nested_nullable_statistics(column)),
+            // statistics are only compared for lists of primitive types
+            ("nested", false) | ("struct", false) => None,
             _ => unreachable!(),
         };
 
         assert_eq!(expected.as_ref(), array.as_ref());
-        assert_eq!(expected_statistics.as_ref(), statistics.unwrap().as_ref());
+        if let Some(expected_statistics) = expected_statistics {
+            assert_eq!(expected_statistics.as_ref(), statistics.unwrap().as_ref());
+        }
 
         Ok(())
     }
@@ -451,10 +485,45 @@ mod tests {
         test_pyarrow_integration(6, 1, "nested", false, false)
     }
 
-    /*#[test]
-    fn v2_nested_nested() {
-        let _ = test_pyarrow_integration(7, 1, "nested",false, false);
-    }*/
+    #[test]
+    fn v2_nested_nested() -> Result<()> {
+        test_pyarrow_integration(7, 2, "nested", false, false)
+    }
+
+    #[test]
+    fn v1_nested_nested() -> Result<()> {
+        test_pyarrow_integration(7, 1, "nested", false, false)
+    }
+
+    #[test]
+    fn v2_struct() -> Result<()> {
+        test_pyarrow_integration(0, 2, "struct", false, false)
+    }
+
+    #[test]
+    fn v1_struct() -> Result<()> {
+        test_pyarrow_integration(0, 1, "struct", false, false)
+    }
+
+    #[test]
+    fn v2_list_struct() -> Result<()> {
+        test_pyarrow_integration(1, 2, "struct", false, false)
+    }
+
+    #[test]
+    fn v1_list_struct() -> Result<()> {
+        test_pyarrow_integration(1, 1, "struct", false, false)
+    }
+
+    #[test]
+    fn v2_struct_list() -> Result<()> {
+        test_pyarrow_integration(2, 2, "struct", false, false)
+    }
+
+    #[test]
+    fn v1_struct_list() -> Result<()> {
+        test_pyarrow_integration(2, 1, "struct", false, false)
+    }
 }
 
 #[cfg(test)]
@@ -1,23 +1,33 @@
 use std::sync::Arc;
 
-use parquet2::schema::{types::ParquetType, Repetition};
+use parquet2::{
+    metadata::ColumnDescriptor,
+    read::levels::{get_bit_width, RLEDecoder},
+};
 
 use crate::{
-    array::{Array, ListArray},
+    array::{growable::make_growable, Array, FixedSizeListArray, ListArray, MapArray, StructArray},
     bitmap::{Bitmap, MutableBitmap},
     buffer::{Buffer, MutableBuffer},
     datatypes::DataType,
     error::{ArrowError, Result},
 };
 
+/// The state of one nesting level of a leaf column: a list, a struct or the leaf itself.
 pub trait Nested: std::fmt::Debug {
     fn inner(&mut self) -> (Buffer<i64>, Option<Bitmap>);
 
-    fn last_offset(&self) -> i64;
-
+    /// Pushes a new item to this level. `length` is the current number of items of the level
+    /// below, used as the start offset of lists.
     fn push(&mut self, length: i64, is_valid: bool);
 
-    fn offsets(&mut self) -> &[i64];
+    /// The number of items pushed to this level.
+    fn num_values(&self) -> usize;
+
+    fn is_nullable(&self) -> bool;
+
+    /// Whether this level is a list.
+    fn is_repeated(&self) -> bool;
 
     fn close(&mut self, length: i64);
 }
@@ -35,18 +45,22 @@ impl Nested for NestedOptional {
         (offsets.into(), validity.into())
     }
 
-    #[inline]
-    fn last_offset(&self) -> i64 {
-        *self.offsets.last().unwrap()
-    }
-
     fn push(&mut self, value: i64, is_valid: bool) {
         self.offsets.push(value);
         self.validity.push(is_valid);
     }
 
-    fn offsets(&mut self) -> &[i64] {
-        &self.offsets
+    #[inline]
+    fn num_values(&self) -> usize {
+        self.validity.len()
+    }
+
+    fn is_nullable(&self) -> bool {
+        true
+    }
+
+    fn is_repeated(&self) -> bool {
+        true
     }
 
     fn close(&mut self, length: i64) {
@@ -65,6 +79,7 @@ impl NestedOptional {
 #[derive(Debug, Default)]
 pub struct NestedValid {
     pub offsets: MutableBuffer<i64>,
+    length: usize,
 }
 
 impl Nested for NestedValid {
@@ -73,17 +88,22 @@ impl Nested for NestedValid {
         (offsets.into(), None)
     }
 
+    fn push(&mut self, value: i64, _is_valid: bool) {
+        self.offsets.push(value);
+        self.length += 1;
+    }
+
     #[inline]
-    fn last_offset(&self) -> i64 {
-        *self.offsets.last().unwrap()
+    fn num_values(&self) -> usize {
+        self.length
     }
 
-    fn push(&mut self, value: i64, _is_valid: bool) {
-        self.offsets.push(value);
+    fn is_nullable(&self) -> bool {
+        false
     }
 
-    fn offsets(&mut self) -> &[i64] {
-        &self.offsets
+    fn is_repeated(&self) -> bool {
+        true
     }
 
     fn close(&mut self, length: i64) {
@@ -94,99 +114,383 @@ impl Nested for NestedValid {
 impl NestedValid {
     pub fn with_capacity(capacity: usize) -> Self {
         let offsets = MutableBuffer::<i64>::with_capacity(capacity + 1);
-        Self { offsets }
+        Self { offsets, length: 0 }
     }
 }
 
-pub fn extend_offsets<R, D>(
-    rep_levels: R,
-    def_levels: D,
+/// A struct level. Structs have no offsets: every item of a struct has exactly one item in
+/// each of its children, even when the struct is null.
+#[derive(Debug)]
+pub struct NestedStruct {
+    validity: MutableBitmap,
     is_nullable: bool,
-    max_rep: u32,
-    max_def: u32,
-    nested: &mut Vec<Box<dyn Nested>>,
-) where
+    length: usize,
+}
+
+impl Nested for NestedStruct {
+    fn inner(&mut self) -> (Buffer<i64>, Option<Bitmap>) {
+        let validity = std::mem::take(&mut self.validity);
+        (Buffer::new(), validity.into())
+    }
+
+    fn push(&mut self, _length: i64, is_valid: bool) {
+        if self.is_nullable {
+            self.validity.push(is_valid);
+        }
+        self.length += 1;
+    }
+
+    #[inline]
+    fn num_values(&self) -> usize {
+        self.length
+    }
+
+    fn is_nullable(&self) -> bool {
+        self.is_nullable
+    }
+
+    fn is_repeated(&self) -> bool {
+        false
+    }
+
+    fn close(&mut self, _length: i64) {}
+}
+
+impl NestedStruct {
+    pub fn with_capacity(is_nullable: bool, capacity: usize) -> Self {
+        let validity = if is_nullable {
+            MutableBitmap::with_capacity(capacity)
+        } else {
+            MutableBitmap::new()
+        };
+        Self {
+            validity,
+            is_nullable,
+            length: 0,
+        }
+    }
+}
+
+/// The leaf level. Its values and validity are read by the leaf's decoder; this only tracks
+/// its length, the end offset of the innermost list.
+#[derive(Debug)]
+pub struct NestedPrimitive {
+    is_nullable: bool,
+    length: usize,
+}
+
+impl Nested for NestedPrimitive {
+    fn inner(&mut self) -> (Buffer<i64>, Option<Bitmap>) {
+        (Buffer::new(), None)
+    }
+
+    fn push(&mut self, _length: i64, _is_valid: bool) {
+        self.length += 1;
+    }
+
+    #[inline]
+    fn num_values(&self) -> usize {
+        self.length
+    }
+
+    fn is_nullable(&self) -> bool {
+        self.is_nullable
+    }
+
+    fn is_repeated(&self) -> bool {
+        false
+    }
+
+    fn close(&mut self, _length: i64) {}
+}
+
+/// Returns an iterator over the `additional` levels of a page. Levels whose maximum is zero
+/// are not written to the page and are all zero.
+pub fn levels<'a>(
+    buffer: &'a [u8],
+    max_level: i16,
+    additional: usize,
+) -> Box<dyn Iterator<Item = u32> + 'a> {
+    if max_level == 0 {
+        Box::new(std::iter::repeat(0).take(additional))
+    } else {
+        Box::new(RLEDecoder::new(
+            buffer,
+            get_bit_width(max_level),
+            additional as u32,
+        ))
+    }
+}
+
+/// Returns, for each level of `nested`, the definition level from which an item of the level
+/// is valid and the definition level from which the level has items below it.
+fn def_thresholds(nested: &[Box<dyn Nested>]) -> Vec<(u32, u32)> {
+    let mut current = 0;
+    nested
+        .iter()
+        .map(|nested| {
+            let valid = current + nested.is_nullable() as u32;
+            current = valid + nested.is_repeated() as u32;
+            (valid, current)
+        })
+        .collect()
+}
+
+/// Returns the minimum definition level of an item (null or not) of the leaf of `nested`.
+pub fn min_def_level(nested: &[Box<dyn Nested>]) -> u32 {
+    def_thresholds(nested)
+        .iter()
+        .zip(nested.iter())
+        .filter(|(_, nested)| nested.is_repeated())
+        .map(|((_, below), _)| *below)
+        .last()
+        .unwrap_or(0)
+}
+
+/// Errors if the levels described by `nested` do not match the ones of `descriptor`.
+pub fn check_levels(nested: &[Box<dyn Nested>], descriptor: &ColumnDescriptor) -> Result<()> {
+    let max_def = def_thresholds(nested).last().map(|x| x.1).unwrap_or(0);
+    let max_rep = nested.iter().filter(|x| x.is_repeated()).count();
+    if max_def != descriptor.max_def_level() as u32
+        || max_rep != descriptor.max_rep_level() as usize
+    {
+        return Err(ArrowError::ExternalFormat(format!(
+            "The parquet column has definition and repetition levels ({}, {}) but its arrow type requires ({}, {})",
+            descriptor.max_def_level(),
+            descriptor.max_rep_level(),
+            max_def,
+            max_rep
+        )));
+    }
+    Ok(())
+}
+
+/// Extends every level of `nested` from the repetition and definition levels of a page.
+pub fn extend_offsets<R, D>(rep_levels: R, def_levels: D, nested: &mut [Box<dyn Nested>])
+where
     R: Iterator<Item = u32>,
     D: Iterator<Item = u32>,
 {
-    assert_eq!(max_rep, 1);
-    let mut values_count = 0;
+    let thresholds = def_thresholds(nested);
+    // the index of the level of each repetition level
+    let lists = nested
+        .iter()
+        .enumerate()
+        .filter(|(_, nested)| nested.is_repeated())
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+
     rep_levels.zip(def_levels).for_each(|(rep, def)| {
-        if rep == 0 {
-            nested[0].push(values_count, def != 0);
-        }
-        if def == max_def || (is_nullable && def == max_def - 1) {
-            values_count += 1;
+        // a repetition level `rep` is a new item of the level below the `rep`th list
+        let start = if rep == 0 {
+            0
+        } else {
+            lists[rep as usize - 1] + 1
+        };
+        for level in start..nested.len() {
+            let (valid, below) = thresholds[level];
+            let length = nested
+                .get(level + 1)
+                .map(|x| x.num_values() as i64)
+                .unwrap_or(0);
+            nested[level].push(length, def >= valid);
+            // null and empty lists have no items below them
+            if nested[level].is_repeated() && def < below {
+                break;
+            }
         }
     });
-    nested[0].close(values_count);
 }
 
-pub fn is_nullable(type_: &ParquetType, container: &mut Vec<bool>) {
-    match type_ {
-        ParquetType::PrimitiveType { basic_info, .. } => {
-            container.push(super::schema::is_nullable(basic_info));
+/// Closes the offsets of every list of `nested`. Must be called once all pages were read.
+pub fn close(nested: &mut [Box<dyn Nested>]) {
+    for level in 0..nested.len() {
+        let length = nested
+            .get(level + 1)
+            .map(|x| x.num_values() as i64)
+            .unwrap_or(0);
+        nested[level].close(length);
+    }
+}
+
+/// Appends to `leaves` the data type of each leaf of a field of `data_type`, together with the
+/// nesting levels from the field to the leaf.
+fn init_leaves(
+    data_type: &DataType,
+    is_nullable: bool,
+    capacity: usize,
+    path: &mut Vec<(DataType, bool)>,
+    leaves: &mut Vec<(DataType, Vec<Box<dyn Nested>>)>,
+) {
+    match data_type {
+        DataType::List(inner) | DataType::LargeList(inner) | DataType::FixedSizeList(inner, _) => {
+            path.push((data_type.clone(), is_nullable));
+            init_leaves(
+                inner.data_type(),
+                inner.is_nullable(),
+                capacity,
+                path,
+                leaves,
+            );
+            path.pop();
         }
-        ParquetType::GroupType {
-            basic_info, fields, ..
-        } => {
-            if basic_info.repetition() != &Repetition::Repeated {
-                container.push(super::schema::is_nullable(basic_info));
-            }
-            for field in fields {
-                is_nullable(field, container)
-            }
+        DataType::Map(inner, _) => {
+            path.push((data_type.clone(), is_nullable));
+            // the entries of a map are the (required) repeated group of the map.
+            init_leaves(inner.data_type(), false, capacity, path, leaves);
+            path.pop();
+        }
+        DataType::Struct(fields) => {
+            path.push((data_type.clone(), is_nullable));
+            fields.iter().for_each(|field| {
+                init_leaves(
+                    field.data_type(),
+                    field.is_nullable(),
+                    capacity,
+                    path,
+                    leaves,
+                )
+            });
+            path.pop();
+        }
+        _ => {
+            let nested = path
+                .iter()
+                .map(|(data_type, is_nullable)| match (data_type, is_nullable) {
+                    (DataType::Struct(_), _) => {
+                        Box::new(NestedStruct::with_capacity(*is_nullable, capacity))
+                            as Box<dyn Nested>
+                    }
+                    (_, true) => Box::new(NestedOptional::with_capacity(capacity)),
+                    (_, false) => Box::new(NestedValid::with_capacity(capacity)),
+                })
+                .chain(std::iter::once(Box::new(NestedPrimitive {
+                    is_nullable,
+                    length: 0,
+                }) as Box<dyn Nested>))
+                .collect();
+            leaves.push((data_type.clone(), nested))
         }
     }
 }
 
-pub fn init_nested(base_type: &ParquetType, capacity: usize) -> (Vec<Box<dyn Nested>>, bool) {
-    let mut nullable = Vec::new();
-    is_nullable(base_type, &mut nullable);
-    // the primitive's nullability is the last on the list
-    let is_nullable = nullable.pop().unwrap();
-
-    (
-        nullable
-            .iter()
-            .map(|is_nullable| {
-                if *is_nullable {
-                    Box::new(NestedOptional::with_capacity(capacity)) as Box<dyn Nested>
-                } else {
-                    Box::new(NestedValid::with_capacity(capacity)) as Box<dyn Nested>
-                }
-            })
-            .collect(),
-        is_nullable,
-    )
-}
-
-pub fn create_list(
-    data_type: DataType,
-    nested: &mut [Box<dyn Nested>],
-    values: Arc<dyn Array>,
+/// Returns the data type and the initial nested state of each leaf column of a field of
+/// `data_type`, in the order of the columns.
+pub fn init_nested(
+    data_type: &DataType,
+    is_nullable: bool,
+    capacity: usize,
+) -> Vec<(DataType, Vec<Box<dyn Nested>>)> {
+    let mut leaves = vec![];
+    init_leaves(data_type, is_nullable, capacity, &mut vec![], &mut leaves);
+    leaves
+}
+
+fn to_i32_offsets(offsets: Buffer<i64>) -> Buffer<i32> {
+    Buffer::<i32>::from_trusted_len_iter(offsets.iter().map(|x| *x as i32))
+}
+
+/// Returns the values of a fixed-size list from the values of a list with `offsets`. Null
+/// slots have no values in parquet and are filled with `size` nulls.
+fn to_fixed_size_values(values: &dyn Array, offsets: &[i64], size: usize) -> Arc<dyn Array> {
+    let mut growable = make_growable(&[values], true, (offsets.len() - 1) * size);
+    offsets.windows(2).for_each(|window| {
+        let start = window[0] as usize;
+        let length = window[1] as usize - start;
+        if length == size {
+            growable.extend(0, start, size)
+        } else {
+            growable.extend_validity(size)
+        }
+    });
+    growable.as_arc()
+}
+
+fn create_array(
+    data_type: &DataType,
+    leaves: &mut [(Option<Box<dyn Array>>, Vec<Box<dyn Nested>>)],
+    leaf: &mut usize,
+    depth: usize,
 ) -> Result<Box<dyn Array>> {
     Ok(match data_type {
-        DataType::List(_) => {
-            let (offsets, validity) = nested[0].inner();
-
-            let offsets = Buffer::<i32>::from_trusted_len_iter(offsets.iter().map(|x| *x as i32));
+        DataType::List(inner) => {
+            let (offsets, validity) = leaves[*leaf].1[depth].inner();
+            let values = create_array(inner.data_type(), leaves, leaf, depth + 1)?;
             Box::new(ListArray::<i32>::from_data(
-                data_type, offsets, values, validity,
+                data_type.clone(),
+                to_i32_offsets(offsets),
+                values.into(),
+                validity,
             ))
         }
-        DataType::LargeList(_) => {
-            let (offsets, validity) = nested[0].inner();
-
+        DataType::LargeList(inner) => {
+            let (offsets, validity) = leaves[*leaf].1[depth].inner();
+            let values = create_array(inner.data_type(), leaves, leaf, depth + 1)?;
             Box::new(ListArray::<i64>::from_data(
-                data_type, offsets, values, validity,
+                data_type.clone(),
+                offsets,
+                values.into(),
+                validity,
+            ))
+        }
+        DataType::FixedSizeList(inner, size) => {
+            let (offsets, validity) = leaves[*leaf].1[depth].inner();
+            let values = create_array(inner.data_type(), leaves, leaf, depth + 1)?;
+            Box::new(FixedSizeListArray::from_data(
+                data_type.clone(),
+                to_fixed_size_values(values.as_ref(), &offsets, *size as usize),
+                validity,
             ))
         }
+        DataType::Map(inner, _) => {
+            let (offsets, validity) = leaves[*leaf].1[depth].inner();
+            let entries = create_array(inner.data_type(), leaves, leaf, depth + 1)?;
+            Box::new(MapArray::from_data(
+                data_type.clone(),
+                to_i32_offsets(offsets),
+                entries.into(),
+                validity,
+            ))
+        }
+        DataType::Struct(fields) => {
+            // all leaves of a struct share its validity; use the one of its first leaf
+            let (_, validity) = leaves[*leaf].1[depth].inner();
+            let values = fields
+                .iter()
+                .map(|field| {
+                    create_array(field.data_type(), leaves, leaf, depth + 1).map(|x| x.into())
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Box::new(StructArray::from_data(fields.clone(), values, validity))
+        }
         _ => {
-            return Err(ArrowError::NotYetImplemented(format!(
-                "Read nested datatype {:?}",
-                data_type
-            )))
+            let array = leaves
+                .get_mut(*leaf)
+                .and_then(|(array, _)| array.take())
+                .ok_or_else(|| {
+                    ArrowError::ExternalFormat(format!(
+                        "The parquet columns do not match the arrow data type {:?}",
+                        data_type
+                    ))
+                })?;
+            *leaf += 1;
+            array
         }
     })
 }
+
+/// Assembles the leaves of a nested field of `data_type`, each with its nested state, into a
+/// single [`Array`].
+pub fn create_nested(
+    data_type: &DataType,
+    leaves: Vec<(Box<dyn Array>, Vec<Box<dyn Nested>>)>,
+) -> Result<Box<dyn Array>> {
+    let mut leaves = leaves
+        .into_iter()
+        .map(|(array, mut nested)| {
+            close(&mut nested);
+            (Some(array), nested)
+        })
+        .collect::<Vec<_>>();
+    create_array(data_type, &mut leaves, &mut 0, 0)
+}
@@ -0,0 +1,402 @@
+//! Reading of parquet files whose column chunks are decompressed and decoded in parallel.
+//!
+//! [`ParallelReader`] only performs IO: it reads the column chunks of each row group into memory
+//! as raw bytes. The resulting [`RowGroupChunks`] is [`Send`] and is decoded into a
+//! [`RecordBatch`] by an executor that decodes each [`ColumnChunk`], e.g. on a thread pool.
+use std::io::{Read, Seek, SeekFrom};
+use std::sync::Arc;
+
+use super::predicate::Predicate;
+use super::read_metadata;
+use super::record_batch::{num_leaves, project, read_field, GroupFilter};
+use super::FileMetaData;
+use crate::array::Array;
+use crate::datatypes::{Field, Schema};
+use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
+
+/// Column chunks of a file loaded in memory, readable and seekable by their position in the
+/// file. Used to decode column chunks without access to the file they were read from.
+#[derive(Debug, Default)]
+pub(super) struct ColumnChunks {
+    chunks: Vec<(u64, Vec<u8>)>,
+    position: u64,
+}
+
+impl ColumnChunks {
+    /// Adds `data`, the bytes of the file starting at `start`, as a new chunk.
+    pub(super) fn push(&mut self, start: u64, data: Vec<u8>) {
+        self.chunks.push((start, data));
+    }
+
+    /// Reads the bytes `[start, start + length)` of `reader` into a new chunk.
+    fn read_chunk<R: Read + Seek>(
+        &mut self,
+        reader: &mut R,
+        start: u64,
+        length: u64,
+    ) -> Result<()> {
+        reader.seek(SeekFrom::Start(start))?;
+        let mut data = vec![0; length as usize];
+        reader.read_exact(&mut data)?;
+        self.push(start, data);
+        Ok(())
+    }
+}
+
+impl Read for ColumnChunks {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let position = self.position;
+        let data = self.chunks.iter().find_map(|(start, data)| {
+            if *start <= position && position < start + data.len() as u64 {
+                Some(&data[(position - start) as usize..])
+            } else {
+                None
+            }
+        });
+        let length = data
+            .map(|data| {
+                let length = std::cmp::min(buf.len(), data.len());
+                buf[..length].copy_from_slice(&data[..length]);
+                length
+            })
+            .unwrap_or(0);
+        self.position += length as u64;
+        Ok(length)
+    }
+}
+
+impl Seek for ColumnChunks {
+    fn seek(&mut self, position: SeekFrom) -> std::io::Result<u64> {
+        self.position = match position {
+            SeekFrom::Start(position) => position,
+            SeekFrom::Current(offset) => (self.position as i64 + offset) as u64,
+            SeekFrom::End(_) => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    "column chunks can't be seeked from their end",
+                ))
+            }
+        };
+        Ok(self.position)
+    }
+}
+
+/// The still compressed and encoded column chunks of a field in a row group, read into memory.
+pub struct ColumnChunk {
+    metadata: Arc<FileMetaData>,
+    row_group: usize,
+    // the first parquet column of `field`
+    column: usize,
+    field: Field,
+    chunks: ColumnChunks,
+    remaining_rows: usize,
+}
+
+impl ColumnChunk {
+    /// The field of this column chunk.
+    pub fn field(&self) -> &Field {
+        &self.field
+    }
+
+    /// Decompresses and decodes this column chunk into an [`Array`].
+    pub fn decode(mut self) -> Result<Arc<dyn Array>> {
+        read_field(
+            &mut self.chunks,
+            &self.metadata,
+            self.row_group,
+            self.column,
+            &self.field,
+            self.remaining_rows,
+            vec![],
+            vec![],
+        )
+        .map(|(array, _, _)| array)
+    }
+}
+
+/// The column chunks of the fields of a row group, read into memory but not yet decoded.
+pub struct RowGroupChunks {
+    schema: Arc<Schema>,
+    columns: Vec<ColumnChunk>,
+}
+
+impl RowGroupChunks {
+    /// The schema of the [`RecordBatch`] of this row group.
+    pub fn schema(&self) -> &Arc<Schema> {
+        &self.schema
+    }
+
+    /// Decodes this row group into a [`RecordBatch`]. `executor` receives the column chunks
+    /// of the row group, one per field, and must return the result of [`ColumnChunk::decode`]
+    /// of each of them in the same order, e.g.
+    /// `|columns| columns.into_par_iter().map(ColumnChunk::decode).collect()` with rayon.
+    /// # Errors
+    /// This function errors if a column chunk fails to decode or if `executor` returns a
+    /// different number of arrays than it received.
+    pub fn decode<F>(self, executor: F) -> Result<RecordBatch>
+    where
+        F: FnOnce(Vec<ColumnChunk>) -> Vec<Result<Arc<dyn Array>>>,
+    {
+        let num_fields = self.columns.len();
+        let columns = executor(self.columns)
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?;
+        if columns.len() != num_fields {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The executor returned {} arrays for a row group of {} column chunks",
+                columns.len(),
+                num_fields
+            )));
+        }
+        RecordBatch::try_new(self.schema, columns)
+    }
+}
+
+/// Iterator of [`RowGroupChunks`] from a parquet file. Unlike [`super::RecordReader`], it only
+/// reads the column chunks of each row group, leaving their decompression and decoding to
+/// [`RowGroupChunks::decode`], which can run in parallel and in other threads.
+pub struct ParallelReader<R: Read + Seek> {
+    reader: R,
+    schema: Arc<Schema>,
+    // the first parquet column of each field in `schema`
+    indices: Vec<usize>,
+    groups_filter: GroupFilter,
+    metadata: Arc<FileMetaData>,
+    current_group: usize,
+    remaining_rows: usize,
+}
+
+impl<R: Read + Seek> ParallelReader<R> {
+    pub fn try_new(
+        mut reader: R,
+        projection: Option<Vec<usize>>,
+        limit: Option<usize>,
+        groups_filter: GroupFilter,
+    ) -> Result<Self> {
+        let metadata = read_metadata(&mut reader)?;
+        let (schema, indices) = project(&metadata, projection)?;
+
+        Ok(Self {
+            reader,
+            schema,
+            indices,
+            groups_filter,
+            metadata: Arc::new(metadata),
+            current_group: 0,
+            remaining_rows: limit.unwrap_or(usize::MAX),
+        })
+    }
+
+    pub fn schema(&self) -> &Arc<Schema> {
+        &self.schema
+    }
+
+    /// Skips the row groups whose statistics prove that none of their rows satisfy `predicate`,
+    /// in addition to the ones skipped by the `groups_filter` of this reader.
+    /// # Errors
+    /// This function errors if a column of `predicate` is not a non-nested column of the file.
+    pub fn with_predicate(mut self, predicate: Predicate) -> Result<Self> {
+        predicate.validate(self.metadata.schema())?;
+        let groups_filter = self.groups_filter.clone();
+        self.groups_filter =
+            Arc::new(move |index, group| groups_filter(index, group) && predicate.may_match(group));
+        Ok(self)
+    }
+
+    /// Reads the column chunks of the fields of this reader in `row_group`.
+    fn read_row_group(&mut self, row_group: usize) -> Result<RowGroupChunks> {
+        let metadata = self.metadata.clone();
+        let group = &metadata.row_groups[row_group];
+
+        let mut columns = Vec::with_capacity(self.schema.fields().len());
+        for (field, start) in self.schema.fields().iter().zip(self.indices.iter()) {
+            let mut chunks = ColumnChunks::default();
+            for column in *start..*start + num_leaves(field.data_type()) {
+                let (start, length) = group.column(column).byte_range();
+                chunks.read_chunk(&mut self.reader, start, length)?;
+            }
+            columns.push(ColumnChunk {
+                metadata: metadata.clone(),
+                row_group,
+                column: *start,
+                field: field.clone(),
+                chunks,
+                remaining_rows: self.remaining_rows,
+            });
+        }
+
+        self.remaining_rows -= std::cmp::min(group.num_rows() as usize, self.remaining_rows);
+        Ok(RowGroupChunks {
+            schema: self.schema.clone(),
+            columns,
+        })
+    }
+}
+
+impl<R: Read + Seek> Iterator for ParallelReader<R> {
+    type Item = Result<RowGroupChunks>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.schema.fields().is_empty()
+                || self.current_group == self.metadata.row_groups.len()
+                || self.remaining_rows == 0
+            {
+                return None;
+            }
+            let row_group = self.current_group;
+            self.current_group += 1;
+            if (self.groups_filter)(row_group, &self.metadata.row_groups[row_group]) {
+                return Some(self.read_row_group(row_group));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::thread;
+
+    use super::*;
+    use crate::array::*;
+    use crate::bitmap::Bitmap;
+    use crate::buffer::Buffer;
+    use crate::datatypes::DataType;
+    use crate::io::parquet::read::RecordReader;
+    use crate::io::parquet::write::*;
+
+    /// A parquet file with three row groups of a nullable, a required and a list column.
+    fn data() -> Result<Vec<u8>> {
+        let list_type = DataType::List(Box::new(Field::new("item", DataType::Int32, true)));
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, false),
+            Field::new("c", list_type.clone(), true),
+        ]));
+        let batch = |offset: i64| {
+            let a = Int64Array::from(&[Some(offset), None, Some(offset + 2)]);
+            let b = Utf8Array::<i32>::from_slice(&["a", "bb", "ccc"]);
+            let c = ListArray::<i32>::from_data(
+                list_type.clone(),
+                Buffer::from([0, 2, 2, 3]),
+                Arc::new(Int32Array::from(&[Some(1), None, Some(3)])),
+                Some(Bitmap::from([true, false, true])),
+            );
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(a), Arc::new(b), Arc::new(c)])
+        };
+
+        let options = WriteOptions {
+            write_statistics: true,
+            compression: CompressionCodec::Uncompressed,
+            version: Version::V2,
+        };
+        let row_groups = RowGroupIterator::try_new(
+            vec![batch(0), batch(10), batch(20)].into_iter(),
+            &schema,
+            options,
+            vec![Encoding::Plain; 3],
+        )?;
+
+        let mut writer = Cursor::new(vec![]);
+        write_file(
+            &mut writer,
+            row_groups,
+            &schema,
+            to_parquet_schema(&schema)?,
+            options,
+            None,
+        )?;
+        Ok(writer.into_inner())
+    }
+
+    /// Decodes each column chunk in its own thread.
+    fn threads(columns: Vec<ColumnChunk>) -> Vec<Result<Arc<dyn Array>>> {
+        let handles = columns
+            .into_iter()
+            .map(|column| thread::spawn(move || column.decode()))
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    }
+
+    fn round_trip(projection: Option<Vec<usize>>, limit: Option<usize>) -> Result<()> {
+        let data = data()?;
+
+        let expected = RecordReader::try_new(
+            Cursor::new(data.clone()),
+            projection.clone(),
+            limit,
+            Arc::new(|_, _| true),
+        )?
+        .collect::<Result<Vec<_>>>()?;
+
+        let reader =
+            ParallelReader::try_new(Cursor::new(data), projection, limit, Arc::new(|_, _| true))?;
+        // row groups are read in one thread and decoded in another
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let decoder = thread::spawn(move || {
+            receiver
+                .into_iter()
+                .map(|chunks: RowGroupChunks| chunks.decode(threads))
+                .collect::<Result<Vec<_>>>()
+        });
+        for chunks in reader {
+            sender.send(chunks?).unwrap();
+        }
+        drop(sender);
+        let result = decoder.join().unwrap()?;
+
+        assert_eq!(expected.len(), result.len());
+        for (expected, result) in expected.iter().zip(result.iter()) {
+            assert_eq!(expected.schema(), result.schema());
+            assert_eq!(expected.columns(), result.columns());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parallel() -> Result<()> {
+        round_trip(None, None)
+    }
+
+    #[test]
+    fn parallel_projection_limit() -> Result<()> {
+        round_trip(Some(vec![2, 0]), Some(4))
+    }
+
+    #[test]
+    fn filter() -> Result<()> {
+        let data = data()?;
+        let reader = ParallelReader::try_new(
+            Cursor::new(data),
+            Some(vec![0]),
+            None,
+            Arc::new(|index, _| index == 1),
+        )?;
+        let batches = reader
+            .map(|chunks| {
+                chunks?.decode(|columns| columns.into_iter().map(ColumnChunk::decode).collect())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(batches.len(), 1);
+        assert_eq!(
+            batches[0].column(0).as_ref(),
+            &Int64Array::from(&[Some(10), None, Some(12)]) as &dyn Array
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn executor_length() -> Result<()> {
+        let data = data()?;
+        let mut reader =
+            ParallelReader::try_new(Cursor::new(data), None, None, Arc::new(|_, _| true))?;
+        let chunks = reader.next().unwrap()?;
+        assert!(chunks.decode(|_| vec![]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,481 @@
+//! Predicates over the columns of a parquet file, used to skip row groups whose statistics
+//! prove that none of their rows satisfy the predicate.
+use std::any::Any;
+use std::cmp::Ordering;
+
+use parquet2::metadata::{ColumnChunkMetaData, RowGroupMetaData, SchemaDescriptor};
+use parquet2::schema::types::ParquetType;
+
+use super::statistics::{
+    deserialize_statistics, BinaryStatistics, BooleanStatistics, PrimitiveStatistics, Statistics,
+    Utf8Statistics,
+};
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+/// A literal value that a column is compared against.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Literal {
+    Boolean(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    Utf8(String),
+    Binary(Vec<u8>),
+}
+
+impl From<bool> for Literal {
+    fn from(value: bool) -> Self {
+        Literal::Boolean(value)
+    }
+}
+
+macro_rules! literal_from {
+    ($type:ty, $variant:ident, $as:ty) => {
+        impl From<$type> for Literal {
+            fn from(value: $type) -> Self {
+                Literal::$variant(value as $as)
+            }
+        }
+    };
+}
+
+literal_from!(i8, Int, i64);
+literal_from!(i16, Int, i64);
+literal_from!(i32, Int, i64);
+literal_from!(i64, Int, i64);
+literal_from!(u8, UInt, u64);
+literal_from!(u16, UInt, u64);
+literal_from!(u32, UInt, u64);
+literal_from!(u64, UInt, u64);
+literal_from!(f32, Float, f64);
+literal_from!(f64, Float, f64);
+
+impl From<&str> for Literal {
+    fn from(value: &str) -> Self {
+        Literal::Utf8(value.to_string())
+    }
+}
+
+impl From<String> for Literal {
+    fn from(value: String) -> Self {
+        Literal::Utf8(value)
+    }
+}
+
+impl From<Vec<u8>> for Literal {
+    fn from(value: Vec<u8>) -> Self {
+        Literal::Binary(value)
+    }
+}
+
+impl Literal {
+    fn to_i128(&self) -> Option<i128> {
+        match self {
+            Literal::Int(x) => Some(*x as i128),
+            Literal::UInt(x) => Some(*x as i128),
+            _ => None,
+        }
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        match self {
+            Literal::Int(x) => Some(*x as f64),
+            Literal::UInt(x) => Some(*x as f64),
+            Literal::Float(x) => Some(*x),
+            _ => None,
+        }
+    }
+}
+
+/// Compares two literals, returning `None` when they are not comparable.
+fn compare(lhs: &Literal, rhs: &Literal) -> Option<Ordering> {
+    match (lhs, rhs) {
+        (Literal::Boolean(lhs), Literal::Boolean(rhs)) => lhs.partial_cmp(rhs),
+        (Literal::Utf8(lhs), Literal::Utf8(rhs)) => lhs.partial_cmp(rhs),
+        (Literal::Binary(lhs), Literal::Binary(rhs)) => lhs.partial_cmp(rhs),
+        _ => match (lhs.to_i128(), rhs.to_i128()) {
+            (Some(lhs), Some(rhs)) => lhs.partial_cmp(&rhs),
+            _ => lhs.to_f64()?.partial_cmp(&rhs.to_f64()?),
+        },
+    }
+}
+
+/// A comparison between a column and a [`Literal`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+    Eq,
+    Neq,
+}
+
+/// A predicate over the (non-nested) columns of a parquet file.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Predicate {
+    /// Compares the column with the given name against a literal.
+    Compare(String, Operator, Literal),
+    /// Whether the column with the given name is null.
+    IsNull(String),
+    /// Whether the column with the given name is not null.
+    IsNotNull(String),
+    And(Box<Predicate>, Box<Predicate>),
+    Or(Box<Predicate>, Box<Predicate>),
+}
+
+/// The minimum, maximum and null count of a column chunk.
+struct Bounds {
+    null_count: Option<i64>,
+    min_value: Option<Literal>,
+    max_value: Option<Literal>,
+}
+
+fn primitive_bounds<T: NativeType, F: Fn(T) -> Literal>(
+    statistics: &dyn Any,
+    to_literal: F,
+) -> Option<Bounds> {
+    statistics
+        .downcast_ref::<PrimitiveStatistics<T>>()
+        .map(|statistics| Bounds {
+            null_count: statistics.null_count,
+            min_value: statistics.min_value.map(&to_literal),
+            max_value: statistics.max_value.map(&to_literal),
+        })
+}
+
+fn bounds(statistics: &dyn Statistics) -> Option<Bounds> {
+    let any = statistics.as_any();
+    if let Some(statistics) = any.downcast_ref::<BooleanStatistics>() {
+        return Some(Bounds {
+            null_count: statistics.null_count,
+            min_value: statistics.min_value.map(Literal::Boolean),
+            max_value: statistics.max_value.map(Literal::Boolean),
+        });
+    }
+    if let Some(statistics) = any.downcast_ref::<Utf8Statistics>() {
+        return Some(Bounds {
+            null_count: statistics.null_count,
+            min_value: statistics.min_value.clone().map(Literal::Utf8),
+            max_value: statistics.max_value.clone().map(Literal::Utf8),
+        });
+    }
+    if let Some(statistics) = any.downcast_ref::<BinaryStatistics>() {
+        return Some(Bounds {
+            null_count: statistics.null_count,
+            min_value: statistics.min_value.clone().map(Literal::Binary),
+            max_value: statistics.max_value.clone().map(Literal::Binary),
+        });
+    }
+    primitive_bounds::<i8, _>(any, Literal::from)
+        .or_else(|| primitive_bounds::<i16, _>(any, Literal::from))
+        .or_else(|| primitive_bounds::<i32, _>(any, Literal::from))
+        .or_else(|| primitive_bounds::<i64, _>(any, Literal::from))
+        .or_else(|| primitive_bounds::<u8, _>(any, Literal::from))
+        .or_else(|| primitive_bounds::<u16, _>(any, Literal::from))
+        .or_else(|| primitive_bounds::<u32, _>(any, Literal::from))
+        .or_else(|| primitive_bounds::<u64, _>(any, Literal::from))
+        .or_else(|| primitive_bounds::<f32, _>(any, Literal::from))
+        .or_else(|| primitive_bounds::<f64, _>(any, Literal::from))
+}
+
+/// Returns the (non-nested) column `name` of `row_group`, if any.
+fn find_column<'a>(row_group: &'a RowGroupMetaData, name: &str) -> Option<&'a ColumnChunkMetaData> {
+    row_group.columns().iter().find(|column| {
+        let type_ = column.descriptor().base_type();
+        matches!(type_, ParquetType::PrimitiveType { .. }) && type_.get_basic_info().name() == name
+    })
+}
+
+/// Returns the [`Bounds`] of `column`, or `None` if they are not available.
+fn column_bounds(column: &ColumnChunkMetaData) -> Option<Bounds> {
+    let statistics = column.statistics()?.ok()?;
+    let statistics = deserialize_statistics(statistics.as_ref()).ok()?;
+    bounds(statistics.as_ref())
+}
+
+/// Returns whether a column whose values are within `[min_value, max_value]` may contain a value
+/// satisfying `operator` against `value`.
+fn may_match_comparison(
+    operator: Operator,
+    min_value: &Literal,
+    max_value: &Literal,
+    value: &Literal,
+) -> bool {
+    let (min, max) = match (compare(min_value, value), compare(max_value, value)) {
+        (Some(min), Some(max)) => (min, max),
+        // not comparable (e.g. NaN or different types): the row group can't be skipped
+        _ => return true,
+    };
+    match operator {
+        Operator::Eq => min != Ordering::Greater && max != Ordering::Less,
+        Operator::Neq => !(min == Ordering::Equal && max == Ordering::Equal),
+        Operator::Lt => min == Ordering::Less,
+        Operator::LtEq => min != Ordering::Greater,
+        Operator::Gt => max == Ordering::Greater,
+        Operator::GtEq => max != Ordering::Less,
+    }
+}
+
+impl Predicate {
+    /// Returns a predicate comparing the column `column` against `value`.
+    pub fn compare<L: Into<Literal>>(column: &str, operator: Operator, value: L) -> Self {
+        Predicate::Compare(column.to_string(), operator, value.into())
+    }
+
+    /// Returns a predicate that is satisfied when both `self` and `other` are.
+    pub fn and(self, other: Predicate) -> Self {
+        Predicate::And(Box::new(self), Box::new(other))
+    }
+
+    /// Returns a predicate that is satisfied when either `self` or `other` is.
+    pub fn or(self, other: Predicate) -> Self {
+        Predicate::Or(Box::new(self), Box::new(other))
+    }
+
+    /// Errors if a column of this predicate is not a non-nested column of `schema`.
+    pub fn validate(&self, schema: &SchemaDescriptor) -> Result<()> {
+        match self {
+            Predicate::Compare(name, _, _)
+            | Predicate::IsNull(name)
+            | Predicate::IsNotNull(name) => {
+                let exists = schema.fields().iter().any(|type_| {
+                    matches!(type_, ParquetType::PrimitiveType { .. })
+                        && type_.get_basic_info().name() == name
+                });
+                if exists {
+                    Ok(())
+                } else {
+                    Err(ArrowError::InvalidArgumentError(format!(
+                        "The predicate's column \"{}\" is not a non-nested column of the parquet file",
+                        name
+                    )))
+                }
+            }
+            Predicate::And(lhs, rhs) | Predicate::Or(lhs, rhs) => {
+                lhs.validate(schema)?;
+                rhs.validate(schema)
+            }
+        }
+    }
+
+    /// Returns whether some rows of `row_group` may satisfy this predicate. This is `false` only
+    /// when the statistics of `row_group` prove that no row does; missing statistics or columns
+    /// never cause a row group to be skipped.
+    pub fn may_match(&self, row_group: &RowGroupMetaData) -> bool {
+        match self {
+            Predicate::Compare(name, operator, value) => {
+                let column = match find_column(row_group, name) {
+                    Some(column) => column,
+                    None => return true,
+                };
+                let bounds = match column_bounds(column) {
+                    Some(bounds) => bounds,
+                    None => return true,
+                };
+                if bounds.null_count == Some(column.num_values()) {
+                    // only nulls, which do not satisfy any comparison
+                    return false;
+                }
+                match (&bounds.min_value, &bounds.max_value) {
+                    (Some(min_value), Some(max_value)) => {
+                        may_match_comparison(*operator, min_value, max_value, value)
+                    }
+                    _ => true,
+                }
+            }
+            Predicate::IsNull(name) => find_column(row_group, name)
+                .and_then(column_bounds)
+                .and_then(|bounds| bounds.null_count)
+                .map(|null_count| null_count > 0)
+                .unwrap_or(true),
+            Predicate::IsNotNull(name) => find_column(row_group, name)
+                .and_then(|column| {
+                    column_bounds(column)
+                        .and_then(|bounds| bounds.null_count)
+                        .map(|null_count| null_count < column.num_values())
+                })
+                .unwrap_or(true),
+            Predicate::And(lhs, rhs) => lhs.may_match(row_group) && rhs.may_match(row_group),
+            Predicate::Or(lhs, rhs) => lhs.may_match(row_group) || rhs.may_match(row_group),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::array::*;
+    use crate::datatypes::{DataType, Field, Schema};
+    use crate::io::parquet::read::RecordReader;
+    use crate::io::parquet::write::*;
+    use crate::record_batch::RecordBatch;
+
+    /// Writes one row group per batch of `[(int64, utf8)]`.
+    fn write(batches: &[&[(Option<i64>, Option<&str>)]]) -> Result<Vec<u8>> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let batches = batches
+            .iter()
+            .map(|rows| {
+                let a = rows.iter().map(|x| x.0).collect::<Int64Array>();
+                let b = rows.iter().map(|x| x.1).collect::<Utf8Array<i32>>();
+                RecordBatch::try_new(schema.clone(), vec![Arc::new(a), Arc::new(b)])
+            })
+            .collect::<Vec<_>>();
+
+        let options = WriteOptions {
+            write_statistics: true,
+            compression: CompressionCodec::Uncompressed,
+            version: Version::V1,
+        };
+        let row_groups = RowGroupIterator::try_new(
+            batches.into_iter(),
+            &schema,
+            options,
+            vec![Encoding::Plain, Encoding::Plain],
+        )?;
+        let parquet_schema = to_parquet_schema(&schema)?;
+
+        let mut writer = Cursor::new(vec![]);
+        write_file(
+            &mut writer,
+            row_groups,
+            &schema,
+            parquet_schema,
+            options,
+            None,
+        )?;
+        Ok(writer.into_inner())
+    }
+
+    fn read_a(data: Vec<u8>, predicate: Predicate) -> Result<Vec<Option<i64>>> {
+        let reader = RecordReader::try_new(Cursor::new(data), None, None, Arc::new(|_, _| true))?
+            .with_predicate(predicate)?;
+        reader
+            .map(|batch| {
+                let batch = batch?;
+                let a = batch
+                    .column(0)
+                    .as_any()
+                    .downcast_ref::<Int64Array>()
+                    .unwrap();
+                Ok(a.iter().map(|x| x.copied()).collect::<Vec<_>>())
+            })
+            .collect::<Result<Vec<_>>>()
+            .map(|x| x.concat())
+    }
+
+    #[test]
+    fn prune_row_groups() -> Result<()> {
+        let data = write(&[
+            &[(Some(1), Some("a")), (Some(3), Some("b"))],
+            &[(Some(10), Some("c")), (None, Some("d"))],
+            &[(None, None), (None, None)],
+        ])?;
+
+        let predicate = Predicate::compare("a", Operator::Gt, 5i64);
+        assert_eq!(read_a(data.clone(), predicate)?, vec![Some(10), None]);
+
+        let predicate = Predicate::compare("a", Operator::Eq, 2i64).or(Predicate::compare(
+            "b",
+            Operator::GtEq,
+            "d",
+        ));
+        assert_eq!(
+            read_a(data.clone(), predicate)?,
+            vec![Some(1), Some(3), Some(10), None]
+        );
+
+        let predicate = Predicate::compare("a", Operator::Lt, 5i64).and(Predicate::compare(
+            "b",
+            Operator::Eq,
+            "c",
+        ));
+        assert_eq!(read_a(data.clone(), predicate)?, Vec::<Option<i64>>::new());
+
+        let predicate = Predicate::IsNull("a".to_string());
+        assert_eq!(
+            read_a(data.clone(), predicate)?,
+            vec![Some(10), None, None, None]
+        );
+
+        let predicate = Predicate::compare("c", Operator::Eq, 1i64);
+        assert!(read_a(data, predicate).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn comparison() {
+        let min = Literal::Int(1);
+        let max = Literal::Int(10);
+        let cases = [
+            (Operator::Eq, 0i64, false),
+            (Operator::Eq, 5, true),
+            (Operator::Eq, 11, false),
+            (Operator::Lt, 1, false),
+            (Operator::LtEq, 1, true),
+            (Operator::Gt, 10, false),
+            (Operator::GtEq, 10, true),
+            (Operator::Neq, 5, true),
+        ];
+        for (operator, value, expected) in cases.iter() {
+            let value = Literal::from(*value);
+            assert_eq!(
+                may_match_comparison(*operator, &min, &max, &value),
+                *expected,
+                "{:?} {:?}",
+                operator,
+                value
+            );
+        }
+        let value = Literal::Int(1);
+        assert!(!may_match_comparison(Operator::Neq, &value, &value, &value));
+    }
+
+    #[test]
+    fn comparison_mixed_types() {
+        let min = Literal::UInt(1);
+        let max = Literal::UInt(10);
+        assert!(!may_match_comparison(
+            Operator::Lt,
+            &min,
+            &max,
+            &Literal::Int(-1)
+        ));
+        assert!(may_match_comparison(
+            Operator::Lt,
+            &min,
+            &max,
+            &Literal::Float(1.5)
+        ));
+        // incomparable literals never skip
+        assert!(may_match_comparison(Operator::Eq, &min, &max, &"a".into()));
+        assert!(may_match_comparison(
+            Operator::Eq,
+            &min,
+            &max,
+            &f64::NAN.into()
+        ));
+    }
+
+    #[test]
+    fn utf8_comparison() {
+        let min = Literal::from("b");
+        let max = Literal::from("d");
+        assert!(!may_match_comparison(Operator::Eq, &min, &max, &"a".into()));
+        assert!(may_match_comparison(Operator::Eq, &min, &max, &"bb".into()));
+        assert!(!may_match_comparison(
+            Operator::GtEq,
+            &min,
+            &max,
+            &"e".into()
+        ));
+    }
+}
@@ -1,126 +1,54 @@
 use parquet2::{
-    encoding::{bitpacking, hybrid_rle, uleb128, Encoding},
-    read::{levels, Page, PageHeader, PrimitivePageDict},
+    encoding::Encoding,
+    read::{Page, PrimitivePageDict},
+    schema::types::{ParquetType, PhysicalType},
     types::NativeType,
 };
 
+use super::super::encodings;
 use super::super::utils as other_utils;
 use super::utils::ExactChunksIter;
 use super::ColumnDescriptor;
 use crate::{
-    bitmap::{utils::BitmapIter, MutableBitmap},
+    bitmap::MutableBitmap,
     buffer::MutableBuffer,
-    error::Result,
+    error::{ArrowError, Result},
     types::NativeType as ArrowNativeType,
 };
 
-fn read_dict_buffer_optional<T, A, F>(
+/// Extends `values` and `validity` with a page of `additional` slots whose non-null values are
+/// `new_values`.
+fn extend<A, I>(
+    is_optional: bool,
     validity_buffer: &[u8],
-    indices_buffer: &[u8],
     additional: usize,
-    dict: &PrimitivePageDict<T>,
+    new_values: I,
     values: &mut MutableBuffer<A>,
     validity: &mut MutableBitmap,
-    op: F,
-) where
-    T: NativeType,
+) -> Result<()>
+where
     A: ArrowNativeType,
-    F: Fn(T) -> A,
+    I: Iterator<Item = A>,
 {
-    let dict_values = dict.values();
-
-    // SPEC: Data page format: the bit width used to encode the entry ids stored as 1 byte (max bit width = 32),
-    // SPEC: followed by the values encoded using RLE/Bit packed described above (with the given bit width).
-    let bit_width = indices_buffer[0];
-    let indices_buffer = &indices_buffer[1..];
-
-    let (_, consumed) = uleb128::decode(&indices_buffer);
-    let indices_buffer = &indices_buffer[consumed..];
-
-    let non_null_indices_len = indices_buffer.len() * 8 / bit_width as usize;
-
-    let mut indices = bitpacking::Decoder::new(indices_buffer, bit_width, non_null_indices_len);
-
-    let validity_iterator = hybrid_rle::Decoder::new(&validity_buffer, 1);
-
-    for run in validity_iterator {
-        match run {
-            hybrid_rle::HybridEncoded::Bitpacked(packed) => {
-                let remaining = additional - values.len();
-                let len = std::cmp::min(packed.len() * 8, remaining);
-                for is_valid in BitmapIter::new(packed, 0, len) {
-                    let value = if is_valid {
-                        op(dict_values[indices.next().unwrap() as usize])
-                    } else {
-                        A::default()
-                    };
-                    values.push(value);
-                }
-                validity.extend_from_slice(packed, 0, len);
-            }
-            hybrid_rle::HybridEncoded::Rle(value, additional) => {
-                let is_set = value[0] == 1;
-                validity.extend_constant(additional, is_set);
-                if is_set {
-                    (0..additional).for_each(|_| {
-                        let index = indices.next().unwrap() as usize;
-                        let value = op(dict_values[index]);
-                        values.push(value)
-                    })
-                } else {
-                    values.extend_constant(additional, A::default())
-                }
-            }
-        }
-    }
+    encodings::extend_page(
+        is_optional,
+        validity_buffer,
+        additional,
+        validity,
+        new_values,
+        |value| values.push(value.unwrap_or_default()),
+    )
 }
 
-fn read_nullable<T, A, F>(
-    validity_buffer: &[u8],
-    values_buffer: &[u8],
-    additional: usize,
-    values: &mut MutableBuffer<A>,
-    validity: &mut MutableBitmap,
-    op: F,
-) where
-    T: NativeType,
-    A: ArrowNativeType,
-    F: Fn(T) -> A,
-{
-    let mut chunks = ExactChunksIter::<T>::new(values_buffer);
-
-    let validity_iterator = hybrid_rle::Decoder::new(&validity_buffer, 1);
-
-    for run in validity_iterator {
-        match run {
-            hybrid_rle::HybridEncoded::Bitpacked(packed) => {
-                // the pack may contain more items than needed.
-                let remaining = additional - values.len();
-                let len = std::cmp::min(packed.len() * 8, remaining);
-                for is_valid in BitmapIter::new(packed, 0, len) {
-                    let value = if is_valid {
-                        op(chunks.next().unwrap())
-                    } else {
-                        A::default()
-                    };
-                    values.push(value);
-                }
-                validity.extend_from_slice(packed, 0, len);
-            }
-            hybrid_rle::HybridEncoded::Rle(value, additional) => {
-                let is_set = value[0] == 1;
-                validity.extend_constant(additional, is_set);
-                if is_set {
-                    (0..additional).for_each(|_| {
-                        let value = op(chunks.next().unwrap());
-                        values.push(value)
-                    })
-                } else {
-                    values.extend_constant(additional, A::default())
-                }
-            }
-        }
+/// Returns an iterator over the `PLAIN`-encoded values of `values_buffer`.
+fn plain<T: NativeType>(values_buffer: &[u8]) -> Result<ExactChunksIter<'_, T>> {
+    if values_buffer.len() % std::mem::size_of::<T>() != 0 {
+        return Err(ArrowError::ExternalFormat(
+            "Invalid parquet page: the page is not a multiple of the size of its physical type"
+                .to_string(),
+        ));
     }
+    Ok(ExactChunksIter::<T>::new(values_buffer))
 }
 
 fn read_required<T, A, F>(
@@ -128,17 +56,39 @@ fn read_required<T, A, F>(
     additional: usize,
     values: &mut MutableBuffer<A>,
     op: F,
-) where
+) -> Result<()>
+where
     T: NativeType,
     A: ArrowNativeType,
     F: Fn(T) -> A,
 {
-    assert_eq!(values_buffer.len(), additional * std::mem::size_of::<T>());
+    if values_buffer.len() != additional * std::mem::size_of::<T>() {
+        return Err(ArrowError::ExternalFormat(format!(
+            "Invalid parquet page: a required page of {} values has {} bytes",
+            additional,
+            values_buffer.len()
+        )));
+    }
     let iterator = ExactChunksIter::<T>::new(values_buffer);
 
     let iterator = iterator.map(|value| op(value));
 
     values.extend_from_trusted_len_iter(iterator);
+    Ok(())
+}
+
+/// Whether the physical type of `descriptor` is an integer that can be delta-encoded.
+fn is_integer(descriptor: &ColumnDescriptor) -> bool {
+    matches!(
+        descriptor.type_(),
+        ParquetType::PrimitiveType {
+            physical_type: PhysicalType::Int32,
+            ..
+        } | ParquetType::PrimitiveType {
+            physical_type: PhysicalType::Int64,
+            ..
+        }
+    )
 }
 
 pub fn extend_from_page<T, A, F>(
@@ -155,84 +105,71 @@ where
 {
     let additional = page.num_values();
 
-    assert_eq!(descriptor.max_rep_level(), 0);
+    let (version, validity_buffer, values_buffer) = other_utils::split_buffer(page, descriptor)?;
     let is_optional = descriptor.max_def_level() == 1;
-    match page.header() {
-        PageHeader::V1(header) => {
-            assert_eq!(header.definition_level_encoding, Encoding::Rle);
-
-            let (_, validity_buffer, values_buffer) =
-                levels::split_buffer_v1(page.buffer(), false, is_optional);
 
-            match (&page.encoding(), page.dictionary_page(), is_optional) {
-                (Encoding::PlainDictionary, Some(dict), true) => read_dict_buffer_optional(
-                    validity_buffer,
-                    values_buffer,
-                    additional,
-                    dict.as_any().downcast_ref().unwrap(),
-                    values,
-                    validity,
-                    op,
-                ),
-                (Encoding::Plain, None, true) => read_nullable(
-                    validity_buffer,
-                    values_buffer,
-                    additional,
-                    values,
-                    validity,
-                    op,
-                ),
-                (Encoding::Plain, None, false) => {
-                    read_required(page.buffer(), additional, values, op)
-                }
-                _ => {
-                    return Err(other_utils::not_implemented(
-                        &page.encoding(),
-                        is_optional,
-                        page.dictionary_page().is_some(),
-                        "V1",
-                        "primitive",
-                    ))
-                }
-            }
+    match (page.encoding(), page.dictionary_page(), is_optional) {
+        (Encoding::PlainDictionary, Some(dict), _) | (Encoding::RleDictionary, Some(dict), _) => {
+            let dict_values = dict
+                .as_any()
+                .downcast_ref::<PrimitivePageDict<T>>()
+                .ok_or_else(|| other_utils::invalid_dictionary("primitive"))?
+                .values();
+            let indices = encodings::dict_indices(values_buffer, additional)?;
+            encodings::check_indices(&indices, dict_values.len())?;
+            let new_values = indices
+                .into_iter()
+                .map(|index| op(dict_values[index as usize]));
+            extend(
+                is_optional,
+                validity_buffer,
+                additional,
+                new_values,
+                values,
+                validity,
+            )
         }
-        PageHeader::V2(header) => {
-            let def_level_buffer_length = header.definition_levels_byte_length as usize;
-
-            let (_, validity_buffer, values_buffer) =
-                levels::split_buffer_v2(page.buffer(), 0, def_level_buffer_length);
-            match (&page.encoding(), page.dictionary_page(), is_optional) {
-                (Encoding::PlainDictionary, Some(dict), true) => read_dict_buffer_optional(
-                    validity_buffer,
-                    values_buffer,
-                    additional,
-                    dict.as_any().downcast_ref().unwrap(),
-                    values,
-                    validity,
-                    op,
-                ),
-                (Encoding::Plain, None, true) => read_nullable(
-                    validity_buffer,
-                    values_buffer,
-                    additional,
-                    values,
-                    validity,
-                    op,
-                ),
-                (Encoding::Plain, None, false) => {
-                    read_required::<T, A, F>(page.buffer(), additional, values, op)
-                }
-                _ => {
-                    return Err(other_utils::not_implemented(
-                        &page.encoding(),
-                        is_optional,
-                        page.dictionary_page().is_some(),
-                        "V2",
-                        "primitive",
-                    ))
-                }
-            }
+        (Encoding::Plain, None, true) => extend(
+            is_optional,
+            validity_buffer,
+            additional,
+            plain::<T>(values_buffer)?.map(op),
+            values,
+            validity,
+        ),
+        (Encoding::Plain, None, false) => read_required(values_buffer, additional, values, op),
+        (Encoding::DeltaBinaryPacked, None, _) if is_integer(descriptor) => {
+            let (new_values, _) = encodings::delta_bitpacked(values_buffer)?;
+            let new_values = new_values
+                .into_iter()
+                .map(|value| encodings::from_i64::<T>(value).map(&op))
+                .collect::<Result<Vec<_>>>()?;
+            extend(
+                is_optional,
+                validity_buffer,
+                additional,
+                new_values.into_iter(),
+                values,
+                validity,
+            )
         }
-    };
-    Ok(())
+        (Encoding::ByteStreamSplit, None, _) => {
+            let new_values = encodings::byte_stream_split::<T>(values_buffer)?;
+            extend(
+                is_optional,
+                validity_buffer,
+                additional,
+                new_values.into_iter().map(op),
+                values,
+                validity,
+            )
+        }
+        _ => Err(other_utils::not_implemented(
+            &page.encoding(),
+            is_optional,
+            page.dictionary_page().is_some(),
+            version,
+            "primitive",
+        )),
+    }
 }
@@ -2,14 +2,12 @@ mod basic;
 mod nested;
 mod utils;
 
-use std::sync::Arc;
-
 use parquet2::{
     read::{Page, StreamingIterator},
     types::NativeType,
 };
 
-use super::nested_utils::*;
+use super::nested_utils::Nested;
 use super::{ColumnChunkMetaData, ColumnDescriptor};
 use crate::{
     array::{Array, PrimitiveArray},
@@ -54,10 +52,13 @@ where
     )))
 }
 
+/// Reads the leaf column `metadata` of a nested field into a [`PrimitiveArray`] of `data_type`,
+/// extending `nested` with its levels.
 pub fn iter_to_array_nested<T, A, I, E, F>(
     mut iter: I,
     metadata: &ColumnChunkMetaData,
     data_type: DataType,
+    nested: &mut Vec<Box<dyn Nested>>,
     op: F,
 ) -> Result<Box<dyn Array>>
 where
@@ -72,38 +73,20 @@ where
     let mut values = MutableBuffer::<A>::with_capacity(capacity);
     let mut validity = MutableBitmap::with_capacity(capacity);
 
-    let (mut nested, is_nullable) = init_nested(metadata.descriptor().base_type(), capacity);
-
     while let Some(page) = iter.next() {
         nested::extend_from_page(
             page.as_ref().map_err(|x| x.clone())?,
             metadata.descriptor(),
-            is_nullable,
-            &mut nested,
+            nested,
             &mut values,
             &mut validity,
             op,
         )?
     }
 
-    let values = match data_type {
-        DataType::List(ref inner) => Arc::new(PrimitiveArray::<A>::from_data(
-            inner.data_type().clone(),
-            values.into(),
-            validity.into(),
-        )),
-        DataType::LargeList(ref inner) => Arc::new(PrimitiveArray::<A>::from_data(
-            inner.data_type().clone(),
-            values.into(),
-            validity.into(),
-        )),
-        _ => {
-            return Err(ArrowError::NotYetImplemented(format!(
-                "Read nested datatype {:?}",
-                data_type
-            )))
-        }
-    };
-
-    create_list(data_type, &mut nested, values)
+    Ok(Box::new(PrimitiveArray::<A>::from_data(
+        data_type,
+        values.into(),
+        validity.into(),
+    )))
 }
@@ -1,23 +1,27 @@
 use parquet2::{
     encoding::Encoding,
     read::{
-        levels::{get_bit_width, split_buffer_v1, split_buffer_v2, RLEDecoder},
+        levels::{split_buffer_v1, split_buffer_v2},
         Page, PageHeader,
     },
     types::NativeType,
 };
 
-use super::super::nested_utils::extend_offsets;
+use super::super::is_type_nullable;
+use super::super::nested_utils::{extend_offsets, levels, min_def_level};
 use super::ColumnDescriptor;
 use super::{super::utils, utils::ExactChunksIter, Nested};
 use crate::{
-    bitmap::MutableBitmap, buffer::MutableBuffer, error::Result, trusted_len::TrustedLen,
+    bitmap::MutableBitmap,
+    buffer::MutableBuffer,
+    error::{ArrowError, Result},
     types::NativeType as ArrowNativeType,
 };
 
 fn read_values<T, D, G, F, A>(
     def_levels: D,
     max_def: u32,
+    min_def: u32,
     mut new_values: G,
     op: F,
     values: &mut MutableBuffer<A>,
@@ -33,24 +37,13 @@ fn read_values<T, D, G, F, A>(
         if def == max_def {
             values.push(op(new_values.next().unwrap()));
             validity.push(true);
-        } else if def == max_def - 1 {
+        } else if def >= min_def {
             values.push(A::default());
             validity.push(false);
         }
     });
 }
 
-fn read_values_required<T, G, F, A>(new_values: G, op: F, values: &mut MutableBuffer<A>)
-where
-    T: NativeType,
-    G: TrustedLen<Item = T>,
-    A: ArrowNativeType,
-    F: Fn(T) -> A,
-{
-    let iterator = new_values.map(|v| op(v));
-    values.extend_from_trusted_len_iter(iterator);
-}
-
 #[allow(clippy::too_many_arguments)]
 fn read<T, A, F>(
     rep_levels: &[u8],
@@ -59,62 +52,49 @@ fn read<T, A, F>(
     additional: usize,
     rep_level_encoding: (&Encoding, i16),
     def_level_encoding: (&Encoding, i16),
-    is_nullable: bool,
     nested: &mut Vec<Box<dyn Nested>>,
     values: &mut MutableBuffer<A>,
     validity: &mut MutableBitmap,
     op: F,
-) where
+) -> Result<()>
+where
     T: NativeType,
     A: ArrowNativeType,
     F: Fn(T) -> A,
 {
     let new_values = ExactChunksIter::<T>::new(values_buffer);
 
-    let max_rep_level = rep_level_encoding.1 as u32;
     let max_def_level = def_level_encoding.1 as u32;
 
     match (rep_level_encoding.0, def_level_encoding.0) {
         (Encoding::Rle, Encoding::Rle) => {
-            let rep_levels = RLEDecoder::new(
-                rep_levels,
-                get_bit_width(rep_level_encoding.1),
-                additional as u32,
+            let def_levels_iter = levels(def_levels, def_level_encoding.1, additional);
+            read_values(
+                def_levels_iter,
+                max_def_level,
+                min_def_level(nested),
+                new_values,
+                op,
+                values,
+                validity,
             );
-            if is_nullable {
-                let def_levels = RLEDecoder::new(
-                    def_levels,
-                    get_bit_width(def_level_encoding.1),
-                    additional as u32,
-                );
-                read_values(def_levels, max_def_level, new_values, op, values, validity)
-            } else {
-                read_values_required(new_values, op, values)
-            }
 
-            let def_levels = RLEDecoder::new(
-                def_levels,
-                get_bit_width(def_level_encoding.1),
-                additional as u32,
-            );
+            let rep_levels = levels(rep_levels, rep_level_encoding.1, additional);
+            let def_levels = levels(def_levels, def_level_encoding.1, additional);
 
-            extend_offsets(
-                rep_levels,
-                def_levels,
-                is_nullable,
-                max_rep_level,
-                max_def_level,
-                nested,
-            )
+            extend_offsets(rep_levels, def_levels, nested);
+            Ok(())
         }
-        _ => todo!(),
+        (rep, def) => Err(ArrowError::NotYetImplemented(format!(
+            "Decoding nested parquet pages with repetition levels encoded as {:?} and definition levels encoded as {:?}",
+            rep, def
+        ))),
     }
 }
 
 pub fn extend_from_page<T, A, F>(
     page: &Page,
     descriptor: &ColumnDescriptor,
-    is_nullable: bool,
     nested: &mut Vec<Box<dyn Nested>>,
     values: &mut MutableBuffer<A>,
     validity: &mut MutableBitmap,
@@ -152,17 +132,16 @@ where
                             &header.definition_level_encoding,
                             descriptor.max_def_level(),
                         ),
-                        is_nullable,
                         nested,
                         values,
                         validity,
                         op,
-                    )
+                    )?
                 }
                 _ => {
                     return Err(utils::not_implemented(
                         &page.encoding(),
-                        is_nullable,
+                        is_type_nullable(descriptor.type_()),
                         page.dictionary_page().is_some(),
                         "V1",
                         "primitive",
@@ -186,17 +165,16 @@ where
                     additional,
                     (&Encoding::Rle, descriptor.max_rep_level()),
                     (&Encoding::Rle, descriptor.max_def_level()),
-                    is_nullable,
                     nested,
                     values,
                     validity,
                     op,
-                )
+                )?
             }
             _ => {
                 return Err(utils::not_implemented(
                     &page.encoding(),
-                    is_nullable,
+                    is_type_nullable(descriptor.type_()),
                     page.dictionary_page().is_some(),
                     "V2",
                     "primitive",
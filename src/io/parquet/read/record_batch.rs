@@ -1,31 +1,234 @@
 use std::{
     io::{Read, Seek},
-    rc::Rc,
     sync::Arc,
 };
 
 use crate::{
-    datatypes::{Field, Schema},
+    array::Array,
+    datatypes::{DataType, Field, Schema},
     error::{ArrowError, Result},
     record_batch::RecordBatch,
 };
 
 use super::{
-    get_page_iterator, get_schema, page_iter_to_array, read_metadata, Decompressor, FileMetaData,
-    RowGroupMetaData,
+    get_page_iterator, get_schema, page_iter_to_array, page_iter_to_leaf, predicate::Predicate,
+    read_metadata, read_nested, Decompressor, FileMetaData, RowGroupMetaData,
 };
 
-type GroupFilter = Arc<dyn Fn(usize, &RowGroupMetaData) -> bool>;
+pub(super) type GroupFilter = Arc<dyn Fn(usize, &RowGroupMetaData) -> bool + Send + Sync>;
+
+/// Returns the number of parquet columns (leaves) that a field with `data_type` is stored in.
+pub(super) fn num_leaves(data_type: &DataType) -> usize {
+    match data_type {
+        DataType::List(inner) | DataType::LargeList(inner) | DataType::FixedSizeList(inner, _) => {
+            num_leaves(inner.data_type())
+        }
+        DataType::Map(inner, _) => num_leaves(inner.data_type()),
+        DataType::Struct(fields) => fields.iter().map(|x| num_leaves(x.data_type())).sum(),
+        _ => 1,
+    }
+}
+
+/// Reads the column chunk `column` of `row_group` into an [`Array`] of `data_type`.
+fn read_column<R: Read + Seek>(
+    reader: &mut R,
+    metadata: &FileMetaData,
+    row_group: usize,
+    column: usize,
+    data_type: DataType,
+    buffer: Vec<u8>,
+    decompress_buffer: Vec<u8>,
+) -> Result<(Box<dyn Array>, Vec<u8>, Vec<u8>)> {
+    let column_meta = &metadata.row_groups[row_group].columns()[column];
+    let pages = get_page_iterator(metadata, row_group, column, reader, buffer)?;
+    let mut pages = Decompressor::new(pages, decompress_buffer);
+
+    let array = page_iter_to_array(&mut pages, column_meta, data_type)?;
+
+    let (buffer, decompress_buffer) = pages.into_buffers();
+    Ok((array, buffer, decompress_buffer))
+}
+
+/// Reads the nested `field`, whose first leaf is the column chunk `column` of `row_group`, into
+/// an [`Array`].
+fn read_nested_column<R: Read + Seek>(
+    reader: &mut R,
+    metadata: &FileMetaData,
+    row_group: usize,
+    column: usize,
+    field: &Field,
+    buffer: Vec<u8>,
+    decompress_buffer: Vec<u8>,
+) -> Result<(Box<dyn Array>, Vec<u8>, Vec<u8>)> {
+    let capacity = metadata.row_groups[row_group].columns()[column].num_values() as usize;
+    let mut buffers = (buffer, decompress_buffer);
+
+    let array = read_nested(
+        field.data_type(),
+        field.is_nullable(),
+        capacity,
+        |leaf, data_type, nested| {
+            let (buffer, decompress_buffer) = std::mem::take(&mut buffers);
+            let column_meta = &metadata.row_groups[row_group].columns()[column + leaf];
+            let pages = get_page_iterator(metadata, row_group, column + leaf, reader, buffer)?;
+            let mut pages = Decompressor::new(pages, decompress_buffer);
+
+            let array = page_iter_to_leaf(&mut pages, column_meta, data_type, nested)?;
+
+            buffers = pages.into_buffers();
+            Ok(array)
+        },
+    )?;
+
+    let (buffer, decompress_buffer) = buffers;
+    Ok((array, buffer, decompress_buffer))
+}
+
+/// Returns the schema of the fields of `metadata` in `projection` (all fields if `None`) and
+/// the index of the first parquet column of each of them.
+pub(super) fn project(
+    metadata: &FileMetaData,
+    projection: Option<Vec<usize>>,
+) -> Result<(Arc<Schema>, Vec<usize>)> {
+    let schema = get_schema(metadata)?;
+
+    let schema_metadata = schema.metadata;
+    // the index of the first parquet column of each field
+    let columns = schema
+        .fields
+        .iter()
+        .scan(0, |column, field| {
+            let start = *column;
+            *column += num_leaves(field.data_type());
+            Some(start)
+        })
+        .collect::<Vec<_>>();
+    let (indices, fields): (Vec<usize>, Vec<Field>) = if let Some(projection) = &projection {
+        schema
+            .fields
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, f)| {
+                if projection.iter().any(|&i| i == index) {
+                    Some((columns[index], f))
+                } else {
+                    None
+                }
+            })
+            .unzip()
+    } else {
+        columns.into_iter().zip(schema.fields.into_iter()).unzip()
+    };
+
+    if let Some(projection) = &projection {
+        if indices.len() != projection.len() {
+            return Err(ArrowError::InvalidArgumentError(
+                "While reading parquet, some columns in the projection do not exist in the file"
+                    .to_string(),
+            ));
+        }
+    }
+
+    let schema = Arc::new(Schema {
+        fields,
+        metadata: schema_metadata,
+    });
+    Ok((schema, indices))
+}
+
+/// Reads `field`, whose first leaf is the column chunk `column` of `row_group`, truncating it to
+/// at most `remaining_rows` rows.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn read_field<R: Read + Seek>(
+    reader: &mut R,
+    metadata: &FileMetaData,
+    row_group: usize,
+    column: usize,
+    field: &Field,
+    remaining_rows: usize,
+    buffer: Vec<u8>,
+    decompress_buffer: Vec<u8>,
+) -> Result<(Arc<dyn Array>, Vec<u8>, Vec<u8>)> {
+    let (array, b1, b2) = match field.data_type() {
+        DataType::List(_)
+        | DataType::LargeList(_)
+        | DataType::FixedSizeList(_, _)
+        | DataType::Map(_, _)
+        | DataType::Struct(_) => read_nested_column(
+            reader,
+            metadata,
+            row_group,
+            column,
+            field,
+            buffer,
+            decompress_buffer,
+        )?,
+        _ => read_column(
+            reader,
+            metadata,
+            row_group,
+            column,
+            field.data_type().clone(),
+            buffer,
+            decompress_buffer,
+        )?,
+    };
+
+    let array = if array.len() > remaining_rows {
+        array.slice(0, remaining_rows)
+    } else {
+        array
+    };
+    Ok((array.into(), b1, b2))
+}
+
+/// Reads the fields of `schema` from `row_group`, where `indices` is the first parquet column of
+/// each field, truncating them to at most `remaining_rows` rows.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn read_columns<R: Read + Seek>(
+    reader: &mut R,
+    metadata: &FileMetaData,
+    row_group: usize,
+    schema: &Schema,
+    indices: &[usize],
+    remaining_rows: usize,
+    buffer: Vec<u8>,
+    decompress_buffer: Vec<u8>,
+) -> Result<(Vec<Arc<dyn Array>>, Vec<u8>, Vec<u8>)> {
+    schema.fields().iter().zip(indices.iter()).try_fold(
+        (
+            Vec::with_capacity(schema.fields().len()),
+            buffer,
+            decompress_buffer,
+        ),
+        |(mut columns, b1, b2), (field, column)| {
+            // column according to the file's indexing
+            let (array, b1, b2) = read_field(
+                reader,
+                metadata,
+                row_group,
+                *column,
+                field,
+                remaining_rows,
+                b1,
+                b2,
+            )?;
+            columns.push(array);
+            Result::Ok((columns, b1, b2))
+        },
+    )
+}
 
 /// Single threaded iterator of [`RecordBatch`] from a parquet file.
 pub struct RecordReader<R: Read + Seek> {
     reader: R,
     schema: Arc<Schema>,
-    indices: Rc<Vec<usize>>,
+    // the first parquet column of each field in `schema`
+    indices: Arc<Vec<usize>>,
     buffer: Vec<u8>,
     decompress_buffer: Vec<u8>,
     groups_filter: GroupFilter,
-    metadata: Rc<FileMetaData>,
+    metadata: Arc<FileMetaData>,
     current_group: usize,
     remaining_rows: usize,
 }
@@ -38,44 +241,14 @@ impl<R: Read + Seek> RecordReader<R> {
         groups_filter: GroupFilter,
     ) -> Result<Self> {
         let metadata = read_metadata(&mut reader)?;
-
-        let schema = get_schema(&metadata)?;
-
-        let schema_metadata = schema.metadata;
-        let (indices, fields): (Vec<usize>, Vec<Field>) = if let Some(projection) = &projection {
-            schema
-                .fields
-                .into_iter()
-                .enumerate()
-                .filter_map(|(index, f)| {
-                    if projection.iter().any(|&i| i == index) {
-                        Some((index, f))
-                    } else {
-                        None
-                    }
-                })
-                .unzip()
-        } else {
-            schema.fields.into_iter().enumerate().unzip()
-        };
-
-        if let Some(projection) = &projection {
-            if indices.len() != projection.len() {
-                return Err(ArrowError::InvalidArgumentError("While reading parquet, some columns in the projection do not exist in the file".to_string()));
-            }
-        }
-
-        let schema = Arc::new(Schema {
-            fields,
-            metadata: schema_metadata,
-        });
+        let (schema, indices) = project(&metadata, projection)?;
 
         Ok(Self {
             reader,
             schema,
-            indices: Rc::new(indices),
+            indices: Arc::new(indices),
             groups_filter,
-            metadata: Rc::new(metadata),
+            metadata: Arc::new(metadata),
             current_group: 0,
             buffer: vec![],
             decompress_buffer: vec![],
@@ -86,6 +259,18 @@ impl<R: Read + Seek> RecordReader<R> {
     pub fn schema(&self) -> &Arc<Schema> {
         &self.schema
     }
+
+    /// Skips the row groups whose statistics prove that none of their rows satisfy `predicate`,
+    /// in addition to the ones skipped by the `groups_filter` of this reader.
+    /// # Errors
+    /// This function errors if a column of `predicate` is not a non-nested column of the file.
+    pub fn with_predicate(mut self, predicate: Predicate) -> Result<Self> {
+        predicate.validate(self.metadata.schema())?;
+        let groups_filter = self.groups_filter.clone();
+        self.groups_filter =
+            Arc::new(move |index, group| groups_filter(index, group) && predicate.may_match(group));
+        Ok(self)
+    }
 }
 
 impl<R: Read + Seek> Iterator for RecordReader<R> {
@@ -110,39 +295,23 @@ impl<R: Read + Seek> Iterator for RecordReader<R> {
             self.current_group += 1;
             return self.next();
         }
-        let columns_meta = group.columns();
-
-        // todo: avoid these clones.
-        let schema = self.schema().clone();
 
         let b1 = std::mem::take(&mut self.buffer);
         let b2 = std::mem::take(&mut self.decompress_buffer);
 
-        let a = schema.fields().iter().enumerate().try_fold(
-            (b1, b2, Vec::with_capacity(schema.fields().len())),
-            |(b1, b2, mut columns), (column, field)| {
-                // column according to the file's indexing
-                let column = self.indices[column];
-                let column_meta = &columns_meta[column];
-                let pages = get_page_iterator(&metadata, row_group, column, &mut self.reader, b1)?;
-                let mut pages = Decompressor::new(pages, b2);
-
-                let array = page_iter_to_array(&mut pages, column_meta, field.data_type().clone())?;
-
-                let array = if array.len() > remaining_rows {
-                    array.slice(0, remaining_rows)
-                } else {
-                    array
-                };
-
-                columns.push(array.into());
-                let (b1, b2) = pages.into_buffers();
-                Result::Ok((b1, b2, columns))
-            },
+        let a = read_columns(
+            &mut self.reader,
+            &metadata,
+            row_group,
+            &self.schema,
+            &self.indices,
+            remaining_rows,
+            b1,
+            b2,
         );
 
         self.current_group += 1;
-        Some(a.and_then(|(b1, b2, columns)| {
+        Some(a.and_then(|(columns, b1, b2)| {
             self.buffer = b1;
             self.decompress_buffer = b2;
             RecordBatch::try_new(self.schema.clone(), columns).map(|batch| {
@@ -273,6 +273,10 @@ fn to_group_type_inner(
     match (logical_type, converted_type) {
         (Some(LogicalType::LIST(_)), _) => to_list(fields, parent_name),
         (None, Some(GroupConvertedType::List)) => to_list(fields, parent_name),
+        (Some(LogicalType::MAP(_)), _) => to_map(fields),
+        (None, Some(GroupConvertedType::Map)) | (None, Some(GroupConvertedType::MapKeyValue)) => {
+            to_map(fields)
+        }
         _ => to_struct(fields),
     }
 }
@@ -293,6 +297,26 @@ fn to_struct(fields: &[ParquetType]) -> Result<Option<DataType>> {
         })
 }
 
+/// Converts a parquet map to arrow map.
+///
+/// A parquet map is a group with a single repeated group (normally named "key_value") whose
+/// fields are the keys and values of the map.
+fn to_map(fields: &[ParquetType]) -> Result<Option<DataType>> {
+    let inner = match fields.first() {
+        Some(ParquetType::GroupType {
+            basic_info, fields, ..
+        }) if fields.len() == 2 && basic_info.repetition() == &Repetition::Repeated => {
+            to_struct(fields)?.map(|dt| Field::new(basic_info.name(), dt, false))
+        }
+        _ => {
+            return Err(ArrowError::ExternalFormat(
+                "A parquet MAP must contain a single repeated group with 2 fields".to_string(),
+            ))
+        }
+    };
+    Ok(inner.map(|field| DataType::Map(Box::new(field), false)))
+}
+
 /// Entry point for converting parquet group type.
 ///
 /// This function takes care of logical type and repetition.
@@ -837,6 +861,52 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parquet_map() -> Result<()> {
+        let entries = |value_nullable| {
+            Box::new(Field::new(
+                "key_value",
+                DataType::Struct(vec![
+                    Field::new("key", DataType::Utf8, false),
+                    Field::new("value", DataType::Int32, value_nullable),
+                ]),
+                false,
+            ))
+        };
+        let arrow_fields = vec![
+            Field::new("map1", DataType::Map(entries(true), false), true),
+            Field::new("map2", DataType::Map(entries(false), false), false),
+        ];
+
+        let message_type = "
+        message test_schema {
+          OPTIONAL GROUP map1 (MAP) {
+            REPEATED GROUP key_value {
+              REQUIRED BINARY key (UTF8);
+              OPTIONAL INT32 value;
+            }
+          }
+          REQUIRED GROUP map2 (MAP_KEY_VALUE) {
+            REPEATED GROUP key_value {
+              REQUIRED BINARY key (UTF8);
+              REQUIRED INT32 value;
+            }
+          }
+        }
+        ";
+
+        let parquet_schema = SchemaDescriptor::try_from_message(message_type)?;
+        let converted_arrow_schema = parquet_to_arrow_schema(&parquet_schema, &None)?;
+
+        let converted_fields = converted_arrow_schema.fields();
+
+        assert_eq!(arrow_fields.len(), converted_fields.len());
+        for i in 0..arrow_fields.len() {
+            assert_eq!(arrow_fields[i], converted_fields[i]);
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_repeated_nested_schema() -> Result<()> {
         let mut arrow_fields = Vec::new();
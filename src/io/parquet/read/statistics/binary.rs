@@ -20,6 +20,10 @@ impl Statistics for BinaryStatistics {
     fn data_type(&self) -> &DataType {
         &DataType::Binary
     }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
 }
 
 impl From<&ParquetByteArrayStatistics> for BinaryStatistics {
@@ -45,6 +49,10 @@ impl Statistics for Utf8Statistics {
     fn data_type(&self) -> &DataType {
         &DataType::Utf8
     }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
 }
 
 impl TryFrom<&ParquetByteArrayStatistics> for Utf8Statistics {
@@ -16,6 +16,8 @@ pub use boolean::*;
 /// Trait denoting a deserialized parquet statistics (into arrow).
 pub trait Statistics: std::fmt::Debug {
     fn data_type(&self) -> &DataType;
+
+    fn as_any(&self) -> &dyn std::any::Any;
 }
 
 impl PartialEq for &dyn Statistics {
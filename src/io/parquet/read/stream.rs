@@ -0,0 +1,350 @@
+//! Asynchronous reading of parquet files from [`AsyncRead`] + [`AsyncSeek`] readers.
+use std::io::SeekFrom;
+use std::sync::Arc;
+
+use futures::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
+use futures::stream::{self, Stream};
+
+use super::parallel::ColumnChunks;
+use super::record_batch::{num_leaves, project, read_columns, GroupFilter};
+use super::{get_page_iterator, read_metadata, CompressedPage, FileMetaData};
+use crate::datatypes::Schema;
+use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
+
+const PARQUET_MAGIC: [u8; 4] = [b'P', b'A', b'R', b'1'];
+const FOOTER_SIZE: u64 = 8;
+
+impl ColumnChunks {
+    /// Reads the bytes `[start, start + length)` of `reader` into a new chunk.
+    async fn read_chunk_async<R: AsyncRead + AsyncSeek + Unpin>(
+        &mut self,
+        reader: &mut R,
+        start: u64,
+        length: u64,
+    ) -> Result<()> {
+        reader.seek(SeekFrom::Start(start)).await?;
+        let mut data = vec![0; length as usize];
+        reader.read_exact(&mut data).await?;
+        self.push(start, data);
+        Ok(())
+    }
+}
+
+/// Reads parquets' metadata asynchronously.
+pub async fn read_metadata_async<R: AsyncRead + AsyncSeek + Unpin>(
+    reader: &mut R,
+) -> Result<FileMetaData> {
+    let file_size = reader.seek(SeekFrom::End(0)).await?;
+    if file_size < PARQUET_MAGIC.len() as u64 + FOOTER_SIZE {
+        return Err(ArrowError::ExternalFormat(
+            "A parquet file must have a header and a footer".to_string(),
+        ));
+    }
+
+    reader.seek(SeekFrom::End(-(FOOTER_SIZE as i64))).await?;
+    let mut footer = [0; FOOTER_SIZE as usize];
+    reader.read_exact(&mut footer).await?;
+    if footer[4..] != PARQUET_MAGIC {
+        return Err(ArrowError::ExternalFormat(
+            "The footer of the parquet file is not \"PAR1\"".to_string(),
+        ));
+    }
+    let metadata_length = u32::from_le_bytes([footer[0], footer[1], footer[2], footer[3]]) as u64;
+    if metadata_length + FOOTER_SIZE + PARQUET_MAGIC.len() as u64 > file_size {
+        return Err(ArrowError::ExternalFormat(
+            "The metadata length of the parquet file is larger than the file".to_string(),
+        ));
+    }
+
+    // the metadata and footer, preceded by the header so that they form a valid file
+    let mut data = vec![0; PARQUET_MAGIC.len() + (metadata_length + FOOTER_SIZE) as usize];
+    data[..PARQUET_MAGIC.len()].copy_from_slice(&PARQUET_MAGIC);
+    reader
+        .seek(SeekFrom::End(-((metadata_length + FOOTER_SIZE) as i64)))
+        .await?;
+    reader.read_exact(&mut data[PARQUET_MAGIC.len()..]).await?;
+
+    read_metadata(&mut std::io::Cursor::new(data))
+}
+
+/// Returns a stream of the compressed pages of the column chunk `column` of `row_group`. The
+/// column chunk is read asynchronously into memory.
+pub async fn get_page_stream<R: AsyncRead + AsyncSeek + Unpin>(
+    metadata: &FileMetaData,
+    row_group: usize,
+    column: usize,
+    reader: &mut R,
+) -> Result<impl Stream<Item = Result<CompressedPage>>> {
+    let (start, length) = metadata.row_groups[row_group].column(column).byte_range();
+    let mut chunks = ColumnChunks::default();
+    chunks.read_chunk_async(reader, start, length).await?;
+
+    let pages = get_page_iterator(metadata, row_group, column, &mut chunks, vec![])?
+        .map(|page| page.map_err(ArrowError::from))
+        .collect::<Vec<_>>();
+    Ok(stream::iter(pages))
+}
+
+/// Asynchronous counterpart of [`super::RecordReader`]: a [`Stream`] of [`RecordBatch`] from a
+/// parquet file read from an [`AsyncRead`] + [`AsyncSeek`] reader.
+pub struct RecordStream<R: AsyncRead + AsyncSeek + Unpin> {
+    reader: R,
+    schema: Arc<Schema>,
+    // the first parquet column of each field in `schema`
+    indices: Vec<usize>,
+    buffer: Vec<u8>,
+    decompress_buffer: Vec<u8>,
+    groups_filter: GroupFilter,
+    metadata: FileMetaData,
+    current_group: usize,
+    remaining_rows: usize,
+}
+
+impl<R: AsyncRead + AsyncSeek + Unpin> RecordStream<R> {
+    pub async fn try_new(
+        mut reader: R,
+        projection: Option<Vec<usize>>,
+        limit: Option<usize>,
+        groups_filter: GroupFilter,
+    ) -> Result<Self> {
+        let metadata = read_metadata_async(&mut reader).await?;
+        let (schema, indices) = project(&metadata, projection)?;
+
+        Ok(Self {
+            reader,
+            schema,
+            indices,
+            groups_filter,
+            metadata,
+            current_group: 0,
+            buffer: vec![],
+            decompress_buffer: vec![],
+            remaining_rows: limit.unwrap_or(usize::MAX),
+        })
+    }
+
+    pub fn schema(&self) -> &Arc<Schema> {
+        &self.schema
+    }
+
+    /// Reads the column chunks of the fields of this stream in `row_group` and decodes them.
+    async fn read_row_group(&mut self, row_group: usize) -> Result<RecordBatch> {
+        let mut chunks = ColumnChunks::default();
+        for (field, start) in self.schema.fields().iter().zip(self.indices.iter()) {
+            for column in *start..*start + num_leaves(field.data_type()) {
+                let (start, length) = self.metadata.row_groups[row_group]
+                    .column(column)
+                    .byte_range();
+                chunks
+                    .read_chunk_async(&mut self.reader, start, length)
+                    .await?;
+            }
+        }
+
+        let b1 = std::mem::take(&mut self.buffer);
+        let b2 = std::mem::take(&mut self.decompress_buffer);
+        let (columns, b1, b2) = read_columns(
+            &mut chunks,
+            &self.metadata,
+            row_group,
+            &self.schema,
+            &self.indices,
+            self.remaining_rows,
+            b1,
+            b2,
+        )?;
+        self.buffer = b1;
+        self.decompress_buffer = b2;
+
+        let batch = RecordBatch::try_new(self.schema.clone(), columns)?;
+        self.remaining_rows -= batch.num_rows();
+        Ok(batch)
+    }
+
+    /// Returns the next [`RecordBatch`], or `None` when there are no more row groups to read.
+    pub async fn next_batch(&mut self) -> Option<Result<RecordBatch>> {
+        loop {
+            if self.schema.fields().is_empty()
+                || self.current_group == self.metadata.row_groups.len()
+                || self.remaining_rows == 0
+            {
+                return None;
+            }
+            let row_group = self.current_group;
+            self.current_group += 1;
+            if (self.groups_filter)(row_group, &self.metadata.row_groups[row_group]) {
+                return Some(self.read_row_group(row_group).await);
+            }
+        }
+    }
+
+    /// Converts this into a [`Stream`] of [`RecordBatch`].
+    pub fn into_stream(self) -> impl Stream<Item = Result<RecordBatch>> {
+        stream::unfold(self, |mut reader| async move {
+            reader.next_batch().await.map(|batch| (batch, reader))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use futures::io::Cursor;
+    use futures::StreamExt;
+
+    use super::*;
+    use crate::array::*;
+    use crate::bitmap::Bitmap;
+    use crate::buffer::Buffer;
+    use crate::datatypes::{DataType, Field};
+    use crate::io::parquet::read::{get_schema, RecordReader};
+    use crate::io::parquet::write::*;
+
+    /// A parquet file with two row groups of a nullable, a required and a list column.
+    fn data() -> Result<Vec<u8>> {
+        let list_type = DataType::List(Box::new(Field::new("item", DataType::Int32, true)));
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, false),
+            Field::new("c", list_type.clone(), true),
+        ]));
+        let batch = |offset: i64| {
+            let a = Int64Array::from(&[Some(offset), None, Some(offset + 2)]);
+            let b = Utf8Array::<i32>::from_slice(&["a", "bb", "ccc"]);
+            let c = ListArray::<i32>::from_data(
+                list_type.clone(),
+                Buffer::from(&[0, 2, 2, 3]),
+                Arc::new(Int32Array::from(&[Some(1), None, Some(3)])),
+                Some(Bitmap::from([true, false, true])),
+            );
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(a), Arc::new(b), Arc::new(c)])
+        };
+
+        let options = WriteOptions {
+            write_statistics: true,
+            compression: CompressionCodec::Uncompressed,
+            version: Version::V2,
+        };
+        let row_groups = RowGroupIterator::try_new(
+            vec![batch(0), batch(10)].into_iter(),
+            &schema,
+            options,
+            vec![Encoding::Plain; 3],
+        )?;
+
+        let mut writer = std::io::Cursor::new(vec![]);
+        write_file(
+            &mut writer,
+            row_groups,
+            &schema,
+            to_parquet_schema(&schema)?,
+            options,
+            None,
+        )?;
+        Ok(writer.into_inner())
+    }
+
+    #[test]
+    fn metadata() -> Result<()> {
+        let data = data()?;
+
+        let sync = read_metadata(&mut std::io::Cursor::new(data.clone()))?;
+        let metadata = block_on(read_metadata_async(&mut Cursor::new(data)))?;
+
+        assert_eq!(metadata.row_groups.len(), sync.row_groups.len());
+        assert_eq!(get_schema(&metadata)?, get_schema(&sync)?);
+        Ok(())
+    }
+
+    #[test]
+    fn metadata_invalid() {
+        let result = block_on(read_metadata_async(&mut Cursor::new(b"PAR1".to_vec())));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn page_stream() -> Result<()> {
+        let data = data()?;
+        let mut reader = Cursor::new(data);
+        let metadata = block_on(read_metadata_async(&mut reader))?;
+
+        let pages = block_on(async {
+            let pages = get_page_stream(&metadata, 0, 0, &mut reader).await?;
+            Result::Ok(pages.collect::<Vec<_>>().await)
+        })?;
+        assert!(!pages.is_empty());
+        for page in pages {
+            page?;
+        }
+        Ok(())
+    }
+
+    fn round_trip(projection: Option<Vec<usize>>, limit: Option<usize>) -> Result<()> {
+        let data = data()?;
+
+        let expected = RecordReader::try_new(
+            std::io::Cursor::new(data.clone()),
+            projection.clone(),
+            limit,
+            Arc::new(|_, _| true),
+        )?
+        .collect::<Result<Vec<_>>>()?;
+
+        let result = block_on(async {
+            let reader =
+                RecordStream::try_new(Cursor::new(data), projection, limit, Arc::new(|_, _| true))
+                    .await?;
+            reader
+                .into_stream()
+                .collect::<Vec<_>>()
+                .await
+                .into_iter()
+                .collect::<Result<Vec<_>>>()
+        })?;
+
+        assert_eq!(expected.len(), result.len());
+        for (expected, result) in expected.iter().zip(result.iter()) {
+            assert_eq!(expected.schema(), result.schema());
+            assert_eq!(expected.columns(), result.columns());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn record_stream() -> Result<()> {
+        round_trip(None, None)
+    }
+
+    #[test]
+    fn record_stream_projection_limit() -> Result<()> {
+        round_trip(Some(vec![0, 2]), Some(4))
+    }
+
+    #[test]
+    fn record_stream_filter() -> Result<()> {
+        let data = data()?;
+        let batches = block_on(async {
+            let reader = RecordStream::try_new(
+                Cursor::new(data),
+                None,
+                None,
+                Arc::new(|index, _| index == 1),
+            )
+            .await?;
+            reader
+                .into_stream()
+                .collect::<Vec<_>>()
+                .await
+                .into_iter()
+                .collect::<Result<Vec<_>>>()
+        })?;
+        assert_eq!(batches.len(), 1);
+        let a = batches[0].column(0);
+        assert_eq!(
+            a.as_ref(),
+            &Int64Array::from(&[Some(10), None, Some(12)]) as &dyn Array
+        );
+        Ok(())
+    }
+}
@@ -1,6 +1,13 @@
-use parquet2::{encoding::get_length, schema::Encoding};
+use std::convert::TryInto;
 
-use crate::error::ArrowError;
+use parquet2::{
+    encoding::get_length,
+    metadata::ColumnDescriptor,
+    read::{levels, Page, PageHeader},
+    schema::Encoding,
+};
+
+use crate::error::{ArrowError, Result};
 
 pub struct BinaryIter<'a> {
     values: &'a [u8],
@@ -17,13 +24,18 @@ impl<'a> Iterator for BinaryIter<'a> {
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.values.is_empty() {
+        if self.values.len() < 4 {
             return None;
         }
         let length = get_length(self.values) as usize;
-        self.values = &self.values[4..];
-        let result = &self.values[..length];
-        self.values = &self.values[length..];
+        let values = &self.values[4..];
+        if length > values.len() {
+            // a truncated value ends the iterator
+            self.values = &[];
+            return None;
+        }
+        let (result, values) = values.split_at(length);
+        self.values = values;
         Some(result)
     }
 }
@@ -42,3 +54,70 @@ pub fn not_implemented(
         encoding, dict, required, version, physical_type
     ))
 }
+
+/// Splits the buffer of a `page` of a non-nested column into its definition levels, which encode
+/// the validity of its values, and its values. Returns them together with the page's version.
+/// # Errors
+/// This function errors if the column is nested, if the definition levels are not RLE-encoded
+/// or if the buffer is shorter than its definition levels.
+pub fn split_buffer<'a>(
+    page: &'a Page,
+    descriptor: &ColumnDescriptor,
+) -> Result<(&'static str, &'a [u8], &'a [u8])> {
+    if descriptor.max_rep_level() != 0 || descriptor.max_def_level() > 1 {
+        return Err(ArrowError::InvalidArgumentError(
+            "Nested parquet columns can't be read as non-nested arrays".to_string(),
+        ));
+    }
+    let is_optional = descriptor.max_def_level() == 1;
+    let buffer = page.buffer();
+    let invalid_levels = || {
+        ArrowError::ExternalFormat(
+            "Invalid parquet page: the definition levels are longer than the page".to_string(),
+        )
+    };
+    match page.header() {
+        PageHeader::V1(header) => {
+            if header.definition_level_encoding != Encoding::Rle {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Decoding \"{:?}\"-encoded definition levels is not yet implemented",
+                    header.definition_level_encoding
+                )));
+            }
+            if is_optional {
+                let length = buffer
+                    .get(..4)
+                    .map(|x| u32::from_le_bytes(x.try_into().unwrap()) as usize)
+                    .ok_or_else(invalid_levels)?;
+                if length > buffer.len() - 4 {
+                    return Err(invalid_levels());
+                }
+            }
+            let (_, validity, values) = levels::split_buffer_v1(buffer, false, is_optional);
+            Ok(("V1", validity, values))
+        }
+        PageHeader::V2(header) => {
+            let rep_level_buffer_length = header.repetition_levels_byte_length as usize;
+            let def_level_buffer_length = header.definition_levels_byte_length as usize;
+            if rep_level_buffer_length != 0 {
+                return Err(ArrowError::ExternalFormat(
+                    "Invalid parquet page: a page of a non-nested column has repetition levels"
+                        .to_string(),
+                ));
+            }
+            if def_level_buffer_length > buffer.len() {
+                return Err(invalid_levels());
+            }
+            let (_, validity, values) = levels::split_buffer_v2(buffer, 0, def_level_buffer_length);
+            Ok(("V2", validity, values))
+        }
+    }
+}
+
+/// The error of a dictionary-encoded page whose dictionary does not match its physical type.
+pub fn invalid_dictionary(physical_type: &str) -> ArrowError {
+    ArrowError::ExternalFormat(format!(
+        "Invalid parquet page: the dictionary of a {} page is of another physical type",
+        physical_type
+    ))
+}
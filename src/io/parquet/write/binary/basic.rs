@@ -1,14 +1,15 @@
 use parquet2::{
+    encoding::Encoding,
     metadata::ColumnDescriptor,
     read::CompressedPage,
     statistics::{serialize_statistics, BinaryStatistics, ParquetStatistics, Statistics},
     write::WriteOptions,
 };
 
-use super::super::utils;
+use super::super::{encodings, utils};
 use crate::{
     array::{Array, BinaryArray, Offset},
-    error::Result,
+    error::{ArrowError, Result},
     io::parquet::read::is_type_nullable,
 };
 
@@ -37,10 +38,22 @@ pub(super) fn encode_plain<O: Offset>(
     }
 }
 
+/// Returns the values of `array`, without the nulls when `is_optional`.
+fn to_values<O: Offset>(array: &BinaryArray<O>, is_optional: bool) -> Vec<&[u8]> {
+    if is_optional {
+        array.iter().flatten().map(|x| x).collect()
+    } else {
+        array.values_iter().map(|x| x).collect()
+    }
+}
+
+/// Writes `array` to a page whose values are encoded with `encoding`, one of `PLAIN`,
+/// `DELTA_LENGTH_BYTE_ARRAY` and `DELTA_BYTE_ARRAY`.
 pub fn array_to_page<O: Offset>(
     array: &BinaryArray<O>,
     options: WriteOptions,
     descriptor: ColumnDescriptor,
+    encoding: Encoding,
 ) -> Result<CompressedPage> {
     let validity = array.validity();
     let is_optional = is_type_nullable(descriptor.type_());
@@ -56,7 +69,23 @@ pub fn array_to_page<O: Offset>(
 
     let definition_levels_byte_length = buffer.len();
 
-    encode_plain(array, is_optional, &mut buffer);
+    match encoding {
+        Encoding::Plain => encode_plain(array, is_optional, &mut buffer),
+        Encoding::DeltaLengthByteArray => {
+            let values = to_values(array, is_optional);
+            encodings::delta_length_byte_array(&values, &mut buffer)
+        }
+        Encoding::DeltaByteArray => {
+            let values = to_values(array, is_optional);
+            encodings::delta_byte_array(&values, &mut buffer)
+        }
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Writing binary parquet pages with encoding {:?}",
+                other
+            )))
+        }
+    }
 
     let uncompressed_page_size = buffer.len();
 
@@ -68,7 +97,7 @@ pub fn array_to_page<O: Offset>(
         None
     };
 
-    utils::build_plain_page(
+    utils::build_page(
         buffer,
         array.len(),
         array.null_count(),
@@ -78,10 +107,14 @@ pub fn array_to_page<O: Offset>(
         statistics,
         descriptor,
         options,
+        encoding,
+        None,
     )
 }
 
-pub(super) fn build_statistics<O: Offset>(
+/// Builds the statistics of `array`, whose values are ordered lexicographically as unsigned
+/// bytes.
+pub fn build_statistics<O: Offset>(
     array: &BinaryArray<O>,
     descriptor: ColumnDescriptor,
 ) -> ParquetStatistics {
@@ -89,36 +122,8 @@ pub(super) fn build_statistics<O: Offset>(
         descriptor,
         null_count: Some(array.null_count() as i64),
         distinct_count: None,
-        max_value: array
-            .iter()
-            .flatten()
-            .max_by(|x, y| ord_binary(x, y))
-            .map(|x| x.to_vec()),
-        min_value: array
-            .iter()
-            .flatten()
-            .min_by(|x, y| ord_binary(x, y))
-            .map(|x| x.to_vec()),
+        max_value: array.iter().flatten().max().map(|x| x.to_vec()),
+        min_value: array.iter().flatten().min().map(|x| x.to_vec()),
     } as &dyn Statistics;
     serialize_statistics(statistics)
 }
-
-/// Returns the ordering of two binary values. This corresponds to pyarrows' ordering
-/// of statistics.
-pub(crate) fn ord_binary<'a>(a: &'a [u8], b: &'a [u8]) -> std::cmp::Ordering {
-    use std::cmp::Ordering::*;
-    match (a.is_empty(), b.is_empty()) {
-        (true, true) => return Equal,
-        (true, false) => return Less,
-        (false, true) => return Greater,
-        (false, false) => {}
-    }
-
-    for (v1, v2) in a.iter().zip(b.iter()) {
-        match v1.cmp(v2) {
-            Equal => continue,
-            other => return other,
-        }
-    }
-    Equal
-}
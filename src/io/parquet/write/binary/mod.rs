@@ -2,5 +2,5 @@ mod basic;
 mod nested;
 
 pub use basic::array_to_page;
-pub(super) use basic::ord_binary;
+pub(super) use basic::build_statistics;
 pub use nested::array_to_page as nested_array_to_page;
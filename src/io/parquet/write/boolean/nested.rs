@@ -3,32 +3,41 @@ use parquet2::{metadata::ColumnDescriptor, read::CompressedPage, write::WriteOpt
 use super::super::{levels, utils};
 use super::basic::{build_statistics, encode_plain};
 use crate::{
-    array::{Array, BooleanArray, Offset},
+    array::{Array, BooleanArray},
     error::Result,
     io::parquet::read::is_type_nullable,
 };
 
-pub fn array_to_page<O>(
+pub fn array_to_page(
     array: &BooleanArray,
     options: WriteOptions,
     descriptor: ColumnDescriptor,
-    nested: levels::NestedInfo<O>,
-) -> Result<CompressedPage>
-where
-    O: Offset,
-{
+    nested: levels::NestedInfo,
+) -> Result<CompressedPage> {
     let is_optional = is_type_nullable(descriptor.type_());
 
-    let validity = array.validity();
+    let levels = nested.levels();
+    // values inside null or empty parents are not written
+    let array = levels::gather(array, &levels.values);
 
     let mut buffer = vec![];
-    levels::write_rep_levels(&mut buffer, &nested, options.version)?;
+    levels::write_levels(
+        &mut buffer,
+        &levels.rep,
+        descriptor.max_rep_level(),
+        options.version,
+    )?;
     let repetition_levels_byte_length = buffer.len();
 
-    levels::write_def_levels(&mut buffer, &nested, validity, options.version)?;
+    levels::write_levels(
+        &mut buffer,
+        &levels.def,
+        descriptor.max_def_level(),
+        options.version,
+    )?;
     let definition_levels_byte_length = buffer.len() - repetition_levels_byte_length;
 
-    encode_plain(array, is_optional, &mut buffer)?;
+    encode_plain(&array, is_optional, &mut buffer)?;
 
     let uncompressed_page_size = buffer.len();
 
@@ -39,15 +48,15 @@ where
     )?;
 
     let statistics = if options.write_statistics {
-        Some(build_statistics(array))
+        Some(build_statistics(&array))
     } else {
         None
     };
 
     utils::build_plain_page(
         buffer,
-        levels::num_values(nested.offsets()),
-        array.null_count(),
+        levels.num_values(),
+        levels.null_count(descriptor.max_def_level() as u32),
         uncompressed_page_size,
         repetition_levels_byte_length,
         definition_levels_byte_length,
@@ -0,0 +1,379 @@
+use std::sync::Arc;
+
+use num::ToPrimitive;
+use parquet2::{
+    encoding::{hybrid_rle::encode_u32, Encoding},
+    metadata::ColumnDescriptor,
+    read::{BinaryPageDict, CompressedPage, DictPage, PrimitivePageDict},
+    statistics::ParquetStatistics,
+    types::NativeType,
+    write::{Version, WriteOptions},
+};
+
+use super::{binary, plain_array_to_page, primitive, utf8, utils};
+use crate::{
+    array::{
+        growable::make_growable, Array, BinaryArray, DictionaryArray, DictionaryKey, MutableArray,
+        MutableBinaryArray, MutableDictionaryArray, MutablePrimitiveArray, MutableUtf8Array,
+        Offset, PrimitiveArray, TryExtend, Utf8Array,
+    },
+    bitmap::Bitmap,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+    io::parquet::read::is_type_nullable,
+    types::NativeType as ArrowNativeType,
+};
+
+/// The maximum size, in bytes, of a dictionary built while writing a non-dictionary array.
+/// Arrays whose dictionary would be larger than this are written with plain encoding.
+pub const DICTIONARY_PAGE_SIZE_LIMIT: usize = 1024 * 1024;
+
+/// Writes the keys of a dictionary-encoded array: the definition levels followed by the
+/// bit width of the indices and the indices in RLE/bit-packed hybrid encoding.
+fn encode_keys<K: DictionaryKey>(
+    keys: &PrimitiveArray<K>,
+    is_optional: bool,
+    version: Version,
+    buffer: &mut Vec<u8>,
+) -> Result<usize> {
+    utils::write_def_levels(buffer, is_optional, keys.validity(), keys.len(), version)?;
+    let definition_levels_byte_length = buffer.len();
+
+    let indices = if is_optional {
+        keys.iter()
+            .flatten()
+            .map(|x| x.to_u32().unwrap())
+            .collect::<Vec<_>>()
+    } else {
+        keys.values()
+            .iter()
+            .map(|x| x.to_u32().unwrap())
+            .collect::<Vec<_>>()
+    };
+
+    // SPEC: the bit width used to encode the entry ids stored as 1 byte (max bit width = 32)
+    let max = indices.iter().copied().max().unwrap_or(0);
+    let num_bits = std::cmp::max(32 - max.leading_zeros(), 1);
+    buffer.push(num_bits as u8);
+    encode_u32(buffer, indices.into_iter(), num_bits as _)?;
+
+    Ok(definition_levels_byte_length)
+}
+
+fn primitive_dictionary<T, R>(values: &dyn Array) -> Arc<dyn DictPage>
+where
+    T: ArrowNativeType + num::cast::AsPrimitive<R>,
+    R: NativeType,
+{
+    let values = values.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let values = values.values().iter().map(|x| x.as_()).collect::<Vec<R>>();
+    Arc::new(PrimitivePageDict::<R>::new(values))
+}
+
+fn binary_dictionary<O: Offset>(values: &[u8], offsets: &[O]) -> Arc<dyn DictPage> {
+    let start = offsets[0].to_usize();
+    let end = offsets[offsets.len() - 1].to_usize();
+    let offsets = offsets
+        .iter()
+        .map(|x| (x.to_usize() - start) as i32)
+        .collect::<Vec<_>>();
+    Arc::new(BinaryPageDict::new(values[start..end].to_vec(), offsets))
+}
+
+/// Returns the dictionary page of `values`, converted to the physical type of the column.
+fn dictionary_page(values: &dyn Array) -> Result<Arc<dyn DictPage>> {
+    use DataType::*;
+    // casts below MUST match the casts done at the metadata (field -> parquet type).
+    Ok(match values.data_type() {
+        UInt8 => primitive_dictionary::<u8, i32>(values),
+        UInt16 => primitive_dictionary::<u16, i32>(values),
+        UInt32 => primitive_dictionary::<u32, i32>(values),
+        UInt64 => primitive_dictionary::<u64, i64>(values),
+        Int8 => primitive_dictionary::<i8, i32>(values),
+        Int16 => primitive_dictionary::<i16, i32>(values),
+        Int32 | Date32 | Time32(_) => primitive_dictionary::<i32, i32>(values),
+        Int64 | Date64 | Time64(_) | Timestamp(_, _) | Duration(_) => {
+            primitive_dictionary::<i64, i64>(values)
+        }
+        Float32 => primitive_dictionary::<f32, f32>(values),
+        Float64 => primitive_dictionary::<f64, f64>(values),
+        Utf8 => {
+            let values = values.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            binary_dictionary(values.values(), values.offsets())
+        }
+        LargeUtf8 => {
+            let values = values.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            binary_dictionary(values.values(), values.offsets())
+        }
+        Binary => {
+            let values = values.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            binary_dictionary(values.values(), values.offsets())
+        }
+        LargeBinary => {
+            let values = values.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            binary_dictionary(values.values(), values.offsets())
+        }
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Writing dictionary-encoded parquet pages for data type {:?}",
+                other
+            )))
+        }
+    })
+}
+
+fn primitive_statistics<T, R>(values: &dyn Array, descriptor: ColumnDescriptor) -> ParquetStatistics
+where
+    T: ArrowNativeType + PartialOrd + num::cast::AsPrimitive<R>,
+    R: NativeType,
+{
+    let values = values.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    primitive::build_statistics::<T, R>(values, descriptor)
+}
+
+/// Returns the statistics of `values`, converted to the physical type of the column.
+fn values_statistics(
+    values: &dyn Array,
+    descriptor: ColumnDescriptor,
+) -> Result<ParquetStatistics> {
+    use DataType::*;
+    // casts below MUST match the casts done at the metadata (field -> parquet type).
+    Ok(match values.data_type() {
+        UInt8 => primitive_statistics::<u8, i32>(values, descriptor),
+        UInt16 => primitive_statistics::<u16, i32>(values, descriptor),
+        UInt32 => primitive_statistics::<u32, i32>(values, descriptor),
+        UInt64 => primitive_statistics::<u64, i64>(values, descriptor),
+        Int8 => primitive_statistics::<i8, i32>(values, descriptor),
+        Int16 => primitive_statistics::<i16, i32>(values, descriptor),
+        Int32 | Date32 | Time32(_) => primitive_statistics::<i32, i32>(values, descriptor),
+        Int64 | Date64 | Time64(_) | Timestamp(_, _) | Duration(_) => {
+            primitive_statistics::<i64, i64>(values, descriptor)
+        }
+        Float32 => primitive_statistics::<f32, f32>(values, descriptor),
+        Float64 => primitive_statistics::<f64, f64>(values, descriptor),
+        Utf8 => utf8::build_statistics::<i32>(values.as_any().downcast_ref().unwrap(), descriptor),
+        LargeUtf8 => {
+            utf8::build_statistics::<i64>(values.as_any().downcast_ref().unwrap(), descriptor)
+        }
+        Binary => {
+            binary::build_statistics::<i32>(values.as_any().downcast_ref().unwrap(), descriptor)
+        }
+        LargeBinary => {
+            binary::build_statistics::<i64>(values.as_any().downcast_ref().unwrap(), descriptor)
+        }
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Writing statistics of dictionary-encoded parquet pages for data type {:?}",
+                other
+            )))
+        }
+    })
+}
+
+/// Builds the statistics of the values `array`'s keys refer to.
+fn build_statistics<K: DictionaryKey>(
+    array: &DictionaryArray<K>,
+    descriptor: ColumnDescriptor,
+    distinct_count: Option<usize>,
+) -> Result<ParquetStatistics> {
+    let keys = array.keys();
+    let mut growable = make_growable(
+        &[array.values().as_ref()],
+        false,
+        keys.len() - keys.null_count(),
+    );
+    keys.iter()
+        .flatten()
+        .for_each(|key| growable.extend(0, key.to_usize().unwrap(), 1));
+    let values = growable.as_box();
+
+    let mut statistics = values_statistics(values.as_ref(), descriptor)?;
+    statistics.null_count = Some((keys.null_count() + values.null_count()) as i64);
+    statistics.distinct_count = distinct_count.map(|x| x as i64);
+    Ok(statistics)
+}
+
+/// Returns the keys of `array` with the keys of null values set to null, so that null values
+/// are written as null slots instead of as entries of the dictionary page.
+fn normalized_keys<K: DictionaryKey>(array: &DictionaryArray<K>) -> PrimitiveArray<K> {
+    let keys = array.keys();
+    let values = array.values();
+    if values.null_count() == 0 {
+        return keys.clone();
+    }
+    let validity = keys
+        .iter()
+        .map(|key| match key {
+            Some(key) => values.is_valid(key.to_usize().unwrap()),
+            None => false,
+        })
+        .collect::<Bitmap>();
+    PrimitiveArray::<K>::from_data(
+        keys.data_type().clone(),
+        keys.values().clone(),
+        Some(validity),
+    )
+}
+
+/// Writes a [`DictionaryArray`] to a dictionary-encoded page: its values are written to the
+/// dictionary page and its keys to the data page. `distinct_count` is written to the
+/// statistics when the values are known to be distinct.
+pub fn array_to_page<K: DictionaryKey>(
+    array: &DictionaryArray<K>,
+    descriptor: ColumnDescriptor,
+    options: WriteOptions,
+    distinct_count: Option<usize>,
+) -> Result<CompressedPage> {
+    let is_optional = is_type_nullable(descriptor.type_());
+    let keys = &normalized_keys(array);
+
+    let dictionary_page = dictionary_page(array.values().as_ref())?;
+
+    let mut buffer = vec![];
+    let definition_levels_byte_length =
+        encode_keys(keys, is_optional, options.version, &mut buffer)?;
+
+    let uncompressed_page_size = buffer.len();
+
+    let buffer = utils::compress(buffer, options, definition_levels_byte_length)?;
+
+    let statistics = if options.write_statistics {
+        Some(build_statistics(array, descriptor.clone(), distinct_count)?)
+    } else {
+        None
+    };
+
+    let encoding = match options.version {
+        Version::V1 => Encoding::PlainDictionary,
+        Version::V2 => Encoding::RleDictionary,
+    };
+
+    utils::build_page(
+        buffer,
+        keys.len(),
+        keys.null_count(),
+        uncompressed_page_size,
+        0,
+        definition_levels_byte_length,
+        statistics,
+        descriptor,
+        options,
+        encoding,
+        Some(dictionary_page),
+    )
+}
+
+/// Dictionary-encodes `array` and writes it. Falls back to plain encoding when `array` can't be
+/// dictionary-encoded or when its dictionary is larger than [`DICTIONARY_PAGE_SIZE_LIMIT`].
+pub fn encode_array_to_page(
+    array: &dyn Array,
+    descriptor: ColumnDescriptor,
+    options: WriteOptions,
+) -> Result<CompressedPage> {
+    let dictionary = match array.data_type() {
+        DataType::UInt8 => encode_primitive::<u8>(array)?,
+        DataType::UInt16 => encode_primitive::<u16>(array)?,
+        DataType::UInt32 => encode_primitive::<u32>(array)?,
+        DataType::UInt64 => encode_primitive::<u64>(array)?,
+        DataType::Int8 => encode_primitive::<i8>(array)?,
+        DataType::Int16 => encode_primitive::<i16>(array)?,
+        DataType::Int32 | DataType::Date32 | DataType::Time32(_) => encode_primitive::<i32>(array)?,
+        DataType::Int64
+        | DataType::Date64
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_) => encode_primitive::<i64>(array)?,
+        DataType::Float32 => encode_float::<f32, u32>(array, f32::to_bits, f32::from_bits)?,
+        DataType::Float64 => encode_float::<f64, u64>(array, f64::to_bits, f64::from_bits)?,
+        DataType::Utf8 => encode_utf8::<i32>(array)?,
+        DataType::LargeUtf8 => encode_utf8::<i64>(array)?,
+        DataType::Binary => encode_binary::<i32>(array)?,
+        DataType::LargeBinary => encode_binary::<i64>(array)?,
+        _ => None,
+    };
+
+    match dictionary {
+        Some(dictionary) => {
+            let distinct_count = dictionary.values().len();
+            array_to_page(&dictionary, descriptor, options, Some(distinct_count))
+        }
+        None => plain_array_to_page(array, descriptor, options),
+    }
+}
+
+fn encode_primitive<T>(array: &dyn Array) -> Result<Option<DictionaryArray<u32>>>
+where
+    T: ArrowNativeType + std::hash::Hash,
+{
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+
+    let mut dictionary = MutableDictionaryArray::<u32, MutablePrimitiveArray<T>>::from(
+        MutablePrimitiveArray::<T>::from(array.data_type().clone()),
+    );
+    for value in array.iter() {
+        dictionary.try_extend(std::iter::once(value.copied()))?;
+        if dictionary.values().len() * std::mem::size_of::<T>() > DICTIONARY_PAGE_SIZE_LIMIT {
+            return Ok(None);
+        }
+    }
+    Ok(Some(dictionary.into()))
+}
+
+/// Dictionary-encodes a float array by the bit representation of its values, as floats are not
+/// `Hash`.
+fn encode_float<T, B>(
+    array: &dyn Array,
+    to_bits: fn(T) -> B,
+    from_bits: fn(B) -> T,
+) -> Result<Option<DictionaryArray<u32>>>
+where
+    T: ArrowNativeType,
+    B: ArrowNativeType + std::hash::Hash,
+{
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let bits = PrimitiveArray::<B>::from_data(
+        B::DATA_TYPE,
+        array.values().iter().map(|x| to_bits(*x)).collect(),
+        array.validity().clone(),
+    );
+
+    Ok(encode_primitive::<B>(&bits)?.map(|dictionary| {
+        let values = dictionary
+            .values()
+            .as_any()
+            .downcast_ref::<PrimitiveArray<B>>()
+            .unwrap();
+        let values = PrimitiveArray::<T>::from_data(
+            array.data_type().clone(),
+            values.values().iter().map(|x| from_bits(*x)).collect(),
+            values.validity().clone(),
+        );
+        DictionaryArray::from_data(dictionary.keys().clone(), Arc::new(values))
+    }))
+}
+
+fn encode_utf8<O: Offset>(array: &dyn Array) -> Result<Option<DictionaryArray<u32>>> {
+    let array = array.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+
+    let mut dictionary = MutableDictionaryArray::<u32, MutableUtf8Array<O>>::new();
+    for value in array.iter() {
+        dictionary.try_extend(std::iter::once(value))?;
+        if dictionary.values().values().len() > DICTIONARY_PAGE_SIZE_LIMIT {
+            return Ok(None);
+        }
+    }
+    Ok(Some(dictionary.into()))
+}
+
+fn encode_binary<O: Offset>(array: &dyn Array) -> Result<Option<DictionaryArray<u32>>> {
+    let array = array.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+
+    let mut dictionary = MutableDictionaryArray::<u32, MutableBinaryArray<O>>::new();
+    for value in array.iter() {
+        dictionary.try_extend(std::iter::once(value))?;
+        if dictionary.values().values().len() > DICTIONARY_PAGE_SIZE_LIMIT {
+            return Ok(None);
+        }
+    }
+    Ok(Some(dictionary.into()))
+}
@@ -0,0 +1,234 @@
+//! Encoders of values to parquet encodings, the counterparts of the decoders used when reading.
+use parquet2::types::NativeType;
+
+/// The number of values of a block of `DELTA_BINARY_PACKED`.
+const BLOCK_SIZE: usize = 128;
+/// The number of miniblocks of a block of `DELTA_BINARY_PACKED`.
+const MINIBLOCKS: usize = 4;
+const VALUES_PER_MINIBLOCK: usize = BLOCK_SIZE / MINIBLOCKS;
+
+/// Appends `value` encoded as an unsigned LEB128 integer to `buffer`.
+fn uleb128(mut value: u64, buffer: &mut Vec<u8>) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            buffer.push(byte);
+            return;
+        }
+        buffer.push(byte | 0x80);
+    }
+}
+
+/// Appends `value` encoded as a zigzag ULEB128 integer to `buffer`.
+fn zigzag(value: i64, buffer: &mut Vec<u8>) {
+    uleb128(((value << 1) ^ (value >> 63)) as u64, buffer)
+}
+
+/// Appends `values` bit-packed with `bit_width` bits each, LSB first, to `buffer`.
+fn pack(values: &[u64], bit_width: usize, buffer: &mut Vec<u8>) {
+    let start = buffer.len();
+    buffer.resize(start + (values.len() * bit_width + 7) / 8, 0);
+    let packed = &mut buffer[start..];
+    for (index, value) in values.iter().enumerate() {
+        let mut written = 0;
+        while written < bit_width {
+            let bit = index * bit_width + written;
+            let offset = bit % 8;
+            let take = std::cmp::min(8 - offset, bit_width - written);
+            let bits = (value >> written) & ((1 << take) - 1);
+            packed[bit / 8] |= (bits << offset) as u8;
+            written += take;
+        }
+    }
+}
+
+/// An integer physical type that can be encoded with `DELTA_BINARY_PACKED`. Its deltas wrap
+/// at its own width, as readers decode `INT32` columns with 32-bit arithmetic.
+pub trait DeltaInteger: Copy + Ord {
+    /// `self - other`, wrapping around at the boundary of the type.
+    fn wrapping_sub(self, other: Self) -> Self;
+    /// `self` sign-extended to `i64`.
+    fn to_i64(self) -> i64;
+    /// The bits of `self` as an unsigned integer of the same width.
+    fn to_unsigned(self) -> u64;
+}
+
+impl DeltaInteger for i32 {
+    #[inline]
+    fn wrapping_sub(self, other: Self) -> Self {
+        self.wrapping_sub(other)
+    }
+
+    #[inline]
+    fn to_i64(self) -> i64 {
+        self as i64
+    }
+
+    #[inline]
+    fn to_unsigned(self) -> u64 {
+        self as u32 as u64
+    }
+}
+
+impl DeltaInteger for i64 {
+    #[inline]
+    fn wrapping_sub(self, other: Self) -> Self {
+        self.wrapping_sub(other)
+    }
+
+    #[inline]
+    fn to_i64(self) -> i64 {
+        self
+    }
+
+    #[inline]
+    fn to_unsigned(self) -> u64 {
+        self as u64
+    }
+}
+
+/// Appends `values` encoded with `DELTA_BINARY_PACKED` to `buffer`, using blocks of 128 values
+/// in 4 miniblocks.
+pub fn delta_bitpacked<T: DeltaInteger>(values: &[T], buffer: &mut Vec<u8>) {
+    uleb128(BLOCK_SIZE as u64, buffer);
+    uleb128(MINIBLOCKS as u64, buffer);
+    uleb128(values.len() as u64, buffer);
+    zigzag(values.first().map(|x| x.to_i64()).unwrap_or(0), buffer);
+
+    let deltas = values
+        .windows(2)
+        .map(|pair| pair[1].wrapping_sub(pair[0]))
+        .collect::<Vec<_>>();
+    for block in deltas.chunks(BLOCK_SIZE) {
+        let min_delta = *block.iter().min().unwrap();
+        zigzag(min_delta.to_i64(), buffer);
+
+        let block = block
+            .iter()
+            .map(|delta| delta.wrapping_sub(min_delta).to_unsigned())
+            .collect::<Vec<_>>();
+        let bit_widths = block
+            .chunks(VALUES_PER_MINIBLOCK)
+            .map(|miniblock| 64 - miniblock.iter().max().unwrap().leading_zeros() as usize)
+            .collect::<Vec<_>>();
+        // miniblocks without values have a bit width but no data
+        (0..MINIBLOCKS).for_each(|i| buffer.push(bit_widths.get(i).copied().unwrap_or(0) as u8));
+
+        for (miniblock, bit_width) in block.chunks(VALUES_PER_MINIBLOCK).zip(bit_widths) {
+            let mut padded = [0u64; VALUES_PER_MINIBLOCK];
+            padded[..miniblock.len()].copy_from_slice(miniblock);
+            pack(&padded, bit_width, buffer);
+        }
+    }
+}
+
+/// Appends `values` encoded with `DELTA_LENGTH_BYTE_ARRAY` to `buffer`: the delta-encoded
+/// lengths of the values followed by their concatenated bytes.
+pub fn delta_length_byte_array(values: &[&[u8]], buffer: &mut Vec<u8>) {
+    let lengths = values.iter().map(|x| x.len() as i64).collect::<Vec<_>>();
+    delta_bitpacked(&lengths, buffer);
+    values.iter().for_each(|x| buffer.extend_from_slice(x));
+}
+
+/// Appends `values` encoded with `DELTA_BYTE_ARRAY` to `buffer`: the delta-encoded lengths of
+/// the prefix each value shares with the previous one followed by the
+/// `DELTA_LENGTH_BYTE_ARRAY`-encoded suffixes.
+pub fn delta_byte_array(values: &[&[u8]], buffer: &mut Vec<u8>) {
+    let mut prefixes = Vec::with_capacity(values.len());
+    let mut suffixes = Vec::with_capacity(values.len());
+    let mut previous: &[u8] = &[];
+    for value in values {
+        let prefix = previous
+            .iter()
+            .zip(value.iter())
+            .take_while(|(a, b)| a == b)
+            .count();
+        prefixes.push(prefix as i64);
+        suffixes.push(&value[prefix..]);
+        previous = value;
+    }
+    delta_bitpacked(&prefixes, buffer);
+    delta_length_byte_array(&suffixes, buffer);
+}
+
+/// Appends `values` encoded with `BYTE_STREAM_SPLIT` to `buffer`, storing the `i`th byte of
+/// every value in the `i`th of `size_of::<T>()` streams.
+pub fn byte_stream_split<T: NativeType>(values: &[T], buffer: &mut Vec<u8>) {
+    let start = buffer.len();
+    buffer.resize(start + values.len() * std::mem::size_of::<T>(), 0);
+    let streams = &mut buffer[start..];
+    for (i, value) in values.iter().enumerate() {
+        for (stream, byte) in value.to_le_bytes().as_ref().iter().enumerate() {
+            streams[stream * values.len() + i] = *byte;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Result;
+    use crate::io::parquet::read::encodings as decode;
+
+    #[test]
+    fn delta_bitpacked_round_trip() -> Result<()> {
+        let cases = vec![
+            vec![],
+            vec![7],
+            vec![1, 2, 4, 5, 8],
+            (0..1000).map(|x| x * 3 - 100).collect::<Vec<_>>(),
+            vec![i64::MIN, i64::MAX, 0, -1, i64::MAX, i64::MIN],
+        ];
+        for values in cases {
+            let mut buffer = vec![];
+            delta_bitpacked(&values, &mut buffer);
+            let (result, consumed) = decode::delta_bitpacked(&buffer)?;
+            assert_eq!(result, values);
+            assert_eq!(consumed, buffer.len());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn delta_bitpacked_i32_round_trip() -> Result<()> {
+        let values = vec![i32::MIN, i32::MAX, 0, -1, i32::MAX, i32::MIN];
+        let mut buffer = vec![];
+        delta_bitpacked(&values, &mut buffer);
+        let (result, consumed) = decode::delta_bitpacked(&buffer)?;
+        let result = result
+            .into_iter()
+            .map(decode::from_i64::<i32>)
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(result, values);
+        assert_eq!(consumed, buffer.len());
+        Ok(())
+    }
+
+    #[test]
+    fn delta_byte_arrays_round_trip() -> Result<()> {
+        let values: Vec<&[u8]> = vec![b"abc", b"abd", b"", b"abde", b"xyz"];
+
+        let mut buffer = vec![];
+        delta_length_byte_array(&values, &mut buffer);
+        assert_eq!(decode::delta_length_byte_array(&buffer)?, values);
+
+        let mut buffer = vec![];
+        delta_byte_array(&values, &mut buffer);
+        let result = decode::delta_byte_array(&buffer)?;
+        assert_eq!(
+            result,
+            values.iter().map(|x| x.to_vec()).collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn byte_stream_split_round_trip() -> Result<()> {
+        let values = vec![1.0f64, -2.5, f64::MAX, 0.0];
+        let mut buffer = vec![];
+        byte_stream_split(&values, &mut buffer);
+        assert_eq!(decode::byte_stream_split::<f64>(&buffer)?, values);
+        Ok(())
+    }
+}
@@ -1,6 +1,8 @@
+use std::cmp::Ordering;
+
 use parquet2::{
     compression::create_codec, metadata::ColumnDescriptor, read::CompressedPage,
-    write::WriteOptions,
+    statistics::ParquetStatistics, write::WriteOptions,
 };
 
 use super::utils;
@@ -10,10 +12,22 @@ use crate::{
     io::parquet::read::is_type_nullable,
 };
 
+/// How the values of a fixed-len column are ordered in its statistics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    /// Lexicographic order of the bytes, as unsigned
+    Unsigned,
+    /// Order of big-endian two's complement integers, e.g. of decimals
+    Signed,
+    /// The values have no order, e.g. intervals, and only their null count is written
+    Undefined,
+}
+
 pub fn array_to_page(
     array: &FixedSizeBinaryArray,
     options: WriteOptions,
     descriptor: ColumnDescriptor,
+    order: SortOrder,
 ) -> Result<CompressedPage> {
     let is_optional = is_type_nullable(descriptor.type_());
     let validity = array.validity();
@@ -54,6 +68,12 @@ pub fn array_to_page(
         buffer
     };
 
+    let statistics = if options.write_statistics {
+        Some(build_statistics(array, order))
+    } else {
+        None
+    };
+
     utils::build_plain_page(
         buffer,
         array.len(),
@@ -61,8 +81,43 @@ pub fn array_to_page(
         uncompressed_page_size,
         0,
         definition_levels_byte_length,
-        None,
+        statistics,
         descriptor,
         options,
     )
 }
+
+/// Compares two big-endian two's complement integers of the same length.
+fn compare_signed(a: &[u8], b: &[u8]) -> Ordering {
+    match (a.first(), b.first()) {
+        (Some(x), Some(y)) => (x ^ 0x80)
+            .cmp(&(y ^ 0x80))
+            .then_with(|| a[1..].cmp(&b[1..])),
+        _ => a.cmp(b),
+    }
+}
+
+pub(super) fn build_statistics(
+    array: &FixedSizeBinaryArray,
+    order: SortOrder,
+) -> ParquetStatistics {
+    let compare = |a: &&[u8], b: &&[u8]| match order {
+        SortOrder::Signed => compare_signed(a, b),
+        _ => a.cmp(b),
+    };
+    let (min_value, max_value) = match order {
+        SortOrder::Undefined => (None, None),
+        _ => (
+            array.iter().flatten().min_by(compare),
+            array.iter().flatten().max_by(compare),
+        ),
+    };
+    ParquetStatistics {
+        max: None,
+        min: None,
+        null_count: Some(array.null_count() as i64),
+        distinct_count: None,
+        max_value: max_value.map(|x| x.to_vec()),
+        min_value: min_value.map(|x| x.to_vec()),
+    }
+}
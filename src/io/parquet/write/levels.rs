@@ -1,171 +1,166 @@
 use parquet2::encoding::hybrid_rle::encode_u32;
+use parquet2::read::levels::get_bit_width;
 use parquet2::write::Version;
 
 use crate::{
-    array::Offset,
-    bitmap::{utils::BitmapIter, Bitmap},
+    array::{growable::make_growable, Array},
+    bitmap::Bitmap,
     error::Result,
 };
 
-pub fn num_values<O: Offset>(offsets: &[O]) -> usize {
-    offsets
-        .windows(2)
-        .map(|w| {
-            let length = w[1].to_usize() - w[0].to_usize();
-            if length == 0 {
-                1
-            } else {
-                length
-            }
-        })
-        .sum()
-}
-
-/// Iterator adapter of parquet / dremel repetition levels
-#[derive(Debug)]
-pub struct RepLevelsIter<'a, O: Offset> {
-    iter: std::slice::Windows<'a, O>,
-    remaining: usize,
-    length: usize,
-    total_size: usize,
+/// The offsets of a list level of a leaf column.
+#[derive(Debug, Clone, Copy)]
+pub enum Offsets<'a> {
+    Small(&'a [i32]),
+    Large(&'a [i64]),
+    /// The size of the items of a fixed-size list.
+    Fixed(usize),
 }
 
-impl<'a, O: Offset> RepLevelsIter<'a, O> {
-    pub fn new(offsets: &'a [O]) -> Self {
-        let total_size = num_values(offsets);
-
-        Self {
-            iter: offsets.windows(2),
-            remaining: 0,
-            length: 0,
-            total_size,
+impl Offsets<'_> {
+    /// Returns the start and end of the slot `index` in the level below.
+    fn range(&self, index: usize) -> (usize, usize) {
+        match self {
+            Offsets::Small(offsets) => (offsets[index] as usize, offsets[index + 1] as usize),
+            Offsets::Large(offsets) => (offsets[index] as usize, offsets[index + 1] as usize),
+            Offsets::Fixed(size) => (index * size, (index + 1) * size),
         }
     }
 }
 
-impl<O: Offset> Iterator for RepLevelsIter<'_, O> {
-    type Item = u32;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.remaining == self.length {
-            if let Some(w) = self.iter.next() {
-                let start = w[0].to_usize();
-                let end = w[1].to_usize();
-                self.length = end - start;
-                self.remaining = 0;
-                if self.length == 0 {
-                    self.total_size -= 1;
-                    return Some(0);
-                }
-            } else {
-                return None;
-            }
-        }
-        let old = self.remaining;
-        self.remaining += 1;
-        self.total_size -= 1;
-        Some((old >= 1) as u32)
-    }
-
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.total_size, Some(self.total_size))
-    }
+/// A nesting level of a leaf column, from the field to the leaf itself. Each level has its
+/// validity and whether it is optional (nullable) in the parquet schema.
+#[derive(Debug, Clone)]
+pub enum Nested<'a> {
+    List(Offsets<'a>, &'a Option<Bitmap>, bool),
+    Struct(&'a Option<Bitmap>, bool),
+    Primitive(&'a Option<Bitmap>, bool),
 }
 
-/// Iterator adapter of parquet / dremel definition levels
-pub struct DefLevelsIter<'a, O: Offset> {
-    iter: std::iter::Zip<std::slice::Windows<'a, O>, Box<dyn Iterator<Item = bool> + 'a>>,
-    primitive_validity: Option<BitmapIter<'a>>,
-    remaining: usize,
-    is_valid: bool,
-    length: usize,
-    total_size: usize,
+/// The repetition and definition levels of a leaf column, together with the slots of the leaf
+/// array that have a level, in order.
+#[derive(Debug, Default, PartialEq)]
+pub struct Levels {
+    pub rep: Vec<u32>,
+    pub def: Vec<u32>,
+    pub values: Vec<usize>,
 }
 
-impl<'a, O: Offset> DefLevelsIter<'a, O> {
-    pub fn new(
-        offsets: &'a [O],
-        validity: &'a Option<Bitmap>,
-        primitive_validity: &'a Option<Bitmap>,
-    ) -> Self {
-        let total_size = num_values(offsets);
-
-        let primitive_validity = primitive_validity.as_ref().map(|x| x.iter());
-
-        let validity = validity
-            .as_ref()
-            .map(|x| Box::new(x.iter()) as Box<dyn Iterator<Item = bool>>)
-            .unwrap_or_else(|| {
-                Box::new(std::iter::repeat(true).take(offsets.len() - 1))
-                    as Box<dyn Iterator<Item = bool>>
-            });
+impl Levels {
+    /// The number of values of the page, i.e. the number of levels.
+    pub fn num_values(&self) -> usize {
+        self.rep.len()
+    }
 
-        Self {
-            iter: offsets.windows(2).zip(validity),
-            primitive_validity,
-            remaining: 0,
-            length: 0,
-            is_valid: false,
-            total_size,
-        }
+    /// The number of levels that are not a (non-null) value of the leaf.
+    pub fn null_count(&self, max_def_level: u32) -> usize {
+        self.def.iter().filter(|x| **x < max_def_level).count()
     }
 }
 
-impl<O: Offset> Iterator for DefLevelsIter<'_, O> {
-    type Item = u32;
+#[inline]
+fn is_valid(validity: &Option<Bitmap>, index: usize) -> bool {
+    validity
+        .as_ref()
+        .map(|validity| validity.get_bit(index))
+        .unwrap_or(true)
+}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.remaining == self.length {
-            if let Some((w, is_valid)) = self.iter.next() {
-                let start = w[0].to_usize();
-                let end = w[1].to_usize();
-                self.length = end - start;
-                self.remaining = 0;
-                self.is_valid = is_valid;
-                if self.length == 0 {
-                    self.total_size -= 1;
-                    return Some(is_valid as u32);
-                }
-            } else {
-                return None;
+/// Extends `levels` with the levels of the slot `index` of `nested[0]`. `rep` and `def` are the
+/// levels of the slot and `depth` the number of lists above it.
+fn extend_levels(
+    nested: &[Nested],
+    index: usize,
+    rep: u32,
+    def: u32,
+    depth: u32,
+    levels: &mut Levels,
+) {
+    match &nested[0] {
+        Nested::Primitive(validity, is_optional) => {
+            let is_valid = is_valid(validity, index);
+            levels.rep.push(rep);
+            levels.def.push(def + (*is_optional && is_valid) as u32);
+            levels.values.push(index);
+        }
+        Nested::Struct(validity, is_optional) => {
+            if *is_optional && !is_valid(validity, index) {
+                levels.rep.push(rep);
+                levels.def.push(def);
+                return;
             }
+            let def = def + *is_optional as u32;
+            extend_levels(&nested[1..], index, rep, def, depth, levels)
+        }
+        Nested::List(offsets, validity, is_optional) => {
+            if *is_optional && !is_valid(validity, index) {
+                levels.rep.push(rep);
+                levels.def.push(def);
+                return;
+            }
+            let def = def + *is_optional as u32;
+            let (start, end) = offsets.range(index);
+            if start == end {
+                levels.rep.push(rep);
+                levels.def.push(def);
+                return;
+            }
+            // the first item repeats at the level of its parent, the others at this list's
+            (start..end).enumerate().for_each(|(i, child)| {
+                let rep = if i == 0 { rep } else { depth + 1 };
+                extend_levels(&nested[1..], child, rep, def + 1, depth + 1, levels)
+            })
         }
-        self.remaining += 1;
-        self.total_size -= 1;
-
-        let (base_def, p_is_valid) = self
-            .primitive_validity
-            .as_mut()
-            .map(|x| (1, x.next().unwrap() as u32))
-            .unwrap_or((0, 0));
-        let def_ = (base_def + 1) * self.is_valid as u32 + p_is_valid;
-        Some(def_)
-    }
-
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.total_size, Some(self.total_size))
     }
 }
 
+/// The nesting levels of a leaf column of a nested array of `length` slots.
 #[derive(Debug)]
-pub struct NestedInfo<'a, O: Offset> {
-    is_optional: bool,
-    offsets: &'a [O],
-    validity: &'a Option<Bitmap>,
+pub struct NestedInfo<'a> {
+    nested: Vec<Nested<'a>>,
+    length: usize,
 }
 
-impl<'a, O: Offset> NestedInfo<'a, O> {
-    pub fn new(offsets: &'a [O], validity: &'a Option<Bitmap>, is_optional: bool) -> Self {
-        Self {
-            is_optional,
-            offsets,
-            validity,
-        }
+impl<'a> NestedInfo<'a> {
+    /// `nested` is the stack of levels from the nested array to the leaf, which must be a
+    /// [`Nested::Primitive`].
+    pub fn new(nested: Vec<Nested<'a>>, length: usize) -> Self {
+        Self { nested, length }
+    }
+
+    /// Returns the repetition and definition levels of the leaf column.
+    pub fn levels(&self) -> Levels {
+        let mut levels = Levels::default();
+        (0..self.length).for_each(|index| extend_levels(&self.nested, index, 0, 0, 0, &mut levels));
+        levels
     }
+}
 
-    pub fn offsets(&self) -> &'a [O] {
-        &self.offsets
+/// Returns an array with the slots `values` of `array`.
+pub fn gather<A: Array + Clone + 'static>(array: &A, values: &[usize]) -> A {
+    // slots are in increasing order: when all are present, there is nothing to gather.
+    if values.len() == array.len() {
+        return array.clone();
+    }
+    let mut growable = make_growable(
+        &[array as &dyn Array],
+        array.validity().is_some(),
+        values.len(),
+    );
+    let mut iter = values.iter().copied().peekable();
+    while let Some(start) = iter.next() {
+        let mut end = start + 1;
+        while iter.next_if_eq(&end).is_some() {
+            end += 1;
+        }
+        growable.extend(0, start, end - start);
     }
+    growable
+        .as_box()
+        .as_any()
+        .downcast_ref::<A>()
+        .unwrap()
+        .clone()
 }
 
 fn write_levels_v1<F: Fn(&mut Vec<u8>) -> Result<()>>(
@@ -186,52 +181,28 @@ fn write_levels_v1<F: Fn(&mut Vec<u8>) -> Result<()>>(
     Ok(())
 }
 
-/// writes the rep levels to a `Vec<u8>`.
-pub fn write_rep_levels<O: Offset>(
+/// Writes the repetition or definition `levels` to a `Vec<u8>`. Nothing is written when
+/// `max_level` is zero.
+pub fn write_levels(
     buffer: &mut Vec<u8>,
-    nested: &NestedInfo<O>,
+    levels: &[u32],
+    max_level: i16,
     version: Version,
 ) -> Result<()> {
-    let num_bits = 1; // todo: compute this
-
-    match version {
-        Version::V1 => {
-            write_levels_v1(buffer, |buffer: &mut Vec<u8>| {
-                let levels = RepLevelsIter::new(&nested.offsets);
-                encode_u32(buffer, levels, num_bits)?;
-                Ok(())
-            })?;
-        }
-        Version::V2 => {
-            let levels = RepLevelsIter::new(&nested.offsets);
-
-            encode_u32(buffer, levels, num_bits)?;
-        }
+    if max_level == 0 {
+        return Ok(());
     }
-
-    Ok(())
-}
-
-/// writes the rep levels to a `Vec<u8>`.
-pub fn write_def_levels<O: Offset>(
-    buffer: &mut Vec<u8>,
-    nested: &NestedInfo<O>,
-    validity: &Option<Bitmap>,
-    version: Version,
-) -> Result<()> {
-    let num_bits = 2;
+    let num_bits = get_bit_width(max_level);
 
     match version {
         Version::V1 => {
             write_levels_v1(buffer, |buffer: &mut Vec<u8>| {
-                let levels = DefLevelsIter::new(&nested.offsets, &nested.validity, validity);
-                encode_u32(buffer, levels, num_bits)?;
+                encode_u32(buffer, levels.iter().copied(), num_bits as _)?;
                 Ok(())
             })?;
         }
         Version::V2 => {
-            let levels = DefLevelsIter::new(&nested.offsets, &nested.validity, validity);
-            encode_u32(buffer, levels, num_bits)?;
+            encode_u32(buffer, levels.iter().copied(), num_bits as _)?;
         }
     }
 
@@ -243,16 +214,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_rep_levels() {
-        let offsets = [0, 2, 2, 5, 8, 8, 11, 11, 12].as_ref();
-        let expected = vec![0u32, 1, 0, 0, 1, 1, 0, 1, 1, 0, 0, 1, 1, 0, 0];
-
-        let result = RepLevelsIter::new(offsets).collect::<Vec<_>>();
-        assert_eq!(result, expected)
-    }
-
-    #[test]
-    fn test_def_levels() {
+    fn test_list() {
         let offsets = [0, 2, 2, 5, 8, 8, 11, 11, 12].as_ref();
         let validity = Some(Bitmap::from([
             true, false, true, true, true, true, false, true,
@@ -264,10 +226,85 @@ mod tests {
             true, true, true, //[7, 8, 9]
             true, //[10]
         ]));
-        let expected = vec![3u32, 3, 0, 3, 2, 3, 3, 3, 3, 1, 3, 3, 3, 0, 3];
+        let nested = vec![
+            Nested::List(Offsets::Small(offsets), &validity, true),
+            Nested::Primitive(&primitive_validity, true),
+        ];
+
+        let levels = NestedInfo::new(nested, 8).levels();
+        assert_eq!(
+            levels.rep,
+            vec![0u32, 1, 0, 0, 1, 1, 0, 1, 1, 0, 0, 1, 1, 0, 0]
+        );
+        assert_eq!(
+            levels.def,
+            vec![3u32, 3, 0, 3, 2, 3, 3, 3, 3, 1, 3, 3, 3, 0, 3]
+        );
+        assert_eq!(levels.values, vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+    }
+
+    #[test]
+    fn test_list_required_values() {
+        let offsets = [0, 2, 2, 3, 3].as_ref();
+        let validity = Some(Bitmap::from([true, false, true, true]));
+        let nested = vec![
+            Nested::List(Offsets::Small(offsets), &validity, true),
+            Nested::Primitive(&None, false),
+        ];
+
+        let levels = NestedInfo::new(nested, 4).levels();
+        assert_eq!(levels.rep, vec![0u32, 1, 0, 0, 0]);
+        assert_eq!(levels.def, vec![2u32, 2, 0, 2, 1]);
+    }
+
+    #[test]
+    fn test_list_of_lists() {
+        // [[[0, 1], None], None, [[], [2]]]
+        let offsets = [0, 2, 2, 4].as_ref();
+        let validity = Some(Bitmap::from([true, false, true]));
+        let inner_offsets = [0, 2, 2, 2, 3].as_ref();
+        let inner_validity = Some(Bitmap::from([true, false, true, true]));
+        let nested = vec![
+            Nested::List(Offsets::Small(offsets), &validity, true),
+            Nested::List(Offsets::Small(inner_offsets), &inner_validity, true),
+            Nested::Primitive(&None, true),
+        ];
+
+        let levels = NestedInfo::new(nested, 3).levels();
+        assert_eq!(levels.rep, vec![0u32, 2, 1, 0, 0, 1]);
+        assert_eq!(levels.def, vec![5u32, 5, 2, 0, 3, 5]);
+        assert_eq!(levels.values, vec![0, 1, 2]);
+    }
 
-        let result =
-            DefLevelsIter::new(offsets, &validity, &primitive_validity).collect::<Vec<_>>();
-        assert_eq!(result, expected)
+    #[test]
+    fn test_struct() {
+        // [{a: 0}, None, {a: None}]
+        let validity = Some(Bitmap::from([true, false, true]));
+        let primitive_validity = Some(Bitmap::from([true, true, false]));
+        let nested = vec![
+            Nested::Struct(&validity, true),
+            Nested::Primitive(&primitive_validity, true),
+        ];
+
+        let levels = NestedInfo::new(nested, 3).levels();
+        assert_eq!(levels.rep, vec![0u32, 0, 0]);
+        assert_eq!(levels.def, vec![2u32, 0, 1]);
+        assert_eq!(levels.values, vec![0, 2]);
+        assert_eq!(levels.null_count(2), 2);
+    }
+
+    #[test]
+    fn test_fixed_size_list() {
+        // [[0, 1], None, [2, 3]]
+        let validity = Some(Bitmap::from([true, false, true]));
+        let nested = vec![
+            Nested::List(Offsets::Fixed(2), &validity, true),
+            Nested::Primitive(&None, false),
+        ];
+
+        let levels = NestedInfo::new(nested, 3).levels();
+        assert_eq!(levels.rep, vec![0u32, 1, 0, 0, 1]);
+        assert_eq!(levels.def, vec![2u32, 2, 0, 2, 2]);
+        assert_eq!(levels.values, vec![0, 1, 4, 5]);
     }
 }
@@ -1,7 +1,10 @@
 mod binary;
 mod boolean;
+mod dictionary;
+mod encodings;
 mod fixed_len_bytes;
 mod levels;
+mod options;
 mod primitive;
 mod record_batch;
 mod schema;
@@ -11,18 +14,20 @@ mod utils;
 pub mod stream;
 
 use crate::array::*;
-use crate::bitmap::Bitmap;
 use crate::buffer::{Buffer, MutableBuffer};
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::io::parquet::read::is_type_nullable;
-use crate::io::parquet::write::levels::NestedInfo;
+use crate::io::parquet::write::levels::{Nested, NestedInfo, Offsets};
 use crate::types::days_ms;
 use crate::types::NativeType;
+use fixed_len_bytes::SortOrder;
 
+pub use options::{ColumnOptions, SizeOptions};
 use parquet2::metadata::ColumnDescriptor;
 pub use parquet2::{
     compression::CompressionCodec,
+    encoding::Encoding,
     read::CompressedPage,
     schema::types::ParquetType,
     write::{DynIter, RowGroupIter},
@@ -87,12 +92,333 @@ where
     )?)
 }
 
+/// Appends to `leaves` each leaf of `array` with the nesting levels from `array` to it.
+fn to_leaves<'a>(
+    array: &'a dyn Array,
+    is_optional: bool,
+    mut nested: Vec<Nested<'a>>,
+    leaves: &mut Vec<(&'a dyn Array, Vec<Nested<'a>>)>,
+) {
+    match array.data_type() {
+        DataType::List(inner) => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            let offsets = Offsets::Small(array.offsets());
+            nested.push(Nested::List(offsets, array.validity(), is_optional));
+            to_leaves(array.values().as_ref(), inner.is_nullable(), nested, leaves)
+        }
+        DataType::LargeList(inner) => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            let offsets = Offsets::Large(array.offsets());
+            nested.push(Nested::List(offsets, array.validity(), is_optional));
+            to_leaves(array.values().as_ref(), inner.is_nullable(), nested, leaves)
+        }
+        DataType::FixedSizeList(inner, size) => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let offsets = Offsets::Fixed(*size as usize);
+            nested.push(Nested::List(offsets, array.validity(), is_optional));
+            to_leaves(array.values().as_ref(), inner.is_nullable(), nested, leaves)
+        }
+        DataType::Map(_, _) => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let offsets = Offsets::Small(array.offsets());
+            nested.push(Nested::List(offsets, array.validity(), is_optional));
+            // the entries of a map are the (required) repeated group of the map.
+            to_leaves(array.field().as_ref(), false, nested, leaves)
+        }
+        DataType::Struct(fields) => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            nested.push(Nested::Struct(array.validity(), is_optional));
+            fields
+                .iter()
+                .zip(array.values().iter())
+                .for_each(|(field, values)| {
+                    to_leaves(values.as_ref(), field.is_nullable(), nested.clone(), leaves)
+                })
+        }
+        _ => {
+            nested.push(Nested::Primitive(array.validity(), is_optional));
+            leaves.push((array, nested))
+        }
+    }
+}
+
+/// Writes `array` to one [`CompressedPage`] per parquet column (leaf) of `array`, in the order
+/// of `descriptors`. Nested arrays (e.g. a [`StructArray`]) are written to as many columns as
+/// they have leaves; other arrays to a single column.
+pub fn array_to_columns(
+    array: &dyn Array,
+    descriptors: Vec<ColumnDescriptor>,
+    options: WriteOptions,
+    encoding: Encoding,
+) -> Result<Vec<CompressedPage>> {
+    match array.data_type() {
+        DataType::List(_)
+        | DataType::LargeList(_)
+        | DataType::FixedSizeList(_, _)
+        | DataType::Map(_, _)
+        | DataType::Struct(_) => {
+            let is_optional = descriptors
+                .first()
+                .map(|descriptor| is_type_nullable(descriptor.base_type()))
+                .unwrap_or(false);
+            let mut leaves = vec![];
+            to_leaves(array, is_optional, vec![], &mut leaves);
+            if leaves.len() != descriptors.len() {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The data type {:?} has {} leaves but {} parquet columns were provided",
+                    array.data_type(),
+                    leaves.len(),
+                    descriptors.len()
+                )));
+            }
+            leaves
+                .into_iter()
+                .zip(descriptors.into_iter())
+                .map(|((leaf, nested), descriptor)| {
+                    let nested = NestedInfo::new(nested, array.len());
+                    nested_array_to_page(leaf, nested, descriptor, options)
+                })
+                .collect()
+        }
+        _ => {
+            if descriptors.len() != 1 {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The data type {:?} is written to one parquet column but {} were provided",
+                    array.data_type(),
+                    descriptors.len()
+                )));
+            }
+            let descriptor = descriptors.into_iter().next().unwrap();
+            Ok(vec![array_to_page(array, descriptor, options, encoding)?])
+        }
+    }
+}
+
+/// Writes `array` to the pages of each of its parquet columns, as [`array_to_columns`] does,
+/// splitting it in pages of at most `sizes.page_rows` rows whose values have about
+/// `sizes.page_bytes` bytes. Dictionary-encoded columns are always written to a single page,
+/// since a column chunk has a single dictionary page.
+///
+/// The statistics of each page are exact. However, parquet2 derives the statistics of each column
+/// chunk from the statistics of its pages in the order of their physical type, and offers no way
+/// to set them. Thus, the minimum and maximum of column chunks of unsigned integers and of
+/// decimals stored as fixed-len bytes that are written in more than one page may be wrong.
+pub fn array_to_pages(
+    array: &dyn Array,
+    descriptors: Vec<ColumnDescriptor>,
+    options: WriteOptions,
+    encoding: Encoding,
+    sizes: SizeOptions,
+) -> Result<Vec<Vec<CompressedPage>>> {
+    let length = array.len();
+    let is_dictionary = matches!(array.data_type(), DataType::Dictionary(_, _))
+        || matches!(
+            encoding,
+            Encoding::PlainDictionary | Encoding::RleDictionary
+        );
+
+    let mut rows = sizes.page_rows.unwrap_or(length);
+    if let Some(page_bytes) = sizes.page_bytes {
+        let size = estimated_size(array);
+        let pages = (size + page_bytes.max(1) - 1) / page_bytes.max(1);
+        if pages > 1 {
+            rows = rows.min((length + pages - 1) / pages);
+        }
+    }
+    if is_dictionary {
+        rows = length;
+    }
+    let rows = rows.max(1);
+
+    let mut columns = descriptors.iter().map(|_| vec![]).collect::<Vec<_>>();
+    let mut offset = 0;
+    loop {
+        let page_length = rows.min(length - offset);
+        let page = array.slice(offset, page_length);
+        let pages = array_to_columns(page.as_ref(), descriptors.clone(), options, encoding)?;
+        columns
+            .iter_mut()
+            .zip(pages.into_iter())
+            .for_each(|(column, page)| column.push(page));
+        offset += page_length;
+        if offset >= length {
+            break;
+        }
+    }
+    Ok(columns)
+}
+
+/// Returns the first and last offset of `offsets`.
+fn offsets_range<O: Offset>(offsets: &[O]) -> (usize, usize) {
+    (offsets[0].to_usize(), offsets[offsets.len() - 1].to_usize())
+}
+
+/// Estimates the size in bytes of the plain-encoded values of `array`.
+fn estimated_size(array: &dyn Array) -> usize {
+    let length = array.len();
+    match array.data_type() {
+        DataType::Null => 0,
+        DataType::Boolean => (length + 7) / 8,
+        DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Date32
+        | DataType::Time32(_)
+        | DataType::Float32 => 4 * length,
+        DataType::Interval(_) => 12 * length,
+        DataType::FixedSizeBinary(size) => *size as usize * length,
+        DataType::Decimal(precision, _) => decimal_length_from_precision(*precision) * length,
+        DataType::Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            let (start, end) = offsets_range(array.offsets());
+            end - start + 4 * length
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            let (start, end) = offsets_range(array.offsets());
+            end - start + 4 * length
+        }
+        DataType::Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            let (start, end) = offsets_range(array.offsets());
+            end - start + 4 * length
+        }
+        DataType::LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            let (start, end) = offsets_range(array.offsets());
+            end - start + 4 * length
+        }
+        DataType::List(_) => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            let (start, end) = offsets_range(array.offsets());
+            estimated_size(array.values().slice(start, end - start).as_ref())
+        }
+        DataType::LargeList(_) => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            let (start, end) = offsets_range(array.offsets());
+            estimated_size(array.values().slice(start, end - start).as_ref())
+        }
+        DataType::FixedSizeList(_, _) => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            estimated_size(array.values().as_ref())
+        }
+        DataType::Map(_, _) => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let (start, end) = offsets_range(array.offsets());
+            estimated_size(array.field().slice(start, end - start).as_ref())
+        }
+        DataType::Struct(_) => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            array
+                .values()
+                .iter()
+                .map(|x| estimated_size(x.as_ref()))
+                .sum()
+        }
+        _ => 8 * length,
+    }
+}
+
+/// Writes `array` to a [`CompressedPage`] of the column `descriptor` using `encoding`.
+///
+/// [`DictionaryArray`]s are always written with dictionary encoding. Other arrays are written
+/// with dictionary encoding when `encoding` is [`Encoding::PlainDictionary`] or
+/// [`Encoding::RleDictionary`], falling back to plain encoding when the dictionary would be too
+/// large.
+///
+/// Integers can also be written with [`Encoding::DeltaBinaryPacked`], strings and binary with
+/// [`Encoding::DeltaLengthByteArray`] and [`Encoding::DeltaByteArray`] and floats with
+/// [`Encoding::ByteStreamSplit`].
 pub fn array_to_page(
     array: &dyn Array,
     descriptor: ColumnDescriptor,
     options: WriteOptions,
+    encoding: Encoding,
+) -> Result<CompressedPage> {
+    match (array.data_type(), encoding) {
+        (DataType::Dictionary(key_type, _), _) => match key_type.as_ref() {
+            DataType::Int8 => dyn_dictionary_to_page::<i8>(array, descriptor, options),
+            DataType::Int16 => dyn_dictionary_to_page::<i16>(array, descriptor, options),
+            DataType::Int32 => dyn_dictionary_to_page::<i32>(array, descriptor, options),
+            DataType::Int64 => dyn_dictionary_to_page::<i64>(array, descriptor, options),
+            DataType::UInt8 => dyn_dictionary_to_page::<u8>(array, descriptor, options),
+            DataType::UInt16 => dyn_dictionary_to_page::<u16>(array, descriptor, options),
+            DataType::UInt32 => dyn_dictionary_to_page::<u32>(array, descriptor, options),
+            DataType::UInt64 => dyn_dictionary_to_page::<u64>(array, descriptor, options),
+            _ => unreachable!(),
+        },
+        (_, Encoding::Plain) => plain_array_to_page(array, descriptor, options),
+        (_, Encoding::PlainDictionary) | (_, Encoding::RleDictionary) => {
+            dictionary::encode_array_to_page(array, descriptor, options)
+        }
+        (data_type, other) if can_encode(data_type, &other) => {
+            encoded_array_to_page(array, descriptor, options, other)
+        }
+        (data_type, other) => Err(ArrowError::NotYetImplemented(format!(
+            "Writing parquet pages of {:?} with encoding {:?}",
+            data_type, other
+        ))),
+    }
+}
+
+/// Whether arrays of `data_type` can be written with the non-plain, non-dictionary `encoding`.
+fn can_encode(data_type: &DataType, encoding: &Encoding) -> bool {
+    match encoding {
+        Encoding::DeltaBinaryPacked => match data_type {
+            DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::UInt64
+            | DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Date32
+            | DataType::Time32(_)
+            | DataType::Int64
+            | DataType::Date64
+            | DataType::Time64(_)
+            | DataType::Timestamp(_, _)
+            | DataType::Duration(_) => true,
+            DataType::Decimal(precision, _) => *precision <= 18,
+            _ => false,
+        },
+        Encoding::DeltaLengthByteArray | Encoding::DeltaByteArray => matches!(
+            data_type,
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Binary | DataType::LargeBinary
+        ),
+        Encoding::ByteStreamSplit => matches!(data_type, DataType::Float32 | DataType::Float64),
+        _ => false,
+    }
+}
+
+fn dyn_dictionary_to_page<K: DictionaryKey>(
+    array: &dyn Array,
+    descriptor: ColumnDescriptor,
+    options: WriteOptions,
+) -> Result<CompressedPage> {
+    let array = array.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    dictionary::array_to_page(array, descriptor, options, None)
+}
+
+fn plain_array_to_page(
+    array: &dyn Array,
+    descriptor: ColumnDescriptor,
+    options: WriteOptions,
+) -> Result<CompressedPage> {
+    encoded_array_to_page(array, descriptor, options, Encoding::Plain)
+}
+
+/// Writes `array` with `encoding`, which must be [`Encoding::Plain`] or an encoding for which
+/// [`can_encode`] holds.
+fn encoded_array_to_page(
+    array: &dyn Array,
+    descriptor: ColumnDescriptor,
+    options: WriteOptions,
+    encoding: Encoding,
 ) -> Result<CompressedPage> {
-    // using plain encoding format
     match array.data_type() {
         DataType::Boolean => {
             boolean::array_to_page(array.as_any().downcast_ref().unwrap(), options, descriptor)
@@ -102,37 +428,44 @@ pub fn array_to_page(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::UInt16 => primitive::array_to_page::<u16, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::UInt32 => primitive::array_to_page::<u32, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::UInt64 => primitive::array_to_page::<u64, i64>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Int8 => primitive::array_to_page::<i8, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Int16 => primitive::array_to_page::<i16, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Int32 | DataType::Date32 | DataType::Time32(_) => {
             primitive::array_to_page::<i32, i32>(
                 array.as_any().downcast_ref().unwrap(),
                 options,
                 descriptor,
+                encoding,
             )
         }
         DataType::Int64
@@ -143,36 +476,47 @@ pub fn array_to_page(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Float32 => primitive::array_to_page::<f32, f32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Float64 => primitive::array_to_page::<f64, f64>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
+        ),
+        DataType::Utf8 => utf8::array_to_page::<i32>(
+            array.as_any().downcast_ref().unwrap(),
+            options,
+            descriptor,
+            encoding,
+        ),
+        DataType::LargeUtf8 => utf8::array_to_page::<i64>(
+            array.as_any().downcast_ref().unwrap(),
+            options,
+            descriptor,
+            encoding,
         ),
-        DataType::Utf8 => {
-            utf8::array_to_page::<i32>(array.as_any().downcast_ref().unwrap(), options, descriptor)
-        }
-        DataType::LargeUtf8 => {
-            utf8::array_to_page::<i64>(array.as_any().downcast_ref().unwrap(), options, descriptor)
-        }
         DataType::Binary => binary::array_to_page::<i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::LargeBinary => binary::array_to_page::<i64>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Null => {
             let array = Int32Array::new_null(DataType::Int32, array.len());
-            primitive::array_to_page::<i32, i32>(&array, options, descriptor)
+            primitive::array_to_page::<i32, i32>(&array, options, descriptor, encoding)
         }
         DataType::Interval(IntervalUnit::YearMonth) => {
             let array = array
@@ -190,7 +534,7 @@ pub fn array_to_page(
                 values.into(),
                 array.validity().clone(),
             );
-            fixed_len_bytes::array_to_page(&array, options, descriptor)
+            fixed_len_bytes::array_to_page(&array, options, descriptor, SortOrder::Undefined)
         }
         DataType::Interval(IntervalUnit::DayTime) => {
             let array = array
@@ -208,12 +552,13 @@ pub fn array_to_page(
                 values.into(),
                 array.validity().clone(),
             );
-            fixed_len_bytes::array_to_page(&array, options, descriptor)
+            fixed_len_bytes::array_to_page(&array, options, descriptor, SortOrder::Undefined)
         }
         DataType::FixedSizeBinary(_) => fixed_len_bytes::array_to_page(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            SortOrder::Unsigned,
         ),
         DataType::Decimal(precision, _) => {
             let precision = *precision;
@@ -229,7 +574,7 @@ pub fn array_to_page(
                     values,
                     array.validity().clone(),
                 );
-                primitive::array_to_page::<i32, i32>(&array, options, descriptor)
+                primitive::array_to_page::<i32, i32>(&array, options, descriptor, encoding)
             } else if precision <= 18 {
                 let array = array
                     .as_any()
@@ -242,7 +587,7 @@ pub fn array_to_page(
                     values,
                     array.validity().clone(),
                 );
-                primitive::array_to_page::<i64, i64>(&array, options, descriptor)
+                primitive::array_to_page::<i64, i64>(&array, options, descriptor, encoding)
             } else {
                 let array = array
                     .as_any()
@@ -261,11 +606,16 @@ pub fn array_to_page(
                     values.into(),
                     array.validity().clone(),
                 );
-                fixed_len_bytes::array_to_page(&array, options, descriptor)
+                fixed_len_bytes::array_to_page(&array, options, descriptor, SortOrder::Signed)
             }
         }
-        DataType::FixedSizeList(_, _) | DataType::List(_) | DataType::LargeList(_) => {
-            nested_array_to_page(array, descriptor, options)
+        DataType::FixedSizeList(_, _)
+        | DataType::List(_)
+        | DataType::LargeList(_)
+        | DataType::Map(_, _)
+        | DataType::Struct(_) => {
+            let mut pages = array_to_columns(array, vec![descriptor], options, Encoding::Plain)?;
+            Ok(pages.pop().unwrap())
         }
         other => Err(ArrowError::NotYetImplemented(format!(
             "Writing parquet V1 pages for data type {:?}",
@@ -275,139 +625,67 @@ pub fn array_to_page(
 }
 
 macro_rules! dyn_nested_prim {
-    ($from:ty, $to:ty, $offset:ty, $values:expr, $nested:expr,$descriptor:expr, $options:expr) => {{
+    ($from:ty, $to:ty, $values:expr, $nested:expr,$descriptor:expr, $options:expr) => {{
         let values = $values.as_any().downcast_ref().unwrap();
 
-        primitive::nested_array_to_page::<$from, $to, $offset>(
-            values,
-            $options,
-            $descriptor,
-            $nested,
-        )
+        primitive::nested_array_to_page::<$from, $to>(values, $options, $descriptor, $nested)
     }};
 }
 
-fn list_array_to_page<O: Offset>(
-    offsets: &[O],
-    validity: &Option<Bitmap>,
+/// Writes the leaf `values` of a nested array to a [`CompressedPage`].
+fn nested_array_to_page(
     values: &dyn Array,
+    nested: NestedInfo,
     descriptor: ColumnDescriptor,
     options: WriteOptions,
 ) -> Result<CompressedPage> {
     use DataType::*;
-    let is_optional = is_type_nullable(descriptor.type_());
-    let nested = NestedInfo::new(offsets, validity, is_optional);
-
     match values.data_type() {
         Boolean => {
             let values = values.as_any().downcast_ref().unwrap();
-            boolean::nested_array_to_page::<O>(values, options, descriptor, nested)
+            boolean::nested_array_to_page(values, options, descriptor, nested)
         }
-        UInt8 => dyn_nested_prim!(u8, i32, O, values, nested, descriptor, options),
-        UInt16 => dyn_nested_prim!(u16, i32, O, values, nested, descriptor, options),
-        UInt32 => dyn_nested_prim!(u32, i32, O, values, nested, descriptor, options),
-        UInt64 => dyn_nested_prim!(u64, i64, O, values, nested, descriptor, options),
+        UInt8 => dyn_nested_prim!(u8, i32, values, nested, descriptor, options),
+        UInt16 => dyn_nested_prim!(u16, i32, values, nested, descriptor, options),
+        UInt32 => dyn_nested_prim!(u32, i32, values, nested, descriptor, options),
+        UInt64 => dyn_nested_prim!(u64, i64, values, nested, descriptor, options),
 
-        Int8 => dyn_nested_prim!(i8, i32, O, values, nested, descriptor, options),
-        Int16 => dyn_nested_prim!(i16, i32, O, values, nested, descriptor, options),
+        Int8 => dyn_nested_prim!(i8, i32, values, nested, descriptor, options),
+        Int16 => dyn_nested_prim!(i16, i32, values, nested, descriptor, options),
         Int32 | Date32 | Time32(_) => {
-            dyn_nested_prim!(i32, i32, O, values, nested, descriptor, options)
+            dyn_nested_prim!(i32, i32, values, nested, descriptor, options)
         }
         Int64 | Date64 | Time64(_) | Timestamp(_, _) | Duration(_) => {
-            dyn_nested_prim!(i64, i64, O, values, nested, descriptor, options)
+            dyn_nested_prim!(i64, i64, values, nested, descriptor, options)
         }
 
-        Float32 => dyn_nested_prim!(f32, f32, O, values, nested, descriptor, options),
-        Float64 => dyn_nested_prim!(f64, f64, O, values, nested, descriptor, options),
+        Float32 => dyn_nested_prim!(f32, f32, values, nested, descriptor, options),
+        Float64 => dyn_nested_prim!(f64, f64, values, nested, descriptor, options),
 
         Utf8 => {
             let values = values.as_any().downcast_ref().unwrap();
-            let is_optional = is_type_nullable(descriptor.type_());
 
-            utf8::nested_array_to_page::<i32, O>(
-                values,
-                options,
-                descriptor,
-                NestedInfo::new(offsets, validity, is_optional),
-            )
+            utf8::nested_array_to_page::<i32>(values, options, descriptor, nested)
         }
         LargeUtf8 => {
             let values = values.as_any().downcast_ref().unwrap();
-            let is_optional = is_type_nullable(descriptor.type_());
 
-            utf8::nested_array_to_page::<i64, O>(
-                values,
-                options,
-                descriptor,
-                NestedInfo::new(offsets, validity, is_optional),
-            )
+            utf8::nested_array_to_page::<i64>(values, options, descriptor, nested)
         }
         Binary => {
             let values = values.as_any().downcast_ref().unwrap();
-            let is_optional = is_type_nullable(descriptor.type_());
 
-            binary::nested_array_to_page::<i32, O>(
-                values,
-                options,
-                descriptor,
-                NestedInfo::new(offsets, validity, is_optional),
-            )
+            binary::nested_array_to_page::<i32>(values, options, descriptor, nested)
         }
         LargeBinary => {
             let values = values.as_any().downcast_ref().unwrap();
-            let is_optional = is_type_nullable(descriptor.type_());
 
-            binary::nested_array_to_page::<i64, O>(
-                values,
-                options,
-                descriptor,
-                NestedInfo::new(offsets, validity, is_optional),
-            )
-        }
-        _ => todo!(),
-    }
-}
-
-fn nested_array_to_page(
-    array: &dyn Array,
-    descriptor: ColumnDescriptor,
-    options: WriteOptions,
-) -> Result<CompressedPage> {
-    match array.data_type() {
-        DataType::List(_) => {
-            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
-            list_array_to_page(
-                array.offsets(),
-                array.validity(),
-                array.values().as_ref(),
-                descriptor,
-                options,
-            )
+            binary::nested_array_to_page::<i64>(values, options, descriptor, nested)
         }
-        DataType::LargeList(_) => {
-            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
-            list_array_to_page(
-                array.offsets(),
-                array.validity(),
-                array.values().as_ref(),
-                descriptor,
-                options,
-            )
-        }
-        DataType::FixedSizeList(_, size) => {
-            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
-            let offsets = (0..array.len())
-                .map(|x| size * x as i32)
-                .collect::<Vec<_>>();
-            list_array_to_page(
-                &offsets,
-                array.validity(),
-                array.values().as_ref(),
-                descriptor,
-                options,
-            )
-        }
-        _ => todo!(),
+        other => Err(ArrowError::NotYetImplemented(format!(
+            "Writing nested parquet columns with leaves of data type {:?}",
+            other
+        ))),
     }
 }
 
@@ -415,6 +693,10 @@ fn nested_array_to_page(
 mod tests {
     use super::*;
 
+    use std::sync::Arc;
+
+    use crate::bitmap::Bitmap;
+
     use crate::error::Result;
     use std::io::Cursor;
 
@@ -462,7 +744,9 @@ mod tests {
             std::iter::once(Result::Ok(DynIter::new(std::iter::once(Ok(DynIter::new(
                 std::iter::once(array.as_ref())
                     .zip(parquet_schema.columns().to_vec().into_iter())
-                    .map(|(array, descriptor)| array_to_page(array, descriptor, options)),
+                    .map(|(array, descriptor)| {
+                        array_to_page(array, descriptor, options, Encoding::Plain)
+                    }),
             ))))));
 
         let mut writer = Cursor::new(vec![]);
@@ -597,4 +881,781 @@ mod tests {
     fn test_list_large_binary_optional_v1() -> Result<()> {
         round_trip(6, true, true, Version::V1, CompressionCodec::Uncompressed)
     }
+
+    fn sized_batch(offset: i64, length: usize) -> Result<crate::record_batch::RecordBatch> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, false),
+        ]));
+        let a = (0..length as i64)
+            .map(|x| offset + x)
+            .map(|x| if x % 3 == 0 { None } else { Some(x) })
+            .collect::<Int64Array>();
+        let b = (0..length as i64)
+            .map(|x| Some((offset + x).to_string()))
+            .collect::<Utf8Array<i32>>();
+        crate::record_batch::RecordBatch::try_new(schema, vec![Arc::new(a), Arc::new(b)])
+    }
+
+    fn write_sized(
+        batches: Vec<crate::record_batch::RecordBatch>,
+        columns: Vec<ColumnOptions>,
+        sizes: SizeOptions,
+    ) -> Result<Vec<u8>> {
+        let schema = batches[0].schema().clone();
+        let options = WriteOptions {
+            write_statistics: true,
+            compression: CompressionCodec::Uncompressed,
+            version: Version::V2,
+        };
+        let row_groups = RowGroupIterator::try_new_with_options(
+            batches.into_iter().map(Ok),
+            &schema,
+            options,
+            columns,
+            sizes,
+        )?;
+
+        let mut writer = Cursor::new(vec![]);
+        write_file(
+            &mut writer,
+            row_groups,
+            &schema,
+            to_parquet_schema(&schema)?,
+            options,
+            None,
+        )?;
+        Ok(writer.into_inner())
+    }
+
+    fn read_sized(data: Vec<u8>) -> Result<Vec<crate::record_batch::RecordBatch>> {
+        crate::io::parquet::read::RecordReader::try_new(
+            Cursor::new(data),
+            None,
+            None,
+            Arc::new(|_, _| true),
+        )?
+        .collect()
+    }
+
+    fn count_pages(data: &[u8], column: usize) -> Result<Vec<usize>> {
+        let mut reader = Cursor::new(data);
+        let metadata = crate::io::parquet::read::read_metadata(&mut reader)?;
+        (0..metadata.row_groups.len())
+            .map(|row_group| {
+                let pages = crate::io::parquet::read::get_page_iterator(
+                    &metadata,
+                    row_group,
+                    column,
+                    &mut reader,
+                    vec![],
+                )?;
+                Ok(pages.count())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn row_group_rows() -> Result<()> {
+        let batches = vec![sized_batch(0, 4)?, sized_batch(4, 4)?, sized_batch(8, 5)?];
+        let sizes = SizeOptions {
+            row_group_rows: Some(5),
+            ..Default::default()
+        };
+        let columns = vec![Encoding::Plain.into(); 2];
+        let result = read_sized(write_sized(batches, columns, sizes)?)?;
+
+        let lengths = result.iter().map(|x| x.num_rows()).collect::<Vec<_>>();
+        assert_eq!(lengths, vec![5, 5, 3]);
+        assert_eq!(result[1], sized_batch(5, 5)?);
+        assert_eq!(result[2], sized_batch(10, 3)?);
+        Ok(())
+    }
+
+    #[test]
+    fn page_rows_and_bytes() -> Result<()> {
+        let batch = sized_batch(0, 100)?;
+        let sizes = SizeOptions {
+            page_rows: Some(30),
+            ..Default::default()
+        };
+        let data = write_sized(vec![batch.clone()], vec![Encoding::Plain.into(); 2], sizes)?;
+        assert_eq!(count_pages(&data, 0)?, vec![4]);
+        assert_eq!(read_sized(data)?, vec![batch.clone()]);
+
+        // 100 values of 8 bytes in pages of about 200 bytes
+        let sizes = SizeOptions {
+            page_bytes: Some(200),
+            ..Default::default()
+        };
+        let data = write_sized(vec![batch.clone()], vec![Encoding::Plain.into(); 2], sizes)?;
+        assert_eq!(count_pages(&data, 0)?, vec![4]);
+        assert_eq!(read_sized(data)?, vec![batch]);
+        Ok(())
+    }
+
+    #[test]
+    fn column_options() -> Result<()> {
+        let batch = sized_batch(0, 10)?;
+        let columns = vec![
+            ColumnOptions {
+                encoding: Encoding::DeltaBinaryPacked,
+                compression: Some(CompressionCodec::Snappy),
+                write_statistics: Some(false),
+            },
+            Encoding::DeltaByteArray.into(),
+        ];
+        let data = write_sized(vec![batch.clone()], columns, SizeOptions::default())?;
+
+        let metadata = crate::io::parquet::read::read_metadata(&mut Cursor::new(&data))?;
+        let row_group = &metadata.row_groups[0];
+        let column = row_group.column(0);
+        assert_eq!(column.column_metadata().codec, CompressionCodec::Snappy);
+        assert!(column.statistics().is_none());
+        // the second column uses the options of the file
+        let column = row_group.column(1);
+        assert_eq!(
+            column.column_metadata().codec,
+            CompressionCodec::Uncompressed
+        );
+        assert!(column.statistics().is_some());
+
+        assert_eq!(read_sized(data)?, vec![batch]);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_sizes() -> Result<()> {
+        let batch = sized_batch(0, 10)?;
+        let sizes = SizeOptions {
+            row_group_rows: Some(0),
+            ..Default::default()
+        };
+        let options = WriteOptions {
+            write_statistics: false,
+            compression: CompressionCodec::Uncompressed,
+            version: Version::V1,
+        };
+        let columns = vec![Encoding::Plain.into(); 2];
+        assert!(RowGroupIterator::try_new_with_options(
+            std::iter::once(Ok(batch.clone())),
+            batch.schema(),
+            options,
+            columns,
+            sizes
+        )
+        .is_err());
+
+        // a row group must have at least one column
+        assert!(RowGroupIterator::try_new_with_options(
+            std::iter::empty::<Result<crate::record_batch::RecordBatch>>(),
+            &Schema::new(vec![]),
+            options,
+            vec![],
+            SizeOptions {
+                row_group_rows: Some(5),
+                ..Default::default()
+            }
+        )
+        .is_err());
+        Ok(())
+    }
+
+    fn round_trip_nested(array: Arc<dyn Array>, version: Version) -> Result<()> {
+        let field = Field::new("a1", array.data_type().clone(), true);
+        let schema = Arc::new(Schema::new(vec![field]));
+        let batch = crate::record_batch::RecordBatch::try_new(schema.clone(), vec![array])?;
+
+        let options = WriteOptions {
+            write_statistics: false,
+            compression: CompressionCodec::Uncompressed,
+            version,
+        };
+
+        let parquet_schema = to_parquet_schema(&schema)?;
+        let row_groups = RowGroupIterator::try_new(
+            std::iter::once(Ok(batch.clone())),
+            &schema,
+            options,
+            vec![Encoding::Plain],
+        )?;
+
+        let mut writer = Cursor::new(vec![]);
+        write_file(
+            &mut writer,
+            row_groups,
+            &schema,
+            parquet_schema,
+            options,
+            None,
+        )?;
+
+        let data = writer.into_inner();
+
+        let mut reader = crate::io::parquet::read::RecordReader::try_new(
+            Cursor::new(data),
+            None,
+            None,
+            Arc::new(|_, _| true),
+        )?;
+        let result = reader.next().unwrap()?;
+        assert_eq!(batch.column(0).as_ref(), result.column(0).as_ref());
+        Ok(())
+    }
+
+    fn round_trip_map(version: Version) -> Result<()> {
+        let fields = vec![
+            Field::new("key", DataType::Utf8, false),
+            Field::new("value", DataType::Int32, true),
+        ];
+        let data_type = DataType::Map(
+            Box::new(Field::new(
+                "entries",
+                DataType::Struct(fields.clone()),
+                false,
+            )),
+            false,
+        );
+        // [{"a": 1, "b": None}, {}, None, {"c": 3}]
+        let entries = StructArray::from_data(
+            fields,
+            vec![
+                Arc::new(Utf8Array::<i32>::from_slice(&["a", "b", "c"])),
+                Arc::new(Int32Array::from(&[Some(1), None, Some(3)])),
+            ],
+            None,
+        );
+        let array = Arc::new(MapArray::from_data(
+            data_type,
+            Buffer::from(&[0, 2, 2, 2, 3]),
+            Arc::new(entries),
+            Some(Bitmap::from([true, true, false, true])),
+        ));
+        round_trip_nested(array, version)
+    }
+
+    #[test]
+    fn test_map_v1() -> Result<()> {
+        round_trip_map(Version::V1)
+    }
+
+    #[test]
+    fn test_map_v2() -> Result<()> {
+        round_trip_map(Version::V2)
+    }
+
+    fn struct_array() -> StructArray {
+        let fields = vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+        ];
+        // [{a: 1, b: "a"}, None, {a: None, b: "c"}, {a: 4, b: "d"}]
+        StructArray::from_data(
+            fields,
+            vec![
+                Arc::new(Int64Array::from(&[Some(1), None, None, Some(4)])),
+                Arc::new(Utf8Array::<i32>::from(&[
+                    Some("a"),
+                    None,
+                    Some("c"),
+                    Some("d"),
+                ])),
+            ],
+            Some(Bitmap::from([true, false, true, true])),
+        )
+    }
+
+    fn list_struct_array() -> ListArray<i32> {
+        let values = struct_array();
+        // [[s0, s1], None, [], [s2, s3]]
+        ListArray::<i32>::from_data(
+            DataType::List(Box::new(Field::new(
+                "item",
+                values.data_type().clone(),
+                true,
+            ))),
+            Buffer::from(&[0, 2, 2, 2, 4]),
+            Arc::new(values),
+            Some(Bitmap::from([true, false, true, true])),
+        )
+    }
+
+    fn struct_list_array() -> StructArray {
+        // [[1, None], None, [], [4]]
+        let list = ListArray::<i32>::from_data(
+            DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+            Buffer::from(&[0, 2, 2, 2, 3]),
+            Arc::new(Int32Array::from(&[Some(1), None, Some(4)])),
+            Some(Bitmap::from([true, false, true, true])),
+        );
+        let fields = vec![
+            Field::new("a", list.data_type().clone(), true),
+            Field::new("b", DataType::Boolean, true),
+        ];
+        StructArray::from_data(
+            fields,
+            vec![
+                Arc::new(list),
+                Arc::new(BooleanArray::from(&[Some(true), None, Some(false), None])),
+            ],
+            None,
+        )
+    }
+
+    fn list_list_array() -> ListArray<i64> {
+        // [[[1, 2], None], None, [[], [3]]]
+        let inner = ListArray::<i32>::from_data(
+            DataType::List(Box::new(Field::new("item", DataType::UInt16, false))),
+            Buffer::from(&[0, 2, 2, 2, 3]),
+            Arc::new(UInt16Array::from_slice(&[1, 2, 3])),
+            Some(Bitmap::from([true, false, true, true])),
+        );
+        ListArray::<i64>::from_data(
+            DataType::LargeList(Box::new(Field::new(
+                "item",
+                inner.data_type().clone(),
+                true,
+            ))),
+            Buffer::from(&[0, 2, 2, 4]),
+            Arc::new(inner),
+            Some(Bitmap::from([true, false, true])),
+        )
+    }
+
+    fn fixed_size_list_array() -> FixedSizeListArray {
+        // [[1, 2], None, [None, 6]]
+        FixedSizeListArray::from_data(
+            DataType::FixedSizeList(Box::new(Field::new("item", DataType::Float64, true)), 2),
+            Arc::new(Float64Array::from(&[
+                Some(1.0),
+                Some(2.0),
+                None,
+                None,
+                None,
+                Some(6.0),
+            ])),
+            Some(Bitmap::from([true, false, true])),
+        )
+    }
+
+    #[test]
+    fn test_struct_v1() -> Result<()> {
+        round_trip_nested(Arc::new(struct_array()), Version::V1)
+    }
+
+    #[test]
+    fn test_struct_v2() -> Result<()> {
+        round_trip_nested(Arc::new(struct_array()), Version::V2)
+    }
+
+    #[test]
+    fn test_list_struct_v1() -> Result<()> {
+        round_trip_nested(Arc::new(list_struct_array()), Version::V1)
+    }
+
+    #[test]
+    fn test_list_struct_v2() -> Result<()> {
+        round_trip_nested(Arc::new(list_struct_array()), Version::V2)
+    }
+
+    #[test]
+    fn test_struct_list_v1() -> Result<()> {
+        round_trip_nested(Arc::new(struct_list_array()), Version::V1)
+    }
+
+    #[test]
+    fn test_struct_list_v2() -> Result<()> {
+        round_trip_nested(Arc::new(struct_list_array()), Version::V2)
+    }
+
+    #[test]
+    fn test_list_list_v1() -> Result<()> {
+        round_trip_nested(Arc::new(list_list_array()), Version::V1)
+    }
+
+    #[test]
+    fn test_list_list_v2() -> Result<()> {
+        round_trip_nested(Arc::new(list_list_array()), Version::V2)
+    }
+
+    #[test]
+    fn test_fixed_size_list_v1() -> Result<()> {
+        round_trip_nested(Arc::new(fixed_size_list_array()), Version::V1)
+    }
+
+    #[test]
+    fn test_fixed_size_list_v2() -> Result<()> {
+        round_trip_nested(Arc::new(fixed_size_list_array()), Version::V2)
+    }
+
+    fn round_trip_encoding(
+        array: &dyn Array,
+        expected: &dyn Array,
+        version: Version,
+        encoding: Encoding,
+    ) -> Result<()> {
+        round_trip_statistics(array, expected, version, encoding).map(|_| ())
+    }
+
+    /// Writes `array` with `encoding`, checks that it is read as `expected` and returns the
+    /// statistics of its column chunk.
+    fn round_trip_statistics(
+        array: &dyn Array,
+        expected: &dyn Array,
+        version: Version,
+        encoding: Encoding,
+    ) -> Result<Box<dyn crate::io::parquet::read::statistics::Statistics>> {
+        let field = Field::new("a1", expected.data_type().clone(), true);
+        let schema = Schema::new(vec![field]);
+
+        let options = WriteOptions {
+            write_statistics: true,
+            compression: CompressionCodec::Uncompressed,
+            version,
+        };
+
+        let parquet_schema = to_parquet_schema(&schema)?;
+
+        let row_groups =
+            std::iter::once(Result::Ok(DynIter::new(std::iter::once(Ok(DynIter::new(
+                std::iter::once(array)
+                    .zip(parquet_schema.columns().to_vec().into_iter())
+                    .map(|(array, descriptor)| array_to_page(array, descriptor, options, encoding)),
+            ))))));
+
+        let mut writer = Cursor::new(vec![]);
+        write_file(
+            &mut writer,
+            row_groups,
+            &schema,
+            parquet_schema,
+            options,
+            None,
+        )?;
+
+        let data = writer.into_inner();
+
+        let (result, statistics) = read_column(&mut Cursor::new(data), 0, 0)?;
+        assert_eq!(expected, result.as_ref());
+        Ok(statistics.unwrap())
+    }
+
+    #[test]
+    fn test_utf8_dictionary_encoded_v1() -> Result<()> {
+        let array = pyarrow_nullable(2);
+        round_trip_encoding(
+            array.as_ref(),
+            array.as_ref(),
+            Version::V1,
+            Encoding::PlainDictionary,
+        )
+    }
+
+    #[test]
+    fn test_utf8_dictionary_encoded_v2() -> Result<()> {
+        let array = pyarrow_nullable(2);
+        round_trip_encoding(
+            array.as_ref(),
+            array.as_ref(),
+            Version::V2,
+            Encoding::RleDictionary,
+        )
+    }
+
+    #[test]
+    fn test_int64_dictionary_encoded_v2() -> Result<()> {
+        let array = pyarrow_nullable(0);
+        round_trip_encoding(
+            array.as_ref(),
+            array.as_ref(),
+            Version::V2,
+            Encoding::RleDictionary,
+        )
+    }
+
+    #[test]
+    fn test_int64_delta_binary_packed_v1() -> Result<()> {
+        let array = pyarrow_nullable(0);
+        round_trip_encoding(
+            array.as_ref(),
+            array.as_ref(),
+            Version::V1,
+            Encoding::DeltaBinaryPacked,
+        )
+    }
+
+    #[test]
+    fn test_uint32_delta_binary_packed_v2() -> Result<()> {
+        let array = UInt32Array::from_slice([u32::MAX, 0, 1, 1, u32::MAX - 10]);
+        round_trip_encoding(&array, &array, Version::V2, Encoding::DeltaBinaryPacked)
+    }
+
+    #[test]
+    fn test_int32_delta_binary_packed_extremes_v2() -> Result<()> {
+        let array = Int32Array::from_slice([i32::MIN, i32::MAX]);
+        round_trip_encoding(&array, &array, Version::V2, Encoding::DeltaBinaryPacked)
+    }
+
+    #[test]
+    fn test_utf8_delta_length_byte_array_v2() -> Result<()> {
+        let array = pyarrow_nullable(2);
+        round_trip_encoding(
+            array.as_ref(),
+            array.as_ref(),
+            Version::V2,
+            Encoding::DeltaLengthByteArray,
+        )
+    }
+
+    #[test]
+    fn test_utf8_delta_byte_array_v1() -> Result<()> {
+        let array = pyarrow_nullable(2);
+        round_trip_encoding(
+            array.as_ref(),
+            array.as_ref(),
+            Version::V1,
+            Encoding::DeltaByteArray,
+        )
+    }
+
+    #[test]
+    fn test_f64_byte_stream_split_v2() -> Result<()> {
+        let array = pyarrow_nullable(1);
+        round_trip_encoding(
+            array.as_ref(),
+            array.as_ref(),
+            Version::V2,
+            Encoding::ByteStreamSplit,
+        )
+    }
+
+    #[test]
+    fn test_dictionary_array_v1() -> Result<()> {
+        let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+        array.try_extend(vec![Some("a"), None, Some("b"), Some("a")])?;
+        let array: DictionaryArray<i32> = array.into();
+
+        let expected = Utf8Array::<i32>::from(&[Some("a"), None, Some("b"), Some("a")]);
+        round_trip_encoding(&array, &expected, Version::V1, Encoding::Plain)
+    }
+
+    #[test]
+    fn test_f64_dictionary_encoded_v2() -> Result<()> {
+        use crate::io::parquet::read::statistics::PrimitiveStatistics;
+        let array = Float64Array::from(&[Some(1.5), None, Some(-0.5), Some(1.5)]);
+        let statistics =
+            round_trip_statistics(&array, &array, Version::V2, Encoding::RleDictionary)?;
+        let statistics = statistics
+            .as_any()
+            .downcast_ref::<PrimitiveStatistics<f64>>()
+            .unwrap();
+        // only dictionary-encoded pages have a distinct count
+        assert_eq!(statistics.distinct_count, Some(2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_dictionary_array_null_values() -> Result<()> {
+        let values = Utf8Array::<i32>::from(&[Some("a"), None]);
+        let keys = PrimitiveArray::<i32>::from(&[Some(0), Some(1), None, Some(0)]);
+        let array = DictionaryArray::<i32>::from_data(keys, Arc::new(values));
+
+        let expected = Utf8Array::<i32>::from(&[Some("a"), None, None, Some("a")]);
+        round_trip_encoding(&array, &expected, Version::V2, Encoding::RleDictionary)
+    }
+
+    #[test]
+    fn statistics_unsigned() -> Result<()> {
+        use crate::io::parquet::read::statistics::PrimitiveStatistics;
+        let array = UInt32Array::from(&[Some(1), None, Some(u32::MAX), Some(2)]);
+        let expected = PrimitiveStatistics::<u32> {
+            data_type: DataType::UInt32,
+            null_count: Some(1),
+            distinct_count: None,
+            min_value: Some(1),
+            max_value: Some(u32::MAX),
+        };
+        for encoding in &[Encoding::Plain, Encoding::RleDictionary] {
+            let statistics = round_trip_statistics(&array, &array, Version::V2, *encoding)?;
+            let statistics = statistics
+                .as_any()
+                .downcast_ref::<PrimitiveStatistics<u32>>();
+            let distinct_count = match encoding {
+                Encoding::Plain => None,
+                _ => Some(3),
+            };
+            let expected = PrimitiveStatistics::<u32> {
+                distinct_count,
+                ..expected.clone()
+            };
+            assert_eq!(statistics, Some(&expected));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn statistics_binary_order() -> Result<()> {
+        use crate::io::parquet::read::statistics::Utf8Statistics;
+        let array = Utf8Array::<i32>::from(&[Some("abc"), Some("ab"), None, Some("b")]);
+        let statistics = round_trip_statistics(&array, &array, Version::V1, Encoding::Plain)?;
+        let expected = Utf8Statistics {
+            null_count: Some(1),
+            distinct_count: None,
+            min_value: Some("ab".to_string()),
+            max_value: Some("b".to_string()),
+        };
+        assert_eq!(
+            statistics.as_any().downcast_ref::<Utf8Statistics>(),
+            Some(&expected)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn statistics_dictionary_array() -> Result<()> {
+        use crate::io::parquet::read::statistics::Utf8Statistics;
+        let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+        array.try_extend(vec![Some("b"), None, Some("c"), Some("b")])?;
+        let array: DictionaryArray<i32> = array.into();
+        let expected = Utf8Array::<i32>::from(&[Some("b"), None, Some("c"), Some("b")]);
+
+        let statistics = round_trip_statistics(&array, &expected, Version::V2, Encoding::Plain)?;
+        let expected = Utf8Statistics {
+            null_count: Some(1),
+            distinct_count: None,
+            min_value: Some("b".to_string()),
+            max_value: Some("c".to_string()),
+        };
+        assert_eq!(
+            statistics.as_any().downcast_ref::<Utf8Statistics>(),
+            Some(&expected)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn statistics_fixed_len() {
+        // decimals of precision 20 are written as 9-byte big-endian two's complement integers
+        let size = decimal_length_from_precision(20);
+        let mut values = vec![];
+        for value in &[-1i128, 1, -256, 0] {
+            values.extend_from_slice(&value.to_be_bytes()[16 - size..]);
+        }
+        let array = FixedSizeBinaryArray::from_data(
+            DataType::FixedSizeBinary(size as i32),
+            values.into(),
+            Some(Bitmap::from([true, true, true, false])),
+        );
+        let bytes = |x: i128| x.to_be_bytes()[16 - size..].to_vec();
+
+        let statistics = fixed_len_bytes::build_statistics(&array, SortOrder::Signed);
+        assert_eq!(statistics.null_count, Some(1));
+        assert_eq!(statistics.min_value, Some(bytes(-256)));
+        assert_eq!(statistics.max_value, Some(bytes(1)));
+
+        let statistics = fixed_len_bytes::build_statistics(&array, SortOrder::Unsigned);
+        assert_eq!(statistics.min_value, Some(bytes(1)));
+        assert_eq!(statistics.max_value, Some(bytes(-1)));
+
+        let statistics = fixed_len_bytes::build_statistics(&array, SortOrder::Undefined);
+        assert_eq!(statistics.null_count, Some(1));
+        assert_eq!(statistics.min_value, None);
+        assert_eq!(statistics.max_value, None);
+    }
+
+    /// Returns the statistics of each page of `column` of the first row group.
+    fn page_statistics(
+        data: &[u8],
+        column: usize,
+    ) -> Result<Vec<Option<parquet2::statistics::ParquetStatistics>>> {
+        use crate::io::parquet::read::{Decompressor, PageHeader, StreamingIterator};
+        let mut reader = Cursor::new(data);
+        let metadata = crate::io::parquet::read::read_metadata(&mut reader)?;
+        let pages =
+            crate::io::parquet::read::get_page_iterator(&metadata, 0, column, &mut reader, vec![])?;
+        let mut pages = Decompressor::new(pages, vec![]);
+        let mut statistics = vec![];
+        while let Some(page) = pages.next() {
+            let page = page.as_ref().map_err(|x| x.clone())?;
+            statistics.push(match page.header() {
+                PageHeader::V1(header) => header.statistics.clone(),
+                PageHeader::V2(header) => header.statistics.clone(),
+            });
+        }
+        Ok(statistics)
+    }
+
+    #[test]
+    fn statistics_unsigned_pages() -> Result<()> {
+        use crate::io::parquet::read::statistics::PrimitiveStatistics;
+        // 3_000_000_000 is negative as an i32, the physical type of the column
+        let array = UInt32Array::from(&[Some(1), Some(10), None, Some(3_000_000_000)]);
+        let field = Field::new("a", DataType::UInt32, true);
+        let schema = Arc::new(Schema::new(vec![field]));
+        let batch =
+            crate::record_batch::RecordBatch::try_new(schema, vec![Arc::new(array.clone())])?;
+        let sizes = SizeOptions {
+            page_rows: Some(1),
+            ..Default::default()
+        };
+        let data = write_sized(vec![batch], vec![Encoding::Plain.into()], sizes)?;
+
+        // the statistics of each page are exact
+        let bytes = |x: u32| Some((x as i32).to_le_bytes().to_vec());
+        let statistics = page_statistics(&data, 0)?
+            .into_iter()
+            .map(|x| {
+                let x = x.unwrap();
+                (x.min_value, x.max_value)
+            })
+            .collect::<Vec<_>>();
+        let expected = vec![
+            (bytes(1), bytes(1)),
+            (bytes(10), bytes(10)),
+            (None, None),
+            (bytes(3_000_000_000), bytes(3_000_000_000)),
+        ];
+        assert_eq!(statistics, expected);
+
+        // known limitation: parquet2 reduces them to the statistics of the column chunk in the
+        // order of INT32, so that the minimum of the chunk is larger than its maximum
+        let (result, statistics) = read_column(Cursor::new(data), 0, 0)?;
+        assert_eq!(&array as &dyn Array, result.as_ref());
+        let statistics = statistics.unwrap();
+        let statistics = statistics
+            .as_any()
+            .downcast_ref::<PrimitiveStatistics<u32>>()
+            .unwrap();
+        assert_eq!(statistics.min_value, Some(3_000_000_000));
+        assert_eq!(statistics.max_value, Some(10));
+        Ok(())
+    }
+
+    #[test]
+    fn test_unknown_encoding() {
+        let array = pyarrow_nullable(0);
+        let field = Field::new("a1", array.data_type().clone(), true);
+        let parquet_schema = to_parquet_schema(&Schema::new(vec![field])).unwrap();
+        let options = WriteOptions {
+            write_statistics: false,
+            compression: CompressionCodec::Uncompressed,
+            version: Version::V2,
+        };
+        assert!(array_to_page(
+            array.as_ref(),
+            parquet_schema.columns()[0].clone(),
+            options,
+            Encoding::BitPacked
+        )
+        .is_err());
+        // floats can't be delta-encoded
+        let array = pyarrow_nullable(1);
+        assert!(array_to_page(
+            array.as_ref(),
+            parquet_schema.columns()[0].clone(),
+            options,
+            Encoding::DeltaBinaryPacked
+        )
+        .is_err());
+    }
 }
@@ -0,0 +1,45 @@
+use super::{CompressionCodec, Encoding, WriteOptions};
+
+/// The [`Encoding`] of a column together with its overrides of the file's [`WriteOptions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnOptions {
+    /// The encoding of the column's pages
+    pub encoding: Encoding,
+    /// The compression of the column; the file's compression when `None`
+    pub compression: Option<CompressionCodec>,
+    /// Whether to write the column's statistics; the file's option when `None`
+    pub write_statistics: Option<bool>,
+}
+
+impl ColumnOptions {
+    /// Returns the [`WriteOptions`] of this column: `options` with the overrides of `self`.
+    pub fn write_options(&self, options: WriteOptions) -> WriteOptions {
+        WriteOptions {
+            write_statistics: self.write_statistics.unwrap_or(options.write_statistics),
+            compression: self.compression.unwrap_or(options.compression),
+            version: options.version,
+        }
+    }
+}
+
+impl From<Encoding> for ColumnOptions {
+    fn from(encoding: Encoding) -> Self {
+        Self {
+            encoding,
+            compression: None,
+            write_statistics: None,
+        }
+    }
+}
+
+/// Targets on the number of rows of row groups and on the size of pages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SizeOptions {
+    /// The number of rows of each row group. Incoming batches are split or concatenated to
+    /// it; when `None`, each batch is written to its own row group.
+    pub row_group_rows: Option<usize>,
+    /// The maximum number of rows of each page.
+    pub page_rows: Option<usize>,
+    /// The approximate size in bytes of the (uncompressed) values of each page.
+    pub page_bytes: Option<usize>,
+}
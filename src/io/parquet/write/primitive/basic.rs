@@ -1,4 +1,5 @@
 use parquet2::{
+    encoding::Encoding,
     metadata::ColumnDescriptor,
     read::CompressedPage,
     statistics::{serialize_statistics, ParquetStatistics, PrimitiveStatistics, Statistics},
@@ -6,10 +7,10 @@ use parquet2::{
     write::WriteOptions,
 };
 
-use super::super::utils;
+use super::super::{encodings, utils};
 use crate::{
     array::{Array, PrimitiveArray},
-    error::Result,
+    error::{ArrowError, Result},
     io::parquet::read::is_type_nullable,
     types::NativeType as ArrowNativeType,
 };
@@ -37,14 +38,31 @@ where
     }
 }
 
+/// Returns the values of `array` as the parquet type `R`, without the nulls when `is_optional`.
+fn to_parquet_values<T, R>(array: &PrimitiveArray<T>, is_optional: bool) -> Vec<R>
+where
+    T: ArrowNativeType,
+    R: NativeType,
+    T: num::cast::AsPrimitive<R>,
+{
+    if is_optional {
+        array.iter().flatten().map(|x| x.as_()).collect()
+    } else {
+        array.values().iter().map(|x| x.as_()).collect()
+    }
+}
+
+/// Writes `array` to a page whose values are encoded with `encoding`, one of `PLAIN`,
+/// `DELTA_BINARY_PACKED` (integers only) and `BYTE_STREAM_SPLIT`.
 pub fn array_to_page<T, R>(
     array: &PrimitiveArray<T>,
     options: WriteOptions,
     descriptor: ColumnDescriptor,
+    encoding: Encoding,
 ) -> Result<CompressedPage>
 where
-    T: ArrowNativeType,
-    R: NativeType,
+    T: ArrowNativeType + PartialOrd,
+    R: NativeType + num::cast::AsPrimitive<i64>,
     T: num::cast::AsPrimitive<R>,
 {
     let is_optional = is_type_nullable(descriptor.type_());
@@ -62,7 +80,32 @@ where
 
     let definition_levels_byte_length = buffer.len();
 
-    encode_plain(array, is_optional, &mut buffer);
+    match encoding {
+        Encoding::Plain => encode_plain(array, is_optional, &mut buffer),
+        Encoding::DeltaBinaryPacked => {
+            let values = to_parquet_values::<T, R>(array, is_optional)
+                .into_iter()
+                .map(|x| x.as_());
+            if std::mem::size_of::<R>() == 4 {
+                // the deltas of INT32 columns wrap at 32 bits
+                let values = values.map(|x: i64| x as i32).collect::<Vec<_>>();
+                encodings::delta_bitpacked(&values, &mut buffer)
+            } else {
+                let values = values.collect::<Vec<i64>>();
+                encodings::delta_bitpacked(&values, &mut buffer)
+            }
+        }
+        Encoding::ByteStreamSplit => {
+            let values = to_parquet_values::<T, R>(array, is_optional);
+            encodings::byte_stream_split(&values, &mut buffer)
+        }
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Writing primitive parquet pages with encoding {:?}",
+                other
+            )))
+        }
+    }
 
     let uncompressed_page_size = buffer.len();
 
@@ -74,7 +117,7 @@ where
         None
     };
 
-    utils::build_plain_page(
+    utils::build_page(
         buffer,
         array.len(),
         array.null_count(),
@@ -84,38 +127,45 @@ where
         statistics,
         descriptor,
         options,
+        encoding,
+        None,
     )
 }
 
+/// Builds the statistics of `array`. Values are compared as `T`, so that unsigned integers,
+/// stored as signed integers, are ordered as unsigned. NaNs are not part of the statistics.
 pub fn build_statistics<T, R>(
     array: &PrimitiveArray<T>,
     descriptor: ColumnDescriptor,
 ) -> ParquetStatistics
 where
-    T: ArrowNativeType,
+    T: ArrowNativeType + PartialOrd,
     R: NativeType,
     T: num::cast::AsPrimitive<R>,
 {
+    let (min_value, max_value) = array
+        .iter()
+        .flatten()
+        .copied()
+        .filter(|x| x.partial_cmp(x).is_some())
+        .fold((None, None), |(min, max): (Option<T>, Option<T>), x| {
+            let min = match min {
+                Some(min) if min <= x => min,
+                _ => x,
+            };
+            let max = match max {
+                Some(max) if max >= x => max,
+                _ => x,
+            };
+            (Some(min), Some(max))
+        });
+
     let statistics = &PrimitiveStatistics::<R> {
         descriptor,
         null_count: Some(array.null_count() as i64),
         distinct_count: None,
-        max_value: array
-            .iter()
-            .flatten()
-            .map(|x| {
-                let x: R = x.as_();
-                x
-            })
-            .max_by(|x, y| x.ord(y)),
-        min_value: array
-            .iter()
-            .flatten()
-            .map(|x| {
-                let x: R = x.as_();
-                x
-            })
-            .min_by(|x, y| x.ord(y)),
+        max_value: max_value.map(|x| x.as_()),
+        min_value: min_value.map(|x| x.as_()),
     } as &dyn Statistics;
     serialize_statistics(statistics)
 }
@@ -6,36 +6,47 @@ use super::super::levels;
 use super::super::utils;
 use super::basic::{build_statistics, encode_plain};
 use crate::{
-    array::{Array, Offset, PrimitiveArray},
+    array::{Array, PrimitiveArray},
     error::Result,
     io::parquet::read::is_type_nullable,
     types::NativeType as ArrowNativeType,
 };
 
-pub fn array_to_page<T, R, O>(
+pub fn array_to_page<T, R>(
     array: &PrimitiveArray<T>,
     options: WriteOptions,
     descriptor: ColumnDescriptor,
-    nested: levels::NestedInfo<O>,
+    nested: levels::NestedInfo,
 ) -> Result<CompressedPage>
 where
-    T: ArrowNativeType,
+    T: ArrowNativeType + PartialOrd,
     R: NativeType,
     T: num::cast::AsPrimitive<R>,
-    O: Offset,
 {
     let is_optional = is_type_nullable(descriptor.type_());
 
-    let validity = array.validity();
+    let levels = nested.levels();
+    // values inside null or empty parents are not written
+    let array = levels::gather(array, &levels.values);
 
     let mut buffer = vec![];
-    levels::write_rep_levels(&mut buffer, &nested, options.version)?;
+    levels::write_levels(
+        &mut buffer,
+        &levels.rep,
+        descriptor.max_rep_level(),
+        options.version,
+    )?;
     let repetition_levels_byte_length = buffer.len();
 
-    levels::write_def_levels(&mut buffer, &nested, validity, options.version)?;
+    levels::write_levels(
+        &mut buffer,
+        &levels.def,
+        descriptor.max_def_level(),
+        options.version,
+    )?;
     let definition_levels_byte_length = buffer.len() - repetition_levels_byte_length;
 
-    encode_plain(array, is_optional, &mut buffer);
+    encode_plain(&array, is_optional, &mut buffer);
 
     let uncompressed_page_size = buffer.len();
 
@@ -46,15 +57,15 @@ where
     )?;
 
     let statistics = if options.write_statistics {
-        Some(build_statistics(array, descriptor.clone()))
+        Some(build_statistics(&array, descriptor.clone()))
     } else {
         None
     };
 
     utils::build_plain_page(
         buffer,
-        levels::num_values(nested.offsets()),
-        array.null_count(),
+        levels.num_values(),
+        levels.null_count(descriptor.max_def_level() as u32),
         uncompressed_page_size,
         repetition_levels_byte_length,
         definition_levels_byte_length,
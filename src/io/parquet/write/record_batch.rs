@@ -1,12 +1,35 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
 use super::{
-    array_to_page, to_parquet_schema, DynIter, RowGroupIter, SchemaDescriptor, WriteOptions,
+    array_to_pages, to_parquet_schema, ColumnOptions, DynIter, Encoding, ParquetType, RowGroupIter,
+    SchemaDescriptor, SizeOptions, WriteOptions,
 };
 use crate::{
+    array::{growable::make_growable, Array},
     datatypes::Schema,
     error::{ArrowError, Result},
     record_batch::RecordBatch,
 };
 
+/// Returns the number of parquet columns (leaves) of `type_`.
+fn num_leaves(type_: &ParquetType) -> usize {
+    match type_ {
+        ParquetType::PrimitiveType { .. } => 1,
+        ParquetType::GroupType { fields, .. } => fields.iter().map(num_leaves).sum(),
+    }
+}
+
+/// Concatenates `arrays`, that have the same data type.
+fn concatenate(arrays: &[&dyn Array]) -> Arc<dyn Array> {
+    let capacity = arrays.iter().map(|array| array.len()).sum();
+    let mut growable = make_growable(arrays, false, capacity);
+    for (i, array) in arrays.iter().enumerate() {
+        growable.extend(i, 0, array.len())
+    }
+    growable.as_arc()
+}
+
 /// An iterator adapter that converts an iterator over [`RecordBatch`] into an iterator
 /// of row groups.
 /// Use it to create an iterator consumable by the parquet's API.
@@ -14,45 +37,174 @@ pub struct RowGroupIterator<I: Iterator<Item = Result<RecordBatch>>> {
     iter: I,
     options: WriteOptions,
     parquet_schema: SchemaDescriptor,
+    columns: Vec<ColumnOptions>,
+    sizes: SizeOptions,
+    // the columns of batches read from `iter` but not yet written to a row group
+    pending: VecDeque<Vec<Arc<dyn Array>>>,
+    // the number of rows of `pending`
+    pending_rows: usize,
 }
 
 impl<I: Iterator<Item = Result<RecordBatch>>> RowGroupIterator<I> {
-    /// Creates a new [`RowGroupIterator`] from an iterator over [`RecordBatch`].
-    pub fn try_new(iter: I, schema: &Schema, options: WriteOptions) -> Result<Self> {
+    /// Creates a new [`RowGroupIterator`] from an iterator over [`RecordBatch`] that writes
+    /// each batch to a row group with a single page per column.
+    /// `encodings` is the [`Encoding`] of each field of `schema`; see [`super::array_to_page`]
+    /// for the encodings supported by each data type.
+    /// # Errors
+    /// This function errors if `encodings` and the fields of `schema` have different lengths.
+    pub fn try_new(
+        iter: I,
+        schema: &Schema,
+        options: WriteOptions,
+        encodings: Vec<Encoding>,
+    ) -> Result<Self> {
+        let columns = encodings.into_iter().map(ColumnOptions::from).collect();
+        Self::try_new_with_options(iter, schema, options, columns, SizeOptions::default())
+    }
+
+    /// Creates a new [`RowGroupIterator`] from an iterator over [`RecordBatch`] where
+    /// `columns` are the [`ColumnOptions`] of each field of `schema` and `sizes` sets the
+    /// number of rows of row groups and the size of pages.
+    /// # Errors
+    /// This function errors if `schema` has no fields, if `columns` and the fields of `schema`
+    /// have different lengths or if the number of rows of row groups is zero.
+    pub fn try_new_with_options(
+        iter: I,
+        schema: &Schema,
+        options: WriteOptions,
+        columns: Vec<ColumnOptions>,
+        sizes: SizeOptions,
+    ) -> Result<Self> {
+        if schema.fields().is_empty() {
+            return Err(ArrowError::InvalidArgumentError(
+                "A row group must have at least one column".to_string(),
+            ));
+        }
+        if columns.len() != schema.fields().len() {
+            return Err(ArrowError::InvalidArgumentError(
+                "The number of column options must equal the number of fields".to_string(),
+            ));
+        }
+        if sizes.row_group_rows == Some(0) {
+            return Err(ArrowError::InvalidArgumentError(
+                "The number of rows of a row group must be larger than zero".to_string(),
+            ));
+        }
         let parquet_schema = to_parquet_schema(schema)?;
 
         Ok(Self {
             iter,
             options,
             parquet_schema,
+            columns,
+            sizes,
+            pending: VecDeque::new(),
+            pending_rows: 0,
         })
     }
 
     pub fn parquet_schema(&self) -> &SchemaDescriptor {
         &self.parquet_schema
     }
+
+    /// Removes the first `rows` rows from the pending batches, returning them as columns.
+    fn take_pending(&mut self, rows: usize) -> Vec<Arc<dyn Array>> {
+        let mut taken = vec![];
+        let mut remaining = rows;
+        while remaining > 0 {
+            let columns = match self.pending.pop_front() {
+                Some(columns) => columns,
+                None => break,
+            };
+            let length = columns[0].len();
+            if length <= remaining {
+                remaining -= length;
+                taken.push(columns);
+            } else {
+                taken.push(
+                    columns
+                        .iter()
+                        .map(|array| array.slice(0, remaining).into())
+                        .collect(),
+                );
+                self.pending.push_front(
+                    columns
+                        .iter()
+                        .map(|array| array.slice(remaining, length - remaining).into())
+                        .collect(),
+                );
+                remaining = 0;
+            }
+        }
+        self.pending_rows -= rows - remaining;
+        if taken.len() == 1 {
+            return taken.pop().unwrap();
+        }
+        (0..taken[0].len())
+            .map(|i| {
+                let arrays = taken
+                    .iter()
+                    .map(|columns| columns[i].as_ref())
+                    .collect::<Vec<_>>();
+                concatenate(&arrays)
+            })
+            .collect()
+    }
+
+    fn row_group(&self, columns: &[Arc<dyn Array>]) -> Result<RowGroupIter<'static, ArrowError>> {
+        let mut descriptors = self.parquet_schema.columns().to_vec().into_iter();
+        let columns = columns
+            .iter()
+            .zip(self.parquet_schema.fields().iter())
+            .zip(self.columns.iter())
+            .map(|((array, type_), column)| {
+                let descriptors = descriptors.by_ref().take(num_leaves(type_)).collect();
+                array_to_pages(
+                    array.as_ref(),
+                    descriptors,
+                    column.write_options(self.options),
+                    column.encoding,
+                    self.sizes,
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DynIter::new(
+            columns
+                .into_iter()
+                .flatten()
+                .map(|pages| Ok(DynIter::new(pages.into_iter().map(Ok)))),
+        ))
+    }
 }
 
 impl<I: Iterator<Item = Result<RecordBatch>>> Iterator for RowGroupIterator<I> {
     type Item = Result<RowGroupIter<'static, ArrowError>>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let options = self.options;
-
-        self.iter.next().map(|batch| {
-            let columns = batch?.columns().to_vec();
-            Ok(DynIter::new(
-                columns
-                    .into_iter()
-                    .zip(self.parquet_schema.columns().to_vec().into_iter())
-                    .map(move |(array, type_)| {
-                        Ok(DynIter::new(std::iter::once(array_to_page(
-                            array.as_ref(),
-                            type_,
-                            options,
-                        ))))
-                    }),
-            ))
-        })
+        let rows = match self.sizes.row_group_rows {
+            Some(rows) => rows,
+            None => {
+                let batch = self.iter.next()?;
+                return Some(batch.and_then(|batch| self.row_group(batch.columns())));
+            }
+        };
+
+        while self.pending_rows < rows {
+            match self.iter.next() {
+                Some(Ok(batch)) => {
+                    if batch.num_rows() > 0 {
+                        self.pending_rows += batch.num_rows();
+                        self.pending.push_back(batch.columns().to_vec())
+                    }
+                }
+                Some(Err(error)) => return Some(Err(error)),
+                None => break,
+            }
+        }
+        if self.pending.is_empty() {
+            return None;
+        }
+        let columns = self.take_pending(rows);
+        Some(self.row_group(&columns))
     }
 }
@@ -335,6 +335,30 @@ pub fn to_parquet_type(field: &Field) -> Result<ParquetType> {
                 None,
             )?)
         }
+        DataType::Map(f, _) => {
+            let fields = match f.data_type() {
+                DataType::Struct(fields) => fields
+                    .iter()
+                    .map(to_parquet_type)
+                    .collect::<Result<Vec<_>>>()?,
+                _ => unreachable!(),
+            };
+            Ok(ParquetType::try_from_group(
+                name,
+                repetition,
+                None,
+                Some(LogicalType::MAP(Default::default())),
+                vec![ParquetType::try_from_group(
+                    "key_value".to_string(),
+                    FieldRepetitionType::Repeated,
+                    None,
+                    None,
+                    fields,
+                    None,
+                )?],
+                None,
+            )?)
+        }
         /*
         DataType::FixedSizeBinary(length) => {
             Type::primitive_type_builder(name, PhysicalType::FIXED_LEN_BYTE_ARRAY)
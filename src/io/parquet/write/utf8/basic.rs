@@ -1,15 +1,15 @@
 use parquet2::{
+    encoding::Encoding,
     metadata::ColumnDescriptor,
     read::CompressedPage,
     statistics::{serialize_statistics, BinaryStatistics, ParquetStatistics, Statistics},
     write::WriteOptions,
 };
 
-use super::super::binary::ord_binary;
-use super::super::utils;
+use super::super::{encodings, utils};
 use crate::{
     array::{Array, Offset, Utf8Array},
-    error::Result,
+    error::{ArrowError, Result},
     io::parquet::read::is_type_nullable,
 };
 
@@ -37,10 +37,22 @@ pub(super) fn encode_plain<O: Offset>(
     }
 }
 
+/// Returns the values of `array`, without the nulls when `is_optional`.
+fn to_values<O: Offset>(array: &Utf8Array<O>, is_optional: bool) -> Vec<&[u8]> {
+    if is_optional {
+        array.iter().flatten().map(|x| x.as_bytes()).collect()
+    } else {
+        array.values_iter().map(|x| x.as_bytes()).collect()
+    }
+}
+
+/// Writes `array` to a page whose values are encoded with `encoding`, one of `PLAIN`,
+/// `DELTA_LENGTH_BYTE_ARRAY` and `DELTA_BYTE_ARRAY`.
 pub fn array_to_page<O: Offset>(
     array: &Utf8Array<O>,
     options: WriteOptions,
     descriptor: ColumnDescriptor,
+    encoding: Encoding,
 ) -> Result<CompressedPage> {
     let validity = array.validity();
     let is_optional = is_type_nullable(descriptor.type_());
@@ -56,7 +68,23 @@ pub fn array_to_page<O: Offset>(
 
     let definition_levels_byte_length = buffer.len();
 
-    encode_plain(array, is_optional, &mut buffer);
+    match encoding {
+        Encoding::Plain => encode_plain(array, is_optional, &mut buffer),
+        Encoding::DeltaLengthByteArray => {
+            let values = to_values(array, is_optional);
+            encodings::delta_length_byte_array(&values, &mut buffer)
+        }
+        Encoding::DeltaByteArray => {
+            let values = to_values(array, is_optional);
+            encodings::delta_byte_array(&values, &mut buffer)
+        }
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Writing utf8 parquet pages with encoding {:?}",
+                other
+            )))
+        }
+    }
 
     let uncompressed_page_size = buffer.len();
 
@@ -68,7 +96,7 @@ pub fn array_to_page<O: Offset>(
         None
     };
 
-    utils::build_plain_page(
+    utils::build_page(
         buffer,
         array.len(),
         array.null_count(),
@@ -78,10 +106,12 @@ pub fn array_to_page<O: Offset>(
         statistics,
         descriptor,
         options,
+        encoding,
+        None,
     )
 }
 
-pub(super) fn build_statistics<O: Offset>(
+pub fn build_statistics<O: Offset>(
     array: &Utf8Array<O>,
     descriptor: ColumnDescriptor,
 ) -> ParquetStatistics {
@@ -93,13 +123,13 @@ pub(super) fn build_statistics<O: Offset>(
             .iter()
             .flatten()
             .map(|x| x.as_bytes())
-            .max_by(|x, y| ord_binary(x, y))
+            .max()
             .map(|x| x.to_vec()),
         min_value: array
             .iter()
             .flatten()
             .map(|x| x.as_bytes())
-            .min_by(|x, y| ord_binary(x, y))
+            .min()
             .map(|x| x.to_vec()),
     } as &dyn Statistics;
     serialize_statistics(statistics)
@@ -1,10 +1,12 @@
+use std::sync::Arc;
+
 use crate::bitmap::Bitmap;
 
 use parquet2::{
     compression::create_codec,
     encoding::{hybrid_rle::encode_bool, Encoding},
     metadata::ColumnDescriptor,
-    read::{CompressedPage, PageHeader},
+    read::{CompressedPage, DictPage, PageHeader},
     schema::{CompressionCodec, DataPageHeader, DataPageHeaderV2},
     statistics::ParquetStatistics,
     write::WriteOptions,
@@ -69,12 +71,43 @@ pub fn build_plain_page(
     statistics: Option<ParquetStatistics>,
     descriptor: ColumnDescriptor,
     options: WriteOptions,
+) -> Result<CompressedPage> {
+    build_page(
+        buffer,
+        len,
+        null_count,
+        uncompressed_page_size,
+        repetition_levels_byte_length,
+        definition_levels_byte_length,
+        statistics,
+        descriptor,
+        options,
+        Encoding::Plain,
+        None,
+    )
+}
+
+/// Builds a data page whose values are encoded with `encoding`. `dictionary_page` must be set
+/// when `encoding` is dictionary-based.
+#[allow(clippy::too_many_arguments)]
+pub fn build_page(
+    buffer: Vec<u8>,
+    len: usize,
+    null_count: usize,
+    uncompressed_page_size: usize,
+    repetition_levels_byte_length: usize,
+    definition_levels_byte_length: usize,
+    statistics: Option<ParquetStatistics>,
+    descriptor: ColumnDescriptor,
+    options: WriteOptions,
+    encoding: Encoding,
+    dictionary_page: Option<Arc<dyn DictPage>>,
 ) -> Result<CompressedPage> {
     match options.version {
         Version::V1 => {
             let header = PageHeader::V1(DataPageHeader {
                 num_values: len as i32,
-                encoding: Encoding::Plain,
+                encoding,
                 definition_level_encoding: Encoding::Rle,
                 repetition_level_encoding: Encoding::Rle,
                 statistics,
@@ -85,14 +118,14 @@ pub fn build_plain_page(
                 buffer,
                 options.compression,
                 uncompressed_page_size,
-                None,
+                dictionary_page,
                 descriptor,
             ))
         }
         Version::V2 => {
             let header = PageHeader::V2(DataPageHeaderV2 {
                 num_values: len as i32,
-                encoding: Encoding::Plain,
+                encoding,
                 num_nulls: null_count as i32,
                 num_rows: len as i32,
                 definition_levels_byte_length: definition_levels_byte_length as i32,
@@ -106,7 +139,7 @@ pub fn build_plain_page(
                 buffer,
                 options.compression,
                 uncompressed_page_size,
-                None,
+                dictionary_page,
                 descriptor,
             ))
         }
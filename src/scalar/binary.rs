@@ -0,0 +1,58 @@
+use crate::{array::*, datatypes::DataType};
+
+use super::Scalar;
+
+/// The implementation of [`Scalar`] for binary, semantically equivalent to [`Option<&[u8]>`].
+#[derive(Debug, Clone)]
+pub struct BinaryScalar<O: Offset> {
+    value: Vec<u8>,
+    is_valid: bool,
+    phantom: std::marker::PhantomData<O>,
+}
+
+impl<O: Offset> PartialEq for BinaryScalar<O> {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_valid == other.is_valid && ((!self.is_valid) | (self.value == other.value))
+    }
+}
+
+impl<O: Offset> BinaryScalar<O> {
+    /// Returns a new [`BinaryScalar`]
+    #[inline]
+    pub fn new<P: AsRef<[u8]>>(v: Option<P>) -> Self {
+        let is_valid = v.is_some();
+        let value = v.map(|x| x.as_ref().to_vec()).unwrap_or_default();
+        Self {
+            value,
+            is_valid,
+            phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns the value, which is only meaningful when [`Scalar::is_valid`] is `true`.
+    #[inline]
+    pub fn value(&self) -> &[u8] {
+        self.value.as_ref()
+    }
+}
+
+impl<O: Offset> Scalar for BinaryScalar<O> {
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn is_valid(&self) -> bool {
+        self.is_valid
+    }
+
+    #[inline]
+    fn data_type(&self) -> &DataType {
+        if O::is_large() {
+            &DataType::LargeBinary
+        } else {
+            &DataType::Binary
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use super::*;
+
+impl PartialEq for dyn Scalar + '_ {
+    fn eq(&self, other: &Self) -> bool {
+        equal(self, other)
+    }
+}
+
+impl PartialEq<dyn Scalar> for Arc<dyn Scalar + '_> {
+    fn eq(&self, other: &dyn Scalar) -> bool {
+        equal(&**self, other)
+    }
+}
+
+impl PartialEq<dyn Scalar> for Box<dyn Scalar + '_> {
+    fn eq(&self, other: &dyn Scalar) -> bool {
+        equal(&**self, other)
+    }
+}
+
+macro_rules! dyn_eq {
+    ($ty:ty, $lhs:expr, $rhs:expr) => {{
+        let lhs = $lhs.as_any().downcast_ref::<$ty>();
+        let rhs = $rhs.as_any().downcast_ref::<$ty>();
+        match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => lhs == rhs,
+            _ => false,
+        }
+    }};
+}
+
+/// Scalars are equal when they have the same logical type and value. Scalars of types without
+/// a [`Scalar`] implementation in this crate are never equal.
+fn equal(lhs: &dyn Scalar, rhs: &dyn Scalar) -> bool {
+    if lhs.data_type() != rhs.data_type() {
+        return false;
+    }
+
+    use DataType::*;
+    match lhs.data_type() {
+        Null => dyn_eq!(NullScalar, lhs, rhs),
+        Boolean => dyn_eq!(BooleanScalar, lhs, rhs),
+        Int8 => dyn_eq!(PrimitiveScalar<i8>, lhs, rhs),
+        Int16 => dyn_eq!(PrimitiveScalar<i16>, lhs, rhs),
+        Int32 | Date32 | Time32(_) | Interval(IntervalUnit::YearMonth) => {
+            dyn_eq!(PrimitiveScalar<i32>, lhs, rhs)
+        }
+        Int64 | Date64 | Time64(_) | Timestamp(_, _) | Duration(_) => {
+            dyn_eq!(PrimitiveScalar<i64>, lhs, rhs)
+        }
+        Interval(IntervalUnit::DayTime) => dyn_eq!(PrimitiveScalar<days_ms>, lhs, rhs),
+        UInt8 => dyn_eq!(PrimitiveScalar<u8>, lhs, rhs),
+        UInt16 => dyn_eq!(PrimitiveScalar<u16>, lhs, rhs),
+        UInt32 => dyn_eq!(PrimitiveScalar<u32>, lhs, rhs),
+        UInt64 => dyn_eq!(PrimitiveScalar<u64>, lhs, rhs),
+        Decimal(_, _) => dyn_eq!(PrimitiveScalar<i128>, lhs, rhs),
+        Float16 => dyn_eq!(PrimitiveScalar<f16>, lhs, rhs),
+        Float32 => dyn_eq!(PrimitiveScalar<f32>, lhs, rhs),
+        Float64 => dyn_eq!(PrimitiveScalar<f64>, lhs, rhs),
+        Utf8 => dyn_eq!(Utf8Scalar<i32>, lhs, rhs),
+        LargeUtf8 => dyn_eq!(Utf8Scalar<i64>, lhs, rhs),
+        Binary => dyn_eq!(BinaryScalar<i32>, lhs, rhs),
+        LargeBinary => dyn_eq!(BinaryScalar<i64>, lhs, rhs),
+        List(_) => dyn_eq!(ListScalar<i32>, lhs, rhs),
+        LargeList(_) => dyn_eq!(ListScalar<i64>, lhs, rhs),
+        Struct(_) => dyn_eq!(StructScalar, lhs, rhs),
+        FixedSizeBinary(_)
+        | FixedSizeList(_, _)
+        | Union(_, _, _)
+        | Map(_, _)
+        | Dictionary(_, _) => false,
+    }
+}
@@ -0,0 +1,275 @@
+//! Declares the [`Scalar`] API, an optional, trait object representing
+//! the concept of an item in an [`Array`] of a given [`DataType`].
+//!
+//! Scalars are used by dynamically typed kernels (e.g. [`crate::compute::comparison::compare_scalar`])
+//! to operate an array with a single value whose type is only known at runtime.
+use std::any::Any;
+
+use crate::{
+    array::*,
+    datatypes::*,
+    error::{ArrowError, Result},
+    types::{days_ms, f16},
+};
+
+mod equal;
+mod primitive;
+pub use primitive::*;
+mod utf8;
+pub use utf8::*;
+mod binary;
+pub use binary::*;
+mod boolean;
+pub use boolean::*;
+mod list;
+pub use list::*;
+mod null;
+pub use null::*;
+mod struct_;
+pub use struct_::*;
+
+/// Trait object declaring an optional value with a logical type.
+pub trait Scalar: std::fmt::Debug + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+
+    /// whether the value is valid (not null).
+    fn is_valid(&self) -> bool;
+
+    /// The [`DataType`] of the scalar.
+    fn data_type(&self) -> &DataType;
+}
+
+macro_rules! dyn_new_primitive {
+    ($array:expr, $index:expr, $type:ty) => {{
+        let array = $array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<$type>>()
+            .unwrap();
+        let value = if array.is_valid($index) {
+            Some(array.value($index))
+        } else {
+            None
+        };
+        Box::new(PrimitiveScalar::new(array.data_type().clone(), value))
+    }};
+}
+
+macro_rules! dyn_new_utf8 {
+    ($array:expr, $index:expr, $type:ty) => {{
+        let array = $array.as_any().downcast_ref::<Utf8Array<$type>>().unwrap();
+        let value = if array.is_valid($index) {
+            Some(array.value($index))
+        } else {
+            None
+        };
+        Box::new(Utf8Scalar::<$type>::new(value))
+    }};
+}
+
+macro_rules! dyn_new_binary {
+    ($array:expr, $index:expr, $type:ty) => {{
+        let array = $array
+            .as_any()
+            .downcast_ref::<BinaryArray<$type>>()
+            .unwrap();
+        let value = if array.is_valid($index) {
+            Some(array.value($index))
+        } else {
+            None
+        };
+        Box::new(BinaryScalar::<$type>::new(value))
+    }};
+}
+
+macro_rules! dyn_new_list {
+    ($array:expr, $index:expr, $type:ty) => {{
+        let array = $array.as_any().downcast_ref::<ListArray<$type>>().unwrap();
+        let value = if array.is_valid($index) {
+            Some(array.value($index).into())
+        } else {
+            None
+        };
+        Box::new(ListScalar::<$type>::new(array.data_type().clone(), value))
+    }};
+}
+
+/// Returns the [`Scalar`] in slot `index` of `array`.
+/// # Errors
+/// This function errors if the array's [`DataType`] is not supported.
+/// # Panics
+/// This function panics if `index >= array.len()`.
+pub fn new_scalar(array: &dyn Array, index: usize) -> Result<Box<dyn Scalar>> {
+    use DataType::*;
+    let scalar: Box<dyn Scalar> = match array.data_type() {
+        Null => Box::new(NullScalar::new()),
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            let value = if array.is_valid(index) {
+                Some(array.value(index))
+            } else {
+                None
+            };
+            Box::new(BooleanScalar::new(value))
+        }
+        Int8 => dyn_new_primitive!(array, index, i8),
+        Int16 => dyn_new_primitive!(array, index, i16),
+        Int32 | Date32 | Time32(_) | Interval(IntervalUnit::YearMonth) => {
+            dyn_new_primitive!(array, index, i32)
+        }
+        Int64 | Date64 | Time64(_) | Duration(_) | Timestamp(_, _) => {
+            dyn_new_primitive!(array, index, i64)
+        }
+        Interval(IntervalUnit::DayTime) => dyn_new_primitive!(array, index, days_ms),
+        UInt8 => dyn_new_primitive!(array, index, u8),
+        UInt16 => dyn_new_primitive!(array, index, u16),
+        UInt32 => dyn_new_primitive!(array, index, u32),
+        UInt64 => dyn_new_primitive!(array, index, u64),
+        Decimal(_, _) => dyn_new_primitive!(array, index, i128),
+        Float16 => dyn_new_primitive!(array, index, f16),
+        Float32 => dyn_new_primitive!(array, index, f32),
+        Float64 => dyn_new_primitive!(array, index, f64),
+        Utf8 => dyn_new_utf8!(array, index, i32),
+        LargeUtf8 => dyn_new_utf8!(array, index, i64),
+        Binary => dyn_new_binary!(array, index, i32),
+        LargeBinary => dyn_new_binary!(array, index, i64),
+        List(_) => dyn_new_list!(array, index, i32),
+        LargeList(_) => dyn_new_list!(array, index, i64),
+        Struct(_) => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            if array.is_valid(index) {
+                let values = array
+                    .values()
+                    .iter()
+                    .map(|x| new_scalar(x.as_ref(), index).map(|x| x.into()))
+                    .collect::<Result<_>>()?;
+                Box::new(StructScalar::new(array.data_type().clone(), Some(values)))
+            } else {
+                Box::new(StructScalar::new(array.data_type().clone(), None))
+            }
+        }
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "A scalar of type {:?} is not yet supported",
+                other
+            )))
+        }
+    };
+    Ok(scalar)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[test]
+    fn primitive() -> Result<()> {
+        let array = Int32Array::from(&[Some(1), None, Some(2)]);
+        let expected = PrimitiveScalar::new(DataType::Int32, Some(1i32));
+        assert_eq!(new_scalar(&array, 0)?.as_ref(), &expected as &dyn Scalar);
+
+        let scalar = new_scalar(&array, 1)?;
+        assert!(!scalar.is_valid());
+        assert_eq!(
+            scalar.as_ref(),
+            &PrimitiveScalar::<i32>::new(DataType::Int32, None) as &dyn Scalar
+        );
+        assert!(scalar.as_ref() != &expected as &dyn Scalar);
+        Ok(())
+    }
+
+    #[test]
+    fn utf8_and_binary() -> Result<()> {
+        let array = Utf8Array::<i64>::from([Some("a"), None]);
+        let scalar = new_scalar(&array, 0)?;
+        assert_eq!(scalar.data_type(), &DataType::LargeUtf8);
+        assert_eq!(
+            scalar.as_ref(),
+            &Utf8Scalar::<i64>::new(Some("a")) as &dyn Scalar
+        );
+
+        let array = BinaryArray::<i32>::from([Some(b"a"), None]);
+        let scalar = new_scalar(&array, 1)?;
+        assert_eq!(
+            scalar.as_ref(),
+            &BinaryScalar::<i32>::new(None::<&[u8]>) as &dyn Scalar
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn nested() -> Result<()> {
+        let values = Arc::new(Int32Array::from_slice([1, 2, 3])) as Arc<dyn Array>;
+        let fields = vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Boolean, true),
+        ];
+        let array = StructArray::from_data(
+            fields.clone(),
+            vec![
+                values.clone(),
+                Arc::new(BooleanArray::from(&[Some(true), None, Some(false)])),
+            ],
+            None,
+        );
+        let expected = StructScalar::new(
+            DataType::Struct(fields),
+            Some(vec![
+                Arc::new(PrimitiveScalar::new(DataType::Int32, Some(2i32))) as Arc<dyn Scalar>,
+                Arc::new(BooleanScalar::new(None)),
+            ]),
+        );
+        assert_eq!(new_scalar(&array, 1)?.as_ref(), &expected as &dyn Scalar);
+
+        let data_type = ListArray::<i32>::default_datatype(DataType::Int32);
+        let scalar = ListScalar::<i32>::new(data_type.clone(), Some(values.clone()));
+        assert_eq!(scalar.values().as_ref(), values.as_ref());
+        assert!(ListScalar::<i32>::new(data_type, None).values().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn not_yet_implemented() -> Result<()> {
+        let array = FixedSizeBinaryArray::from_data(
+            DataType::FixedSizeBinary(1),
+            vec![1u8, 2].into(),
+            None,
+        );
+        assert!(new_scalar(&array, 0).is_err());
+
+        #[derive(Debug)]
+        struct Other(DataType);
+
+        impl Scalar for Other {
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn is_valid(&self) -> bool {
+                true
+            }
+
+            fn data_type(&self) -> &DataType {
+                &self.0
+            }
+        }
+
+        // scalars without an implementation in this crate are never equal
+        let lhs = Other(DataType::FixedSizeBinary(1));
+        let rhs = Other(DataType::FixedSizeBinary(1));
+        assert!(&lhs as &dyn Scalar != &rhs as &dyn Scalar);
+
+        // neither are scalars whose physical type does not match their logical type
+        let lhs = Other(DataType::Int32);
+        let rhs = PrimitiveScalar::new(DataType::Int32, Some(1i32));
+        assert!(&lhs as &dyn Scalar != &rhs as &dyn Scalar);
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic]
+    fn primitive_wrong_type() {
+        PrimitiveScalar::<i64>::new(DataType::Int32, None);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{datatypes::DataType, error::ArrowError, types::NativeType};
+
+use super::Scalar;
+
+/// The implementation of [`Scalar`] for primitive, semantically equivalent to [`Option<T>`]
+/// with [`DataType`].
+#[derive(Debug, Clone)]
+pub struct PrimitiveScalar<T: NativeType> {
+    // Not Option<T> because this offers a stabler pointer offset on the struct
+    value: T,
+    is_valid: bool,
+    data_type: DataType,
+}
+
+impl<T: NativeType> PartialEq for PrimitiveScalar<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data_type == other.data_type
+            && self.is_valid == other.is_valid
+            && ((!self.is_valid) | (self.value == other.value))
+    }
+}
+
+impl<T: NativeType> PrimitiveScalar<T> {
+    /// Returns a new [`PrimitiveScalar`] of `data_type`.
+    /// # Panics
+    /// This function panics iff `data_type` is not supported by the physical type `T`.
+    #[inline]
+    pub fn new(data_type: DataType, v: Option<T>) -> Self {
+        if !T::is_valid(&data_type) {
+            Err(ArrowError::InvalidArgumentError(format!(
+                "Type {} does not support logical type {}",
+                std::any::type_name::<T>(),
+                data_type
+            )))
+            .unwrap()
+        }
+        let is_valid = v.is_some();
+        Self {
+            value: v.unwrap_or_default(),
+            is_valid,
+            data_type,
+        }
+    }
+
+    /// Returns the value, which is only meaningful when [`Scalar::is_valid`] is `true`.
+    #[inline]
+    pub fn value(&self) -> T {
+        self.value
+    }
+}
+
+impl<T: NativeType> Scalar for PrimitiveScalar<T> {
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn is_valid(&self) -> bool {
+        self.is_valid
+    }
+
+    #[inline]
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+}
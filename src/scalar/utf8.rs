@@ -0,0 +1,58 @@
+use crate::{array::*, datatypes::DataType};
+
+use super::Scalar;
+
+/// The implementation of [`Scalar`] for utf8, semantically equivalent to [`Option<&str>`].
+#[derive(Debug, Clone)]
+pub struct Utf8Scalar<O: Offset> {
+    value: String,
+    is_valid: bool,
+    phantom: std::marker::PhantomData<O>,
+}
+
+impl<O: Offset> PartialEq for Utf8Scalar<O> {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_valid == other.is_valid && ((!self.is_valid) | (self.value == other.value))
+    }
+}
+
+impl<O: Offset> Utf8Scalar<O> {
+    /// Returns a new [`Utf8Scalar`]
+    #[inline]
+    pub fn new<P: AsRef<str>>(v: Option<P>) -> Self {
+        let is_valid = v.is_some();
+        let value = v.map(|x| x.as_ref().to_string()).unwrap_or_default();
+        Self {
+            value,
+            is_valid,
+            phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns the value, which is only meaningful when [`Scalar::is_valid`] is `true`.
+    #[inline]
+    pub fn value(&self) -> &str {
+        self.value.as_ref()
+    }
+}
+
+impl<O: Offset> Scalar for Utf8Scalar<O> {
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn is_valid(&self) -> bool {
+        self.is_valid
+    }
+
+    #[inline]
+    fn data_type(&self) -> &DataType {
+        if O::is_large() {
+            &DataType::LargeUtf8
+        } else {
+            &DataType::Utf8
+        }
+    }
+}
@@ -118,6 +118,7 @@ natural_type!(i32, DataType::Int32);
 natural_type!(i64, DataType::Int64);
 natural_type!(f32, DataType::Float32);
 natural_type!(f64, DataType::Float64);
+natural_type!(f16, DataType::Float16);
 natural_type!(days_ms, DataType::Interval(IntervalUnit::DayTime));
 natural_type!(i128, DataType::Decimal(32, 32)); // users should set the decimal when creating an array
 
@@ -244,3 +245,189 @@ impl PartialOrd for days_ms {
         Some(self.cmp(other))
     }
 }
+
+/// The in-memory representation of arrow's half-precision floating point type, "Float16",
+/// following IEEE 754 binary16. Arithmetics are not supported; use [`f16::to_f32`] and
+/// [`f16::from_f32`] to convert from and to single precision.
+#[derive(Copy, Clone, Default)]
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+pub struct f16(u16);
+
+unsafe impl NativeType for f16 {
+    type Bytes = [u8; 2];
+    #[inline]
+    fn to_le_bytes(&self) -> Self::Bytes {
+        self.0.to_le_bytes()
+    }
+
+    #[inline]
+    fn to_be_bytes(&self) -> Self::Bytes {
+        self.0.to_be_bytes()
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        Self(u16::from_be_bytes(bytes))
+    }
+}
+
+create_relation!(f16, &DataType::Float16);
+
+impl f16 {
+    /// Creates a [`f16`] from its bit representation.
+    #[inline]
+    pub const fn from_bits(bits: u16) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the bit representation of this [`f16`].
+    #[inline]
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Returns whether this value is NaN.
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.0 & 0x7C00 == 0x7C00 && self.0 & 0x03FF != 0
+    }
+
+    /// Converts a `f32` to the nearest [`f16`], rounding ties to even. Values too large to be
+    /// represented become infinite.
+    #[inline]
+    pub fn from_f32(value: f32) -> Self {
+        // every f32 is exactly representable as f64
+        Self::from_f64(value as f64)
+    }
+
+    /// Converts a `f64` to the nearest [`f16`], rounding ties to even. Values too large to be
+    /// represented become infinite.
+    pub fn from_f64(value: f64) -> Self {
+        let bits = value.to_bits();
+        let sign = ((bits >> 48) & 0x8000) as u16;
+        let exponent = ((bits >> 52) & 0x7FF) as i64;
+        let mantissa = bits & 0x000F_FFFF_FFFF_FFFF;
+
+        if exponent == 0x7FF {
+            // infinity or NaN, in which case at least one bit of the mantissa is kept
+            let nan = if mantissa == 0 { 0 } else { 0x0200 };
+            return Self(sign | 0x7C00 | nan | (mantissa >> 42) as u16);
+        }
+
+        let half_exponent = exponent - 1023 + 15;
+        if half_exponent >= 0x1F {
+            return Self(sign | 0x7C00);
+        }
+
+        // the mantissa with its implicit bit and the number of bits dropped from it
+        let (mantissa, shift, half_exponent) = if half_exponent <= 0 {
+            // subnormal or zero
+            (mantissa | (1 << 52), 43 - half_exponent, 0)
+        } else {
+            (mantissa, 42, half_exponent as u64)
+        };
+        if shift > 53 {
+            return Self(sign);
+        }
+        let half = (half_exponent << 10) | (mantissa >> shift);
+
+        // round to nearest, ties to even. A carry into the exponent is the correct result.
+        let round_bit = 1u64 << (shift - 1);
+        let half = if mantissa & round_bit != 0 && mantissa & (3 * round_bit - 1) != 0 {
+            half + 1
+        } else {
+            half
+        };
+        Self(sign | half as u16)
+    }
+
+    /// Converts this value to a `f32`, which is exact.
+    pub fn to_f32(self) -> f32 {
+        let sign = ((self.0 & 0x8000) as u32) << 16;
+        let exponent = ((self.0 >> 10) & 0x1F) as u32;
+        let mantissa = (self.0 & 0x03FF) as u32;
+
+        match exponent {
+            0 => {
+                // zero or subnormal: mantissa * 2^-24
+                let value = mantissa as f32 * f32::from_bits(0x3380_0000);
+                f32::from_bits(sign | value.to_bits())
+            }
+            0x1F => f32::from_bits(sign | 0x7F80_0000 | (mantissa << 13)),
+            _ => f32::from_bits(sign | ((exponent + 127 - 15) << 23) | (mantissa << 13)),
+        }
+    }
+
+    /// Converts this value to a `f64`, which is exact.
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.to_f32() as f64
+    }
+}
+
+impl PartialEq for f16 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.to_f32() == other.to_f32()
+    }
+}
+
+impl PartialOrd for f16 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.to_f32().partial_cmp(&other.to_f32())
+    }
+}
+
+impl std::fmt::Debug for f16 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.to_f32())
+    }
+}
+
+impl std::fmt::Display for f16 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_f32())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn f16_from_f32() {
+        assert_eq!(f16::from_f32(1.0).to_bits(), 0x3C00);
+        assert_eq!(f16::from_f32(-2.0).to_bits(), 0xC000);
+        assert_eq!(f16::from_f32(65504.0).to_bits(), 0x7BFF);
+        // overflow
+        assert_eq!(f16::from_f32(65520.0).to_bits(), 0x7C00);
+        assert_eq!(f16::from_f32(f32::NEG_INFINITY).to_bits(), 0xFC00);
+        assert!(f16::from_f32(f32::NAN).is_nan());
+        // smallest subnormal and underflow
+        assert_eq!(f16::from_f32(5.960_464_5e-8).to_bits(), 0x0001);
+        assert_eq!(f16::from_f32(1.0e-8).to_bits(), 0x0000);
+        assert_eq!(f16::from_f32(-0.0).to_bits(), 0x8000);
+        // ties to even: 1 + 2^-11 is halfway between 1 and 1 + 2^-10
+        assert_eq!(f16::from_f32(1.000_488_3).to_bits(), 0x3C00);
+        assert_eq!(f16::from_f32(1.001_464_8).to_bits(), 0x3C02);
+    }
+
+    #[test]
+    fn f16_to_f32() {
+        assert_eq!(f16::from_bits(0x3C00).to_f32(), 1.0);
+        assert_eq!(f16::from_bits(0xC000).to_f32(), -2.0);
+        assert_eq!(f16::from_bits(0x7BFF).to_f32(), 65504.0);
+        assert_eq!(f16::from_bits(0x0001).to_f32(), 5.960_464_5e-8);
+        assert_eq!(f16::from_bits(0x8001).to_f32(), -5.960_464_5e-8);
+        assert_eq!(f16::from_bits(0x7C00).to_f32(), f32::INFINITY);
+        assert!(f16::from_bits(0x7E00).to_f32().is_nan());
+
+        // every finite value round trips
+        (0..=u16::MAX)
+            .map(f16::from_bits)
+            .filter(|x| !x.is_nan())
+            .for_each(|x| assert_eq!(f16::from_f32(x.to_f32()).to_bits(), x.to_bits()));
+    }
+}
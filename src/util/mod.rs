@@ -39,6 +39,86 @@ pub fn lexical_to_string<N: lexical_core::ToLexical>(n: N) -> String {
     unsafe { String::from_utf8_unchecked(lexical_to_bytes(n)) }
 }
 
+/// Converts a decimal stored as an `i128` with `scale` digits after the decimal point to a
+/// `String`, e.g. `-105` with a scale of 2 to `"-1.05"`.
+pub fn decimal_to_string(value: i128, scale: usize) -> String {
+    let sign = if value < 0 { "-" } else { "" };
+    let digits = value.unsigned_abs().to_string();
+    if scale == 0 {
+        return format!("{}{}", sign, digits);
+    }
+    // pad with zeros so that there is at least one digit before the decimal point
+    let digits = format!("{:0>width$}", digits, width = scale + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - scale);
+    format!("{}{}.{}", sign, integer, fraction)
+}
+
+/// Parses a decimal such as `"-1.05"` to an `i128` with `scale` digits after the decimal
+/// point, truncating any further digit.
+/// Returns `None` if `value` is not a decimal or if it does not fit in `precision` digits.
+pub fn parse_decimal(value: &[u8], precision: usize, scale: usize) -> Option<i128> {
+    let (negative, value) = match value.first()? {
+        b'-' => (true, &value[1..]),
+        b'+' => (false, &value[1..]),
+        _ => (false, value),
+    };
+    let mut parts = value.splitn(2, |x| *x == b'.');
+    let integer = parts.next()?;
+    let fraction = parts.next().unwrap_or(&[]);
+    if (integer.is_empty() && fraction.is_empty())
+        || !integer.iter().chain(fraction).all(|x| x.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let fraction = fraction.iter().chain(std::iter::repeat(&b'0')).take(scale);
+    let mut result = 0i128;
+    for digit in integer.iter().chain(fraction) {
+        result = result
+            .checked_mul(10)?
+            .checked_add((digit - b'0') as i128)?;
+    }
+    let max = 10i128
+        .checked_pow(precision as u32)
+        .map(|x| x - 1)
+        .unwrap_or(i128::MAX);
+    if result > max {
+        return None;
+    }
+    Some(if negative { -result } else { result })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decimal_string() {
+        assert_eq!(decimal_to_string(12345, 2), "123.45");
+        assert_eq!(decimal_to_string(-105, 2), "-1.05");
+        assert_eq!(decimal_to_string(5, 3), "0.005");
+        assert_eq!(decimal_to_string(-5, 0), "-5");
+        assert_eq!(
+            decimal_to_string(i128::MIN, 2),
+            "-1701411834604692317316873037158841057.28"
+        );
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(parse_decimal(b"123.45", 5, 2), Some(12345));
+        assert_eq!(parse_decimal(b"-1.05", 5, 2), Some(-105));
+        assert_eq!(parse_decimal(b"+1", 5, 2), Some(100));
+        assert_eq!(parse_decimal(b".5", 5, 2), Some(50));
+        assert_eq!(parse_decimal(b"1.999", 5, 2), Some(199));
+        // too many digits for the precision
+        assert_eq!(parse_decimal(b"1000.00", 5, 2), None);
+        assert_eq!(parse_decimal(b"1.0e3", 5, 2), None);
+        assert_eq!(parse_decimal(b"-", 5, 2), None);
+        assert_eq!(parse_decimal(b"", 5, 2), None);
+    }
+}
+
 #[cfg(test)]
 pub mod test_util;
 